@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+use src_domain::services::baseline::BaselineMethod;
+
+#[derive(Deserialize, Serialize)]
+pub struct ColumnBaselineCorrectCommand {
+    pub(super) y_column_id: String,
+    // 省略した場合は行番号を x とする
+    #[serde(default)]
+    pub(super) x_column_id: Option<String>,
+    pub(super) method: BaselineMethodInCommand,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum BaselineMethodInCommand {
+    Polynomial {
+        degree: usize,
+        anchors: Vec<AnchorRegionInCommand>,
+    },
+    AsymmetricLeastSquares {
+        smoothness: f64,
+        asymmetry: f64,
+        iterations: usize,
+    },
+    RollingBall {
+        radius: f64,
+    },
+}
+
+// 多項式を当てはめる x の範囲 (両端を含む)
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+pub struct AnchorRegionInCommand {
+    pub(super) start: f64,
+    pub(super) end: f64,
+}
+
+impl BaselineMethodInCommand {
+    pub(super) fn to_baseline_method(&self) -> BaselineMethod {
+        match self {
+            BaselineMethodInCommand::Polynomial { degree, anchors } => BaselineMethod::Polynomial {
+                degree: *degree,
+                anchors: anchors
+                    .iter()
+                    .map(|anchor| (anchor.start, anchor.end))
+                    .collect(),
+            },
+            BaselineMethodInCommand::AsymmetricLeastSquares {
+                smoothness,
+                asymmetry,
+                iterations,
+            } => BaselineMethod::AsymmetricLeastSquares {
+                smoothness: *smoothness,
+                asymmetry: *asymmetry,
+                iterations: *iterations,
+            },
+            BaselineMethodInCommand::RollingBall { radius } => {
+                BaselineMethod::RollingBall { radius: *radius }
+            }
+        }
+    }
+}
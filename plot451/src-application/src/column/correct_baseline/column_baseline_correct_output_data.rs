@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+
+use src_domain::{
+    models::column::{column_id::ColumnId, column_with_cells::ColumnWithCells},
+    shared::value_object::ValueObject,
+};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct ColumnBaselineCorrectOutputData {
+    pub(super) source_column_id: String,
+    pub(super) baseline: ColumnInOutputData,
+    // 元の値からベースラインを差し引いた値
+    pub(super) corrected: ColumnInOutputData,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub(super) struct ColumnInOutputData {
+    pub(super) column_id: String,
+    pub(super) column_name: String,
+    pub(super) cells: Vec<ColumnCellInOutputData>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub(super) struct ColumnCellInOutputData {
+    pub(super) cell_id: String,
+    pub(super) cell_value: Option<f64>,
+}
+
+impl ColumnInOutputData {
+    fn new(column: ColumnWithCells) -> Self {
+        Self {
+            column_id: column.id().clone_value(),
+            column_name: column.name().clone_value(),
+            cells: column
+                .cells()
+                .iter()
+                .map(|cell| ColumnCellInOutputData {
+                    cell_id: cell.id().clone_value(),
+                    cell_value: cell.cell_value().clone_value(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl ColumnBaselineCorrectOutputData {
+    pub(super) fn new(
+        source: &ColumnId,
+        baseline: ColumnWithCells,
+        corrected: ColumnWithCells,
+    ) -> Self {
+        Self {
+            source_column_id: source.clone_value(),
+            baseline: ColumnInOutputData::new(baseline),
+            corrected: ColumnInOutputData::new(corrected),
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use thiserror::Error;
+
+use src_domain::{
+    models::column::{
+        column_id::{ColumnId, ColumnIdError},
+        column_name::ColumnNameError,
+        column_repository::ColumnRepositoryError,
+    },
+    services::{baseline::BaselineError, column_creation_service::ColumnCreationServiceError},
+};
+
+use super::{
+    column_baseline_correct_command::ColumnBaselineCorrectCommand,
+    column_baseline_correct_output_data::ColumnBaselineCorrectOutputData,
+};
+
+pub type ColumnBaselineCorrectServiceResult<T> =
+    anyhow::Result<T, ColumnBaselineCorrectServiceError>;
+
+pub trait IColumnBaselineCorrectService {
+    fn handle(
+        &self,
+        command: ColumnBaselineCorrectCommand,
+    ) -> impl std::future::Future<
+        Output = ColumnBaselineCorrectServiceResult<ColumnBaselineCorrectOutputData>,
+    > + Send;
+}
+
+#[derive(Debug, Error)]
+pub enum ColumnBaselineCorrectServiceError {
+    // repository errors
+    #[error("ColumnRepositoryError: [{0}]")]
+    ColumnRepositoryError(ColumnRepositoryError),
+
+    // value object errors
+    #[error("ColumnIdError: [{0}]")]
+    ColumnIdError(ColumnIdError),
+    #[error("ColumnNameError: [{0}]")]
+    ColumnNameError(ColumnNameError),
+
+    // domain service errors
+    #[error("BaselineError: [{0}]")]
+    BaselineError(BaselineError),
+    #[error("ColumnCreationServiceError: [{0}]")]
+    ColumnCreationServiceError(ColumnCreationServiceError),
+
+    // not found errors
+    #[error("Column not found, column_id: {0:?}")]
+    ColumnNotFound(ColumnId),
+}
@@ -0,0 +1,257 @@
+use src_domain::{
+    models::column::{
+        column::Column,
+        column_cell::column_cell_value::CellRawValue,
+        column_factory::IColumnFactory,
+        column_id::ColumnId,
+        column_name::ColumnName,
+        column_provenance::{AnalysisKind, ColumnAnalysis, ColumnProvenance},
+        column_repository::IColumnRepository,
+        column_with_cells::ColumnWithCells,
+    },
+    services::{
+        baseline::Baseline, column_creation_service::ColumnCreationService,
+        column_values_service::ColumnValuesService,
+    },
+    shared::value_object::ValueObject,
+};
+
+use super::{
+    column_baseline_correct_command::ColumnBaselineCorrectCommand,
+    column_baseline_correct_output_data::ColumnBaselineCorrectOutputData,
+    column_baseline_correct_service::{
+        ColumnBaselineCorrectServiceError, ColumnBaselineCorrectServiceResult,
+        IColumnBaselineCorrectService,
+    },
+};
+
+pub struct ColumnBaselineCorrectService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory,
+    CR: IColumnRepository,
+{
+    column_factory: &'a CF,
+    column_repository: &'b CR,
+}
+
+impl<'a, 'b, CF, CR> ColumnBaselineCorrectService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+{
+    pub fn new(column_factory: &'a CF, column_repository: &'b CR) -> Self {
+        Self {
+            column_factory,
+            column_repository,
+        }
+    }
+
+    // Y カラムと同じディレクトリに "{y} {suffix}" という名前のカラムを作成する
+    async fn create_column(
+        &self,
+        y_column: &Column,
+        suffix: &str,
+        values: Vec<CellRawValue>,
+        provenance: &ColumnProvenance,
+    ) -> ColumnBaselineCorrectServiceResult<ColumnWithCells> {
+        let name = ColumnName::new(format!("{} {}", y_column.name(), suffix))
+            .map_err(ColumnBaselineCorrectServiceError::ColumnNameError)?;
+        let (_, column_with_cells) =
+            ColumnCreationService::new(self.column_factory, self.column_repository)
+                .create_column_from(
+                    name,
+                    y_column.directory_id().clone(),
+                    values,
+                    provenance.clone(),
+                )
+                .await
+                .map_err(ColumnBaselineCorrectServiceError::ColumnCreationServiceError)?;
+        Ok(column_with_cells)
+    }
+}
+
+impl<'a, 'b, CF, CR> IColumnBaselineCorrectService for ColumnBaselineCorrectService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+{
+    async fn handle(
+        &self,
+        command: ColumnBaselineCorrectCommand,
+    ) -> ColumnBaselineCorrectServiceResult<ColumnBaselineCorrectOutputData> {
+        let ColumnBaselineCorrectCommand {
+            y_column_id,
+            x_column_id,
+            method,
+        } = command;
+
+        // 値オブジェクトのインスタンス化
+        let y_column_id =
+            ColumnId::new(y_column_id).map_err(ColumnBaselineCorrectServiceError::ColumnIdError)?;
+        let x_column_id = x_column_id
+            .map(ColumnId::new)
+            .transpose()
+            .map_err(ColumnBaselineCorrectServiceError::ColumnIdError)?;
+
+        // ベースラインの推定 (X カラムがなければ行番号を x とする)
+        let (y_column, y_values) = ColumnValuesService::new(self.column_repository)
+            .find_column_values(&y_column_id)
+            .await
+            .map_err(ColumnBaselineCorrectServiceError::ColumnRepositoryError)?
+            .ok_or(ColumnBaselineCorrectServiceError::ColumnNotFound(
+                y_column_id.clone(),
+            ))?;
+        let x_values = match &x_column_id {
+            Some(x_column_id) => {
+                ColumnValuesService::new(self.column_repository)
+                    .find_column_values(x_column_id)
+                    .await
+                    .map_err(ColumnBaselineCorrectServiceError::ColumnRepositoryError)?
+                    .ok_or(ColumnBaselineCorrectServiceError::ColumnNotFound(
+                        x_column_id.clone(),
+                    ))?
+                    .1
+            }
+            None => (0..y_values.len()).map(|row| Some(row as f64)).collect(),
+        };
+        let baseline = Baseline::estimate(&x_values, &y_values, &method.to_baseline_method())
+            .map_err(ColumnBaselineCorrectServiceError::BaselineError)?;
+
+        // ベースラインと補正後の値をカラムとして保存する
+        let mut sources = vec![y_column_id.clone()];
+        sources.extend(x_column_id);
+        let provenance = ColumnProvenance::Analysis(ColumnAnalysis::new(
+            AnalysisKind::BaselineCorrection,
+            sources,
+        ));
+        let baseline_column = self
+            .create_column(
+                &y_column,
+                "baseline",
+                baseline.baseline().clone(),
+                &provenance,
+            )
+            .await?;
+        let corrected_column = self
+            .create_column(
+                &y_column,
+                "corrected",
+                baseline.corrected().clone(),
+                &provenance,
+            )
+            .await?;
+
+        Ok(ColumnBaselineCorrectOutputData::new(
+            &y_column_id,
+            baseline_column,
+            corrected_column,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use src_domain::services::baseline::BaselineError;
+    use src_in_memory_infrastructure::column::{
+        in_memory_column_factory::InMemoryColumnFactory,
+        in_memory_column_repository::InMemoryColumnRepository,
+    };
+
+    use crate::column::correct_baseline::{
+        column_baseline_correct_command::{AnchorRegionInCommand, BaselineMethodInCommand},
+        column_baseline_correct_output_data::ColumnCellInOutputData,
+    };
+    use crate::test_utils::save_column_in_directory;
+
+    use super::*;
+
+    // 丸め誤差を除いたセルの値
+    fn values(cells: &[ColumnCellInOutputData]) -> Vec<Option<f64>> {
+        cells
+            .iter()
+            .map(|cell| cell.cell_value.map(|value| (value * 1e9).round() / 1e9))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_handle() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        // 直線の背景 (1 + x) の上のピーク
+        let y = save_column_in_directory(
+            &column_repository,
+            "signal",
+            "4",
+            vec![Some(1.), Some(2.), Some(8.), None, Some(5.), Some(6.)],
+        )
+        .await?;
+
+        let service = ColumnBaselineCorrectService::new(&column_factory, &column_repository);
+        let command = ColumnBaselineCorrectCommand {
+            y_column_id: y.clone_value(),
+            x_column_id: None,
+            method: BaselineMethodInCommand::Polynomial {
+                degree: 1,
+                anchors: vec![
+                    AnchorRegionInCommand { start: 0., end: 1. },
+                    AnchorRegionInCommand { start: 4., end: 5. },
+                ],
+            },
+        };
+        let output_data = service.handle(command).await?;
+        assert_eq!(output_data.source_column_id, y.clone_value());
+        assert_eq!(output_data.baseline.column_name, "signal baseline");
+        assert_eq!(output_data.corrected.column_name, "signal corrected");
+        assert_eq!(
+            values(&output_data.baseline.cells),
+            vec![Some(1.), Some(2.), Some(3.), None, Some(5.), Some(6.)]
+        );
+        assert_eq!(
+            values(&output_data.corrected.cells),
+            vec![Some(0.), Some(0.), Some(5.), None, Some(0.), Some(0.)]
+        );
+
+        // 作成したカラムは Y カラムと同じディレクトリにある
+        let column = column_repository
+            .find(&ColumnId::new(output_data.corrected.column_id)?)
+            .await?
+            .unwrap();
+        assert_eq!(column.directory_id().value(), "4");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handle_with_x_column() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let x = save_column_in_directory(
+            &column_repository,
+            "x",
+            "4",
+            vec![Some(0.), Some(2.), Some(1.)],
+        )
+        .await?;
+        let y = save_column_in_directory(
+            &column_repository,
+            "y",
+            "4",
+            vec![Some(1.), Some(2.), Some(3.)],
+        )
+        .await?;
+
+        let service = ColumnBaselineCorrectService::new(&column_factory, &column_repository);
+        let command = ColumnBaselineCorrectCommand {
+            y_column_id: y.clone_value(),
+            x_column_id: Some(x.clone_value()),
+            method: BaselineMethodInCommand::RollingBall { radius: 1. },
+        };
+        assert!(matches!(
+            service.handle(command).await,
+            Err(ColumnBaselineCorrectServiceError::BaselineError(
+                BaselineError::NonIncreasingX(2)
+            ))
+        ));
+        assert_eq!(column_repository.find_all().await?.len(), 2);
+        Ok(())
+    }
+}
@@ -0,0 +1,10 @@
+/* ベースライン補正用アプリケーションサービス */
+// コマンドオブジェクト
+pub mod column_baseline_correct_command;
+
+// アプリケーションサービス
+pub mod column_baseline_correct_service;
+pub mod column_baseline_correct_service_impl;
+
+// DTO
+pub mod column_baseline_correct_output_data;
@@ -1,8 +1,35 @@
 use serde::{Deserialize, Serialize};
 
+use src_domain::models::column::column_constraint::ColumnConstraint;
+
 #[derive(Deserialize, Serialize)]
 pub struct ColumnCreateCommand {
     pub(super) name: String,
     pub(super) directory_id: String,
-    pub(super) cells: Vec<Option<f64>>
-}
\ No newline at end of file
+    pub(super) cells: Vec<Option<f64>>,
+    #[serde(default)]
+    pub(super) constraints: Vec<ColumnConstraintInCommand>,
+}
+
+// カラムの制約 (制約編集のコマンドと共通)
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ColumnConstraintInCommand {
+    NonNull,
+    Min { value: f64 },
+    Max { value: f64 },
+    StrictlyIncreasing,
+    IntegerOnly,
+}
+
+impl ColumnConstraintInCommand {
+    pub(crate) fn to_column_constraint(&self) -> ColumnConstraint {
+        match self {
+            ColumnConstraintInCommand::NonNull => ColumnConstraint::NonNull,
+            ColumnConstraintInCommand::Min { value } => ColumnConstraint::Min(*value),
+            ColumnConstraintInCommand::Max { value } => ColumnConstraint::Max(*value),
+            ColumnConstraintInCommand::StrictlyIncreasing => ColumnConstraint::StrictlyIncreasing,
+            ColumnConstraintInCommand::IntegerOnly => ColumnConstraint::IntegerOnly,
+        }
+    }
+}
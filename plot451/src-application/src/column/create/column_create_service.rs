@@ -2,6 +2,7 @@ use thiserror::Error;
 
 use src_domain::models::column::{
     column_cell::column_cell_value::ColumnCellValueError,
+    column_constraint_specification::ColumnConstraintSpecificationError,
     column_directory::column_directory_id::ColumnDirectoryIdError,
     column_factory::ColumnFactoryError, column_id::ColumnIdError, column_name::ColumnNameError,
     column_repository::ColumnRepositoryError,
@@ -36,6 +37,10 @@ pub enum ColumnCreateServiceError {
     #[error("ColumnDirectoryIdError: [{0}]")]
     ColumnDirectoryIdError(ColumnDirectoryIdError),
 
+    // specification errors
+    #[error("ColumnConstraintSpecificationError: [{0}]")]
+    ColumnConstraintSpecificationError(ColumnConstraintSpecificationError),
+
     // factory errors
     #[error("ColumnFactoryError: [{0}]")]
     ColumnFactoryError(ColumnFactoryError),
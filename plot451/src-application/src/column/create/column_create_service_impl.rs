@@ -1,10 +1,11 @@
 use src_domain::{
     models::column::{
-        column_cell::column_cell_value::ColumnCellValue,
+        column_cell::column_cell_value::ColumnCellValue, column_constraint::ColumnConstraint,
+        column_constraint_specification::ColumnValuesConstraintsSpecification,
         column_directory::column_directory_id::ColumnDirectoryId, column_factory::IColumnFactory,
         column_name::ColumnName, column_repository::IColumnRepository, column_with_cells,
     },
-    shared::value_object::ValueObject,
+    shared::{specification::Specification, value_object::ValueObject},
 };
 
 use super::{
@@ -55,6 +56,16 @@ where
         let directory_id = ColumnDirectoryId::new(command.directory_id)
             .map_err(|e| ColumnCreateServiceError::ColumnDirectoryIdError(e))?;
 
+        // 取り込む値が制約を満たすかを永続化の前にチェックする
+        let constraints: Vec<ColumnConstraint> = command
+            .constraints
+            .iter()
+            .map(|constraint| constraint.to_column_constraint())
+            .collect();
+        ColumnValuesConstraintsSpecification::new(constraints.clone())
+            .is_satisfied_by(&command.cells)
+            .map_err(ColumnCreateServiceError::ColumnConstraintSpecificationError)?;
+
         // セルのインスタンス化～永続化
         let mut cells = vec![];
         let mut cell_ids = vec![];
@@ -87,6 +98,7 @@ where
             .create_column(column_name, directory_id, cell_ids)
             .await
             .map_err(|e| ColumnCreateServiceError::ColumnFactoryError(e))?;
+        column.change_constraints(constraints);
 
         // カラムの永続化
         let column_id = self
@@ -108,12 +120,17 @@ where
 
 #[cfg(test)]
 mod tests {
-    use src_domain::models::column::column_id::ColumnId;
+    use src_domain::models::column::{
+        column_cell::column_cell_id::ColumnCellId,
+        column_constraint_specification::ColumnConstraintSpecificationError, column_id::ColumnId,
+    };
     use src_in_memory_infrastructure::column::{
         in_memory_column_factory::InMemoryColumnFactory,
         in_memory_column_repository::InMemoryColumnRepository,
     };
 
+    use crate::column::create::column_create_command::ColumnConstraintInCommand;
+
     use super::*;
 
     #[tokio::test]
@@ -129,6 +146,7 @@ mod tests {
             name: "test_column".to_string(),
             directory_id: "0".to_string(),
             cells: vec![Some(1.), Some(2.), Some(3.), None, Some(5.)],
+            constraints: vec![],
         };
 
         // カラム作成サービスの実行
@@ -161,4 +179,33 @@ mod tests {
             }
         }
     }
+
+    #[tokio::test]
+    async fn test_handle_constraint_violated() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let service = ColumnCreateService::new(&column_factory, &column_repository);
+
+        let command = ColumnCreateCommand {
+            name: "test_column".to_string(),
+            directory_id: "0".to_string(),
+            cells: vec![Some(1.), None, Some(3.)],
+            constraints: vec![ColumnConstraintInCommand::NonNull],
+        };
+
+        match service.handle(command).await {
+            Err(ColumnCreateServiceError::ColumnConstraintSpecificationError(
+                ColumnConstraintSpecificationError::RowsViolated(violations),
+            )) => assert_eq!(violations[0].rows(), &vec![1]),
+            _ => panic!("unexpected result"),
+        }
+
+        // 制約に違反した場合はカラムもセルも残らない
+        assert!(column_repository.find_all().await?.is_empty());
+        assert!(column_repository
+            .find_cell(&ColumnCellId::new("1".to_string())?)
+            .await?
+            .is_none());
+        Ok(())
+    }
 }
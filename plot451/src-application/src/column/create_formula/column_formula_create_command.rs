@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize)]
+pub struct ColumnFormulaCreateCommand {
+    pub(super) name: String,
+    pub(super) directory_id: String,
+    // 例: col("V") / col("I"), log10(col("f"))
+    pub(super) expression: String,
+}
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+use src_domain::{
+    models::column::{column::Column, column_with_cells::ColumnWithCells},
+    shared::value_object::ValueObject,
+};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct ColumnFormulaCreateOutputData {
+    pub(super) column_id: String,
+    pub(super) column_name: String,
+    pub(super) expression: String,
+    pub(super) dependencies: Vec<String>,
+    pub(super) cells: Vec<ColumnCellInOutputData>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub(super) struct ColumnCellInOutputData {
+    pub(super) cell_id: String,
+    pub(super) cell_value: Option<f64>,
+}
+
+impl ColumnFormulaCreateOutputData {
+    pub(super) fn new(column: &Column, source: ColumnWithCells) -> Self {
+        let formula = column.formula().as_ref().expect("column is not derived");
+        Self {
+            column_id: source.id().clone_value(),
+            column_name: source.name().clone_value(),
+            expression: formula.clone_value(),
+            dependencies: formula
+                .dependencies()
+                .iter()
+                .map(|column_id| column_id.clone_value())
+                .collect(),
+            cells: source
+                .cells()
+                .iter()
+                .map(|cell| ColumnCellInOutputData {
+                    cell_id: cell.id().clone_value(),
+                    cell_value: cell.cell_value().clone_value(),
+                })
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use thiserror::Error;
+
+use src_domain::{
+    models::column::{
+        column_directory::column_directory_id::ColumnDirectoryIdError,
+        column_factory::ColumnFactoryError,
+        column_formula::formula_expression::FormulaExpressionError, column_name::ColumnNameError,
+        column_repository::ColumnRepositoryError,
+    },
+    services::derived_column_service::DerivedColumnServiceError,
+};
+
+use super::{
+    column_formula_create_command::ColumnFormulaCreateCommand,
+    column_formula_create_output_data::ColumnFormulaCreateOutputData,
+};
+
+pub type ColumnFormulaCreateServiceResult<T> = anyhow::Result<T, ColumnFormulaCreateServiceError>;
+
+pub trait IColumnFormulaCreateService {
+    fn handle(
+        &self,
+        command: ColumnFormulaCreateCommand,
+    ) -> impl std::future::Future<
+        Output = ColumnFormulaCreateServiceResult<ColumnFormulaCreateOutputData>,
+    > + Send;
+}
+
+#[derive(Debug, Error)]
+pub enum ColumnFormulaCreateServiceError {
+    // repository errors
+    #[error("ColumnRepositoryError: [{0}]")]
+    ColumnRepositoryError(ColumnRepositoryError),
+
+    // value object errors
+    #[error("ColumnNameError: [{0}]")]
+    ColumnNameError(ColumnNameError),
+    #[error("ColumnDirectoryIdError: [{0}]")]
+    ColumnDirectoryIdError(ColumnDirectoryIdError),
+    #[error("FormulaExpressionError: [{0}]")]
+    FormulaExpressionError(FormulaExpressionError),
+
+    // factory errors
+    #[error("ColumnFactoryError: [{0}]")]
+    ColumnFactoryError(ColumnFactoryError),
+
+    // domain service errors
+    #[error("DerivedColumnServiceError: [{0}]")]
+    DerivedColumnServiceError(DerivedColumnServiceError),
+}
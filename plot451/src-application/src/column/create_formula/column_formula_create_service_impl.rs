@@ -0,0 +1,229 @@
+use src_domain::{
+    models::column::{
+        column_directory::column_directory_id::ColumnDirectoryId, column_factory::IColumnFactory,
+        column_formula::column_formula::ColumnFormula, column_name::ColumnName,
+        column_repository::IColumnRepository, column_with_cells::ColumnWithCells,
+    },
+    services::derived_column_service::DerivedColumnService,
+    shared::value_object::ValueObject,
+};
+
+use super::{
+    column_formula_create_command::ColumnFormulaCreateCommand,
+    column_formula_create_output_data::ColumnFormulaCreateOutputData,
+    column_formula_create_service::{
+        ColumnFormulaCreateServiceError, ColumnFormulaCreateServiceResult,
+        IColumnFormulaCreateService,
+    },
+};
+
+pub struct ColumnFormulaCreateService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory,
+    CR: IColumnRepository,
+{
+    column_factory: &'a CF,
+    column_repository: &'b CR,
+}
+
+impl<'a, 'b, CF, CR> ColumnFormulaCreateService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory,
+    CR: IColumnRepository,
+{
+    pub fn new(column_factory: &'a CF, column_repository: &'b CR) -> Self {
+        Self {
+            column_factory,
+            column_repository,
+        }
+    }
+}
+
+impl<'a, 'b, CF, CR> IColumnFormulaCreateService for ColumnFormulaCreateService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+{
+    async fn handle(
+        &self,
+        command: ColumnFormulaCreateCommand,
+    ) -> ColumnFormulaCreateServiceResult<ColumnFormulaCreateOutputData> {
+        let ColumnFormulaCreateCommand {
+            name,
+            directory_id,
+            expression,
+        } = command;
+
+        // 値オブジェクトのインスタンス化
+        let column_name =
+            ColumnName::new(name).map_err(ColumnFormulaCreateServiceError::ColumnNameError)?;
+        let directory_id = ColumnDirectoryId::new(directory_id)
+            .map_err(ColumnFormulaCreateServiceError::ColumnDirectoryIdError)?;
+        let formula = ColumnFormula::new(expression)
+            .map_err(ColumnFormulaCreateServiceError::FormulaExpressionError)?;
+
+        // カラム名による参照は同じディレクトリ内のカラムから解決する
+        let candidates = self
+            .column_repository
+            .find_by_directory_id(&directory_id)
+            .await
+            .map_err(ColumnFormulaCreateServiceError::ColumnRepositoryError)?;
+        let formula = formula
+            .resolve(&candidates)
+            .map_err(ColumnFormulaCreateServiceError::FormulaExpressionError)?;
+
+        // カラムのインスタンス化～永続化
+        let mut column = self
+            .column_factory
+            .create_column(column_name, directory_id, vec![])
+            .await
+            .map_err(ColumnFormulaCreateServiceError::ColumnFactoryError)?;
+        column.change_formula(Some(formula));
+        let column_id = self
+            .column_repository
+            .save(&column)
+            .await
+            .map_err(ColumnFormulaCreateServiceError::ColumnRepositoryError)?;
+        column.set_id(column_id);
+
+        // 数式の評価結果をセルとして永続化
+        // 評価に失敗した場合は作成したカラムを削除する
+        let derived_column_service =
+            DerivedColumnService::new(self.column_factory, self.column_repository);
+        let cells = match derived_column_service.recompute(&mut column).await {
+            Ok(cells) => cells,
+            Err(e) => {
+                self.column_repository
+                    .delete(column)
+                    .await
+                    .map_err(ColumnFormulaCreateServiceError::ColumnRepositoryError)?;
+                return Err(ColumnFormulaCreateServiceError::DerivedColumnServiceError(
+                    e,
+                ));
+            }
+        };
+
+        let column_with_cells = ColumnWithCells::new(&column, cells);
+        Ok(ColumnFormulaCreateOutputData::new(
+            &column,
+            column_with_cells,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use src_domain::{
+        models::column::{
+            column_cell::column_cell_id::ColumnCellId,
+            column_formula::formula_expression::FormulaExpressionError, column_id::ColumnId,
+        },
+        services::derived_column_service::DerivedColumnServiceError,
+    };
+    use src_in_memory_infrastructure::column::{
+        in_memory_column_factory::InMemoryColumnFactory,
+        in_memory_column_repository::InMemoryColumnRepository,
+    };
+
+    use crate::test_utils::save_column;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let v = save_column(&column_repository, "V", vec![Some(1.), Some(4.), None]).await?;
+        let i = save_column(&column_repository, "I", vec![Some(2.), Some(2.), Some(1.)]).await?;
+
+        let service = ColumnFormulaCreateService::new(&column_factory, &column_repository);
+        let command = ColumnFormulaCreateCommand {
+            name: "R".to_string(),
+            directory_id: "0".to_string(),
+            expression: "col(\"V\") / col(\"I\")".to_string(),
+        };
+        let output_data = service.handle(command).await?;
+        assert_eq!(output_data.column_name, "R");
+        assert_eq!(output_data.expression, "col(\"V\") / col(\"I\")");
+        assert_eq!(
+            output_data.dependencies,
+            vec![v.clone_value(), i.clone_value()]
+        );
+        assert_eq!(
+            output_data
+                .cells
+                .iter()
+                .map(|cell| cell.cell_value)
+                .collect::<Vec<_>>(),
+            vec![Some(0.5), Some(2.), None]
+        );
+
+        // 派生カラムとして永続化されていることを確認
+        let column = column_repository
+            .find(&ColumnId::new(output_data.column_id.clone())?)
+            .await?
+            .unwrap();
+        assert!(column.is_derived());
+        let cells = column_repository.find_cells_by_ids(column.cells()).await?;
+        assert_eq!(
+            cells
+                .iter()
+                .map(|cell| (cell.id().clone(), cell.cell_value().clone_value()))
+                .collect::<Vec<_>>(),
+            output_data
+                .cells
+                .iter()
+                .map(|cell| (
+                    ColumnCellId::new(cell.cell_id.clone()).unwrap(),
+                    cell.cell_value
+                ))
+                .collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handle_unresolved_column() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        save_column(&column_repository, "V", vec![Some(1.)]).await?;
+
+        let service = ColumnFormulaCreateService::new(&column_factory, &column_repository);
+        let command = ColumnFormulaCreateCommand {
+            name: "R".to_string(),
+            directory_id: "0".to_string(),
+            expression: "col(\"V\") / col(\"I\")".to_string(),
+        };
+        match service.handle(command).await {
+            Err(ColumnFormulaCreateServiceError::FormulaExpressionError(
+                FormulaExpressionError::UnresolvedColumnName(name),
+            )) => assert_eq!(name, "I"),
+            _ => panic!("unexpected result"),
+        }
+        assert_eq!(column_repository.find_all().await?.len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handle_missing_column_id() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        save_column(&column_repository, "V", vec![Some(1.)]).await?;
+
+        // 存在しない id を参照した場合は作成したカラムが削除される
+        let service = ColumnFormulaCreateService::new(&column_factory, &column_repository);
+        let command = ColumnFormulaCreateCommand {
+            name: "R".to_string(),
+            directory_id: "0".to_string(),
+            expression: "col_id(\"100\") * 2".to_string(),
+        };
+        match service.handle(command).await {
+            Err(ColumnFormulaCreateServiceError::DerivedColumnServiceError(
+                DerivedColumnServiceError::ColumnRepositoryError(_),
+            )) => {}
+            _ => panic!("unexpected result"),
+        }
+        assert_eq!(column_repository.find_all().await?.len(), 1);
+        Ok(())
+    }
+}
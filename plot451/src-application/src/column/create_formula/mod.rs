@@ -0,0 +1,10 @@
+/* 派生カラム (数式カラム) 新規作成用アプリケーションサービス */
+// コマンドオブジェクト
+pub mod column_formula_create_command;
+
+// アプリケーションサービス
+pub mod column_formula_create_service;
+pub mod column_formula_create_service_impl;
+
+// DTO
+pub mod column_formula_create_output_data;
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Deserialize, Serialize)]
+pub struct ColumnDeleteOutputData {}
+
+impl ColumnDeleteOutputData {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
@@ -0,0 +1,48 @@
+use thiserror::Error;
+
+use src_domain::models::{
+    column::{
+        column_id::{ColumnId, ColumnIdError},
+        column_repository::ColumnRepositoryError,
+    },
+    plot_2d::plot_2d_repository::Plot2DRepositoryError,
+    table::{table_id::TableId, table_repository::TableRepositoryError},
+};
+
+use super::{
+    column_delete_command::ColumnDeleteCommand, column_delete_output_data::ColumnDeleteOutputData,
+};
+
+pub type ColumnDeleteServiceResult<T> = anyhow::Result<T, ColumnDeleteServiceError>;
+
+pub trait IColumnDeleteService {
+    fn handle(
+        &self,
+        command: ColumnDeleteCommand,
+    ) -> impl std::future::Future<Output = ColumnDeleteServiceResult<ColumnDeleteOutputData>> + Send;
+}
+
+#[derive(Debug, Error)]
+pub enum ColumnDeleteServiceError {
+    // repository errors
+    #[error("ColumnRepositoryError: [{0}]")]
+    ColumnRepositoryError(ColumnRepositoryError),
+    #[error("Plot2DRepositoryError: [{0}]")]
+    Plot2DRepositoryError(Plot2DRepositoryError),
+    #[error("TableRepositoryError: [{0}]")]
+    TableRepositoryError(TableRepositoryError),
+
+    // value object errors
+    #[error("ColumnIdError: [{0}]")]
+    ColumnIdError(ColumnIdError),
+
+    // not found errors
+    #[error("Column not found, column_id: {0:?}")]
+    ColumnNotFound(ColumnId),
+
+    // reference errors
+    #[error("Column {0:?} is used by tables: {1:?}")]
+    ColumnUsedByTables(ColumnId, Vec<TableId>),
+    #[error("Column {0:?} is referenced by formula columns: {1:?}")]
+    ColumnReferencedByFormulas(ColumnId, Vec<ColumnId>),
+}
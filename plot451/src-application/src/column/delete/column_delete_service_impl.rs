@@ -0,0 +1,273 @@
+use src_domain::{
+    models::{
+        column::{column_id::ColumnId, column_repository::IColumnRepository},
+        plot_2d::plot_2d_repository::IPlot2DRepository,
+        table::table_repository::ITableRepository,
+    },
+    shared::value_object::ValueObject,
+};
+
+use super::{
+    column_delete_command::ColumnDeleteCommand,
+    column_delete_output_data::ColumnDeleteOutputData,
+    column_delete_service::{
+        ColumnDeleteServiceError, ColumnDeleteServiceResult, IColumnDeleteService,
+    },
+};
+
+pub struct ColumnDeleteService<'a, 'b, 'c, CR, TR, PR>
+where
+    CR: IColumnRepository,
+    TR: ITableRepository,
+    PR: IPlot2DRepository,
+{
+    column_repository: &'a CR,
+    table_repository: &'b TR,
+    plot_2d_repository: &'c PR,
+}
+
+impl<'a, 'b, 'c, CR, TR, PR> ColumnDeleteService<'a, 'b, 'c, CR, TR, PR>
+where
+    CR: IColumnRepository,
+    TR: ITableRepository,
+    PR: IPlot2DRepository,
+{
+    pub fn new(
+        column_repository: &'a CR,
+        table_repository: &'b TR,
+        plot_2d_repository: &'c PR,
+    ) -> Self {
+        Self {
+            column_repository,
+            table_repository,
+            plot_2d_repository,
+        }
+    }
+}
+
+impl<'a, 'b, 'c, CR, TR, PR> IColumnDeleteService for ColumnDeleteService<'a, 'b, 'c, CR, TR, PR>
+where
+    CR: IColumnRepository + Sync,
+    TR: ITableRepository + Sync,
+    PR: IPlot2DRepository + Sync,
+{
+    async fn handle(
+        &self,
+        command: ColumnDeleteCommand,
+    ) -> ColumnDeleteServiceResult<ColumnDeleteOutputData> {
+        let ColumnDeleteCommand { id } = command;
+
+        // 値オブジェクトのインスタンス化
+        let column_id = ColumnId::new(id).map_err(ColumnDeleteServiceError::ColumnIdError)?;
+
+        let column = self
+            .column_repository
+            .find(&column_id)
+            .await
+            .map_err(ColumnDeleteServiceError::ColumnRepositoryError)?
+            .ok_or(ColumnDeleteServiceError::ColumnNotFound(column_id.clone()))?;
+
+        // テーブルに含まれるカラムは削除できない (フィルタの元のテーブルも含む)
+        let tables = self
+            .table_repository
+            .find_parent_table_by_column_id(&column_id)
+            .await
+            .map_err(ColumnDeleteServiceError::TableRepositoryError)?;
+        if !tables.is_empty() {
+            return Err(ColumnDeleteServiceError::ColumnUsedByTables(
+                column_id,
+                tables.iter().map(|table| table.id().clone()).collect(),
+            ));
+        }
+
+        // 数式で参照されているカラムは削除できない
+        let dependents: Vec<ColumnId> = self
+            .column_repository
+            .find_all()
+            .await
+            .map_err(ColumnDeleteServiceError::ColumnRepositoryError)?
+            .iter()
+            .filter(|other| {
+                other
+                    .formula()
+                    .as_ref()
+                    .is_some_and(|formula| formula.dependencies().contains(&column_id))
+            })
+            .map(|other| other.id().clone())
+            .collect();
+        if !dependents.is_empty() {
+            return Err(ColumnDeleteServiceError::ColumnReferencedByFormulas(
+                column_id, dependents,
+            ));
+        }
+
+        // カラムを参照するグラフの系列を削除する
+        let plots = self
+            .plot_2d_repository
+            .find_by_column_id(&column_id)
+            .await
+            .map_err(ColumnDeleteServiceError::Plot2DRepositoryError)?;
+        for mut plot in plots {
+            plot.remove_series_by_column_id(&column_id);
+            self.plot_2d_repository
+                .save(&plot)
+                .await
+                .map_err(ColumnDeleteServiceError::Plot2DRepositoryError)?;
+        }
+
+        self.column_repository
+            .delete(column)
+            .await
+            .map_err(ColumnDeleteServiceError::ColumnRepositoryError)?;
+
+        Ok(ColumnDeleteOutputData::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use src_domain::models::{
+        column::column_formula::column_formula::ColumnFormula,
+        plot_2d::{
+            plot_2d::Plot2D, plot_2d_axis::Plot2DAxis, plot_2d_id::Plot2DId,
+            plot_2d_series::Plot2DSeries, plot_2d_series_style::SeriesStyle,
+            plot_2d_title::Plot2DTitle,
+        },
+        table::{table::Table, table_id::TableId, table_name::TableName},
+    };
+    use src_in_memory_infrastructure::{
+        column::in_memory_column_repository::InMemoryColumnRepository,
+        plot_2d::in_memory_plot_2d_repository::InMemoryPlot2DRepository,
+        table::in_memory_table_repository::InMemoryTableRepository,
+    };
+
+    use crate::test_utils::save_column;
+
+    use super::*;
+
+    async fn save_plot(
+        plot_2d_repository: &InMemoryPlot2DRepository,
+        series: Vec<(ColumnId, ColumnId)>,
+    ) -> anyhow::Result<Plot2DId> {
+        let plot = Plot2D::new(
+            None,
+            Plot2DTitle::new("plot".to_string())?,
+            series
+                .into_iter()
+                .map(|(x, y)| Plot2DSeries::new(x, y, String::new(), SeriesStyle::default()))
+                .collect(),
+            Plot2DAxis::default(),
+            Plot2DAxis::default(),
+        );
+        Ok(plot_2d_repository.save(&plot).await?)
+    }
+
+    #[tokio::test]
+    async fn test_handle() -> anyhow::Result<()> {
+        let column_repository = InMemoryColumnRepository::new();
+        let table_repository = InMemoryTableRepository::new();
+        let plot_2d_repository = InMemoryPlot2DRepository::new();
+        let x = save_column(&column_repository, "x", vec![Some(0.), Some(1.)]).await?;
+        let y1 = save_column(&column_repository, "y1", vec![Some(1.), Some(2.)]).await?;
+        let y2 = save_column(&column_repository, "y2", vec![Some(3.), None]).await?;
+        let plot_id1 = save_plot(
+            &plot_2d_repository,
+            vec![(x.clone(), y1.clone()), (x.clone(), y2.clone())],
+        )
+        .await?;
+        let plot_id2 = save_plot(&plot_2d_repository, vec![(y1.clone(), y2.clone())]).await?;
+        let cell_ids = column_repository.find(&y2).await?.unwrap().cells().clone();
+
+        let service =
+            ColumnDeleteService::new(&column_repository, &table_repository, &plot_2d_repository);
+        let command = ColumnDeleteCommand {
+            id: y2.clone_value(),
+        };
+        let ColumnDeleteOutputData {} = service.handle(command).await?;
+
+        // カラムとセルが削除されたことを確認
+        assert!(column_repository.find(&y2).await?.is_none());
+        for cell_id in &cell_ids {
+            assert!(column_repository.find_cell(cell_id).await?.is_none());
+        }
+        assert!(column_repository.find(&y1).await?.is_some());
+
+        // 削除したカラムを参照する系列だけが取り除かれたことを確認
+        let plot1 = plot_2d_repository.find(&plot_id1).await?.unwrap();
+        assert_eq!(plot1.series().len(), 1);
+        assert_eq!(plot1.series()[0].y(), &y1);
+        let plot2 = plot_2d_repository.find(&plot_id2).await?.unwrap();
+        assert!(plot2.series().is_empty());
+        assert!(plot_2d_repository.find_by_column_id(&y2).await?.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handle_not_found() -> anyhow::Result<()> {
+        let column_repository = InMemoryColumnRepository::new();
+        let table_repository = InMemoryTableRepository::new();
+        let plot_2d_repository = InMemoryPlot2DRepository::new();
+        let service =
+            ColumnDeleteService::new(&column_repository, &table_repository, &plot_2d_repository);
+
+        let command = ColumnDeleteCommand {
+            id: "1".to_string(),
+        };
+        match service.handle(command).await {
+            Err(ColumnDeleteServiceError::ColumnNotFound(_)) => Ok(()),
+            _ => panic!("unexpected error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handle_referenced_column() -> anyhow::Result<()> {
+        let column_repository = InMemoryColumnRepository::new();
+        let table_repository = InMemoryTableRepository::new();
+        let plot_2d_repository = InMemoryPlot2DRepository::new();
+        let x = save_column(&column_repository, "x", vec![Some(1.), Some(2.)]).await?;
+        let y = save_column(&column_repository, "y", vec![Some(2.), Some(4.)]).await?;
+        let mut column = column_repository.find(&y).await?.unwrap();
+        column.change_formula(Some(ColumnFormula::new(format!(
+            "col_id(\"{}\") * 2",
+            x.value()
+        ))?));
+        column_repository.save(&column).await?;
+        let z = save_column(&column_repository, "z", vec![Some(0.), None]).await?;
+        let table = Table::new(
+            Some(TableId::new("table_id_1".to_string())?),
+            TableName::new("table_name_1".to_string())?,
+            vec![z.clone()],
+        )?;
+        table_repository.save(&table).await?;
+
+        let service =
+            ColumnDeleteService::new(&column_repository, &table_repository, &plot_2d_repository);
+
+        // 数式で参照されているカラム
+        let command = ColumnDeleteCommand {
+            id: x.clone_value(),
+        };
+        match service.handle(command).await {
+            Err(ColumnDeleteServiceError::ColumnReferencedByFormulas(_, dependents)) => {
+                assert_eq!(dependents, vec![y.clone()])
+            }
+            _ => panic!("unexpected result"),
+        }
+
+        // テーブルに含まれるカラム
+        let command = ColumnDeleteCommand {
+            id: z.clone_value(),
+        };
+        match service.handle(command).await {
+            Err(ColumnDeleteServiceError::ColumnUsedByTables(_, tables)) => {
+                assert_eq!(tables, vec![table.id().clone()])
+            }
+            _ => panic!("unexpected result"),
+        }
+
+        // どちらのカラムも削除されていない
+        assert!(column_repository.find(&x).await?.is_some());
+        assert!(column_repository.find(&z).await?.is_some());
+        Ok(())
+    }
+}
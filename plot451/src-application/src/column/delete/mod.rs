@@ -0,0 +1,10 @@
+/* カラム削除用アプリケーションサービス */
+// コマンドオブジェクト
+pub mod column_delete_command;
+
+// アプリケーションサービス
+pub mod column_delete_service;
+pub mod column_delete_service_impl;
+
+// DTO
+pub mod column_delete_output_data;
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+// しきい値は省略すると条件なし (距離と幅は x の単位)
+#[derive(Deserialize, Serialize)]
+pub struct ColumnPeakDetectCommand {
+    pub(super) x_column_id: String,
+    pub(super) y_column_id: String,
+    #[serde(default)]
+    pub(super) min_height: Option<f64>,
+    #[serde(default)]
+    pub(super) min_prominence: Option<f64>,
+    #[serde(default)]
+    pub(super) min_distance: Option<f64>,
+    #[serde(default)]
+    pub(super) min_width: Option<f64>,
+    // 結果のテーブル名 (省略した場合は "{Y カラム名} peaks")
+    #[serde(default)]
+    pub(super) table_name: Option<String>,
+}
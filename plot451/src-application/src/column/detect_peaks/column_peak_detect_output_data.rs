@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+
+use src_domain::{
+    models::table::table_with_columns_and_cells::TableWithColumnsAndCells,
+    services::peak_detection::Peak, shared::value_object::ValueObject,
+};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct ColumnPeakDetectOutputData {
+    pub(super) peaks: Vec<PeakInOutputData>,
+    // 結果を保存したテーブル
+    pub(super) table_id: String,
+    pub(super) table_name: String,
+    pub(super) columns: Vec<ColumnInOutputData>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub(super) struct PeakInOutputData {
+    // 元のカラムの行番号
+    pub(super) row: usize,
+    pub(super) position: f64,
+    pub(super) height: f64,
+    pub(super) prominence: f64,
+    pub(super) fwhm: Option<f64>,
+    pub(super) area: f64,
+    // 面積を求めた範囲 (左右の谷の x)
+    pub(super) left_base: f64,
+    pub(super) right_base: f64,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub(super) struct ColumnInOutputData {
+    pub(super) column_id: String,
+    pub(super) column_name: String,
+}
+
+impl ColumnPeakDetectOutputData {
+    pub(super) fn new(peaks: &[Peak], table: TableWithColumnsAndCells) -> Self {
+        Self {
+            peaks: peaks
+                .iter()
+                .map(|peak| PeakInOutputData {
+                    row: peak.row(),
+                    position: peak.position(),
+                    height: peak.height(),
+                    prominence: peak.prominence(),
+                    fwhm: peak.fwhm(),
+                    area: peak.area(),
+                    left_base: peak.left_base(),
+                    right_base: peak.right_base(),
+                })
+                .collect(),
+            table_id: table.id().clone_value(),
+            table_name: table.name().clone_value(),
+            columns: table
+                .columns()
+                .iter()
+                .map(|column| ColumnInOutputData {
+                    column_id: column.id().clone_value(),
+                    column_name: column.name().clone_value(),
+                })
+                .collect(),
+        }
+    }
+}
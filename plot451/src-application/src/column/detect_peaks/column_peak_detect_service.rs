@@ -0,0 +1,64 @@
+use thiserror::Error;
+
+use src_domain::{
+    models::{
+        column::{
+            column_id::{ColumnId, ColumnIdError},
+            column_name::ColumnNameError,
+            column_repository::ColumnRepositoryError,
+        },
+        table::{
+            table_factory::TableFactoryError, table_name::TableNameError,
+            table_repository::TableRepositoryError,
+        },
+    },
+    services::{
+        column_creation_service::ColumnCreationServiceError, peak_detection::PeakDetectionError,
+    },
+};
+
+use super::{
+    column_peak_detect_command::ColumnPeakDetectCommand,
+    column_peak_detect_output_data::ColumnPeakDetectOutputData,
+};
+
+pub type ColumnPeakDetectServiceResult<T> = anyhow::Result<T, ColumnPeakDetectServiceError>;
+
+pub trait IColumnPeakDetectService {
+    fn handle(
+        &self,
+        command: ColumnPeakDetectCommand,
+    ) -> impl std::future::Future<Output = ColumnPeakDetectServiceResult<ColumnPeakDetectOutputData>>
+           + Send;
+}
+
+#[derive(Debug, Error)]
+pub enum ColumnPeakDetectServiceError {
+    // repository errors
+    #[error("ColumnRepositoryError: [{0}]")]
+    ColumnRepositoryError(ColumnRepositoryError),
+    #[error("TableRepositoryError: [{0}]")]
+    TableRepositoryError(TableRepositoryError),
+
+    // value object errors
+    #[error("ColumnIdError: [{0}]")]
+    ColumnIdError(ColumnIdError),
+    #[error("ColumnNameError: [{0}]")]
+    ColumnNameError(ColumnNameError),
+    #[error("TableNameError: [{0}]")]
+    TableNameError(TableNameError),
+
+    // factory errors
+    #[error("TableFactoryError: [{0}]")]
+    TableFactoryError(TableFactoryError),
+
+    // domain service errors
+    #[error("PeakDetectionError: [{0}]")]
+    PeakDetectionError(PeakDetectionError),
+    #[error("ColumnCreationServiceError: [{0}]")]
+    ColumnCreationServiceError(ColumnCreationServiceError),
+
+    // not found errors
+    #[error("Column not found, column_id: {0:?}")]
+    ColumnNotFound(ColumnId),
+}
@@ -0,0 +1,300 @@
+use src_domain::{
+    models::{
+        column::{
+            column_cell::column_cell_value::CellRawValue,
+            column_factory::IColumnFactory,
+            column_id::ColumnId,
+            column_name::ColumnName,
+            column_provenance::{AnalysisKind, ColumnAnalysis, ColumnProvenance},
+            column_repository::IColumnRepository,
+        },
+        table::{
+            table_factory::ITableFactory, table_name::TableName,
+            table_repository::ITableRepository,
+            table_with_columns_and_cells::TableWithColumnsAndCells,
+        },
+    },
+    services::{
+        column_creation_service::ColumnCreationService,
+        column_values_service::ColumnValuesService,
+        peak_detection::{find_peaks, PeakCriteria},
+    },
+    shared::value_object::ValueObject,
+};
+
+use super::{
+    column_peak_detect_command::ColumnPeakDetectCommand,
+    column_peak_detect_output_data::ColumnPeakDetectOutputData,
+    column_peak_detect_service::{
+        ColumnPeakDetectServiceError, ColumnPeakDetectServiceResult, IColumnPeakDetectService,
+    },
+};
+
+pub struct ColumnPeakDetectService<'a, 'b, 'c, 'd, CF, CR, TF, TR>
+where
+    CF: IColumnFactory,
+    CR: IColumnRepository,
+    TF: ITableFactory,
+    TR: ITableRepository,
+{
+    column_factory: &'a CF,
+    column_repository: &'b CR,
+    table_factory: &'c TF,
+    table_repository: &'d TR,
+}
+
+impl<'a, 'b, 'c, 'd, CF, CR, TF, TR> ColumnPeakDetectService<'a, 'b, 'c, 'd, CF, CR, TF, TR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+    TF: ITableFactory + Sync,
+    TR: ITableRepository + Sync,
+{
+    pub fn new(
+        column_factory: &'a CF,
+        column_repository: &'b CR,
+        table_factory: &'c TF,
+        table_repository: &'d TR,
+    ) -> Self {
+        Self {
+            column_factory,
+            column_repository,
+            table_factory,
+            table_repository,
+        }
+    }
+}
+
+impl<'a, 'b, 'c, 'd, CF, CR, TF, TR> IColumnPeakDetectService
+    for ColumnPeakDetectService<'a, 'b, 'c, 'd, CF, CR, TF, TR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+    TF: ITableFactory + Sync,
+    TR: ITableRepository + Sync,
+{
+    // TODO: トランザクション処理を追加する
+    async fn handle(
+        &self,
+        command: ColumnPeakDetectCommand,
+    ) -> ColumnPeakDetectServiceResult<ColumnPeakDetectOutputData> {
+        let ColumnPeakDetectCommand {
+            x_column_id,
+            y_column_id,
+            min_height,
+            min_prominence,
+            min_distance,
+            min_width,
+            table_name,
+        } = command;
+
+        // 値オブジェクトのインスタンス化
+        let x_column_id =
+            ColumnId::new(x_column_id).map_err(ColumnPeakDetectServiceError::ColumnIdError)?;
+        let y_column_id =
+            ColumnId::new(y_column_id).map_err(ColumnPeakDetectServiceError::ColumnIdError)?;
+        let criteria = PeakCriteria::new(min_height, min_prominence, min_distance, min_width)
+            .map_err(ColumnPeakDetectServiceError::PeakDetectionError)?;
+
+        // ピーク検出
+        let (_, x_values) = ColumnValuesService::new(self.column_repository)
+            .find_column_values(&x_column_id)
+            .await
+            .map_err(ColumnPeakDetectServiceError::ColumnRepositoryError)?
+            .ok_or(ColumnPeakDetectServiceError::ColumnNotFound(
+                x_column_id.clone(),
+            ))?;
+        let (y_column, y_values) = ColumnValuesService::new(self.column_repository)
+            .find_column_values(&y_column_id)
+            .await
+            .map_err(ColumnPeakDetectServiceError::ColumnRepositoryError)?
+            .ok_or(ColumnPeakDetectServiceError::ColumnNotFound(
+                y_column_id.clone(),
+            ))?;
+        let table_name =
+            TableName::new(table_name.unwrap_or_else(|| format!("{} peaks", y_column.name())))
+                .map_err(ColumnPeakDetectServiceError::TableNameError)?;
+        let peaks = find_peaks(&x_values, &y_values, &criteria)
+            .map_err(ColumnPeakDetectServiceError::PeakDetectionError)?;
+
+        // ピークごとの値をカラムとして Y カラムと同じディレクトリに保存する
+        let columns: Vec<(&str, Vec<CellRawValue>)> = vec![
+            (
+                "position",
+                peaks.iter().map(|peak| Some(peak.position())).collect(),
+            ),
+            (
+                "height",
+                peaks.iter().map(|peak| Some(peak.height())).collect(),
+            ),
+            (
+                "prominence",
+                peaks.iter().map(|peak| Some(peak.prominence())).collect(),
+            ),
+            ("FWHM", peaks.iter().map(|peak| peak.fwhm()).collect()),
+            ("area", peaks.iter().map(|peak| Some(peak.area())).collect()),
+        ];
+        let column_creation_service =
+            ColumnCreationService::new(self.column_factory, self.column_repository);
+        let provenance = ColumnProvenance::Analysis(ColumnAnalysis::new(
+            AnalysisKind::PeakDetection,
+            vec![x_column_id.clone(), y_column_id.clone()],
+        ));
+        let mut column_ids = vec![];
+        let mut columns_with_cells = vec![];
+        for (name, values) in columns {
+            let name = ColumnName::new(name.to_string())
+                .map_err(ColumnPeakDetectServiceError::ColumnNameError)?;
+            let (column, column_with_cells) = column_creation_service
+                .create_column_from(
+                    name,
+                    y_column.directory_id().clone(),
+                    values,
+                    provenance.clone(),
+                )
+                .await
+                .map_err(ColumnPeakDetectServiceError::ColumnCreationServiceError)?;
+            column_ids.push(column.id().clone());
+            columns_with_cells.push(column_with_cells);
+        }
+
+        // 結果のテーブルの作成と永続化
+        let mut table = self
+            .table_factory
+            .create_table(table_name, column_ids)
+            .await
+            .map_err(ColumnPeakDetectServiceError::TableFactoryError)?;
+        let table_id = self
+            .table_repository
+            .save(&table)
+            .await
+            .map_err(ColumnPeakDetectServiceError::TableRepositoryError)?;
+        table.set_id(table_id);
+
+        Ok(ColumnPeakDetectOutputData::new(
+            &peaks,
+            TableWithColumnsAndCells::new(&table, columns_with_cells),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use src_domain::{
+        models::table::table_id::TableId, services::peak_detection::PeakDetectionError,
+    };
+    use src_in_memory_infrastructure::{
+        column::{
+            in_memory_column_factory::InMemoryColumnFactory,
+            in_memory_column_repository::InMemoryColumnRepository,
+        },
+        table::{
+            in_memory_table_factory::InMemoryTableFactory,
+            in_memory_table_repository::InMemoryTableRepository,
+        },
+    };
+
+    use crate::test_utils::save_column;
+
+    use super::*;
+
+    fn command(x: &ColumnId, y: &ColumnId, min_height: Option<f64>) -> ColumnPeakDetectCommand {
+        ColumnPeakDetectCommand {
+            x_column_id: x.clone_value(),
+            y_column_id: y.clone_value(),
+            min_height,
+            min_prominence: None,
+            min_distance: None,
+            min_width: None,
+            table_name: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handle() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let table_factory = InMemoryTableFactory::new();
+        let table_repository = InMemoryTableRepository::new();
+        // 三角形のピーク 2 つ (頂点 x = 2 で高さ 4、x = 6 で高さ 1)
+        let x = save_column(
+            &column_repository,
+            "x",
+            (0..=8).map(|i| Some(i as f64)).collect(),
+        )
+        .await?;
+        let y = save_column(
+            &column_repository,
+            "intensity",
+            [0., 2., 4., 2., 0., 0.5, 1., 0.5, 0.]
+                .iter()
+                .map(|y| Some(*y))
+                .collect(),
+        )
+        .await?;
+
+        let service = ColumnPeakDetectService::new(
+            &column_factory,
+            &column_repository,
+            &table_factory,
+            &table_repository,
+        );
+        let output_data = service.handle(command(&x, &y, None)).await?;
+        assert_eq!(output_data.table_name, "intensity peaks");
+        assert_eq!(output_data.peaks.len(), 2);
+        let peak = &output_data.peaks[0];
+        assert_eq!((peak.row, peak.position, peak.height), (2, 2., 4.));
+        assert_eq!(peak.fwhm, Some(2.));
+        assert_eq!(peak.area, 8.);
+        assert_eq!((peak.left_base, peak.right_base), (0., 4.));
+
+        // 結果がテーブルとして保存されている
+        let table = table_repository
+            .find(&TableId::new(output_data.table_id)?)
+            .await?
+            .unwrap();
+        let columns = column_repository.find_by_ids(table.columns()).await?;
+        let names: Vec<&str> = columns
+            .iter()
+            .map(|column| column.name().value().as_str())
+            .collect();
+        assert_eq!(
+            names,
+            vec!["position", "height", "prominence", "FWHM", "area"]
+        );
+        let positions = ColumnValuesService::new(&column_repository)
+            .find_values(&columns[0])
+            .await?;
+        assert_eq!(positions, vec![Some(2.), Some(6.)]);
+
+        // 高さの条件
+        let output_data = service.handle(command(&x, &y, Some(2.))).await?;
+        assert_eq!(output_data.peaks.len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handle_invalid_x() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let table_factory = InMemoryTableFactory::new();
+        let table_repository = InMemoryTableRepository::new();
+        let x = save_column(&column_repository, "x", vec![Some(1.), Some(0.)]).await?;
+        let y = save_column(&column_repository, "y", vec![Some(1.), Some(2.)]).await?;
+
+        let service = ColumnPeakDetectService::new(
+            &column_factory,
+            &column_repository,
+            &table_factory,
+            &table_repository,
+        );
+        assert!(matches!(
+            service.handle(command(&x, &y, None)).await,
+            Err(ColumnPeakDetectServiceError::PeakDetectionError(
+                PeakDetectionError::NonIncreasingX(1)
+            ))
+        ));
+        assert!(table_repository.find_all().await?.is_empty());
+        Ok(())
+    }
+}
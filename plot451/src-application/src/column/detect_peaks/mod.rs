@@ -0,0 +1,10 @@
+/* ピーク検出用アプリケーションサービス */
+// コマンドオブジェクト
+pub mod column_peak_detect_command;
+
+// アプリケーションサービス
+pub mod column_peak_detect_service;
+pub mod column_peak_detect_service_impl;
+
+// DTO
+pub mod column_peak_detect_output_data;
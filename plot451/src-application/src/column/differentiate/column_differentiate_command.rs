@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+use src_domain::services::numerical_calculus::{DifferenceMethod, GapHandling};
+
+#[derive(Deserialize, Serialize)]
+pub struct ColumnDifferentiateCommand {
+    pub(super) x_column_id: String,
+    pub(super) y_column_id: String,
+    pub(super) method: DifferenceMethodInCommand,
+    #[serde(default)]
+    pub(super) gap_handling: GapHandlingInCommand,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DifferenceMethodInCommand {
+    Forward,
+    Central,
+    NonUniform,
+}
+
+impl DifferenceMethodInCommand {
+    pub(super) fn to_difference_method(self) -> DifferenceMethod {
+        match self {
+            DifferenceMethodInCommand::Forward => DifferenceMethod::Forward,
+            DifferenceMethodInCommand::Central => DifferenceMethod::Central,
+            DifferenceMethodInCommand::NonUniform => DifferenceMethod::NonUniform,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum GapHandlingInCommand {
+    #[default]
+    Bridge,
+    Split,
+}
+
+impl GapHandlingInCommand {
+    pub(super) fn to_gap_handling(self) -> GapHandling {
+        match self {
+            GapHandlingInCommand::Bridge => GapHandling::Bridge,
+            GapHandlingInCommand::Split => GapHandling::Split,
+        }
+    }
+}
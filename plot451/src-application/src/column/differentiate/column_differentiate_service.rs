@@ -0,0 +1,52 @@
+use thiserror::Error;
+
+use src_domain::{
+    models::column::{
+        column_id::{ColumnId, ColumnIdError},
+        column_name::ColumnNameError,
+        column_repository::ColumnRepositoryError,
+    },
+    services::{
+        column_creation_service::ColumnCreationServiceError,
+        numerical_calculus::NumericalCalculusError,
+    },
+};
+
+use super::{
+    column_differentiate_command::ColumnDifferentiateCommand,
+    column_differentiate_output_data::ColumnDifferentiateOutputData,
+};
+
+pub type ColumnDifferentiateServiceResult<T> = anyhow::Result<T, ColumnDifferentiateServiceError>;
+
+pub trait IColumnDifferentiateService {
+    fn handle(
+        &self,
+        command: ColumnDifferentiateCommand,
+    ) -> impl std::future::Future<
+        Output = ColumnDifferentiateServiceResult<ColumnDifferentiateOutputData>,
+    > + Send;
+}
+
+#[derive(Debug, Error)]
+pub enum ColumnDifferentiateServiceError {
+    // repository errors
+    #[error("ColumnRepositoryError: [{0}]")]
+    ColumnRepositoryError(ColumnRepositoryError),
+
+    // value object errors
+    #[error("ColumnIdError: [{0}]")]
+    ColumnIdError(ColumnIdError),
+    #[error("ColumnNameError: [{0}]")]
+    ColumnNameError(ColumnNameError),
+
+    // domain service errors
+    #[error("NumericalCalculusError: [{0}]")]
+    NumericalCalculusError(NumericalCalculusError),
+    #[error("ColumnCreationServiceError: [{0}]")]
+    ColumnCreationServiceError(ColumnCreationServiceError),
+
+    // not found errors
+    #[error("Column not found, column_id: {0:?}")]
+    ColumnNotFound(ColumnId),
+}
@@ -0,0 +1,194 @@
+use src_domain::{
+    models::column::{
+        column_factory::IColumnFactory,
+        column_id::ColumnId,
+        column_name::ColumnName,
+        column_provenance::{AnalysisKind, ColumnAnalysis, ColumnProvenance},
+        column_repository::IColumnRepository,
+    },
+    services::{
+        column_creation_service::ColumnCreationService, column_values_service::ColumnValuesService,
+        numerical_calculus::SampledFunction,
+    },
+    shared::value_object::ValueObject,
+};
+
+use super::{
+    column_differentiate_command::ColumnDifferentiateCommand,
+    column_differentiate_output_data::ColumnDifferentiateOutputData,
+    column_differentiate_service::{
+        ColumnDifferentiateServiceError, ColumnDifferentiateServiceResult,
+        IColumnDifferentiateService,
+    },
+};
+
+pub struct ColumnDifferentiateService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory,
+    CR: IColumnRepository,
+{
+    column_factory: &'a CF,
+    column_repository: &'b CR,
+}
+
+impl<'a, 'b, CF, CR> ColumnDifferentiateService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+{
+    pub fn new(column_factory: &'a CF, column_repository: &'b CR) -> Self {
+        Self {
+            column_factory,
+            column_repository,
+        }
+    }
+}
+
+impl<'a, 'b, CF, CR> IColumnDifferentiateService for ColumnDifferentiateService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+{
+    async fn handle(
+        &self,
+        command: ColumnDifferentiateCommand,
+    ) -> ColumnDifferentiateServiceResult<ColumnDifferentiateOutputData> {
+        let ColumnDifferentiateCommand {
+            x_column_id,
+            y_column_id,
+            method,
+            gap_handling,
+        } = command;
+
+        // 値オブジェクトのインスタンス化
+        let x_column_id =
+            ColumnId::new(x_column_id).map_err(ColumnDifferentiateServiceError::ColumnIdError)?;
+        let y_column_id =
+            ColumnId::new(y_column_id).map_err(ColumnDifferentiateServiceError::ColumnIdError)?;
+
+        // 微分
+        let (x_column, x_values) = ColumnValuesService::new(self.column_repository)
+            .find_column_values(&x_column_id)
+            .await
+            .map_err(ColumnDifferentiateServiceError::ColumnRepositoryError)?
+            .ok_or(ColumnDifferentiateServiceError::ColumnNotFound(
+                x_column_id.clone(),
+            ))?;
+        let (y_column, y_values) = ColumnValuesService::new(self.column_repository)
+            .find_column_values(&y_column_id)
+            .await
+            .map_err(ColumnDifferentiateServiceError::ColumnRepositoryError)?
+            .ok_or(ColumnDifferentiateServiceError::ColumnNotFound(
+                y_column_id.clone(),
+            ))?;
+        let derivative = SampledFunction::new(&x_values, &y_values, gap_handling.to_gap_handling())
+            .map_err(ColumnDifferentiateServiceError::NumericalCalculusError)?
+            .derivative(method.to_difference_method());
+
+        // Y カラムと同じディレクトリに保存
+        let name = ColumnName::new(format!("d{}/d{}", y_column.name(), x_column.name()))
+            .map_err(ColumnDifferentiateServiceError::ColumnNameError)?;
+        let (_, column_with_cells) =
+            ColumnCreationService::new(self.column_factory, self.column_repository)
+                .create_column_from(
+                    name,
+                    y_column.directory_id().clone(),
+                    derivative,
+                    ColumnProvenance::Analysis(ColumnAnalysis::new(
+                        AnalysisKind::Differentiation,
+                        vec![x_column_id.clone(), y_column_id.clone()],
+                    )),
+                )
+                .await
+                .map_err(ColumnDifferentiateServiceError::ColumnCreationServiceError)?;
+
+        Ok(ColumnDifferentiateOutputData::new(column_with_cells))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use src_domain::services::numerical_calculus::NumericalCalculusError;
+    use src_in_memory_infrastructure::column::{
+        in_memory_column_factory::InMemoryColumnFactory,
+        in_memory_column_repository::InMemoryColumnRepository,
+    };
+
+    use crate::column::differentiate::column_differentiate_command::{
+        DifferenceMethodInCommand, GapHandlingInCommand,
+    };
+    use crate::test_utils::save_column_in_directory;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let x = save_column_in_directory(
+            &column_repository,
+            "t",
+            "2",
+            vec![Some(0.), Some(1.), Some(2.), Some(3.), Some(4.)],
+        )
+        .await?;
+        let y = save_column_in_directory(
+            &column_repository,
+            "s",
+            "2",
+            vec![Some(0.), Some(1.), None, Some(9.), Some(16.)],
+        )
+        .await?;
+
+        let service = ColumnDifferentiateService::new(&column_factory, &column_repository);
+        let command = ColumnDifferentiateCommand {
+            x_column_id: x.clone_value(),
+            y_column_id: y.clone_value(),
+            method: DifferenceMethodInCommand::Central,
+            gap_handling: GapHandlingInCommand::Split,
+        };
+        let output_data = service.handle(command).await?;
+        assert_eq!(output_data.column_name, "ds/dt");
+        let values: Vec<Option<f64>> = output_data
+            .cells
+            .iter()
+            .map(|cell| cell.cell_value)
+            .collect();
+        assert_eq!(values, vec![Some(1.), Some(1.), None, Some(7.), Some(7.)]);
+
+        // Y カラムと同じディレクトリに保存されている
+        let column = column_repository
+            .find(&ColumnId::new(output_data.column_id)?)
+            .await?
+            .unwrap();
+        assert_eq!(column.directory_id().value(), "2");
+        assert_eq!(column.cells().len(), 5);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handle_non_increasing_x() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let x = save_column_in_directory(&column_repository, "x", "2", vec![Some(1.), Some(0.)])
+            .await?;
+        let y = save_column_in_directory(&column_repository, "y", "2", vec![Some(1.), Some(3.)])
+            .await?;
+
+        let service = ColumnDifferentiateService::new(&column_factory, &column_repository);
+        let command = ColumnDifferentiateCommand {
+            x_column_id: x.clone_value(),
+            y_column_id: y.clone_value(),
+            method: DifferenceMethodInCommand::Forward,
+            gap_handling: GapHandlingInCommand::Bridge,
+        };
+        assert!(matches!(
+            service.handle(command).await,
+            Err(ColumnDifferentiateServiceError::NumericalCalculusError(
+                NumericalCalculusError::NonIncreasingX(1)
+            ))
+        ));
+        assert_eq!(column_repository.find_all().await?.len(), 2);
+        Ok(())
+    }
+}
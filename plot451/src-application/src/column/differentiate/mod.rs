@@ -0,0 +1,10 @@
+/* 数値微分用アプリケーションサービス */
+// コマンドオブジェクト
+pub mod column_differentiate_command;
+
+// アプリケーションサービス
+pub mod column_differentiate_service;
+pub mod column_differentiate_service_impl;
+
+// DTO
+pub mod column_differentiate_output_data;
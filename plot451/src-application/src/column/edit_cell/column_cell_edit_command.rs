@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize)]
+pub struct ColumnCellEditCommand {
+    pub(super) column_id: String,
+    pub(super) cell_id: String,
+    pub(super) cell_value: Option<f64>,
+}
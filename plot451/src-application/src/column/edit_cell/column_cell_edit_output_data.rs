@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+use src_domain::{
+    models::column::{column_cell::column_cell::ColumnCell, column_id::ColumnId},
+    shared::value_object::ValueObject,
+};
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+pub struct ColumnCellEditOutputData {
+    pub(super) cell_id: String,
+    pub(super) cell_value: Option<f64>,
+    // 連動して再計算された派生カラム
+    pub(super) recomputed_column_ids: Vec<String>,
+}
+
+impl ColumnCellEditOutputData {
+    pub(super) fn new(source: ColumnCell, recomputed_column_ids: Vec<ColumnId>) -> Self {
+        Self {
+            cell_id: source.id().clone_value(),
+            cell_value: source.cell_value().clone_value(),
+            recomputed_column_ids: recomputed_column_ids
+                .iter()
+                .map(|column_id| column_id.clone_value())
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use thiserror::Error;
+
+use src_domain::{
+    models::column::{
+        column_cell::{
+            column_cell_id::{ColumnCellId, ColumnCellIdError},
+            column_cell_value::ColumnCellValueError,
+        },
+        column_constraint_specification::ColumnConstraintSpecificationError,
+        column_id::{ColumnId, ColumnIdError},
+        column_repository::ColumnRepositoryError,
+    },
+    services::derived_column_service::DerivedColumnServiceError,
+};
+
+use super::{
+    column_cell_edit_command::ColumnCellEditCommand,
+    column_cell_edit_output_data::ColumnCellEditOutputData,
+};
+
+pub type ColumnCellEditServiceResult<T> = anyhow::Result<T, ColumnCellEditServiceError>;
+
+pub trait IColumnCellEditService {
+    fn handle(
+        &self,
+        command: ColumnCellEditCommand,
+    ) -> impl std::future::Future<Output = ColumnCellEditServiceResult<ColumnCellEditOutputData>> + Send;
+}
+
+#[derive(Debug, Error)]
+pub enum ColumnCellEditServiceError {
+    // repository errors
+    #[error("ColumnRepositoryError: [{0}]")]
+    ColumnRepositoryError(ColumnRepositoryError),
+
+    // value object errors
+    #[error("ColumnIdError: [{0}]")]
+    ColumnIdError(ColumnIdError),
+    #[error("ColumnCellIdError: [{0}]")]
+    ColumnCellIdError(ColumnCellIdError),
+    #[error("ColumnCellValueError: [{0}]")]
+    ColumnCellValueError(ColumnCellValueError),
+
+    // specification errors
+    #[error("ColumnConstraintSpecificationError: [{0}]")]
+    ColumnConstraintSpecificationError(ColumnConstraintSpecificationError),
+
+    // domain service errors
+    #[error("DerivedColumnServiceError: [{0}]")]
+    DerivedColumnServiceError(DerivedColumnServiceError),
+
+    // 派生カラムのセルは数式から計算されるため直接編集できない
+    #[error("Derived column cannot be edited directly, column_id: {0:?}")]
+    DerivedColumnNotEditable(ColumnId),
+
+    // not found errors
+    #[error("Column not found, column_id: {0:?}")]
+    ColumnNotFound(ColumnId),
+    #[error("Cell not found in column, cell_id: {0:?}")]
+    ColumnCellNotFound(ColumnCellId),
+}
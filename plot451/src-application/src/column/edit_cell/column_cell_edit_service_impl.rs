@@ -0,0 +1,367 @@
+use src_domain::{
+    models::column::{
+        column_cell::{column_cell_id::ColumnCellId, column_cell_value::ColumnCellValue},
+        column_constraint_specification::ColumnConstraintsSpecification,
+        column_factory::IColumnFactory,
+        column_id::ColumnId,
+        column_repository::IColumnRepository,
+        column_with_cells::ColumnWithCells,
+    },
+    services::derived_column_service::DerivedColumnService,
+    shared::{specification::Specification, value_object::ValueObject},
+};
+
+use super::{
+    column_cell_edit_command::ColumnCellEditCommand,
+    column_cell_edit_output_data::ColumnCellEditOutputData,
+    column_cell_edit_service::{
+        ColumnCellEditServiceError, ColumnCellEditServiceResult, IColumnCellEditService,
+    },
+};
+
+pub struct ColumnCellEditService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory,
+    CR: IColumnRepository,
+{
+    column_factory: &'a CF,
+    column_repository: &'b CR,
+}
+
+impl<'a, 'b, CF, CR> ColumnCellEditService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory,
+    CR: IColumnRepository,
+{
+    pub fn new(column_factory: &'a CF, column_repository: &'b CR) -> Self {
+        Self {
+            column_factory,
+            column_repository,
+        }
+    }
+}
+
+impl<'a, 'b, CF, CR> IColumnCellEditService for ColumnCellEditService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+{
+    async fn handle(
+        &self,
+        command: ColumnCellEditCommand,
+    ) -> ColumnCellEditServiceResult<ColumnCellEditOutputData> {
+        let ColumnCellEditCommand {
+            column_id,
+            cell_id,
+            cell_value,
+        } = command;
+
+        // 値オブジェクトのインスタンス化
+        let column_id =
+            ColumnId::new(column_id).map_err(ColumnCellEditServiceError::ColumnIdError)?;
+        let cell_id =
+            ColumnCellId::new(cell_id).map_err(ColumnCellEditServiceError::ColumnCellIdError)?;
+        let cell_value = ColumnCellValue::new(cell_value)
+            .map_err(ColumnCellEditServiceError::ColumnCellValueError)?;
+
+        // カラムの取得
+        let column = self
+            .column_repository
+            .find(&column_id)
+            .await
+            .map_err(ColumnCellEditServiceError::ColumnRepositoryError)?
+            .ok_or(ColumnCellEditServiceError::ColumnNotFound(column_id))?;
+        if column.is_derived() {
+            return Err(ColumnCellEditServiceError::DerivedColumnNotEditable(
+                column.id().clone(),
+            ));
+        }
+        if !column.cells().contains(&cell_id) {
+            return Err(ColumnCellEditServiceError::ColumnCellNotFound(cell_id));
+        }
+
+        // 編集後のセルの一覧を作成
+        let mut cells = self
+            .column_repository
+            .find_cells_by_ids(column.cells())
+            .await
+            .map_err(ColumnCellEditServiceError::ColumnRepositoryError)?;
+        let cell = cells.iter_mut().find(|cell| cell.id() == &cell_id).ok_or(
+            ColumnCellEditServiceError::ColumnCellNotFound(cell_id.clone()),
+        )?;
+        cell.edit_cell_value(cell_value);
+        let edited_cell = cell.clone();
+
+        // カラムに設定された制約のチェック
+        let column_with_cells = ColumnWithCells::new(&column, cells);
+        let constraints_spec = ColumnConstraintsSpecification::new(column.constraints().clone());
+        constraints_spec
+            .is_satisfied_by(&column_with_cells)
+            .map_err(ColumnCellEditServiceError::ColumnConstraintSpecificationError)?;
+
+        // このカラムを参照する派生カラムの再計算 (永続化の前にすべてチェックする)
+        let values = column_with_cells
+            .cells()
+            .iter()
+            .map(|cell| cell.cell_value().clone_value())
+            .collect();
+        let derived_column_service =
+            DerivedColumnService::new(self.column_factory, self.column_repository);
+        let recomputed_columns = derived_column_service
+            .compute_dependents(vec![(column, values)])
+            .await
+            .map_err(ColumnCellEditServiceError::DerivedColumnServiceError)?;
+
+        // セルと派生カラムの永続化
+        self.column_repository
+            .save_cell(&edited_cell)
+            .await
+            .map_err(ColumnCellEditServiceError::ColumnRepositoryError)?;
+        let recomputed_column_ids = derived_column_service
+            .save_all(recomputed_columns)
+            .await
+            .map_err(ColumnCellEditServiceError::DerivedColumnServiceError)?;
+
+        Ok(ColumnCellEditOutputData::new(
+            edited_cell,
+            recomputed_column_ids,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use src_domain::models::column::{
+        column::Column, column_cell::column_cell::ColumnCell, column_constraint::ColumnConstraint,
+        column_constraint_specification::ColumnConstraintSpecificationError,
+        column_directory::column_directory_id::ColumnDirectoryId,
+        column_formula::column_formula::ColumnFormula, column_name::ColumnName,
+    };
+    use src_domain::services::derived_column_service::DerivedColumnServiceError;
+    use src_in_memory_infrastructure::column::{
+        in_memory_column_factory::InMemoryColumnFactory,
+        in_memory_column_repository::InMemoryColumnRepository,
+    };
+
+    use super::*;
+
+    // 値が [1, 2, 3] で狭義単調増加の制約を持つカラムを作成する
+    async fn prepare(column_repository: &InMemoryColumnRepository) -> anyhow::Result<()> {
+        let mut cell_ids = vec![];
+        for (i, value) in [1.0, 2.0, 3.0].into_iter().enumerate() {
+            let cell = ColumnCell::new(
+                Some(ColumnCellId::new(format!("cell_id_{}", i + 1))?),
+                ColumnCellValue::new(Some(value))?,
+            );
+            column_repository.save_cell(&cell).await?;
+            cell_ids.push(cell.id().clone());
+        }
+
+        let mut column = Column::new(
+            Some(ColumnId::new("column_id_1".to_string())?),
+            ColumnName::new("column_name_1".to_string())?,
+            ColumnDirectoryId::new("0".to_string())?,
+            cell_ids,
+        );
+        column.change_constraints(vec![ColumnConstraint::StrictlyIncreasing]);
+        column_repository.save(&column).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handle() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        prepare(&column_repository).await?;
+
+        let service = ColumnCellEditService::new(&column_factory, &column_repository);
+        let command = ColumnCellEditCommand {
+            column_id: "column_id_1".to_string(),
+            cell_id: "cell_id_2".to_string(),
+            cell_value: Some(2.5),
+        };
+        let output_data = service.handle(command).await?;
+        assert_eq!(
+            output_data,
+            ColumnCellEditOutputData {
+                cell_id: "cell_id_2".to_string(),
+                cell_value: Some(2.5),
+                recomputed_column_ids: vec![],
+            }
+        );
+
+        // セルの値が更新されていることを確認
+        let cell = column_repository
+            .find_cell(&ColumnCellId::new("cell_id_2".to_string())?)
+            .await?
+            .unwrap();
+        assert_eq!(cell.cell_value().value(), &Some(2.5));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handle_constraint_violated() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        prepare(&column_repository).await?;
+
+        let service = ColumnCellEditService::new(&column_factory, &column_repository);
+        let command = ColumnCellEditCommand {
+            column_id: "column_id_1".to_string(),
+            cell_id: "cell_id_2".to_string(),
+            cell_value: Some(5.0),
+        };
+        match service.handle(command).await {
+            Err(ColumnCellEditServiceError::ColumnConstraintSpecificationError(
+                ColumnConstraintSpecificationError::ConstraintsViolated(violations),
+            )) => {
+                assert_eq!(violations.len(), 1);
+                assert_eq!(
+                    violations[0].cell_ids(),
+                    &vec![ColumnCellId::new("cell_id_3".to_string())?]
+                );
+            }
+            _ => panic!("unexpected result"),
+        }
+
+        // 制約に違反した場合はセルが更新されない
+        let cell = column_repository
+            .find_cell(&ColumnCellId::new("cell_id_2".to_string())?)
+            .await?
+            .unwrap();
+        assert_eq!(cell.cell_value().value(), &Some(2.0));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handle_cell_not_found() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        prepare(&column_repository).await?;
+
+        let service = ColumnCellEditService::new(&column_factory, &column_repository);
+        let command = ColumnCellEditCommand {
+            column_id: "column_id_1".to_string(),
+            cell_id: "cell_id_4".to_string(),
+            cell_value: None,
+        };
+        match service.handle(command).await {
+            Err(ColumnCellEditServiceError::ColumnCellNotFound(_)) => Ok(()),
+            _ => panic!("unexpected result"),
+        }
+    }
+
+    // column_id_1 を参照する派生カラム (2 倍) を作成する
+    async fn prepare_derived(
+        column_factory: &InMemoryColumnFactory,
+        column_repository: &InMemoryColumnRepository,
+    ) -> anyhow::Result<ColumnId> {
+        let mut column = Column::new(
+            None,
+            ColumnName::new("derived".to_string())?,
+            ColumnDirectoryId::new("0".to_string())?,
+            vec![],
+        );
+        column.change_formula(Some(ColumnFormula::new(
+            "col_id(\"column_id_1\") * 2".to_string(),
+        )?));
+        let column_id = column_repository.save(&column).await?;
+        column.set_id(column_id.clone());
+        DerivedColumnService::new(column_factory, column_repository)
+            .recompute(&mut column)
+            .await?;
+        Ok(column_id)
+    }
+
+    #[tokio::test]
+    async fn test_handle_recompute_dependents() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        prepare(&column_repository).await?;
+        let derived_id = prepare_derived(&column_factory, &column_repository).await?;
+
+        let service = ColumnCellEditService::new(&column_factory, &column_repository);
+        let command = ColumnCellEditCommand {
+            column_id: "column_id_1".to_string(),
+            cell_id: "cell_id_3".to_string(),
+            cell_value: Some(10.0),
+        };
+        let output_data = service.handle(command).await?;
+        assert_eq!(
+            output_data.recomputed_column_ids,
+            vec![derived_id.clone_value()]
+        );
+
+        // 派生カラムの値が再計算されていることを確認
+        let derived = column_repository.find(&derived_id).await?.unwrap();
+        let values: Vec<Option<f64>> = column_repository
+            .find_cells_by_ids(derived.cells())
+            .await?
+            .iter()
+            .map(|cell| cell.cell_value().clone_value())
+            .collect();
+        assert_eq!(values, vec![Some(2.0), Some(4.0), Some(20.0)]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handle_recompute_constraint_violated() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        prepare(&column_repository).await?;
+        let derived_id = prepare_derived(&column_factory, &column_repository).await?;
+        let mut derived = column_repository.find(&derived_id).await?.unwrap();
+        derived.change_constraints(vec![ColumnConstraint::Max(10.0)]);
+        column_repository.save(&derived).await?;
+
+        let service = ColumnCellEditService::new(&column_factory, &column_repository);
+        let command = ColumnCellEditCommand {
+            column_id: "column_id_1".to_string(),
+            cell_id: "cell_id_3".to_string(),
+            cell_value: Some(10.0),
+        };
+        assert!(matches!(
+            service.handle(command).await,
+            Err(ColumnCellEditServiceError::DerivedColumnServiceError(
+                DerivedColumnServiceError::ColumnConstraintSpecificationError(_)
+            ))
+        ));
+
+        // 再計算後の値が制約に違反する場合は派生カラムのセルが更新されない
+        let values: Vec<Option<f64>> = column_repository
+            .find_cells_by_ids(derived.cells())
+            .await?
+            .iter()
+            .map(|cell| cell.cell_value().clone_value())
+            .collect();
+        assert_eq!(values, vec![Some(2.0), Some(4.0), Some(6.0)]);
+
+        // 編集したセルも更新されない
+        let cell = column_repository
+            .find_cell(&ColumnCellId::new("cell_id_3".to_string())?)
+            .await?
+            .unwrap();
+        assert_eq!(cell.cell_value().value(), &Some(3.0));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handle_derived_column() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        prepare(&column_repository).await?;
+        let derived_id = prepare_derived(&column_factory, &column_repository).await?;
+        let derived = column_repository.find(&derived_id).await?.unwrap();
+
+        let service = ColumnCellEditService::new(&column_factory, &column_repository);
+        let command = ColumnCellEditCommand {
+            column_id: derived_id.clone_value(),
+            cell_id: derived.cells()[0].clone_value(),
+            cell_value: Some(0.0),
+        };
+        match service.handle(command).await {
+            Err(ColumnCellEditServiceError::DerivedColumnNotEditable(_)) => Ok(()),
+            _ => panic!("unexpected result"),
+        }
+    }
+}
@@ -0,0 +1,10 @@
+/* セル編集用アプリケーションサービス */
+// コマンドオブジェクト
+pub mod column_cell_edit_command;
+
+// アプリケーションサービス
+pub mod column_cell_edit_service;
+pub mod column_cell_edit_service_impl;
+
+// DTO
+pub mod column_cell_edit_output_data;
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+use crate::column::create::column_create_command::ColumnConstraintInCommand;
+
+#[derive(Deserialize, Serialize)]
+pub struct ColumnConstraintsEditCommand {
+    pub(super) column_id: String,
+    pub(super) constraints: Vec<ColumnConstraintInCommand>,
+}
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+use src_domain::{
+    models::column::{column::Column, column_constraint::ColumnConstraint},
+    shared::value_object::ValueObject,
+};
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+pub struct ColumnConstraintsEditOutputData {
+    pub(super) column_id: String,
+    pub(super) constraints: Vec<ColumnConstraintInOutputData>,
+}
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub(super) enum ColumnConstraintInOutputData {
+    NonNull,
+    Min { value: f64 },
+    Max { value: f64 },
+    StrictlyIncreasing,
+    IntegerOnly,
+}
+
+impl ColumnConstraintsEditOutputData {
+    pub(super) fn new(source: Column) -> Self {
+        Self {
+            column_id: source.id().clone_value(),
+            constraints: source
+                .constraints()
+                .iter()
+                .map(|constraint| match constraint {
+                    ColumnConstraint::NonNull => ColumnConstraintInOutputData::NonNull,
+                    ColumnConstraint::Min(value) => {
+                        ColumnConstraintInOutputData::Min { value: *value }
+                    }
+                    ColumnConstraint::Max(value) => {
+                        ColumnConstraintInOutputData::Max { value: *value }
+                    }
+                    ColumnConstraint::StrictlyIncreasing => {
+                        ColumnConstraintInOutputData::StrictlyIncreasing
+                    }
+                    ColumnConstraint::IntegerOnly => ColumnConstraintInOutputData::IntegerOnly,
+                })
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use thiserror::Error;
+
+use src_domain::models::column::{
+    column_constraint_specification::ColumnConstraintSpecificationError,
+    column_id::{ColumnId, ColumnIdError},
+    column_repository::ColumnRepositoryError,
+};
+
+use super::{
+    column_constraints_edit_command::ColumnConstraintsEditCommand,
+    column_constraints_edit_output_data::ColumnConstraintsEditOutputData,
+};
+
+pub type ColumnConstraintsEditServiceResult<T> =
+    anyhow::Result<T, ColumnConstraintsEditServiceError>;
+
+pub trait IColumnConstraintsEditService {
+    fn handle(
+        &self,
+        command: ColumnConstraintsEditCommand,
+    ) -> impl std::future::Future<
+        Output = ColumnConstraintsEditServiceResult<ColumnConstraintsEditOutputData>,
+    > + Send;
+}
+
+#[derive(Debug, Error)]
+pub enum ColumnConstraintsEditServiceError {
+    // repository errors
+    #[error("ColumnRepositoryError: [{0}]")]
+    ColumnRepositoryError(ColumnRepositoryError),
+
+    // value object errors
+    #[error("ColumnIdError: [{0}]")]
+    ColumnIdError(ColumnIdError),
+
+    // specification errors
+    #[error("ColumnConstraintSpecificationError: [{0}]")]
+    ColumnConstraintSpecificationError(ColumnConstraintSpecificationError),
+
+    // not found errors
+    #[error("Column not found, column_id: {0:?}")]
+    ColumnNotFound(ColumnId),
+}
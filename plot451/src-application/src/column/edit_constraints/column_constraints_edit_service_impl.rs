@@ -0,0 +1,190 @@
+use src_domain::{
+    models::column::{
+        column_constraint_specification::ColumnConstraintsSpecification, column_id::ColumnId,
+        column_repository::IColumnRepository, column_with_cells::ColumnWithCells,
+    },
+    shared::{specification::Specification, value_object::ValueObject},
+};
+
+use super::{
+    column_constraints_edit_command::ColumnConstraintsEditCommand,
+    column_constraints_edit_output_data::ColumnConstraintsEditOutputData,
+    column_constraints_edit_service::{
+        ColumnConstraintsEditServiceError, ColumnConstraintsEditServiceResult,
+        IColumnConstraintsEditService,
+    },
+};
+
+pub struct ColumnConstraintsEditService<'a, CR>
+where
+    CR: IColumnRepository,
+{
+    column_repository: &'a CR,
+}
+
+impl<'a, CR> ColumnConstraintsEditService<'a, CR>
+where
+    CR: IColumnRepository,
+{
+    pub fn new(column_repository: &'a CR) -> Self {
+        Self { column_repository }
+    }
+}
+
+impl<'a, CR> IColumnConstraintsEditService for ColumnConstraintsEditService<'a, CR>
+where
+    CR: IColumnRepository + Sync,
+{
+    async fn handle(
+        &self,
+        command: ColumnConstraintsEditCommand,
+    ) -> ColumnConstraintsEditServiceResult<ColumnConstraintsEditOutputData> {
+        let ColumnConstraintsEditCommand {
+            column_id,
+            constraints,
+        } = command;
+
+        // 値オブジェクトのインスタンス化
+        let column_id =
+            ColumnId::new(column_id).map_err(ColumnConstraintsEditServiceError::ColumnIdError)?;
+        let constraints = constraints
+            .iter()
+            .map(|constraint| constraint.to_column_constraint())
+            .collect();
+
+        // カラムの取得
+        let mut column = self
+            .column_repository
+            .find(&column_id)
+            .await
+            .map_err(ColumnConstraintsEditServiceError::ColumnRepositoryError)?
+            .ok_or(ColumnConstraintsEditServiceError::ColumnNotFound(column_id))?;
+        column.change_constraints(constraints);
+
+        // 既存のセルが新しい制約を満たすかチェック
+        let cells = self
+            .column_repository
+            .find_cells_by_ids(column.cells())
+            .await
+            .map_err(ColumnConstraintsEditServiceError::ColumnRepositoryError)?;
+        let column_with_cells = ColumnWithCells::new(&column, cells);
+        let constraints_spec = ColumnConstraintsSpecification::new(column.constraints().clone());
+        constraints_spec
+            .is_satisfied_by(&column_with_cells)
+            .map_err(ColumnConstraintsEditServiceError::ColumnConstraintSpecificationError)?;
+
+        // カラムの永続化
+        self.column_repository
+            .save(&column)
+            .await
+            .map_err(ColumnConstraintsEditServiceError::ColumnRepositoryError)?;
+
+        Ok(ColumnConstraintsEditOutputData::new(column))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use src_domain::models::column::{
+        column::Column,
+        column_cell::{
+            column_cell::ColumnCell, column_cell_id::ColumnCellId,
+            column_cell_value::ColumnCellValue,
+        },
+        column_constraint::ColumnConstraint,
+        column_directory::column_directory_id::ColumnDirectoryId,
+        column_name::ColumnName,
+    };
+    use src_in_memory_infrastructure::column::in_memory_column_repository::InMemoryColumnRepository;
+
+    use crate::column::create::column_create_command::ColumnConstraintInCommand;
+    use crate::column::edit_constraints::column_constraints_edit_output_data::ColumnConstraintInOutputData;
+
+    use super::*;
+
+    // 値が [1, None, 2.5] のカラムを作成する
+    async fn prepare(column_repository: &InMemoryColumnRepository) -> anyhow::Result<()> {
+        let mut cell_ids = vec![];
+        for (i, value) in [Some(1.0), None, Some(2.5)].into_iter().enumerate() {
+            let cell = ColumnCell::new(
+                Some(ColumnCellId::new(format!("cell_id_{}", i + 1))?),
+                ColumnCellValue::new(value)?,
+            );
+            column_repository.save_cell(&cell).await?;
+            cell_ids.push(cell.id().clone());
+        }
+
+        let column = Column::new(
+            Some(ColumnId::new("column_id_1".to_string())?),
+            ColumnName::new("column_name_1".to_string())?,
+            ColumnDirectoryId::new("0".to_string())?,
+            cell_ids,
+        );
+        column_repository.save(&column).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handle() -> anyhow::Result<()> {
+        let column_repository = InMemoryColumnRepository::new();
+        prepare(&column_repository).await?;
+
+        let service = ColumnConstraintsEditService::new(&column_repository);
+        let command = ColumnConstraintsEditCommand {
+            column_id: "column_id_1".to_string(),
+            constraints: vec![
+                ColumnConstraintInCommand::Min { value: 0.0 },
+                ColumnConstraintInCommand::StrictlyIncreasing,
+            ],
+        };
+        let ColumnConstraintsEditOutputData { constraints, .. } = service.handle(command).await?;
+        assert_eq!(
+            constraints,
+            vec![
+                ColumnConstraintInOutputData::Min { value: 0.0 },
+                ColumnConstraintInOutputData::StrictlyIncreasing,
+            ]
+        );
+
+        // 制約が永続化されていることを確認
+        let column = column_repository
+            .find(&ColumnId::new("column_id_1".to_string())?)
+            .await?
+            .unwrap();
+        assert_eq!(
+            column.constraints(),
+            &vec![
+                ColumnConstraint::Min(0.0),
+                ColumnConstraint::StrictlyIncreasing
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handle_existing_cells_violate() -> anyhow::Result<()> {
+        let column_repository = InMemoryColumnRepository::new();
+        prepare(&column_repository).await?;
+
+        let service = ColumnConstraintsEditService::new(&column_repository);
+        let command = ColumnConstraintsEditCommand {
+            column_id: "column_id_1".to_string(),
+            constraints: vec![
+                ColumnConstraintInCommand::NonNull,
+                ColumnConstraintInCommand::IntegerOnly,
+            ],
+        };
+        match service.handle(command).await {
+            Err(ColumnConstraintsEditServiceError::ColumnConstraintSpecificationError(_)) => {}
+            _ => panic!("unexpected result"),
+        }
+
+        // 制約に違反した場合は制約が設定されない
+        let column = column_repository
+            .find(&ColumnId::new("column_id_1".to_string())?)
+            .await?
+            .unwrap();
+        assert!(column.constraints().is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,10 @@
+/* カラムの制約編集用アプリケーションサービス */
+// コマンドオブジェクト
+pub mod column_constraints_edit_command;
+
+// アプリケーションサービス
+pub mod column_constraints_edit_service;
+pub mod column_constraints_edit_service_impl;
+
+// DTO
+pub mod column_constraints_edit_output_data;
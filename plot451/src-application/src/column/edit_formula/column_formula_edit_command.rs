@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize)]
+pub struct ColumnFormulaEditCommand {
+    pub(super) column_id: String,
+    // None の場合は数式を外して通常のカラムに戻す (セルの値はそのまま残る)
+    pub(super) expression: Option<String>,
+}
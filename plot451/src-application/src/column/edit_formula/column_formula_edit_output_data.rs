@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+use src_domain::{
+    models::column::{column::Column, column_id::ColumnId, column_with_cells::ColumnWithCells},
+    shared::value_object::ValueObject,
+};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct ColumnFormulaEditOutputData {
+    pub(super) column_id: String,
+    pub(super) expression: Option<String>,
+    pub(super) dependencies: Vec<String>,
+    pub(super) cells: Vec<ColumnCellInOutputData>,
+    // 連動して再計算された派生カラム
+    pub(super) recomputed_column_ids: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub(super) struct ColumnCellInOutputData {
+    pub(super) cell_id: String,
+    pub(super) cell_value: Option<f64>,
+}
+
+impl ColumnFormulaEditOutputData {
+    pub(super) fn new(
+        column: &Column,
+        source: ColumnWithCells,
+        recomputed_column_ids: Vec<ColumnId>,
+    ) -> Self {
+        Self {
+            column_id: source.id().clone_value(),
+            expression: column
+                .formula()
+                .as_ref()
+                .map(|formula| formula.clone_value()),
+            dependencies: column
+                .formula()
+                .as_ref()
+                .map(|formula| {
+                    formula
+                        .dependencies()
+                        .iter()
+                        .map(|column_id| column_id.clone_value())
+                        .collect()
+                })
+                .unwrap_or_default(),
+            cells: source
+                .cells()
+                .iter()
+                .map(|cell| ColumnCellInOutputData {
+                    cell_id: cell.id().clone_value(),
+                    cell_value: cell.cell_value().clone_value(),
+                })
+                .collect(),
+            recomputed_column_ids: recomputed_column_ids
+                .iter()
+                .map(|column_id| column_id.clone_value())
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use thiserror::Error;
+
+use src_domain::{
+    models::column::{
+        column_formula::formula_expression::FormulaExpressionError,
+        column_id::{ColumnId, ColumnIdError},
+        column_repository::ColumnRepositoryError,
+    },
+    services::derived_column_service::DerivedColumnServiceError,
+};
+
+use super::{
+    column_formula_edit_command::ColumnFormulaEditCommand,
+    column_formula_edit_output_data::ColumnFormulaEditOutputData,
+};
+
+pub type ColumnFormulaEditServiceResult<T> = anyhow::Result<T, ColumnFormulaEditServiceError>;
+
+pub trait IColumnFormulaEditService {
+    fn handle(
+        &self,
+        command: ColumnFormulaEditCommand,
+    ) -> impl std::future::Future<Output = ColumnFormulaEditServiceResult<ColumnFormulaEditOutputData>>
+           + Send;
+}
+
+#[derive(Debug, Error)]
+pub enum ColumnFormulaEditServiceError {
+    // repository errors
+    #[error("ColumnRepositoryError: [{0}]")]
+    ColumnRepositoryError(ColumnRepositoryError),
+
+    // value object errors
+    #[error("ColumnIdError: [{0}]")]
+    ColumnIdError(ColumnIdError),
+    #[error("FormulaExpressionError: [{0}]")]
+    FormulaExpressionError(FormulaExpressionError),
+
+    // domain service errors
+    #[error("DerivedColumnServiceError: [{0}]")]
+    DerivedColumnServiceError(DerivedColumnServiceError),
+
+    // dependency errors
+    #[error("Cyclic dependency, path: {0:?}")]
+    CyclicDependency(Vec<ColumnId>),
+
+    // not found errors
+    #[error("Column not found, column_id: {0:?}")]
+    ColumnNotFound(ColumnId),
+}
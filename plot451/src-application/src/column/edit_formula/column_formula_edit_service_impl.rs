@@ -0,0 +1,284 @@
+use src_domain::{
+    models::column::{
+        column_factory::IColumnFactory,
+        column_formula::{
+            column_dependency_graph::ColumnDependencyGraph, column_formula::ColumnFormula,
+        },
+        column_id::ColumnId,
+        column_repository::IColumnRepository,
+        column_with_cells::ColumnWithCells,
+    },
+    services::{
+        column_values_service::ColumnValuesService, derived_column_service::DerivedColumnService,
+    },
+    shared::value_object::ValueObject,
+};
+
+use super::{
+    column_formula_edit_command::ColumnFormulaEditCommand,
+    column_formula_edit_output_data::ColumnFormulaEditOutputData,
+    column_formula_edit_service::{
+        ColumnFormulaEditServiceError, ColumnFormulaEditServiceResult, IColumnFormulaEditService,
+    },
+};
+
+pub struct ColumnFormulaEditService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory,
+    CR: IColumnRepository,
+{
+    column_factory: &'a CF,
+    column_repository: &'b CR,
+}
+
+impl<'a, 'b, CF, CR> ColumnFormulaEditService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory,
+    CR: IColumnRepository,
+{
+    pub fn new(column_factory: &'a CF, column_repository: &'b CR) -> Self {
+        Self {
+            column_factory,
+            column_repository,
+        }
+    }
+}
+
+impl<'a, 'b, CF, CR> IColumnFormulaEditService for ColumnFormulaEditService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+{
+    async fn handle(
+        &self,
+        command: ColumnFormulaEditCommand,
+    ) -> ColumnFormulaEditServiceResult<ColumnFormulaEditOutputData> {
+        let ColumnFormulaEditCommand {
+            column_id,
+            expression,
+        } = command;
+
+        // 値オブジェクトのインスタンス化
+        let column_id =
+            ColumnId::new(column_id).map_err(ColumnFormulaEditServiceError::ColumnIdError)?;
+        let formula = expression
+            .map(ColumnFormula::new)
+            .transpose()
+            .map_err(ColumnFormulaEditServiceError::FormulaExpressionError)?;
+
+        // カラムの取得
+        let mut column = self
+            .column_repository
+            .find(&column_id)
+            .await
+            .map_err(ColumnFormulaEditServiceError::ColumnRepositoryError)?
+            .ok_or(ColumnFormulaEditServiceError::ColumnNotFound(
+                column_id.clone(),
+            ))?;
+
+        match formula {
+            Some(formula) => {
+                // カラム名による参照は同じディレクトリ内のカラムから解決する
+                let candidates = self
+                    .column_repository
+                    .find_by_directory_id(column.directory_id())
+                    .await
+                    .map_err(ColumnFormulaEditServiceError::ColumnRepositoryError)?;
+                let formula = formula
+                    .resolve(&candidates)
+                    .map_err(ColumnFormulaEditServiceError::FormulaExpressionError)?;
+
+                // 循環参照のチェック
+                let columns = self
+                    .column_repository
+                    .find_all()
+                    .await
+                    .map_err(ColumnFormulaEditServiceError::ColumnRepositoryError)?;
+                if let Some(cycle) = ColumnDependencyGraph::new(&columns)
+                    .find_cycle(&column_id, &formula.dependencies())
+                {
+                    return Err(ColumnFormulaEditServiceError::CyclicDependency(cycle));
+                }
+
+                column.change_formula(Some(formula));
+            }
+            None => column.change_formula(None),
+        }
+
+        // このカラム (派生カラムの場合) と参照する派生カラムの再計算
+        // 制約のチェックまで終えてから永続化する
+        let values = ColumnValuesService::new(self.column_repository)
+            .find_values(&column)
+            .await
+            .map_err(ColumnFormulaEditServiceError::ColumnRepositoryError)?;
+        let derived_column_service =
+            DerivedColumnService::new(self.column_factory, self.column_repository);
+        let recomputed_columns = derived_column_service
+            .compute_dependents(vec![(column.clone(), values)])
+            .await
+            .map_err(ColumnFormulaEditServiceError::DerivedColumnServiceError)?;
+
+        // 永続化
+        self.column_repository
+            .save(&column)
+            .await
+            .map_err(ColumnFormulaEditServiceError::ColumnRepositoryError)?;
+        let mut recomputed_column_ids =
+            derived_column_service
+                .save_all(recomputed_columns)
+                .await
+                .map_err(ColumnFormulaEditServiceError::DerivedColumnServiceError)?;
+        recomputed_column_ids.retain(|recomputed_id| recomputed_id != &column_id);
+
+        let column = self
+            .column_repository
+            .find(&column_id)
+            .await
+            .map_err(ColumnFormulaEditServiceError::ColumnRepositoryError)?
+            .ok_or(ColumnFormulaEditServiceError::ColumnNotFound(
+                column_id.clone(),
+            ))?;
+        let cells = self
+            .column_repository
+            .find_cells_by_ids(column.cells())
+            .await
+            .map_err(ColumnFormulaEditServiceError::ColumnRepositoryError)?;
+        let column_with_cells = ColumnWithCells::new(&column, cells);
+        Ok(ColumnFormulaEditOutputData::new(
+            &column,
+            column_with_cells,
+            recomputed_column_ids,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use src_in_memory_infrastructure::column::{
+        in_memory_column_factory::InMemoryColumnFactory,
+        in_memory_column_repository::InMemoryColumnRepository,
+    };
+
+    use crate::test_utils::save_column;
+
+    use super::*;
+
+    async fn set_formula(
+        column_repository: &InMemoryColumnRepository,
+        column_id: &ColumnId,
+        expression: String,
+    ) -> anyhow::Result<()> {
+        let mut column = column_repository.find(column_id).await?.unwrap();
+        column.change_formula(Some(ColumnFormula::new(expression)?));
+        column_repository.save(&column).await?;
+        Ok(())
+    }
+
+    async fn values(
+        column_repository: &InMemoryColumnRepository,
+        column_id: &ColumnId,
+    ) -> anyhow::Result<Vec<Option<f64>>> {
+        let (_, values) = ColumnValuesService::new(column_repository)
+            .find_column_values(column_id)
+            .await?
+            .unwrap();
+        Ok(values)
+    }
+
+    // x = [1, 2, 3], y = 2x (x の派生カラム), z = y + 1 (y の派生カラム)
+    async fn prepare(
+        column_repository: &InMemoryColumnRepository,
+    ) -> anyhow::Result<(ColumnId, ColumnId, ColumnId)> {
+        let x = save_column(column_repository, "x", vec![Some(1.), Some(2.), Some(3.)]).await?;
+        let y = save_column(column_repository, "y", vec![Some(2.), Some(4.), Some(6.)]).await?;
+        set_formula(
+            column_repository,
+            &y,
+            format!("col_id(\"{}\") * 2", x.value()),
+        )
+        .await?;
+        let z = save_column(column_repository, "z", vec![Some(3.), Some(5.), Some(7.)]).await?;
+        set_formula(
+            column_repository,
+            &z,
+            format!("col_id(\"{}\") + 1", y.value()),
+        )
+        .await?;
+        Ok((x, y, z))
+    }
+
+    #[tokio::test]
+    async fn test_handle() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let (_, y, z) = prepare(&column_repository).await?;
+
+        // y = x^2 に変更すると z も再計算される
+        let service = ColumnFormulaEditService::new(&column_factory, &column_repository);
+        let command = ColumnFormulaEditCommand {
+            column_id: y.clone_value(),
+            expression: Some("col(\"x\")^2".to_string()),
+        };
+        let output_data = service.handle(command).await?;
+        assert_eq!(output_data.recomputed_column_ids, vec![z.clone_value()]);
+        assert_eq!(
+            values(&column_repository, &y).await?,
+            vec![Some(1.), Some(4.), Some(9.)]
+        );
+        assert_eq!(
+            values(&column_repository, &z).await?,
+            vec![Some(2.), Some(5.), Some(10.)]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handle_cyclic_dependency() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let (x, y, z) = prepare(&column_repository).await?;
+
+        // x = z とすると x -> z -> y -> x の循環参照になる
+        let service = ColumnFormulaEditService::new(&column_factory, &column_repository);
+        let command = ColumnFormulaEditCommand {
+            column_id: x.clone_value(),
+            expression: Some("col(\"z\")".to_string()),
+        };
+        match service.handle(command).await {
+            Err(ColumnFormulaEditServiceError::CyclicDependency(cycle)) => {
+                assert_eq!(cycle, vec![x.clone(), z, y, x.clone()]);
+            }
+            _ => panic!("unexpected result"),
+        }
+
+        // 循環参照の場合は数式が設定されない
+        let column = column_repository.find(&x).await?.unwrap();
+        assert!(!column.is_derived());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handle_remove_formula() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let (_, y, _) = prepare(&column_repository).await?;
+
+        let service = ColumnFormulaEditService::new(&column_factory, &column_repository);
+        let command = ColumnFormulaEditCommand {
+            column_id: y.clone_value(),
+            expression: None,
+        };
+        let output_data = service.handle(command).await?;
+        assert_eq!(output_data.expression, None);
+
+        // 数式を外しても値は残る
+        let column = column_repository.find(&y).await?.unwrap();
+        assert!(!column.is_derived());
+        assert_eq!(
+            values(&column_repository, &y).await?,
+            vec![Some(2.), Some(4.), Some(6.)]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,10 @@
+/* 派生カラムの数式編集用アプリケーションサービス */
+// コマンドオブジェクト
+pub mod column_formula_edit_command;
+
+// アプリケーションサービス
+pub mod column_formula_edit_service;
+pub mod column_formula_edit_service_impl;
+
+// DTO
+pub mod column_formula_edit_output_data;
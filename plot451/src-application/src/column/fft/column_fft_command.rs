@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+
+use src_domain::services::fourier::{WindowFunction, ZeroPadding};
+
+#[derive(Deserialize, Serialize)]
+pub struct ColumnFftCommand {
+    pub(super) y_column_id: String,
+    pub(super) sampling: SamplingInCommand,
+    #[serde(default)]
+    pub(super) window: WindowFunctionInCommand,
+    #[serde(default)]
+    pub(super) zero_padding: ZeroPaddingInCommand,
+    // 指定した場合は周波数帯域を絞って逆変換した信号のカラムも作成する
+    #[serde(default)]
+    pub(super) reconstruction: Option<FrequencyBandInCommand>,
+}
+
+// 標本化間隔の指定
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SamplingInCommand {
+    // 間隔を直接指定する
+    Interval { interval: f64 },
+    // 等間隔の x のカラムから求める
+    Column { column_id: String },
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum WindowFunctionInCommand {
+    #[default]
+    Rectangular,
+    Hann,
+    Hamming,
+    Blackman,
+}
+
+impl WindowFunctionInCommand {
+    pub(super) fn to_window_function(self) -> WindowFunction {
+        match self {
+            WindowFunctionInCommand::Rectangular => WindowFunction::Rectangular,
+            WindowFunctionInCommand::Hann => WindowFunction::Hann,
+            WindowFunctionInCommand::Hamming => WindowFunction::Hamming,
+            WindowFunctionInCommand::Blackman => WindowFunction::Blackman,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Default)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ZeroPaddingInCommand {
+    #[default]
+    None,
+    NextPowerOfTwo,
+    Length {
+        length: usize,
+    },
+}
+
+impl ZeroPaddingInCommand {
+    pub(super) fn to_zero_padding(self) -> ZeroPadding {
+        match self {
+            ZeroPaddingInCommand::None => ZeroPadding::None,
+            ZeroPaddingInCommand::NextPowerOfTwo => ZeroPadding::NextPowerOfTwo,
+            ZeroPaddingInCommand::Length { length } => ZeroPadding::Length(length),
+        }
+    }
+}
+
+// 残す周波数の範囲 (省略した側は制限しない)
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+pub struct FrequencyBandInCommand {
+    #[serde(default)]
+    pub(super) low: Option<f64>,
+    #[serde(default)]
+    pub(super) high: Option<f64>,
+}
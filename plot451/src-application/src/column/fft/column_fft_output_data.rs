@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+
+use src_domain::{
+    models::column::column_id::ColumnId, services::fourier::Spectrum,
+    shared::value_object::ValueObject,
+};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct ColumnFftOutputData {
+    pub(super) sampling_interval: f64,
+    // ゼロ詰め後の長さ
+    pub(super) length: usize,
+    pub(super) frequency_resolution: f64,
+    pub(super) frequencies: Vec<f64>,
+    pub(super) amplitudes: Vec<f64>,
+    pub(super) phases: Vec<f64>,
+    pub(super) power_spectral_density: Vec<f64>,
+    // 作成したカラム
+    pub(super) frequency_column_id: String,
+    pub(super) amplitude_column_id: String,
+    pub(super) phase_column_id: String,
+    pub(super) power_spectral_density_column_id: String,
+    pub(super) reconstructed_column_id: Option<String>,
+}
+
+// 作成したカラムの ID
+pub(super) struct SpectrumColumnIds {
+    pub(super) frequency: ColumnId,
+    pub(super) amplitude: ColumnId,
+    pub(super) phase: ColumnId,
+    pub(super) power_spectral_density: ColumnId,
+    pub(super) reconstructed: Option<ColumnId>,
+}
+
+impl ColumnFftOutputData {
+    pub(super) fn new(spectrum: &Spectrum, column_ids: SpectrumColumnIds) -> Self {
+        Self {
+            sampling_interval: spectrum.sampling_interval(),
+            length: spectrum.length(),
+            frequency_resolution: 1. / (spectrum.length() as f64 * spectrum.sampling_interval()),
+            frequencies: spectrum.frequencies().clone(),
+            amplitudes: spectrum.amplitudes().clone(),
+            phases: spectrum.phases().clone(),
+            power_spectral_density: spectrum.power_spectral_density().clone(),
+            frequency_column_id: column_ids.frequency.clone_value(),
+            amplitude_column_id: column_ids.amplitude.clone_value(),
+            phase_column_id: column_ids.phase.clone_value(),
+            power_spectral_density_column_id: column_ids.power_spectral_density.clone_value(),
+            reconstructed_column_id: column_ids
+                .reconstructed
+                .map(|column_id| column_id.clone_value()),
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use thiserror::Error;
+
+use src_domain::{
+    models::column::{
+        column_id::{ColumnId, ColumnIdError},
+        column_name::ColumnNameError,
+        column_repository::ColumnRepositoryError,
+    },
+    services::{column_creation_service::ColumnCreationServiceError, fourier::FourierError},
+};
+
+use super::{column_fft_command::ColumnFftCommand, column_fft_output_data::ColumnFftOutputData};
+
+pub type ColumnFftServiceResult<T> = anyhow::Result<T, ColumnFftServiceError>;
+
+pub trait IColumnFftService {
+    fn handle(
+        &self,
+        command: ColumnFftCommand,
+    ) -> impl std::future::Future<Output = ColumnFftServiceResult<ColumnFftOutputData>> + Send;
+}
+
+#[derive(Debug, Error)]
+pub enum ColumnFftServiceError {
+    // repository errors
+    #[error("ColumnRepositoryError: [{0}]")]
+    ColumnRepositoryError(ColumnRepositoryError),
+
+    // value object errors
+    #[error("ColumnIdError: [{0}]")]
+    ColumnIdError(ColumnIdError),
+    #[error("ColumnNameError: [{0}]")]
+    ColumnNameError(ColumnNameError),
+
+    // domain service errors
+    #[error("FourierError: [{0}]")]
+    FourierError(FourierError),
+    #[error("ColumnCreationServiceError: [{0}]")]
+    ColumnCreationServiceError(ColumnCreationServiceError),
+
+    // command errors
+    #[error("numbers of x and y values differ: x {0}, y {1}")]
+    LengthMismatch(usize, usize),
+
+    // not found errors
+    #[error("Column not found, column_id: {0:?}")]
+    ColumnNotFound(ColumnId),
+}
@@ -0,0 +1,354 @@
+use src_domain::{
+    models::column::{
+        column::Column,
+        column_cell::column_cell_value::CellRawValue,
+        column_factory::IColumnFactory,
+        column_id::ColumnId,
+        column_name::ColumnName,
+        column_provenance::{AnalysisKind, ColumnAnalysis, ColumnProvenance},
+        column_repository::IColumnRepository,
+    },
+    services::{
+        column_creation_service::ColumnCreationService,
+        column_values_service::ColumnValuesService,
+        fourier::{sampling_interval, Spectrum},
+    },
+    shared::value_object::ValueObject,
+};
+
+use super::{
+    column_fft_command::{ColumnFftCommand, SamplingInCommand},
+    column_fft_output_data::{ColumnFftOutputData, SpectrumColumnIds},
+    column_fft_service::{ColumnFftServiceError, ColumnFftServiceResult, IColumnFftService},
+};
+
+pub struct ColumnFftService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory,
+    CR: IColumnRepository,
+{
+    column_factory: &'a CF,
+    column_repository: &'b CR,
+}
+
+impl<'a, 'b, CF, CR> ColumnFftService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+{
+    pub fn new(column_factory: &'a CF, column_repository: &'b CR) -> Self {
+        Self {
+            column_factory,
+            column_repository,
+        }
+    }
+
+    // Y カラムと同じディレクトリに "{y} {suffix}" という名前のカラムを作成する
+    async fn create_column(
+        &self,
+        y_column: &Column,
+        suffix: &str,
+        values: Vec<CellRawValue>,
+        provenance: &ColumnProvenance,
+    ) -> ColumnFftServiceResult<ColumnId> {
+        let name = ColumnName::new(format!("{} {}", y_column.name(), suffix))
+            .map_err(ColumnFftServiceError::ColumnNameError)?;
+        let (column, _) = ColumnCreationService::new(self.column_factory, self.column_repository)
+            .create_column_from(
+                name,
+                y_column.directory_id().clone(),
+                values,
+                provenance.clone(),
+            )
+            .await
+            .map_err(ColumnFftServiceError::ColumnCreationServiceError)?;
+        Ok(column.id().clone())
+    }
+}
+
+impl<'a, 'b, CF, CR> IColumnFftService for ColumnFftService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+{
+    async fn handle(
+        &self,
+        command: ColumnFftCommand,
+    ) -> ColumnFftServiceResult<ColumnFftOutputData> {
+        let ColumnFftCommand {
+            y_column_id,
+            sampling,
+            window,
+            zero_padding,
+            reconstruction,
+        } = command;
+
+        // 値オブジェクトのインスタンス化
+        let y_column_id =
+            ColumnId::new(y_column_id).map_err(ColumnFftServiceError::ColumnIdError)?;
+
+        // 信号とする Y カラムの値
+        let (y_column, y_values) = ColumnValuesService::new(self.column_repository)
+            .find_column_values(&y_column_id)
+            .await
+            .map_err(ColumnFftServiceError::ColumnRepositoryError)?
+            .ok_or(ColumnFftServiceError::ColumnNotFound(y_column_id.clone()))?;
+
+        // 標本化間隔
+        let mut sources = vec![y_column_id.clone()];
+        let interval = match sampling {
+            SamplingInCommand::Interval { interval } => interval,
+            SamplingInCommand::Column { column_id } => {
+                let column_id =
+                    ColumnId::new(column_id).map_err(ColumnFftServiceError::ColumnIdError)?;
+                let (_, x_values) = ColumnValuesService::new(self.column_repository)
+                    .find_column_values(&column_id)
+                    .await
+                    .map_err(ColumnFftServiceError::ColumnRepositoryError)?
+                    .ok_or(ColumnFftServiceError::ColumnNotFound(column_id.clone()))?;
+                if x_values.len() != y_values.len() {
+                    return Err(ColumnFftServiceError::LengthMismatch(
+                        x_values.len(),
+                        y_values.len(),
+                    ));
+                }
+                sources.push(column_id);
+                sampling_interval(&x_values).map_err(ColumnFftServiceError::FourierError)?
+            }
+        };
+
+        // スペクトルの計算
+        let spectrum = Spectrum::compute(
+            &y_values,
+            interval,
+            window.to_window_function(),
+            zero_padding.to_zero_padding(),
+        )
+        .map_err(ColumnFftServiceError::FourierError)?;
+
+        // 結果をカラムとして保存する
+        let provenance = ColumnProvenance::Analysis(ColumnAnalysis::new(
+            AnalysisKind::FourierTransform,
+            sources,
+        ));
+        let to_cells = |values: &Vec<f64>| values.iter().copied().map(Some).collect();
+        let column_ids = SpectrumColumnIds {
+            frequency: self
+                .create_column(
+                    &y_column,
+                    "frequency",
+                    to_cells(spectrum.frequencies()),
+                    &provenance,
+                )
+                .await?,
+            amplitude: self
+                .create_column(
+                    &y_column,
+                    "amplitude",
+                    to_cells(spectrum.amplitudes()),
+                    &provenance,
+                )
+                .await?,
+            phase: self
+                .create_column(&y_column, "phase", to_cells(spectrum.phases()), &provenance)
+                .await?,
+            power_spectral_density: self
+                .create_column(
+                    &y_column,
+                    "PSD",
+                    to_cells(spectrum.power_spectral_density()),
+                    &provenance,
+                )
+                .await?,
+            reconstructed: match reconstruction {
+                Some(band) => Some(
+                    self.create_column(
+                        &y_column,
+                        "filtered",
+                        spectrum.reconstruct(band.low, band.high),
+                        &provenance,
+                    )
+                    .await?,
+                ),
+                None => None,
+            },
+        };
+
+        Ok(ColumnFftOutputData::new(&spectrum, column_ids))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use src_domain::services::fourier::FourierError;
+    use src_in_memory_infrastructure::column::{
+        in_memory_column_factory::InMemoryColumnFactory,
+        in_memory_column_repository::InMemoryColumnRepository,
+    };
+
+    use crate::column::fft::column_fft_command::{
+        FrequencyBandInCommand, WindowFunctionInCommand, ZeroPaddingInCommand,
+    };
+    use crate::test_utils::{save_column, save_column_in_directory};
+
+    use super::*;
+
+    async fn column_values(
+        column_repository: &InMemoryColumnRepository,
+        column_id: &str,
+    ) -> anyhow::Result<(Column, Vec<Option<f64>>)> {
+        Ok(ColumnValuesService::new(column_repository)
+            .find_column_values(&ColumnId::new(column_id.to_string())?)
+            .await?
+            .unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_handle() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        // 2 Hz と 12 Hz の正弦波, Δt = 0.025, N = 40
+        let t: Vec<f64> = (0..40).map(|i| i as f64 * 0.025).collect();
+        let x = save_column_in_directory(
+            &column_repository,
+            "t",
+            "6",
+            t.iter().map(|t| Some(*t)).collect(),
+        )
+        .await?;
+        let y = save_column_in_directory(
+            &column_repository,
+            "signal",
+            "6",
+            t.iter()
+                .map(|t| Some((2. * PI * 2. * t).sin() + 0.5 * (2. * PI * 12. * t).sin()))
+                .collect(),
+        )
+        .await?;
+
+        let service = ColumnFftService::new(&column_factory, &column_repository);
+        let command = ColumnFftCommand {
+            y_column_id: y.clone_value(),
+            sampling: SamplingInCommand::Column {
+                column_id: x.clone_value(),
+            },
+            window: WindowFunctionInCommand::Rectangular,
+            zero_padding: ZeroPaddingInCommand::None,
+            reconstruction: Some(FrequencyBandInCommand {
+                low: None,
+                high: Some(5.),
+            }),
+        };
+        let output_data = service.handle(command).await?;
+        assert!((output_data.sampling_interval - 0.025).abs() < 1e-12);
+        assert_eq!(output_data.length, 40);
+        assert!((output_data.frequency_resolution - 1.).abs() < 1e-12);
+        assert_eq!(output_data.frequencies.len(), 21);
+        assert!((output_data.amplitudes[2] - 1.).abs() < 1e-9);
+        assert!((output_data.amplitudes[12] - 0.5).abs() < 1e-9);
+
+        // スペクトルのカラムが Y カラムと同じディレクトリに保存されている
+        let (column, values) =
+            column_values(&column_repository, &output_data.amplitude_column_id).await?;
+        assert_eq!(column.name().value(), "signal amplitude");
+        assert_eq!(column.directory_id().value(), "6");
+        assert_eq!(values.len(), 21);
+        let (column, _) = column_values(
+            &column_repository,
+            &output_data.power_spectral_density_column_id,
+        )
+        .await?;
+        assert_eq!(column.name().value(), "signal PSD");
+        assert_eq!(
+            column.provenance(),
+            &Some(ColumnProvenance::Analysis(ColumnAnalysis::new(
+                AnalysisKind::FourierTransform,
+                vec![y.clone(), x.clone()]
+            )))
+        );
+
+        // 12 Hz の成分を取り除いた信号
+        let (column, values) = column_values(
+            &column_repository,
+            output_data.reconstructed_column_id.as_ref().unwrap(),
+        )
+        .await?;
+        assert_eq!(column.name().value(), "signal filtered");
+        for (value, t) in values.iter().zip(&t) {
+            assert!((value.unwrap() - (2. * PI * 2. * t).sin()).abs() < 1e-9);
+        }
+        assert_eq!(column_repository.find_all().await?.len(), 7);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handle_errors() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let x = save_column_in_directory(
+            &column_repository,
+            "t",
+            "6",
+            vec![Some(0.), Some(1.), Some(3.)],
+        )
+        .await?;
+        let y = save_column_in_directory(
+            &column_repository,
+            "signal",
+            "6",
+            vec![Some(1.), None, Some(2.)],
+        )
+        .await?;
+
+        let service = ColumnFftService::new(&column_factory, &column_repository);
+        let command = ColumnFftCommand {
+            y_column_id: y.clone_value(),
+            sampling: SamplingInCommand::Column {
+                column_id: x.clone_value(),
+            },
+            window: WindowFunctionInCommand::Hann,
+            zero_padding: ZeroPaddingInCommand::NextPowerOfTwo,
+            reconstruction: None,
+        };
+        assert!(matches!(
+            service.handle(command).await,
+            Err(ColumnFftServiceError::FourierError(
+                FourierError::NonUniformSampling(_)
+            ))
+        ));
+
+        let command = ColumnFftCommand {
+            y_column_id: y.clone_value(),
+            sampling: SamplingInCommand::Interval { interval: 0.1 },
+            window: WindowFunctionInCommand::Hann,
+            zero_padding: ZeroPaddingInCommand::None,
+            reconstruction: None,
+        };
+        assert!(matches!(
+            service.handle(command).await,
+            Err(ColumnFftServiceError::FourierError(
+                FourierError::MissingValue(1)
+            ))
+        ));
+
+        // X と Y の長さが異なる
+        let short_x = save_column(&column_repository, "short t", vec![Some(0.), Some(1.)]).await?;
+        let command = ColumnFftCommand {
+            y_column_id: y.clone_value(),
+            sampling: SamplingInCommand::Column {
+                column_id: short_x.clone_value(),
+            },
+            window: WindowFunctionInCommand::Rectangular,
+            zero_padding: ZeroPaddingInCommand::None,
+            reconstruction: None,
+        };
+        assert!(matches!(
+            service.handle(command).await,
+            Err(ColumnFftServiceError::LengthMismatch(2, 3))
+        ));
+        assert_eq!(column_repository.find_all().await?.len(), 3);
+        Ok(())
+    }
+}
@@ -0,0 +1,10 @@
+/* フーリエ変換によるスペクトル解析用アプリケーションサービス */
+// コマンドオブジェクト
+pub mod column_fft_command;
+
+// アプリケーションサービス
+pub mod column_fft_service;
+pub mod column_fft_service_impl;
+
+// DTO
+pub mod column_fft_output_data;
@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+
+use src_domain::services::column_generator::{ColumnGenerator, RandomDistribution};
+
+#[derive(Deserialize, Serialize)]
+pub struct ColumnGenerateCommand {
+    pub(super) name: String,
+    pub(super) directory_id: String,
+    pub(super) generator: ColumnGeneratorInCommand,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ColumnGeneratorInCommand {
+    Sequence {
+        start: f64,
+        step: f64,
+        count: usize,
+    },
+    Linspace {
+        start: f64,
+        end: f64,
+        count: usize,
+    },
+    Logspace {
+        start: f64,
+        end: f64,
+        count: usize,
+    },
+    Random {
+        distribution: RandomDistributionInCommand,
+        count: usize,
+        seed: u64,
+    },
+}
+
+impl ColumnGeneratorInCommand {
+    pub(super) fn to_column_generator(self) -> ColumnGenerator {
+        match self {
+            ColumnGeneratorInCommand::Sequence { start, step, count } => {
+                ColumnGenerator::Sequence { start, step, count }
+            }
+            ColumnGeneratorInCommand::Linspace { start, end, count } => {
+                ColumnGenerator::Linspace { start, end, count }
+            }
+            ColumnGeneratorInCommand::Logspace { start, end, count } => {
+                ColumnGenerator::Logspace { start, end, count }
+            }
+            ColumnGeneratorInCommand::Random {
+                distribution,
+                count,
+                seed,
+            } => ColumnGenerator::Random {
+                distribution: distribution.to_random_distribution(),
+                count,
+                seed,
+            },
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RandomDistributionInCommand {
+    Uniform { low: f64, high: f64 },
+    Normal { mean: f64, standard_deviation: f64 },
+    Poisson { mean: f64 },
+    Exponential { rate: f64 },
+}
+
+impl RandomDistributionInCommand {
+    pub(super) fn to_random_distribution(self) -> RandomDistribution {
+        match self {
+            RandomDistributionInCommand::Uniform { low, high } => {
+                RandomDistribution::Uniform { low, high }
+            }
+            RandomDistributionInCommand::Normal {
+                mean,
+                standard_deviation,
+            } => RandomDistribution::Normal {
+                mean,
+                standard_deviation,
+            },
+            RandomDistributionInCommand::Poisson { mean } => RandomDistribution::Poisson { mean },
+            RandomDistributionInCommand::Exponential { rate } => {
+                RandomDistribution::Exponential { rate }
+            }
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+use src_domain::{
+    models::column::column_with_cells::ColumnWithCells,
+    services::column_generator::ColumnGenerator, shared::value_object::ValueObject,
+};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct ColumnGenerateOutputData {
+    pub(super) column_id: String,
+    pub(super) column_name: String,
+    // 生成方法とパラメータ
+    pub(super) generator: String,
+    pub(super) cells: Vec<ColumnCellInOutputData>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub(super) struct ColumnCellInOutputData {
+    pub(super) cell_id: String,
+    pub(super) cell_value: Option<f64>,
+}
+
+impl ColumnGenerateOutputData {
+    pub(super) fn new(generator: &ColumnGenerator, source: ColumnWithCells) -> Self {
+        Self {
+            column_id: source.id().clone_value(),
+            column_name: source.name().clone_value(),
+            generator: generator.to_string(),
+            cells: source
+                .cells()
+                .iter()
+                .map(|cell| ColumnCellInOutputData {
+                    cell_id: cell.id().clone_value(),
+                    cell_value: cell.cell_value().clone_value(),
+                })
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use thiserror::Error;
+
+use src_domain::{
+    models::column::{
+        column_directory::column_directory_id::ColumnDirectoryIdError, column_name::ColumnNameError,
+    },
+    services::{
+        column_creation_service::ColumnCreationServiceError, column_generator::ColumnGeneratorError,
+    },
+};
+
+use super::{
+    column_generate_command::ColumnGenerateCommand,
+    column_generate_output_data::ColumnGenerateOutputData,
+};
+
+pub type ColumnGenerateServiceResult<T> = anyhow::Result<T, ColumnGenerateServiceError>;
+
+pub trait IColumnGenerateService {
+    fn handle(
+        &self,
+        command: ColumnGenerateCommand,
+    ) -> impl std::future::Future<Output = ColumnGenerateServiceResult<ColumnGenerateOutputData>> + Send;
+}
+
+#[derive(Debug, Error)]
+pub enum ColumnGenerateServiceError {
+    // value object errors
+    #[error("ColumnNameError: [{0}]")]
+    ColumnNameError(ColumnNameError),
+    #[error("ColumnDirectoryIdError: [{0}]")]
+    ColumnDirectoryIdError(ColumnDirectoryIdError),
+
+    // domain service errors
+    #[error("ColumnGeneratorError: [{0}]")]
+    ColumnGeneratorError(ColumnGeneratorError),
+    #[error("ColumnCreationServiceError: [{0}]")]
+    ColumnCreationServiceError(ColumnCreationServiceError),
+}
@@ -0,0 +1,171 @@
+use src_domain::{
+    models::column::{
+        column_directory::column_directory_id::ColumnDirectoryId, column_factory::IColumnFactory,
+        column_name::ColumnName, column_repository::IColumnRepository,
+    },
+    services::column_creation_service::ColumnCreationService,
+    shared::value_object::ValueObject,
+};
+
+use super::{
+    column_generate_command::ColumnGenerateCommand,
+    column_generate_output_data::ColumnGenerateOutputData,
+    column_generate_service::{
+        ColumnGenerateServiceError, ColumnGenerateServiceResult, IColumnGenerateService,
+    },
+};
+
+pub struct ColumnGenerateService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory,
+    CR: IColumnRepository,
+{
+    column_factory: &'a CF,
+    column_repository: &'b CR,
+}
+
+impl<'a, 'b, CF, CR> ColumnGenerateService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+{
+    pub fn new(column_factory: &'a CF, column_repository: &'b CR) -> Self {
+        Self {
+            column_factory,
+            column_repository,
+        }
+    }
+}
+
+impl<'a, 'b, CF, CR> IColumnGenerateService for ColumnGenerateService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+{
+    // TODO: トランザクション処理を追加する
+    async fn handle(
+        &self,
+        command: ColumnGenerateCommand,
+    ) -> ColumnGenerateServiceResult<ColumnGenerateOutputData> {
+        let ColumnGenerateCommand {
+            name,
+            directory_id,
+            generator,
+        } = command;
+
+        // 値オブジェクトのインスタンス化
+        let name = ColumnName::new(name).map_err(ColumnGenerateServiceError::ColumnNameError)?;
+        let directory_id = ColumnDirectoryId::new(directory_id)
+            .map_err(ColumnGenerateServiceError::ColumnDirectoryIdError)?;
+        let generator = generator.to_column_generator();
+
+        // 値を生成してカラムを作成する
+        let values = generator
+            .generate()
+            .map_err(ColumnGenerateServiceError::ColumnGeneratorError)?;
+        let (_, column_with_cells) =
+            ColumnCreationService::new(self.column_factory, self.column_repository)
+                .create_column(name, directory_id, values.into_iter().map(Some).collect())
+                .await
+                .map_err(ColumnGenerateServiceError::ColumnCreationServiceError)?;
+
+        Ok(ColumnGenerateOutputData::new(&generator, column_with_cells))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use src_domain::{
+        models::column::column_id::ColumnId, services::column_generator::ColumnGeneratorError,
+    };
+    use src_in_memory_infrastructure::column::{
+        in_memory_column_factory::InMemoryColumnFactory,
+        in_memory_column_repository::InMemoryColumnRepository,
+    };
+
+    use crate::column::generate::column_generate_command::{
+        ColumnGeneratorInCommand, RandomDistributionInCommand,
+    };
+
+    use super::*;
+
+    fn values(output_data: &ColumnGenerateOutputData) -> Vec<Option<f64>> {
+        output_data
+            .cells
+            .iter()
+            .map(|cell| cell.cell_value)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_handle() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+
+        let service = ColumnGenerateService::new(&column_factory, &column_repository);
+        let command = ColumnGenerateCommand {
+            name: "x".to_string(),
+            directory_id: "2".to_string(),
+            generator: ColumnGeneratorInCommand::Linspace {
+                start: 0.,
+                end: 2.,
+                count: 5,
+            },
+        };
+        let output_data = service.handle(command).await?;
+        assert_eq!(output_data.column_name, "x");
+        assert_eq!(output_data.generator, "linspace (0 to 2, 5 values)");
+        assert_eq!(
+            values(&output_data),
+            vec![Some(0.), Some(0.5), Some(1.), Some(1.5), Some(2.)]
+        );
+        let column = column_repository
+            .find(&ColumnId::new(output_data.column_id)?)
+            .await?
+            .unwrap();
+        assert_eq!(column.directory_id().value(), "2");
+        assert_eq!(column.cells().len(), 5);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handle_random() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+
+        let service = ColumnGenerateService::new(&column_factory, &column_repository);
+        let command = |distribution| ColumnGenerateCommand {
+            name: "noise".to_string(),
+            directory_id: "2".to_string(),
+            generator: ColumnGeneratorInCommand::Random {
+                distribution,
+                count: 10,
+                seed: 2024,
+            },
+        };
+        let normal = RandomDistributionInCommand::Normal {
+            mean: 0.,
+            standard_deviation: 1.,
+        };
+        // 同じ seed からは同じ値が生成される
+        let first = service.handle(command(normal)).await?;
+        let second = service.handle(command(normal)).await?;
+        assert_eq!(values(&first), values(&second));
+        assert_ne!(first.column_id, second.column_id);
+        assert_eq!(
+            first.generator,
+            "random normal(0, 1) (10 values, seed 2024)"
+        );
+
+        assert!(matches!(
+            service
+                .handle(command(RandomDistributionInCommand::Poisson { mean: -1. }))
+                .await,
+            Err(ColumnGenerateServiceError::ColumnGeneratorError(
+                ColumnGeneratorError::InvalidParameter(_)
+            ))
+        ));
+        assert_eq!(column_repository.find_all().await?.len(), 2);
+        Ok(())
+    }
+}
@@ -0,0 +1,10 @@
+/* 数列・乱数によるカラム生成用アプリケーションサービス */
+// コマンドオブジェクト
+pub mod column_generate_command;
+
+// アプリケーションサービス
+pub mod column_generate_service;
+pub mod column_generate_service_impl;
+
+// DTO
+pub mod column_generate_output_data;
@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+use src_domain::services::histogram::BinningRule;
+
+#[derive(Deserialize, Serialize)]
+pub struct ColumnHistogramCommand {
+    pub(super) column_id: String,
+    pub(super) binning: BinningRuleInCommand,
+    // 省略した場合は "{カラム名} histogram"
+    #[serde(default)]
+    pub(super) table_name: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum BinningRuleInCommand {
+    Count { count: usize },
+    Width { width: f64 },
+    Sturges,
+    FreedmanDiaconis,
+    Edges { edges: Vec<f64> },
+}
+
+impl BinningRuleInCommand {
+    pub(super) fn to_binning_rule(&self) -> BinningRule {
+        match self {
+            BinningRuleInCommand::Count { count } => BinningRule::Count(*count),
+            BinningRuleInCommand::Width { width } => BinningRule::Width(*width),
+            BinningRuleInCommand::Sturges => BinningRule::Sturges,
+            BinningRuleInCommand::FreedmanDiaconis => BinningRule::FreedmanDiaconis,
+            BinningRuleInCommand::Edges { edges } => BinningRule::Edges(edges.clone()),
+        }
+    }
+}
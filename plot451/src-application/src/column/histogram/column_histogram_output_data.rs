@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+
+use src_domain::{
+    models::table::table_with_columns_and_cells::TableWithColumnsAndCells,
+    services::histogram::Histogram, shared::value_object::ValueObject,
+};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct ColumnHistogramOutputData {
+    // ヒストグラムを保存したテーブル
+    pub(super) table_id: String,
+    pub(super) table_name: String,
+    pub(super) columns: Vec<ColumnInOutputData>,
+    pub(super) bins: Vec<BinInOutputData>,
+    // ビンの範囲外で数えなかった値の数
+    pub(super) outside: usize,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub(super) struct ColumnInOutputData {
+    pub(super) column_id: String,
+    pub(super) column_name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub(super) struct BinInOutputData {
+    pub(super) center: f64,
+    pub(super) lower: f64,
+    pub(super) upper: f64,
+    pub(super) count: usize,
+    pub(super) density: f64,
+    pub(super) cumulative: usize,
+}
+
+impl ColumnHistogramOutputData {
+    pub(super) fn new(histogram: &Histogram, table: TableWithColumnsAndCells) -> Self {
+        let centers = histogram.centers();
+        let densities = histogram.densities();
+        let cumulative_counts = histogram.cumulative_counts();
+        Self {
+            table_id: table.id().clone_value(),
+            table_name: table.name().clone_value(),
+            columns: table
+                .columns()
+                .iter()
+                .map(|column| ColumnInOutputData {
+                    column_id: column.id().clone_value(),
+                    column_name: column.name().clone_value(),
+                })
+                .collect(),
+            bins: (0..histogram.counts().len())
+                .map(|i| BinInOutputData {
+                    center: centers[i],
+                    lower: histogram.edges()[i],
+                    upper: histogram.edges()[i + 1],
+                    count: histogram.counts()[i],
+                    density: densities[i],
+                    cumulative: cumulative_counts[i],
+                })
+                .collect(),
+            outside: histogram.outside(),
+        }
+    }
+}
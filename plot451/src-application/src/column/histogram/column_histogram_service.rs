@@ -0,0 +1,61 @@
+use thiserror::Error;
+
+use src_domain::{
+    models::{
+        column::{
+            column_id::{ColumnId, ColumnIdError},
+            column_name::ColumnNameError,
+            column_repository::ColumnRepositoryError,
+        },
+        table::{
+            table_factory::TableFactoryError, table_name::TableNameError,
+            table_repository::TableRepositoryError,
+        },
+    },
+    services::{column_creation_service::ColumnCreationServiceError, histogram::HistogramError},
+};
+
+use super::{
+    column_histogram_command::ColumnHistogramCommand,
+    column_histogram_output_data::ColumnHistogramOutputData,
+};
+
+pub type ColumnHistogramServiceResult<T> = anyhow::Result<T, ColumnHistogramServiceError>;
+
+pub trait IColumnHistogramService {
+    fn handle(
+        &self,
+        command: ColumnHistogramCommand,
+    ) -> impl std::future::Future<Output = ColumnHistogramServiceResult<ColumnHistogramOutputData>> + Send;
+}
+
+#[derive(Debug, Error)]
+pub enum ColumnHistogramServiceError {
+    // repository errors
+    #[error("ColumnRepositoryError: [{0}]")]
+    ColumnRepositoryError(ColumnRepositoryError),
+    #[error("TableRepositoryError: [{0}]")]
+    TableRepositoryError(TableRepositoryError),
+
+    // value object errors
+    #[error("ColumnIdError: [{0}]")]
+    ColumnIdError(ColumnIdError),
+    #[error("ColumnNameError: [{0}]")]
+    ColumnNameError(ColumnNameError),
+    #[error("TableNameError: [{0}]")]
+    TableNameError(TableNameError),
+
+    // factory errors
+    #[error("TableFactoryError: [{0}]")]
+    TableFactoryError(TableFactoryError),
+
+    // domain service errors
+    #[error("HistogramError: [{0}]")]
+    HistogramError(HistogramError),
+    #[error("ColumnCreationServiceError: [{0}]")]
+    ColumnCreationServiceError(ColumnCreationServiceError),
+
+    // not found errors
+    #[error("Column not found, column_id: {0:?}")]
+    ColumnNotFound(ColumnId),
+}
@@ -0,0 +1,268 @@
+use src_domain::{
+    models::{
+        column::{
+            column_cell::column_cell_value::CellRawValue,
+            column_factory::IColumnFactory,
+            column_id::ColumnId,
+            column_name::ColumnName,
+            column_provenance::{AnalysisKind, ColumnAnalysis, ColumnProvenance},
+            column_repository::IColumnRepository,
+        },
+        table::{
+            table_factory::ITableFactory, table_name::TableName,
+            table_repository::ITableRepository,
+            table_with_columns_and_cells::TableWithColumnsAndCells,
+        },
+    },
+    services::{
+        column_creation_service::ColumnCreationService, column_values_service::ColumnValuesService,
+        histogram::Histogram,
+    },
+    shared::value_object::ValueObject,
+};
+
+use super::{
+    column_histogram_command::ColumnHistogramCommand,
+    column_histogram_output_data::ColumnHistogramOutputData,
+    column_histogram_service::{
+        ColumnHistogramServiceError, ColumnHistogramServiceResult, IColumnHistogramService,
+    },
+};
+
+pub struct ColumnHistogramService<'a, 'b, 'c, 'd, CF, CR, TF, TR>
+where
+    CF: IColumnFactory,
+    CR: IColumnRepository,
+    TF: ITableFactory,
+    TR: ITableRepository,
+{
+    column_factory: &'a CF,
+    column_repository: &'b CR,
+    table_factory: &'c TF,
+    table_repository: &'d TR,
+}
+
+impl<'a, 'b, 'c, 'd, CF, CR, TF, TR> ColumnHistogramService<'a, 'b, 'c, 'd, CF, CR, TF, TR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+    TF: ITableFactory + Sync,
+    TR: ITableRepository + Sync,
+{
+    pub fn new(
+        column_factory: &'a CF,
+        column_repository: &'b CR,
+        table_factory: &'c TF,
+        table_repository: &'d TR,
+    ) -> Self {
+        Self {
+            column_factory,
+            column_repository,
+            table_factory,
+            table_repository,
+        }
+    }
+}
+
+impl<'a, 'b, 'c, 'd, CF, CR, TF, TR> IColumnHistogramService
+    for ColumnHistogramService<'a, 'b, 'c, 'd, CF, CR, TF, TR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+    TF: ITableFactory + Sync,
+    TR: ITableRepository + Sync,
+{
+    // TODO: トランザクション処理を追加する
+    async fn handle(
+        &self,
+        command: ColumnHistogramCommand,
+    ) -> ColumnHistogramServiceResult<ColumnHistogramOutputData> {
+        let ColumnHistogramCommand {
+            column_id,
+            binning,
+            table_name,
+        } = command;
+
+        // 値オブジェクトのインスタンス化
+        let column_id =
+            ColumnId::new(column_id).map_err(ColumnHistogramServiceError::ColumnIdError)?;
+
+        // 元のカラムの取得
+        let (source, values) = ColumnValuesService::new(self.column_repository)
+            .find_column_values(&column_id)
+            .await
+            .map_err(ColumnHistogramServiceError::ColumnRepositoryError)?
+            .ok_or(ColumnHistogramServiceError::ColumnNotFound(
+                column_id.clone(),
+            ))?;
+        let table_name =
+            TableName::new(table_name.unwrap_or_else(|| format!("{} histogram", source.name())))
+                .map_err(ColumnHistogramServiceError::TableNameError)?;
+
+        let histogram = Histogram::new(&values, &binning.to_binning_rule())
+            .map_err(ColumnHistogramServiceError::HistogramError)?;
+
+        // ビンごとの値をカラムとして元のカラムと同じディレクトリに保存する
+        let as_cells = |values: Vec<f64>| values.into_iter().map(Some).collect();
+        let as_count_cells =
+            |counts: &[usize]| counts.iter().map(|count| Some(*count as f64)).collect();
+        let columns: Vec<(&str, Vec<CellRawValue>)> = vec![
+            ("center", as_cells(histogram.centers())),
+            ("lower", as_cells(histogram.lower_edges())),
+            ("upper", as_cells(histogram.upper_edges())),
+            ("count", as_count_cells(histogram.counts())),
+            ("density", as_cells(histogram.densities())),
+            ("cumulative", as_count_cells(&histogram.cumulative_counts())),
+        ];
+        let column_creation_service =
+            ColumnCreationService::new(self.column_factory, self.column_repository);
+        let provenance = ColumnProvenance::Analysis(ColumnAnalysis::new(
+            AnalysisKind::Histogram,
+            vec![source.id().clone()],
+        ));
+        let mut column_ids = vec![];
+        let mut columns_with_cells = vec![];
+        for (name, values) in columns {
+            let name = ColumnName::new(name.to_string())
+                .map_err(ColumnHistogramServiceError::ColumnNameError)?;
+            let (column, column_with_cells) = column_creation_service
+                .create_column_from(
+                    name,
+                    source.directory_id().clone(),
+                    values,
+                    provenance.clone(),
+                )
+                .await
+                .map_err(ColumnHistogramServiceError::ColumnCreationServiceError)?;
+            column_ids.push(column.id().clone());
+            columns_with_cells.push(column_with_cells);
+        }
+
+        // テーブルの作成と永続化
+        let mut table = self
+            .table_factory
+            .create_table(table_name, column_ids)
+            .await
+            .map_err(ColumnHistogramServiceError::TableFactoryError)?;
+        let table_id = self
+            .table_repository
+            .save(&table)
+            .await
+            .map_err(ColumnHistogramServiceError::TableRepositoryError)?;
+        table.set_id(table_id);
+
+        Ok(ColumnHistogramOutputData::new(
+            &histogram,
+            TableWithColumnsAndCells::new(&table, columns_with_cells),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use src_domain::{models::table::table_id::TableId, services::histogram::HistogramError};
+    use src_in_memory_infrastructure::{
+        column::{
+            in_memory_column_factory::InMemoryColumnFactory,
+            in_memory_column_repository::InMemoryColumnRepository,
+        },
+        table::{
+            in_memory_table_factory::InMemoryTableFactory,
+            in_memory_table_repository::InMemoryTableRepository,
+        },
+    };
+
+    use crate::column::histogram::column_histogram_command::BinningRuleInCommand;
+    use crate::test_utils::save_column_in_directory;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let table_factory = InMemoryTableFactory::new();
+        let table_repository = InMemoryTableRepository::new();
+        let source = save_column_in_directory(
+            &column_repository,
+            "length",
+            "8",
+            vec![Some(0.2), Some(0.7), None, Some(1.1), Some(1.4), Some(1.9)],
+        )
+        .await?;
+
+        let service = ColumnHistogramService::new(
+            &column_factory,
+            &column_repository,
+            &table_factory,
+            &table_repository,
+        );
+        let command = ColumnHistogramCommand {
+            column_id: source.clone_value(),
+            binning: BinningRuleInCommand::Width { width: 0.5 },
+            table_name: None,
+        };
+        let output_data = service.handle(command).await?;
+        assert_eq!(output_data.table_name, "length histogram");
+        let counts: Vec<usize> = output_data.bins.iter().map(|bin| bin.count).collect();
+        assert_eq!(counts, vec![1, 1, 2, 1]);
+        assert_eq!(output_data.bins[2].lower, 1.);
+        assert_eq!(output_data.bins[2].center, 1.25);
+        assert_eq!(output_data.bins[3].cumulative, 5);
+        assert!((output_data.bins[2].density - 0.8).abs() < 1e-12);
+
+        // ビンの値がテーブルとして保存されている
+        let table = table_repository
+            .find(&TableId::new(output_data.table_id)?)
+            .await?
+            .unwrap();
+        let columns = column_repository.find_by_ids(table.columns()).await?;
+        let names: Vec<&str> = columns
+            .iter()
+            .map(|column| column.name().value().as_str())
+            .collect();
+        assert_eq!(
+            names,
+            vec!["center", "lower", "upper", "count", "density", "cumulative"]
+        );
+        assert!(columns
+            .iter()
+            .all(|column| column.directory_id().value() == "8"));
+        let count_values = ColumnValuesService::new(&column_repository)
+            .find_values(&columns[3])
+            .await?;
+        assert_eq!(count_values, vec![Some(1.), Some(1.), Some(2.), Some(1.)]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handle_invalid_binning() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let table_factory = InMemoryTableFactory::new();
+        let table_repository = InMemoryTableRepository::new();
+        let source =
+            save_column_in_directory(&column_repository, "length", "8", vec![Some(1.)]).await?;
+
+        let service = ColumnHistogramService::new(
+            &column_factory,
+            &column_repository,
+            &table_factory,
+            &table_repository,
+        );
+        let command = ColumnHistogramCommand {
+            column_id: source.clone_value(),
+            binning: BinningRuleInCommand::Edges { edges: vec![1.] },
+            table_name: Some("h".to_string()),
+        };
+        assert!(matches!(
+            service.handle(command).await,
+            Err(ColumnHistogramServiceError::HistogramError(
+                HistogramError::InsufficientEdges
+            ))
+        ));
+        assert_eq!(column_repository.find_all().await?.len(), 1);
+        assert!(table_repository.find_all().await?.is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,10 @@
+/* ヒストグラム作成用アプリケーションサービス */
+// コマンドオブジェクト
+pub mod column_histogram_command;
+
+// アプリケーションサービス
+pub mod column_histogram_service;
+pub mod column_histogram_service_impl;
+
+// DTO
+pub mod column_histogram_output_data;
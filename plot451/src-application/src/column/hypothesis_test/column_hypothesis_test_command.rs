@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+
+use src_domain::services::hypothesis_test::{Alternative, HypothesisTest};
+
+#[derive(Deserialize, Serialize)]
+pub struct ColumnHypothesisTestCommand {
+    pub(super) test: HypothesisTestInCommand,
+    // 検定する標本のカラム (検定ごとに必要な個数が決まっている)
+    pub(super) column_ids: Vec<String>,
+    #[serde(default)]
+    pub(super) alternative: AlternativeInCommand,
+    // 省略した場合は 0.95
+    #[serde(default)]
+    pub(super) confidence_level: Option<f64>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum HypothesisTestInCommand {
+    OneSampleT {
+        #[serde(default)]
+        mean: f64,
+    },
+    WelchT,
+    PairedT,
+    OneWayAnova,
+    MannWhitneyU,
+    KolmogorovSmirnov,
+    ChiSquareGoodnessOfFit,
+    ChiSquareIndependence,
+}
+
+impl HypothesisTestInCommand {
+    pub(super) fn to_hypothesis_test(self) -> HypothesisTest {
+        match self {
+            HypothesisTestInCommand::OneSampleT { mean } => HypothesisTest::OneSampleT { mean },
+            HypothesisTestInCommand::WelchT => HypothesisTest::WelchT,
+            HypothesisTestInCommand::PairedT => HypothesisTest::PairedT,
+            HypothesisTestInCommand::OneWayAnova => HypothesisTest::OneWayAnova,
+            HypothesisTestInCommand::MannWhitneyU => HypothesisTest::MannWhitneyU,
+            HypothesisTestInCommand::KolmogorovSmirnov => HypothesisTest::KolmogorovSmirnov,
+            HypothesisTestInCommand::ChiSquareGoodnessOfFit => {
+                HypothesisTest::ChiSquareGoodnessOfFit
+            }
+            HypothesisTestInCommand::ChiSquareIndependence => HypothesisTest::ChiSquareIndependence,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum AlternativeInCommand {
+    #[default]
+    TwoSided,
+    Less,
+    Greater,
+}
+
+impl AlternativeInCommand {
+    pub(super) fn to_alternative(self) -> Alternative {
+        match self {
+            AlternativeInCommand::TwoSided => Alternative::TwoSided,
+            AlternativeInCommand::Less => Alternative::Less,
+            AlternativeInCommand::Greater => Alternative::Greater,
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+
+use src_domain::{
+    models::column::column_id::ColumnId,
+    services::hypothesis_test::{Alternative, HypothesisTest, TestResult},
+    shared::value_object::ValueObject,
+};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct ColumnHypothesisTestOutputData {
+    pub(super) test: String,
+    pub(super) alternative: String,
+    pub(super) column_ids: Vec<String>,
+    // 検定に使った値の個数
+    pub(super) sample_sizes: Vec<usize>,
+    pub(super) statistic: f64,
+    pub(super) degrees_of_freedom: Vec<f64>,
+    pub(super) p_value: f64,
+    pub(super) confidence_interval: Option<ConfidenceIntervalInOutputData>,
+}
+
+// 片側の区間の無限大の端は None
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub(super) struct ConfidenceIntervalInOutputData {
+    pub(super) level: f64,
+    pub(super) estimate: f64,
+    pub(super) lower: Option<f64>,
+    pub(super) upper: Option<f64>,
+}
+
+impl ColumnHypothesisTestOutputData {
+    pub(super) fn new(
+        test: HypothesisTest,
+        alternative: Alternative,
+        column_ids: &[ColumnId],
+        result: &TestResult,
+    ) -> Self {
+        let finite = |value: f64| value.is_finite().then_some(value);
+        Self {
+            test: test.to_string(),
+            alternative: alternative.to_string(),
+            column_ids: column_ids
+                .iter()
+                .map(|column_id| column_id.clone_value())
+                .collect(),
+            sample_sizes: result.sample_sizes().clone(),
+            statistic: result.statistic(),
+            degrees_of_freedom: result.degrees_of_freedom().clone(),
+            p_value: result.p_value(),
+            confidence_interval: result.confidence_interval().map(|interval| {
+                ConfidenceIntervalInOutputData {
+                    level: interval.level(),
+                    estimate: interval.estimate(),
+                    lower: finite(interval.lower()),
+                    upper: finite(interval.upper()),
+                }
+            }),
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use thiserror::Error;
+
+use src_domain::{
+    models::column::{
+        column_id::{ColumnId, ColumnIdError},
+        column_repository::ColumnRepositoryError,
+    },
+    services::hypothesis_test::HypothesisTestError,
+};
+
+use super::{
+    column_hypothesis_test_command::ColumnHypothesisTestCommand,
+    column_hypothesis_test_output_data::ColumnHypothesisTestOutputData,
+};
+
+pub type ColumnHypothesisTestServiceResult<T> = anyhow::Result<T, ColumnHypothesisTestServiceError>;
+
+pub trait IColumnHypothesisTestService {
+    fn handle(
+        &self,
+        command: ColumnHypothesisTestCommand,
+    ) -> impl std::future::Future<
+        Output = ColumnHypothesisTestServiceResult<ColumnHypothesisTestOutputData>,
+    > + Send;
+}
+
+#[derive(Debug, Error)]
+pub enum ColumnHypothesisTestServiceError {
+    // repository errors
+    #[error("ColumnRepositoryError: [{0}]")]
+    ColumnRepositoryError(ColumnRepositoryError),
+
+    // value object errors
+    #[error("ColumnIdError: [{0}]")]
+    ColumnIdError(ColumnIdError),
+
+    // domain service errors
+    #[error("HypothesisTestError: [{0}]")]
+    HypothesisTestError(HypothesisTestError),
+
+    // not found errors
+    #[error("Column not found, column_id: {0:?}")]
+    ColumnNotFound(ColumnId),
+}
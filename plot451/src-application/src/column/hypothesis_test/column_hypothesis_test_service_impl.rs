@@ -0,0 +1,178 @@
+use src_domain::{
+    models::column::{
+        column_cell::column_cell_value::CellRawValue, column_id::ColumnId,
+        column_repository::IColumnRepository,
+    },
+    services::column_values_service::ColumnValuesService,
+    shared::value_object::ValueObject,
+};
+
+use super::{
+    column_hypothesis_test_command::ColumnHypothesisTestCommand,
+    column_hypothesis_test_output_data::ColumnHypothesisTestOutputData,
+    column_hypothesis_test_service::{
+        ColumnHypothesisTestServiceError, ColumnHypothesisTestServiceResult,
+        IColumnHypothesisTestService,
+    },
+};
+
+pub struct ColumnHypothesisTestService<'a, CR>
+where
+    CR: IColumnRepository,
+{
+    column_repository: &'a CR,
+}
+
+impl<'a, CR> ColumnHypothesisTestService<'a, CR>
+where
+    CR: IColumnRepository,
+{
+    pub fn new(column_repository: &'a CR) -> Self {
+        Self { column_repository }
+    }
+}
+
+impl<'a, CR> IColumnHypothesisTestService for ColumnHypothesisTestService<'a, CR>
+where
+    CR: IColumnRepository + Sync,
+{
+    async fn handle(
+        &self,
+        command: ColumnHypothesisTestCommand,
+    ) -> ColumnHypothesisTestServiceResult<ColumnHypothesisTestOutputData> {
+        let ColumnHypothesisTestCommand {
+            test,
+            column_ids,
+            alternative,
+            confidence_level,
+        } = command;
+
+        // 値オブジェクトのインスタンス化
+        let column_ids = column_ids
+            .into_iter()
+            .map(ColumnId::new)
+            .collect::<Result<Vec<ColumnId>, _>>()
+            .map_err(ColumnHypothesisTestServiceError::ColumnIdError)?;
+        let test = test.to_hypothesis_test();
+        let alternative = alternative.to_alternative();
+
+        // 標本の値の取得
+        let column_values_service = ColumnValuesService::new(self.column_repository);
+        let mut samples: Vec<Vec<CellRawValue>> = vec![];
+        for column_id in &column_ids {
+            let (_, values) = column_values_service
+                .find_column_values(column_id)
+                .await
+                .map_err(ColumnHypothesisTestServiceError::ColumnRepositoryError)?
+                .ok_or(ColumnHypothesisTestServiceError::ColumnNotFound(
+                    column_id.clone(),
+                ))?;
+            samples.push(values);
+        }
+
+        let result = test
+            .run(&samples, alternative, confidence_level.unwrap_or(0.95))
+            .map_err(ColumnHypothesisTestServiceError::HypothesisTestError)?;
+        Ok(ColumnHypothesisTestOutputData::new(
+            test,
+            alternative,
+            &column_ids,
+            &result,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use src_domain::services::hypothesis_test::HypothesisTestError;
+    use src_in_memory_infrastructure::column::in_memory_column_repository::InMemoryColumnRepository;
+
+    use crate::column::hypothesis_test::column_hypothesis_test_command::{
+        AlternativeInCommand, HypothesisTestInCommand,
+    };
+    use crate::test_utils::save_column;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle() -> anyhow::Result<()> {
+        let column_repository = InMemoryColumnRepository::new();
+        let before = save_column(
+            &column_repository,
+            "before",
+            vec![
+                Some(72.),
+                Some(80.),
+                Some(65.),
+                Some(90.),
+                Some(77.),
+                Some(84.),
+            ],
+        )
+        .await?;
+        let after = save_column(
+            &column_repository,
+            "after",
+            vec![
+                Some(70.),
+                Some(76.),
+                Some(66.),
+                Some(85.),
+                Some(72.),
+                Some(80.),
+            ],
+        )
+        .await?;
+
+        let service = ColumnHypothesisTestService::new(&column_repository);
+        let command = ColumnHypothesisTestCommand {
+            test: HypothesisTestInCommand::PairedT,
+            column_ids: vec![before.clone_value(), after.clone_value()],
+            alternative: AlternativeInCommand::Greater,
+            confidence_level: Some(0.9),
+        };
+        let output_data = service.handle(command).await?;
+        assert_eq!(output_data.test, "paired t-test");
+        assert_eq!(output_data.alternative, "greater");
+        assert_eq!(output_data.sample_sizes, vec![6, 6]);
+        assert_eq!(output_data.degrees_of_freedom, vec![5.]);
+        assert!((output_data.p_value - 0.010_183_218).abs() < 1e-7);
+        let interval = output_data.confidence_interval.unwrap();
+        assert_eq!(interval.level, 0.9);
+        assert!(interval.lower.unwrap() < interval.estimate);
+        assert_eq!(interval.upper, None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handle_errors() -> anyhow::Result<()> {
+        let column_repository = InMemoryColumnRepository::new();
+        let column_id = save_column(&column_repository, "x", vec![Some(1.), Some(2.)]).await?;
+
+        let service = ColumnHypothesisTestService::new(&column_repository);
+        let command = ColumnHypothesisTestCommand {
+            test: HypothesisTestInCommand::OneWayAnova,
+            column_ids: vec![column_id.clone_value()],
+            alternative: AlternativeInCommand::TwoSided,
+            confidence_level: None,
+        };
+        assert!(matches!(
+            service.handle(command).await,
+            Err(ColumnHypothesisTestServiceError::HypothesisTestError(
+                HypothesisTestError::InvalidSampleCount(1)
+            ))
+        ));
+
+        let command = ColumnHypothesisTestCommand {
+            test: HypothesisTestInCommand::WelchT,
+            column_ids: vec![column_id.clone_value(), "100".to_string()],
+            alternative: AlternativeInCommand::TwoSided,
+            confidence_level: None,
+        };
+        assert!(matches!(
+            service.handle(command).await,
+            Err(ColumnHypothesisTestServiceError::ColumnNotFound(_))
+        ));
+        Ok(())
+    }
+}
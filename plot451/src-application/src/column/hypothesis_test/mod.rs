@@ -0,0 +1,10 @@
+/* 統計的仮説検定用アプリケーションサービス */
+// コマンドオブジェクト
+pub mod column_hypothesis_test_command;
+
+// アプリケーションサービス
+pub mod column_hypothesis_test_service;
+pub mod column_hypothesis_test_service_impl;
+
+// DTO
+pub mod column_hypothesis_test_output_data;
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+use src_domain::services::numerical_calculus::{GapHandling, IntegrationMethod};
+
+#[derive(Deserialize, Serialize)]
+pub struct ColumnIntegrateCommand {
+    pub(super) x_column_id: String,
+    pub(super) y_column_id: String,
+    pub(super) method: IntegrationMethodInCommand,
+    #[serde(default)]
+    pub(super) gap_handling: GapHandlingInCommand,
+    // 定積分の範囲 (省略した場合は x の最小値・最大値)
+    #[serde(default)]
+    pub(super) lower_limit: Option<f64>,
+    #[serde(default)]
+    pub(super) upper_limit: Option<f64>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum IntegrationMethodInCommand {
+    Trapezoid,
+    Simpson,
+}
+
+impl IntegrationMethodInCommand {
+    pub(super) fn to_integration_method(self) -> IntegrationMethod {
+        match self {
+            IntegrationMethodInCommand::Trapezoid => IntegrationMethod::Trapezoid,
+            IntegrationMethodInCommand::Simpson => IntegrationMethod::Simpson,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum GapHandlingInCommand {
+    #[default]
+    Bridge,
+    Split,
+}
+
+impl GapHandlingInCommand {
+    pub(super) fn to_gap_handling(self) -> GapHandling {
+        match self {
+            GapHandlingInCommand::Bridge => GapHandling::Bridge,
+            GapHandlingInCommand::Split => GapHandling::Split,
+        }
+    }
+}
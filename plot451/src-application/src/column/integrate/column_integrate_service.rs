@@ -0,0 +1,50 @@
+use thiserror::Error;
+
+use src_domain::{
+    models::column::{
+        column_id::{ColumnId, ColumnIdError},
+        column_name::ColumnNameError,
+        column_repository::ColumnRepositoryError,
+    },
+    services::{
+        column_creation_service::ColumnCreationServiceError,
+        numerical_calculus::NumericalCalculusError,
+    },
+};
+
+use super::{
+    column_integrate_command::ColumnIntegrateCommand,
+    column_integrate_output_data::ColumnIntegrateOutputData,
+};
+
+pub type ColumnIntegrateServiceResult<T> = anyhow::Result<T, ColumnIntegrateServiceError>;
+
+pub trait IColumnIntegrateService {
+    fn handle(
+        &self,
+        command: ColumnIntegrateCommand,
+    ) -> impl std::future::Future<Output = ColumnIntegrateServiceResult<ColumnIntegrateOutputData>> + Send;
+}
+
+#[derive(Debug, Error)]
+pub enum ColumnIntegrateServiceError {
+    // repository errors
+    #[error("ColumnRepositoryError: [{0}]")]
+    ColumnRepositoryError(ColumnRepositoryError),
+
+    // value object errors
+    #[error("ColumnIdError: [{0}]")]
+    ColumnIdError(ColumnIdError),
+    #[error("ColumnNameError: [{0}]")]
+    ColumnNameError(ColumnNameError),
+
+    // domain service errors
+    #[error("NumericalCalculusError: [{0}]")]
+    NumericalCalculusError(NumericalCalculusError),
+    #[error("ColumnCreationServiceError: [{0}]")]
+    ColumnCreationServiceError(ColumnCreationServiceError),
+
+    // not found errors
+    #[error("Column not found, column_id: {0:?}")]
+    ColumnNotFound(ColumnId),
+}
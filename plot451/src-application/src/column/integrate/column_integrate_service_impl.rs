@@ -0,0 +1,214 @@
+use src_domain::{
+    models::column::{
+        column_factory::IColumnFactory,
+        column_id::ColumnId,
+        column_name::ColumnName,
+        column_provenance::{AnalysisKind, ColumnAnalysis, ColumnProvenance},
+        column_repository::IColumnRepository,
+    },
+    services::{
+        column_creation_service::ColumnCreationService, column_values_service::ColumnValuesService,
+        numerical_calculus::SampledFunction,
+    },
+    shared::value_object::ValueObject,
+};
+
+use super::{
+    column_integrate_command::ColumnIntegrateCommand,
+    column_integrate_output_data::ColumnIntegrateOutputData,
+    column_integrate_service::{
+        ColumnIntegrateServiceError, ColumnIntegrateServiceResult, IColumnIntegrateService,
+    },
+};
+
+pub struct ColumnIntegrateService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory,
+    CR: IColumnRepository,
+{
+    column_factory: &'a CF,
+    column_repository: &'b CR,
+}
+
+impl<'a, 'b, CF, CR> ColumnIntegrateService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+{
+    pub fn new(column_factory: &'a CF, column_repository: &'b CR) -> Self {
+        Self {
+            column_factory,
+            column_repository,
+        }
+    }
+}
+
+impl<'a, 'b, CF, CR> IColumnIntegrateService for ColumnIntegrateService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+{
+    async fn handle(
+        &self,
+        command: ColumnIntegrateCommand,
+    ) -> ColumnIntegrateServiceResult<ColumnIntegrateOutputData> {
+        let ColumnIntegrateCommand {
+            x_column_id,
+            y_column_id,
+            method,
+            gap_handling,
+            lower_limit,
+            upper_limit,
+        } = command;
+
+        // 値オブジェクトのインスタンス化
+        let x_column_id =
+            ColumnId::new(x_column_id).map_err(ColumnIntegrateServiceError::ColumnIdError)?;
+        let y_column_id =
+            ColumnId::new(y_column_id).map_err(ColumnIntegrateServiceError::ColumnIdError)?;
+
+        // 累積積分と定積分
+        let (x_column, x_values) = ColumnValuesService::new(self.column_repository)
+            .find_column_values(&x_column_id)
+            .await
+            .map_err(ColumnIntegrateServiceError::ColumnRepositoryError)?
+            .ok_or(ColumnIntegrateServiceError::ColumnNotFound(
+                x_column_id.clone(),
+            ))?;
+        let (y_column, y_values) = ColumnValuesService::new(self.column_repository)
+            .find_column_values(&y_column_id)
+            .await
+            .map_err(ColumnIntegrateServiceError::ColumnRepositoryError)?
+            .ok_or(ColumnIntegrateServiceError::ColumnNotFound(
+                y_column_id.clone(),
+            ))?;
+        let function = SampledFunction::new(&x_values, &y_values, gap_handling.to_gap_handling())
+            .map_err(ColumnIntegrateServiceError::NumericalCalculusError)?;
+        let method = method.to_integration_method();
+        let cumulative_integral = function.cumulative_integral(method);
+        let (x_min, x_max) = function.x_range();
+        let lower_limit = lower_limit.unwrap_or(x_min);
+        let upper_limit = upper_limit.unwrap_or(x_max);
+        let area = function
+            .definite_integral(method, lower_limit, upper_limit)
+            .map_err(ColumnIntegrateServiceError::NumericalCalculusError)?;
+
+        // Y カラムと同じディレクトリに保存
+        let name = ColumnName::new(format!("∫{}d{}", y_column.name(), x_column.name()))
+            .map_err(ColumnIntegrateServiceError::ColumnNameError)?;
+        let (_, column_with_cells) =
+            ColumnCreationService::new(self.column_factory, self.column_repository)
+                .create_column_from(
+                    name,
+                    y_column.directory_id().clone(),
+                    cumulative_integral,
+                    ColumnProvenance::Analysis(ColumnAnalysis::new(
+                        AnalysisKind::Integration,
+                        vec![x_column_id.clone(), y_column_id.clone()],
+                    )),
+                )
+                .await
+                .map_err(ColumnIntegrateServiceError::ColumnCreationServiceError)?;
+
+        Ok(ColumnIntegrateOutputData::new(
+            column_with_cells,
+            lower_limit,
+            upper_limit,
+            area,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use src_domain::services::numerical_calculus::NumericalCalculusError;
+    use src_in_memory_infrastructure::column::{
+        in_memory_column_factory::InMemoryColumnFactory,
+        in_memory_column_repository::InMemoryColumnRepository,
+    };
+
+    use crate::column::integrate::column_integrate_command::{
+        GapHandlingInCommand, IntegrationMethodInCommand,
+    };
+    use crate::test_utils::save_column;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let x = save_column(
+            &column_repository,
+            "t",
+            vec![Some(0.), Some(1.), Some(2.), Some(3.), Some(4.)],
+        )
+        .await?;
+        let y = save_column(
+            &column_repository,
+            "v",
+            vec![Some(0.), Some(1.), None, Some(3.), Some(4.)],
+        )
+        .await?;
+
+        let service = ColumnIntegrateService::new(&column_factory, &column_repository);
+        let command = ColumnIntegrateCommand {
+            x_column_id: x.clone_value(),
+            y_column_id: y.clone_value(),
+            method: IntegrationMethodInCommand::Trapezoid,
+            gap_handling: GapHandlingInCommand::Bridge,
+            lower_limit: None,
+            upper_limit: None,
+        };
+        let output_data = service.handle(command).await?;
+        assert_eq!(output_data.column_name, "∫vdt");
+        let values: Vec<Option<f64>> = output_data
+            .cells
+            .iter()
+            .map(|cell| cell.cell_value)
+            .collect();
+        assert_eq!(values, vec![Some(0.), Some(0.5), None, Some(4.5), Some(8.)]);
+        assert_eq!((output_data.lower_limit, output_data.upper_limit), (0., 4.));
+        assert_eq!(output_data.area, 8.);
+
+        // 範囲を指定した定積分 (欠損行で区切る)
+        let command = ColumnIntegrateCommand {
+            x_column_id: x.clone_value(),
+            y_column_id: y.clone_value(),
+            method: IntegrationMethodInCommand::Trapezoid,
+            gap_handling: GapHandlingInCommand::Split,
+            lower_limit: Some(0.5),
+            upper_limit: Some(4.),
+        };
+        let output_data = service.handle(command).await?;
+        assert!((output_data.area - (0.375 + 3.5)).abs() < 1e-12);
+        assert_eq!(column_repository.find_all().await?.len(), 4);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handle_limit_out_of_range() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let x = save_column(&column_repository, "x", vec![Some(0.), Some(1.)]).await?;
+        let y = save_column(&column_repository, "y", vec![Some(1.), Some(3.)]).await?;
+
+        let service = ColumnIntegrateService::new(&column_factory, &column_repository);
+        let command = ColumnIntegrateCommand {
+            x_column_id: x.clone_value(),
+            y_column_id: y.clone_value(),
+            method: IntegrationMethodInCommand::Simpson,
+            gap_handling: GapHandlingInCommand::Bridge,
+            lower_limit: None,
+            upper_limit: Some(2.),
+        };
+        assert!(matches!(
+            service.handle(command).await,
+            Err(ColumnIntegrateServiceError::NumericalCalculusError(
+                NumericalCalculusError::LimitOutOfRange(_)
+            ))
+        ));
+        assert_eq!(column_repository.find_all().await?.len(), 2);
+        Ok(())
+    }
+}
@@ -0,0 +1,10 @@
+/* 数値積分用アプリケーションサービス */
+// コマンドオブジェクト
+pub mod column_integrate_command;
+
+// アプリケーションサービス
+pub mod column_integrate_service;
+pub mod column_integrate_service_impl;
+
+// DTO
+pub mod column_integrate_output_data;
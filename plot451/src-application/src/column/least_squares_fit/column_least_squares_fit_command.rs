@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+use src_domain::services::least_squares::LeastSquaresModel;
+
+#[derive(Deserialize, Serialize)]
+pub struct ColumnLeastSquaresFitCommand {
+    pub(super) x_column_id: String,
+    pub(super) y_column_id: String,
+    pub(super) model: LeastSquaresModelInCommand,
+    // 指定した場合は当てはめ曲線と残差をカラムとしてディレクトリに保存する
+    #[serde(default)]
+    pub(super) save_directory_id: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LeastSquaresModelInCommand {
+    Linear,
+    Polynomial { degree: usize },
+    LinearFixedIntercept { intercept: f64 },
+}
+
+impl LeastSquaresModelInCommand {
+    pub(super) fn to_least_squares_model(&self) -> LeastSquaresModel {
+        match self {
+            LeastSquaresModelInCommand::Linear => LeastSquaresModel::linear(),
+            LeastSquaresModelInCommand::Polynomial { degree } => {
+                LeastSquaresModel::Polynomial(*degree)
+            }
+            LeastSquaresModelInCommand::LinearFixedIntercept { intercept } => {
+                LeastSquaresModel::LinearFixedIntercept(*intercept)
+            }
+        }
+    }
+}
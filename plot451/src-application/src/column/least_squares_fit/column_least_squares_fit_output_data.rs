@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+
+use src_domain::{
+    models::column::column_id::ColumnId, services::least_squares::LeastSquaresFit,
+    shared::value_object::ValueObject,
+};
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+pub struct ColumnLeastSquaresFitOutputData {
+    pub(super) x_column_id: String,
+    pub(super) y_column_id: String,
+    // 次数の昇順 (c0, c1, ...)
+    pub(super) coefficients: Vec<FitCoefficientInOutputData>,
+    pub(super) r_squared: Option<f64>,
+    pub(super) degrees_of_freedom: usize,
+    pub(super) residual_sum_of_squares: f64,
+    pub(super) fitted: Vec<Option<f64>>,
+    pub(super) residuals: Vec<Option<f64>>,
+    pub(super) fitted_column_id: Option<String>,
+    pub(super) residuals_column_id: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+pub(super) struct FitCoefficientInOutputData {
+    pub(super) value: f64,
+    pub(super) standard_error: Option<f64>,
+    pub(super) fixed: bool,
+}
+
+impl ColumnLeastSquaresFitOutputData {
+    pub(super) fn new(
+        x_column_id: &ColumnId,
+        y_column_id: &ColumnId,
+        source: LeastSquaresFit,
+        saved_column_ids: Option<(ColumnId, ColumnId)>,
+    ) -> Self {
+        let (fitted_column_id, residuals_column_id) = match saved_column_ids {
+            Some((fitted, residuals)) => {
+                (Some(fitted.clone_value()), Some(residuals.clone_value()))
+            }
+            None => (None, None),
+        };
+        Self {
+            x_column_id: x_column_id.clone_value(),
+            y_column_id: y_column_id.clone_value(),
+            coefficients: source
+                .coefficients()
+                .iter()
+                .map(|coefficient| FitCoefficientInOutputData {
+                    value: coefficient.value(),
+                    standard_error: coefficient.standard_error(),
+                    fixed: coefficient.fixed(),
+                })
+                .collect(),
+            r_squared: source.r_squared(),
+            degrees_of_freedom: source.degrees_of_freedom(),
+            residual_sum_of_squares: source.residual_sum_of_squares(),
+            fitted: source.fitted().clone(),
+            residuals: source.residuals().clone(),
+            fitted_column_id,
+            residuals_column_id,
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use thiserror::Error;
+
+use src_domain::{
+    models::column::{
+        column_directory::column_directory_id::ColumnDirectoryIdError,
+        column_id::{ColumnId, ColumnIdError},
+        column_name::ColumnNameError,
+        column_repository::ColumnRepositoryError,
+    },
+    services::{
+        column_creation_service::ColumnCreationServiceError, least_squares::LeastSquaresError,
+    },
+};
+
+use super::{
+    column_least_squares_fit_command::ColumnLeastSquaresFitCommand,
+    column_least_squares_fit_output_data::ColumnLeastSquaresFitOutputData,
+};
+
+pub type ColumnLeastSquaresFitServiceResult<T> =
+    anyhow::Result<T, ColumnLeastSquaresFitServiceError>;
+
+pub trait IColumnLeastSquaresFitService {
+    fn handle(
+        &self,
+        command: ColumnLeastSquaresFitCommand,
+    ) -> impl std::future::Future<
+        Output = ColumnLeastSquaresFitServiceResult<ColumnLeastSquaresFitOutputData>,
+    > + Send;
+}
+
+#[derive(Debug, Error)]
+pub enum ColumnLeastSquaresFitServiceError {
+    // repository errors
+    #[error("ColumnRepositoryError: [{0}]")]
+    ColumnRepositoryError(ColumnRepositoryError),
+
+    // value object errors
+    #[error("ColumnIdError: [{0}]")]
+    ColumnIdError(ColumnIdError),
+    #[error("ColumnNameError: [{0}]")]
+    ColumnNameError(ColumnNameError),
+    #[error("ColumnDirectoryIdError: [{0}]")]
+    ColumnDirectoryIdError(ColumnDirectoryIdError),
+
+    // domain service errors
+    #[error("LeastSquaresError: [{0}]")]
+    LeastSquaresError(LeastSquaresError),
+    #[error("ColumnCreationServiceError: [{0}]")]
+    ColumnCreationServiceError(ColumnCreationServiceError),
+
+    // not found errors
+    #[error("Column not found, column_id: {0:?}")]
+    ColumnNotFound(ColumnId),
+}
@@ -0,0 +1,243 @@
+use src_domain::{
+    models::column::{
+        column_directory::column_directory_id::ColumnDirectoryId,
+        column_factory::IColumnFactory,
+        column_id::ColumnId,
+        column_name::ColumnName,
+        column_provenance::{AnalysisKind, ColumnAnalysis, ColumnProvenance},
+        column_repository::IColumnRepository,
+    },
+    services::{
+        column_creation_service::ColumnCreationService, column_values_service::ColumnValuesService,
+        least_squares::LeastSquaresFit,
+    },
+    shared::value_object::ValueObject,
+};
+
+use super::{
+    column_least_squares_fit_command::ColumnLeastSquaresFitCommand,
+    column_least_squares_fit_output_data::ColumnLeastSquaresFitOutputData,
+    column_least_squares_fit_service::{
+        ColumnLeastSquaresFitServiceError, ColumnLeastSquaresFitServiceResult,
+        IColumnLeastSquaresFitService,
+    },
+};
+
+pub struct ColumnLeastSquaresFitService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory,
+    CR: IColumnRepository,
+{
+    column_factory: &'a CF,
+    column_repository: &'b CR,
+}
+
+impl<'a, 'b, CF, CR> ColumnLeastSquaresFitService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+{
+    pub fn new(column_factory: &'a CF, column_repository: &'b CR) -> Self {
+        Self {
+            column_factory,
+            column_repository,
+        }
+    }
+}
+
+impl<'a, 'b, CF, CR> IColumnLeastSquaresFitService for ColumnLeastSquaresFitService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+{
+    async fn handle(
+        &self,
+        command: ColumnLeastSquaresFitCommand,
+    ) -> ColumnLeastSquaresFitServiceResult<ColumnLeastSquaresFitOutputData> {
+        let ColumnLeastSquaresFitCommand {
+            x_column_id,
+            y_column_id,
+            model,
+            save_directory_id,
+        } = command;
+
+        // 値オブジェクトのインスタンス化
+        let x_column_id =
+            ColumnId::new(x_column_id).map_err(ColumnLeastSquaresFitServiceError::ColumnIdError)?;
+        let y_column_id =
+            ColumnId::new(y_column_id).map_err(ColumnLeastSquaresFitServiceError::ColumnIdError)?;
+        let save_directory_id = save_directory_id
+            .map(ColumnDirectoryId::new)
+            .transpose()
+            .map_err(ColumnLeastSquaresFitServiceError::ColumnDirectoryIdError)?;
+
+        // 当てはめ
+        let (_, x_values) = ColumnValuesService::new(self.column_repository)
+            .find_column_values(&x_column_id)
+            .await
+            .map_err(ColumnLeastSquaresFitServiceError::ColumnRepositoryError)?
+            .ok_or(ColumnLeastSquaresFitServiceError::ColumnNotFound(
+                x_column_id.clone(),
+            ))?;
+        let (y_column, y_values) = ColumnValuesService::new(self.column_repository)
+            .find_column_values(&y_column_id)
+            .await
+            .map_err(ColumnLeastSquaresFitServiceError::ColumnRepositoryError)?
+            .ok_or(ColumnLeastSquaresFitServiceError::ColumnNotFound(
+                y_column_id.clone(),
+            ))?;
+        let fit = LeastSquaresFit::fit(model.to_least_squares_model(), &x_values, &y_values)
+            .map_err(ColumnLeastSquaresFitServiceError::LeastSquaresError)?;
+
+        // 当てはめ曲線と残差をカラムとして保存
+        let saved_column_ids = match save_directory_id {
+            Some(directory_id) => {
+                let column_creation_service =
+                    ColumnCreationService::new(self.column_factory, self.column_repository);
+                let provenance = ColumnProvenance::Analysis(ColumnAnalysis::new(
+                    AnalysisKind::LeastSquaresFit,
+                    vec![x_column_id.clone(), y_column_id.clone()],
+                ));
+                let fitted_name = ColumnName::new(format!("{} fit", y_column.name()))
+                    .map_err(ColumnLeastSquaresFitServiceError::ColumnNameError)?;
+                let (fitted_column, _) = column_creation_service
+                    .create_column_from(
+                        fitted_name,
+                        directory_id.clone(),
+                        fit.fitted().clone(),
+                        provenance.clone(),
+                    )
+                    .await
+                    .map_err(ColumnLeastSquaresFitServiceError::ColumnCreationServiceError)?;
+                let residuals_name = ColumnName::new(format!("{} residuals", y_column.name()))
+                    .map_err(ColumnLeastSquaresFitServiceError::ColumnNameError)?;
+                let (residuals_column, _) = column_creation_service
+                    .create_column_from(
+                        residuals_name,
+                        directory_id,
+                        fit.residuals().clone(),
+                        provenance,
+                    )
+                    .await
+                    .map_err(ColumnLeastSquaresFitServiceError::ColumnCreationServiceError)?;
+                Some((fitted_column.id().clone(), residuals_column.id().clone()))
+            }
+            None => None,
+        };
+
+        Ok(ColumnLeastSquaresFitOutputData::new(
+            &x_column_id,
+            &y_column_id,
+            fit,
+            saved_column_ids,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use src_domain::services::least_squares::LeastSquaresError;
+    use src_in_memory_infrastructure::column::{
+        in_memory_column_factory::InMemoryColumnFactory,
+        in_memory_column_repository::InMemoryColumnRepository,
+    };
+
+    use crate::column::least_squares_fit::column_least_squares_fit_command::LeastSquaresModelInCommand;
+    use crate::test_utils::save_column;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let x = save_column(
+            &column_repository,
+            "x",
+            vec![Some(0.), Some(1.), Some(2.), None],
+        )
+        .await?;
+        let y = save_column(
+            &column_repository,
+            "y",
+            vec![Some(1.), Some(3.), Some(5.), Some(7.)],
+        )
+        .await?;
+
+        let service = ColumnLeastSquaresFitService::new(&column_factory, &column_repository);
+        let command = ColumnLeastSquaresFitCommand {
+            x_column_id: x.clone_value(),
+            y_column_id: y.clone_value(),
+            model: LeastSquaresModelInCommand::Linear,
+            save_directory_id: Some("1".to_string()),
+        };
+        let output_data = service.handle(command).await?;
+        let coefficients: Vec<f64> = output_data
+            .coefficients
+            .iter()
+            .map(|coefficient| coefficient.value)
+            .collect();
+        assert!((coefficients[0] - 1.).abs() < 1e-12);
+        assert!((coefficients[1] - 2.).abs() < 1e-12);
+        assert!((output_data.r_squared.unwrap() - 1.).abs() < 1e-12);
+        assert_eq!(output_data.residuals.len(), 4);
+        assert!(output_data.residuals[..3]
+            .iter()
+            .all(|residual| residual.unwrap().abs() < 1e-12));
+        assert_eq!(output_data.residuals[3], None);
+
+        // 当てはめ曲線が指定したディレクトリに保存されている
+        let fitted_column_id = ColumnId::new(output_data.fitted_column_id.unwrap())?;
+        let fitted_column = column_repository.find(&fitted_column_id).await?.unwrap();
+        assert_eq!(fitted_column.name().value(), "y fit");
+        assert_eq!(fitted_column.directory_id().value(), "1");
+        assert_eq!(
+            fitted_column.provenance(),
+            &Some(ColumnProvenance::Analysis(ColumnAnalysis::new(
+                AnalysisKind::LeastSquaresFit,
+                vec![x.clone(), y.clone()]
+            )))
+        );
+        let fitted = ColumnValuesService::new(&column_repository)
+            .find_values(&fitted_column)
+            .await?;
+        assert_eq!(fitted.len(), 4);
+        assert!((fitted[2].unwrap() - 5.).abs() < 1e-12);
+        assert_eq!(fitted[3], None);
+        assert!(output_data.residuals_column_id.is_some());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handle_without_save() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let x = save_column(&column_repository, "x", vec![Some(0.), Some(1.)]).await?;
+        let y = save_column(&column_repository, "y", vec![Some(1.), Some(3.)]).await?;
+
+        let service = ColumnLeastSquaresFitService::new(&column_factory, &column_repository);
+        let command = ColumnLeastSquaresFitCommand {
+            x_column_id: x.clone_value(),
+            y_column_id: y.clone_value(),
+            model: LeastSquaresModelInCommand::Polynomial { degree: 2 },
+            save_directory_id: None,
+        };
+        match service.handle(command).await {
+            Err(ColumnLeastSquaresFitServiceError::LeastSquaresError(
+                LeastSquaresError::InsufficientPoints(3, 2),
+            )) => {}
+            _ => panic!("unexpected result"),
+        }
+
+        let command = ColumnLeastSquaresFitCommand {
+            x_column_id: x.clone_value(),
+            y_column_id: y.clone_value(),
+            model: LeastSquaresModelInCommand::LinearFixedIntercept { intercept: 0. },
+            save_directory_id: None,
+        };
+        let output_data = service.handle(command).await?;
+        assert_eq!(output_data.fitted_column_id, None);
+        assert_eq!(column_repository.find_all().await?.len(), 2);
+        Ok(())
+    }
+}
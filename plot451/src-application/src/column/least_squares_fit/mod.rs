@@ -0,0 +1,10 @@
+/* 線形最小二乗法による当てはめ用アプリケーションサービス */
+// コマンドオブジェクト
+pub mod column_least_squares_fit_command;
+
+// アプリケーションサービス
+pub mod column_least_squares_fit_service;
+pub mod column_least_squares_fit_service_impl;
+
+// DTO
+pub mod column_least_squares_fit_output_data;
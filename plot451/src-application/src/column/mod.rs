@@ -20,4 +20,55 @@ pub mod create_directory;
 pub mod delete_directory;
 
 // セル編集用アプリケーションサー編集
-pub mod edit_cell;
\ No newline at end of file
+pub mod edit_cell;
+
+// カラムの制約編集用アプリケーションサービス
+pub mod edit_constraints;
+
+// 派生カラム作成用アプリケーションサービス
+pub mod create_formula;
+
+// 派生カラムの数式編集用アプリケーションサービス
+pub mod edit_formula;
+
+// 記述統計量計算用アプリケーションサービス
+pub mod statistics;
+
+// 線形最小二乗法による当てはめ用アプリケーションサービス
+pub mod least_squares_fit;
+
+// 非線形最小二乗法による当てはめ用アプリケーションサービス
+pub mod nonlinear_fit;
+
+// 数値微分用アプリケーションサービス
+pub mod differentiate;
+
+// 数値積分用アプリケーションサービス
+pub mod integrate;
+
+// 補間による再標本化用アプリケーションサービス
+pub mod resample;
+
+// 平滑化用アプリケーションサービス
+pub mod smooth;
+
+// フーリエ変換によるスペクトル解析用アプリケーションサービス
+pub mod fft;
+
+// ヒストグラム作成用アプリケーションサービス
+pub mod histogram;
+
+// ピーク検出用アプリケーションサービス
+pub mod detect_peaks;
+
+// ベースライン補正用アプリケーションサービス
+pub mod correct_baseline;
+
+// 統計的仮説検定用アプリケーションサービス
+pub mod hypothesis_test;
+
+// カラムの変換・正規化用アプリケーションサービス
+pub mod transform;
+
+// 数列・乱数によるカラム生成用アプリケーションサービス
+pub mod generate;
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+use src_domain::services::nonlinear_fit::{FitParameter, NonlinearFitError};
+
+#[derive(Deserialize, Serialize)]
+pub struct ColumnNonlinearFitCommand {
+    pub(super) x_column_id: String,
+    pub(super) y_column_id: String,
+    // 指定した場合は 1 / σ² で重み付けする
+    #[serde(default)]
+    pub(super) error_column_id: Option<String>,
+    // 独立変数 x とパラメータの式 (例: "A * exp(-x / tau) + C")
+    pub(super) expression: String,
+    pub(super) parameters: Vec<FitParameterInCommand>,
+    #[serde(default)]
+    pub(super) max_iterations: Option<usize>,
+    // true の場合は当てはめ曲線と残差を Y カラムと同じディレクトリに保存する
+    #[serde(default)]
+    pub(super) save: bool,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct FitParameterInCommand {
+    pub(super) name: String,
+    // 省略した場合は 1 (範囲外の場合は範囲内に収める)
+    #[serde(default)]
+    pub(super) initial_value: Option<f64>,
+    #[serde(default)]
+    pub(super) fixed: bool,
+    #[serde(default)]
+    pub(super) lower_bound: Option<f64>,
+    #[serde(default)]
+    pub(super) upper_bound: Option<f64>,
+}
+
+impl FitParameterInCommand {
+    pub(super) fn to_fit_parameter(&self) -> Result<FitParameter, NonlinearFitError> {
+        let initial_value = self.initial_value.unwrap_or_else(|| {
+            let value = self.lower_bound.map_or(1., |lower| lower.max(1.));
+            self.upper_bound.map_or(value, |upper| upper.min(value))
+        });
+        FitParameter::new(
+            self.name.clone(),
+            initial_value,
+            self.fixed,
+            self.lower_bound,
+            self.upper_bound,
+        )
+    }
+}
@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+
+use src_domain::{
+    models::column::column_id::ColumnId,
+    services::nonlinear_fit::{FitTermination, NonlinearFit},
+    shared::value_object::ValueObject,
+};
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+pub struct ColumnNonlinearFitOutputData {
+    pub(super) x_column_id: String,
+    pub(super) y_column_id: String,
+    pub(super) error_column_id: Option<String>,
+    pub(super) expression: String,
+    // コマンドで指定した順序
+    pub(super) parameters: Vec<FittedParameterInOutputData>,
+    // parameters と同じ順序 (固定したパラメータの行と列は 0)
+    pub(super) covariance: Option<Vec<Vec<f64>>>,
+    pub(super) chi_square: f64,
+    pub(super) reduced_chi_square: Option<f64>,
+    pub(super) degrees_of_freedom: usize,
+    pub(super) iterations: usize,
+    pub(super) converged: bool,
+    pub(super) termination: FitTerminationInOutputData,
+    pub(super) fitted: Vec<Option<f64>>,
+    pub(super) residuals: Vec<Option<f64>>,
+    pub(super) fitted_column_id: Option<String>,
+    pub(super) residuals_column_id: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+pub(super) struct FittedParameterInOutputData {
+    pub(super) name: String,
+    pub(super) value: f64,
+    pub(super) standard_error: Option<f64>,
+    pub(super) fixed: bool,
+}
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub(super) enum FitTerminationInOutputData {
+    ChiSquareConverged,
+    StepConverged,
+    MaxIterations,
+    DampingOverflow,
+}
+
+impl From<FitTermination> for FitTerminationInOutputData {
+    fn from(termination: FitTermination) -> Self {
+        match termination {
+            FitTermination::ChiSquareConverged => FitTerminationInOutputData::ChiSquareConverged,
+            FitTermination::StepConverged => FitTerminationInOutputData::StepConverged,
+            FitTermination::MaxIterations => FitTerminationInOutputData::MaxIterations,
+            FitTermination::DampingOverflow => FitTerminationInOutputData::DampingOverflow,
+        }
+    }
+}
+
+impl ColumnNonlinearFitOutputData {
+    pub(super) fn new(
+        x_column_id: &ColumnId,
+        y_column_id: &ColumnId,
+        error_column_id: Option<&ColumnId>,
+        expression: String,
+        source: NonlinearFit,
+        saved_column_ids: Option<(ColumnId, ColumnId)>,
+    ) -> Self {
+        let (fitted_column_id, residuals_column_id) = match saved_column_ids {
+            Some((fitted, residuals)) => {
+                (Some(fitted.clone_value()), Some(residuals.clone_value()))
+            }
+            None => (None, None),
+        };
+        Self {
+            x_column_id: x_column_id.clone_value(),
+            y_column_id: y_column_id.clone_value(),
+            error_column_id: error_column_id.map(|column_id| column_id.clone_value()),
+            expression,
+            parameters: source
+                .parameters()
+                .iter()
+                .map(|parameter| FittedParameterInOutputData {
+                    name: parameter.name().to_string(),
+                    value: parameter.value(),
+                    standard_error: parameter.standard_error(),
+                    fixed: parameter.fixed(),
+                })
+                .collect(),
+            covariance: source.covariance().cloned(),
+            chi_square: source.chi_square(),
+            reduced_chi_square: source.reduced_chi_square(),
+            degrees_of_freedom: source.degrees_of_freedom(),
+            iterations: source.iterations(),
+            converged: source.converged(),
+            termination: source.termination().into(),
+            fitted: source.fitted().clone(),
+            residuals: source.residuals().clone(),
+            fitted_column_id,
+            residuals_column_id,
+        }
+    }
+}
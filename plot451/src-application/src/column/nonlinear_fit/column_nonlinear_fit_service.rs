@@ -0,0 +1,54 @@
+use thiserror::Error;
+
+use src_domain::{
+    models::column::{
+        column_formula::formula_expression::FormulaExpressionError,
+        column_id::{ColumnId, ColumnIdError},
+        column_name::ColumnNameError,
+        column_repository::ColumnRepositoryError,
+    },
+    services::{
+        column_creation_service::ColumnCreationServiceError, nonlinear_fit::NonlinearFitError,
+    },
+};
+
+use super::{
+    column_nonlinear_fit_command::ColumnNonlinearFitCommand,
+    column_nonlinear_fit_output_data::ColumnNonlinearFitOutputData,
+};
+
+pub type ColumnNonlinearFitServiceResult<T> = anyhow::Result<T, ColumnNonlinearFitServiceError>;
+
+pub trait IColumnNonlinearFitService {
+    fn handle(
+        &self,
+        command: ColumnNonlinearFitCommand,
+    ) -> impl std::future::Future<
+        Output = ColumnNonlinearFitServiceResult<ColumnNonlinearFitOutputData>,
+    > + Send;
+}
+
+#[derive(Debug, Error)]
+pub enum ColumnNonlinearFitServiceError {
+    // repository errors
+    #[error("ColumnRepositoryError: [{0}]")]
+    ColumnRepositoryError(ColumnRepositoryError),
+
+    // value object errors
+    #[error("ColumnIdError: [{0}]")]
+    ColumnIdError(ColumnIdError),
+    #[error("ColumnNameError: [{0}]")]
+    ColumnNameError(ColumnNameError),
+    #[error("FormulaExpressionError: [{0}]")]
+    FormulaExpressionError(FormulaExpressionError),
+
+    // domain service errors
+    #[error("NonlinearFitError: [{0}]")]
+    NonlinearFitError(NonlinearFitError),
+    #[error("ColumnCreationServiceError: [{0}]")]
+    ColumnCreationServiceError(ColumnCreationServiceError),
+
+    // not found errors
+    #[error("Column not found, column_id: {0:?}")]
+    ColumnNotFound(ColumnId),
+}
@@ -0,0 +1,310 @@
+use src_domain::{
+    models::column::{
+        column_factory::IColumnFactory,
+        column_formula::formula_expression::FormulaExpression,
+        column_id::ColumnId,
+        column_name::ColumnName,
+        column_provenance::{AnalysisKind, ColumnAnalysis, ColumnProvenance},
+        column_repository::IColumnRepository,
+    },
+    services::{
+        column_creation_service::ColumnCreationService,
+        column_values_service::ColumnValuesService,
+        nonlinear_fit::{FitModel, FitParameter, NonlinearFit},
+    },
+    shared::value_object::ValueObject,
+};
+
+use super::{
+    column_nonlinear_fit_command::ColumnNonlinearFitCommand,
+    column_nonlinear_fit_output_data::ColumnNonlinearFitOutputData,
+    column_nonlinear_fit_service::{
+        ColumnNonlinearFitServiceError, ColumnNonlinearFitServiceResult, IColumnNonlinearFitService,
+    },
+};
+
+// 最大反復回数の既定値
+const DEFAULT_MAX_ITERATIONS: usize = 200;
+
+pub struct ColumnNonlinearFitService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory,
+    CR: IColumnRepository,
+{
+    column_factory: &'a CF,
+    column_repository: &'b CR,
+}
+
+impl<'a, 'b, CF, CR> ColumnNonlinearFitService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+{
+    pub fn new(column_factory: &'a CF, column_repository: &'b CR) -> Self {
+        Self {
+            column_factory,
+            column_repository,
+        }
+    }
+}
+
+impl<'a, 'b, CF, CR> IColumnNonlinearFitService for ColumnNonlinearFitService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+{
+    async fn handle(
+        &self,
+        command: ColumnNonlinearFitCommand,
+    ) -> ColumnNonlinearFitServiceResult<ColumnNonlinearFitOutputData> {
+        let ColumnNonlinearFitCommand {
+            x_column_id,
+            y_column_id,
+            error_column_id,
+            expression,
+            parameters,
+            max_iterations,
+            save,
+        } = command;
+
+        // 値オブジェクトのインスタンス化
+        let x_column_id =
+            ColumnId::new(x_column_id).map_err(ColumnNonlinearFitServiceError::ColumnIdError)?;
+        let y_column_id =
+            ColumnId::new(y_column_id).map_err(ColumnNonlinearFitServiceError::ColumnIdError)?;
+        let error_column_id = error_column_id
+            .map(ColumnId::new)
+            .transpose()
+            .map_err(ColumnNonlinearFitServiceError::ColumnIdError)?;
+
+        // モデルの組み立て
+        let parameters = parameters
+            .iter()
+            .map(|parameter| parameter.to_fit_parameter())
+            .collect::<Result<Vec<FitParameter>, _>>()
+            .map_err(ColumnNonlinearFitServiceError::NonlinearFitError)?;
+        let model = FitModel::new(
+            FormulaExpression::parse(&expression)
+                .map_err(ColumnNonlinearFitServiceError::FormulaExpressionError)?,
+            parameters,
+        )
+        .map_err(ColumnNonlinearFitServiceError::NonlinearFitError)?;
+
+        // 当てはめ
+        let (_, x_values) = ColumnValuesService::new(self.column_repository)
+            .find_column_values(&x_column_id)
+            .await
+            .map_err(ColumnNonlinearFitServiceError::ColumnRepositoryError)?
+            .ok_or(ColumnNonlinearFitServiceError::ColumnNotFound(
+                x_column_id.clone(),
+            ))?;
+        let (y_column, y_values) = ColumnValuesService::new(self.column_repository)
+            .find_column_values(&y_column_id)
+            .await
+            .map_err(ColumnNonlinearFitServiceError::ColumnRepositoryError)?
+            .ok_or(ColumnNonlinearFitServiceError::ColumnNotFound(
+                y_column_id.clone(),
+            ))?;
+        let error_values = match &error_column_id {
+            Some(column_id) => Some(
+                ColumnValuesService::new(self.column_repository)
+                    .find_column_values(column_id)
+                    .await
+                    .map_err(ColumnNonlinearFitServiceError::ColumnRepositoryError)?
+                    .ok_or(ColumnNonlinearFitServiceError::ColumnNotFound(
+                        column_id.clone(),
+                    ))?
+                    .1,
+            ),
+            None => None,
+        };
+        let fit = NonlinearFit::fit(
+            &model,
+            &x_values,
+            &y_values,
+            error_values.as_deref(),
+            max_iterations.unwrap_or(DEFAULT_MAX_ITERATIONS),
+        )
+        .map_err(ColumnNonlinearFitServiceError::NonlinearFitError)?;
+
+        // 当てはめ曲線と残差を Y カラムと同じディレクトリに保存
+        let saved_column_ids = if save {
+            let column_creation_service =
+                ColumnCreationService::new(self.column_factory, self.column_repository);
+            let mut sources = vec![x_column_id.clone(), y_column_id.clone()];
+            sources.extend(error_column_id.clone());
+            let provenance = ColumnProvenance::Analysis(ColumnAnalysis::new(
+                AnalysisKind::NonlinearFit,
+                sources,
+            ));
+            let fitted_name = ColumnName::new(format!("{} fit", y_column.name()))
+                .map_err(ColumnNonlinearFitServiceError::ColumnNameError)?;
+            let (fitted_column, _) = column_creation_service
+                .create_column_from(
+                    fitted_name,
+                    y_column.directory_id().clone(),
+                    fit.fitted().clone(),
+                    provenance.clone(),
+                )
+                .await
+                .map_err(ColumnNonlinearFitServiceError::ColumnCreationServiceError)?;
+            let residuals_name = ColumnName::new(format!("{} residuals", y_column.name()))
+                .map_err(ColumnNonlinearFitServiceError::ColumnNameError)?;
+            let (residuals_column, _) = column_creation_service
+                .create_column_from(
+                    residuals_name,
+                    y_column.directory_id().clone(),
+                    fit.residuals().clone(),
+                    provenance,
+                )
+                .await
+                .map_err(ColumnNonlinearFitServiceError::ColumnCreationServiceError)?;
+            Some((fitted_column.id().clone(), residuals_column.id().clone()))
+        } else {
+            None
+        };
+
+        Ok(ColumnNonlinearFitOutputData::new(
+            &x_column_id,
+            &y_column_id,
+            error_column_id.as_ref(),
+            expression,
+            fit,
+            saved_column_ids,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use src_domain::services::nonlinear_fit::NonlinearFitError;
+    use src_in_memory_infrastructure::column::{
+        in_memory_column_factory::InMemoryColumnFactory,
+        in_memory_column_repository::InMemoryColumnRepository,
+    };
+
+    use crate::column::nonlinear_fit::{
+        column_nonlinear_fit_command::FitParameterInCommand,
+        column_nonlinear_fit_output_data::FitTerminationInOutputData,
+    };
+    use crate::test_utils::save_column_in_directory;
+
+    use super::*;
+
+    fn parameter(name: &str, initial_value: Option<f64>, fixed: bool) -> FitParameterInCommand {
+        FitParameterInCommand {
+            name: name.to_string(),
+            initial_value,
+            fixed,
+            lower_bound: None,
+            upper_bound: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handle() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let x_values: Vec<Option<f64>> = (0..15).map(|i| Some(i as f64 * 0.4)).collect();
+        let y_values: Vec<Option<f64>> = x_values
+            .iter()
+            .map(|x| x.map(|x| 2. * (-x / 1.5).exp() + 0.3))
+            .collect();
+        let x = save_column_in_directory(&column_repository, "t", "3", x_values).await?;
+        let y = save_column_in_directory(&column_repository, "signal", "3", y_values).await?;
+        let error =
+            save_column_in_directory(&column_repository, "error", "3", vec![Some(0.01); 15])
+                .await?;
+
+        let service = ColumnNonlinearFitService::new(&column_factory, &column_repository);
+        let command = ColumnNonlinearFitCommand {
+            x_column_id: x.clone_value(),
+            y_column_id: y.clone_value(),
+            error_column_id: Some(error.clone_value()),
+            expression: "A * exp(-x / tau) + C".to_string(),
+            parameters: vec![
+                parameter("A", None, false),
+                FitParameterInCommand {
+                    lower_bound: Some(0.1),
+                    ..parameter("tau", Some(1.), false)
+                },
+                parameter("C", Some(0.3), true),
+            ],
+            max_iterations: None,
+            save: true,
+        };
+        let output_data = service.handle(command).await?;
+        assert!(output_data.converged);
+        assert_ne!(
+            output_data.termination,
+            FitTerminationInOutputData::MaxIterations
+        );
+        let names: Vec<&str> = output_data
+            .parameters
+            .iter()
+            .map(|parameter| parameter.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["A", "tau", "C"]);
+        assert!((output_data.parameters[0].value - 2.).abs() < 1e-6);
+        assert!((output_data.parameters[1].value - 1.5).abs() < 1e-6);
+        assert!(output_data.parameters[2].fixed);
+        assert!(output_data.parameters[0].standard_error.is_some());
+        assert_eq!(output_data.degrees_of_freedom, 13);
+        assert!(output_data.chi_square < 1e-9);
+        assert_eq!(output_data.covariance.as_ref().unwrap().len(), 3);
+
+        // 当てはめ曲線と残差が Y カラムと同じディレクトリに保存されている
+        let fitted_column_id = ColumnId::new(output_data.fitted_column_id.unwrap())?;
+        let fitted_column = column_repository.find(&fitted_column_id).await?.unwrap();
+        assert_eq!(fitted_column.name().value(), "signal fit");
+        assert_eq!(fitted_column.directory_id().value(), "3");
+        assert_eq!(fitted_column.cells().len(), 15);
+        let residuals_column_id = ColumnId::new(output_data.residuals_column_id.unwrap())?;
+        let residuals_column = column_repository.find(&residuals_column_id).await?.unwrap();
+        assert_eq!(residuals_column.name().value(), "signal residuals");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handle_errors() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let x = save_column_in_directory(&column_repository, "x", "3", vec![Some(0.), Some(1.)])
+            .await?;
+        let y = save_column_in_directory(&column_repository, "y", "3", vec![Some(1.), Some(3.)])
+            .await?;
+
+        let service = ColumnNonlinearFitService::new(&column_factory, &column_repository);
+        let command = ColumnNonlinearFitCommand {
+            x_column_id: x.clone_value(),
+            y_column_id: y.clone_value(),
+            error_column_id: None,
+            expression: "a * x + b".to_string(),
+            parameters: vec![parameter("a", None, false)],
+            max_iterations: None,
+            save: false,
+        };
+        match service.handle(command).await {
+            Err(ColumnNonlinearFitServiceError::NonlinearFitError(
+                NonlinearFitError::UndefinedParameter(name),
+            )) => assert_eq!(name, "b"),
+            _ => panic!("unexpected result"),
+        }
+
+        let command = ColumnNonlinearFitCommand {
+            x_column_id: x.clone_value(),
+            y_column_id: "999".to_string(),
+            error_column_id: None,
+            expression: "a * x".to_string(),
+            parameters: vec![parameter("a", None, false)],
+            max_iterations: None,
+            save: false,
+        };
+        assert!(matches!(
+            service.handle(command).await,
+            Err(ColumnNonlinearFitServiceError::ColumnNotFound(_))
+        ));
+        assert_eq!(column_repository.find_all().await?.len(), 2);
+        Ok(())
+    }
+}
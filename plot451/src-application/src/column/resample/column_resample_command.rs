@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+use src_domain::services::interpolation::{Extrapolation, InterpolationMethod};
+
+#[derive(Deserialize, Serialize)]
+pub struct ColumnResampleCommand {
+    pub(super) x_column_id: String,
+    pub(super) y_column_id: String,
+    pub(super) target: ResampleTargetInCommand,
+    pub(super) method: InterpolationMethodInCommand,
+    #[serde(default)]
+    pub(super) extrapolation: ExtrapolationInCommand,
+}
+
+// 再標本化先の x
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ResampleTargetInCommand {
+    // 既存のカラム
+    Column { column_id: String },
+    // 等間隔の点列 (新しいカラムとして保存する)
+    Linspace { start: f64, end: f64, count: usize },
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum InterpolationMethodInCommand {
+    Linear,
+    CubicSpline,
+    Akima,
+    Nearest,
+}
+
+impl InterpolationMethodInCommand {
+    pub(super) fn to_interpolation_method(self) -> InterpolationMethod {
+        match self {
+            InterpolationMethodInCommand::Linear => InterpolationMethod::Linear,
+            InterpolationMethodInCommand::CubicSpline => InterpolationMethod::CubicSpline,
+            InterpolationMethodInCommand::Akima => InterpolationMethod::Akima,
+            InterpolationMethodInCommand::Nearest => InterpolationMethod::Nearest,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ExtrapolationInCommand {
+    #[default]
+    Missing,
+    Clamp,
+    Extend,
+}
+
+impl ExtrapolationInCommand {
+    pub(super) fn to_extrapolation(self) -> Extrapolation {
+        match self {
+            ExtrapolationInCommand::Missing => Extrapolation::Missing,
+            ExtrapolationInCommand::Clamp => Extrapolation::Clamp,
+            ExtrapolationInCommand::Extend => Extrapolation::Extend,
+        }
+    }
+}
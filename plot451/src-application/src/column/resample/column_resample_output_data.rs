@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+
+use src_domain::{
+    models::column::{column_id::ColumnId, column_with_cells::ColumnWithCells},
+    shared::value_object::ValueObject,
+};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct ColumnResampleOutputData {
+    // 再標本化先の x のカラム (等間隔の点列の場合は新しく作成したカラム)
+    pub(super) x_column_id: String,
+    // 補間した y のカラム
+    pub(super) column_id: String,
+    pub(super) column_name: String,
+    pub(super) cells: Vec<ColumnCellInOutputData>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub(super) struct ColumnCellInOutputData {
+    pub(super) cell_id: String,
+    pub(super) cell_value: Option<f64>,
+}
+
+impl ColumnResampleOutputData {
+    pub(super) fn new(x_column_id: &ColumnId, source: ColumnWithCells) -> Self {
+        Self {
+            x_column_id: x_column_id.clone_value(),
+            column_id: source.id().clone_value(),
+            column_name: source.name().clone_value(),
+            cells: source
+                .cells()
+                .iter()
+                .map(|cell| ColumnCellInOutputData {
+                    cell_id: cell.id().clone_value(),
+                    cell_value: cell.cell_value().clone_value(),
+                })
+                .collect(),
+        }
+    }
+}
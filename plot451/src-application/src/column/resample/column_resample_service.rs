@@ -0,0 +1,49 @@
+use thiserror::Error;
+
+use src_domain::{
+    models::column::{
+        column_id::{ColumnId, ColumnIdError},
+        column_name::ColumnNameError,
+        column_repository::ColumnRepositoryError,
+    },
+    services::{
+        column_creation_service::ColumnCreationServiceError, interpolation::InterpolationError,
+    },
+};
+
+use super::{
+    column_resample_command::ColumnResampleCommand,
+    column_resample_output_data::ColumnResampleOutputData,
+};
+
+pub type ColumnResampleServiceResult<T> = anyhow::Result<T, ColumnResampleServiceError>;
+
+pub trait IColumnResampleService {
+    fn handle(
+        &self,
+        command: ColumnResampleCommand,
+    ) -> impl std::future::Future<Output = ColumnResampleServiceResult<ColumnResampleOutputData>> + Send;
+}
+
+#[derive(Debug, Error)]
+pub enum ColumnResampleServiceError {
+    // repository errors
+    #[error("ColumnRepositoryError: [{0}]")]
+    ColumnRepositoryError(ColumnRepositoryError),
+
+    // value object errors
+    #[error("ColumnIdError: [{0}]")]
+    ColumnIdError(ColumnIdError),
+    #[error("ColumnNameError: [{0}]")]
+    ColumnNameError(ColumnNameError),
+
+    // domain service errors
+    #[error("InterpolationError: [{0}]")]
+    InterpolationError(InterpolationError),
+    #[error("ColumnCreationServiceError: [{0}]")]
+    ColumnCreationServiceError(ColumnCreationServiceError),
+
+    // not found errors
+    #[error("Column not found, column_id: {0:?}")]
+    ColumnNotFound(ColumnId),
+}
@@ -0,0 +1,280 @@
+use src_domain::{
+    models::column::{
+        column_cell::column_cell_value::CellRawValue,
+        column_factory::IColumnFactory,
+        column_id::ColumnId,
+        column_name::ColumnName,
+        column_provenance::{AnalysisKind, ColumnAnalysis, ColumnProvenance},
+        column_repository::IColumnRepository,
+    },
+    services::{
+        column_creation_service::ColumnCreationService,
+        column_values_service::ColumnValuesService,
+        interpolation::{linspace, Interpolator},
+    },
+    shared::value_object::ValueObject,
+};
+
+use super::{
+    column_resample_command::{ColumnResampleCommand, ResampleTargetInCommand},
+    column_resample_output_data::ColumnResampleOutputData,
+    column_resample_service::{
+        ColumnResampleServiceError, ColumnResampleServiceResult, IColumnResampleService,
+    },
+};
+
+pub struct ColumnResampleService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory,
+    CR: IColumnRepository,
+{
+    column_factory: &'a CF,
+    column_repository: &'b CR,
+}
+
+impl<'a, 'b, CF, CR> ColumnResampleService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+{
+    pub fn new(column_factory: &'a CF, column_repository: &'b CR) -> Self {
+        Self {
+            column_factory,
+            column_repository,
+        }
+    }
+}
+
+impl<'a, 'b, CF, CR> IColumnResampleService for ColumnResampleService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+{
+    async fn handle(
+        &self,
+        command: ColumnResampleCommand,
+    ) -> ColumnResampleServiceResult<ColumnResampleOutputData> {
+        let ColumnResampleCommand {
+            x_column_id,
+            y_column_id,
+            target,
+            method,
+            extrapolation,
+        } = command;
+
+        // 値オブジェクトのインスタンス化
+        let x_column_id =
+            ColumnId::new(x_column_id).map_err(ColumnResampleServiceError::ColumnIdError)?;
+        let y_column_id =
+            ColumnId::new(y_column_id).map_err(ColumnResampleServiceError::ColumnIdError)?;
+
+        // 補間
+        let (x_column, x_values) = ColumnValuesService::new(self.column_repository)
+            .find_column_values(&x_column_id)
+            .await
+            .map_err(ColumnResampleServiceError::ColumnRepositoryError)?
+            .ok_or(ColumnResampleServiceError::ColumnNotFound(
+                x_column_id.clone(),
+            ))?;
+        let (y_column, y_values) = ColumnValuesService::new(self.column_repository)
+            .find_column_values(&y_column_id)
+            .await
+            .map_err(ColumnResampleServiceError::ColumnRepositoryError)?
+            .ok_or(ColumnResampleServiceError::ColumnNotFound(
+                y_column_id.clone(),
+            ))?;
+        let interpolator =
+            Interpolator::new(&x_values, &y_values, method.to_interpolation_method())
+                .map_err(ColumnResampleServiceError::InterpolationError)?;
+
+        // 再標本化先の x (等間隔の点列は Y カラムと同じディレクトリに保存)
+        let column_creation_service =
+            ColumnCreationService::new(self.column_factory, self.column_repository);
+        let (target_column_id, target_values) = match target {
+            ResampleTargetInCommand::Column { column_id } => {
+                let column_id =
+                    ColumnId::new(column_id).map_err(ColumnResampleServiceError::ColumnIdError)?;
+                let (_, values) = ColumnValuesService::new(self.column_repository)
+                    .find_column_values(&column_id)
+                    .await
+                    .map_err(ColumnResampleServiceError::ColumnRepositoryError)?
+                    .ok_or(ColumnResampleServiceError::ColumnNotFound(
+                        column_id.clone(),
+                    ))?;
+                (column_id, values)
+            }
+            ResampleTargetInCommand::Linspace { start, end, count } => {
+                let values: Vec<CellRawValue> = linspace(start, end, count)
+                    .map_err(ColumnResampleServiceError::InterpolationError)?
+                    .into_iter()
+                    .map(Some)
+                    .collect();
+                let name = ColumnName::new(format!("{} resampled", x_column.name()))
+                    .map_err(ColumnResampleServiceError::ColumnNameError)?;
+                let (column, _) = column_creation_service
+                    .create_column(name, y_column.directory_id().clone(), values.clone())
+                    .await
+                    .map_err(ColumnResampleServiceError::ColumnCreationServiceError)?;
+                (column.id().clone(), values)
+            }
+        };
+
+        let resampled = interpolator.resample(&target_values, extrapolation.to_extrapolation());
+        let name = ColumnName::new(format!("{} resampled", y_column.name()))
+            .map_err(ColumnResampleServiceError::ColumnNameError)?;
+        let provenance = ColumnProvenance::Analysis(ColumnAnalysis::new(
+            AnalysisKind::Resampling,
+            vec![
+                x_column_id.clone(),
+                y_column_id.clone(),
+                target_column_id.clone(),
+            ],
+        ));
+        let (_, column_with_cells) = column_creation_service
+            .create_column_from(name, y_column.directory_id().clone(), resampled, provenance)
+            .await
+            .map_err(ColumnResampleServiceError::ColumnCreationServiceError)?;
+
+        Ok(ColumnResampleOutputData::new(
+            &target_column_id,
+            column_with_cells,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use src_domain::services::interpolation::InterpolationError;
+    use src_in_memory_infrastructure::column::{
+        in_memory_column_factory::InMemoryColumnFactory,
+        in_memory_column_repository::InMemoryColumnRepository,
+    };
+
+    use crate::column::resample::column_resample_command::{
+        ExtrapolationInCommand, InterpolationMethodInCommand,
+    };
+    use crate::test_utils::save_column_in_directory;
+
+    use super::*;
+
+    fn values(output_data: &ColumnResampleOutputData) -> Vec<Option<f64>> {
+        output_data
+            .cells
+            .iter()
+            .map(|cell| cell.cell_value)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_handle_column_target() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let x = save_column_in_directory(
+            &column_repository,
+            "x",
+            "4",
+            vec![Some(0.), Some(1.), Some(2.)],
+        )
+        .await?;
+        let y = save_column_in_directory(
+            &column_repository,
+            "y",
+            "4",
+            vec![Some(0.), Some(2.), Some(0.)],
+        )
+        .await?;
+        let target = save_column_in_directory(
+            &column_repository,
+            "x2",
+            "4",
+            vec![Some(0.5), None, Some(1.5), Some(3.)],
+        )
+        .await?;
+
+        let service = ColumnResampleService::new(&column_factory, &column_repository);
+        let command = ColumnResampleCommand {
+            x_column_id: x.clone_value(),
+            y_column_id: y.clone_value(),
+            target: ResampleTargetInCommand::Column {
+                column_id: target.clone_value(),
+            },
+            method: InterpolationMethodInCommand::Linear,
+            extrapolation: ExtrapolationInCommand::Missing,
+        };
+        let output_data = service.handle(command).await?;
+        assert_eq!(output_data.x_column_id, target.clone_value());
+        assert_eq!(output_data.column_name, "y resampled");
+        assert_eq!(values(&output_data), vec![Some(1.), None, Some(1.), None]);
+        // 補間した y のカラムだけが追加される
+        assert_eq!(column_repository.find_all().await?.len(), 4);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handle_linspace_target() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let x = save_column_in_directory(
+            &column_repository,
+            "x",
+            "4",
+            vec![Some(0.), Some(1.), Some(2.)],
+        )
+        .await?;
+        let y = save_column_in_directory(
+            &column_repository,
+            "y",
+            "4",
+            vec![Some(1.), Some(3.), Some(5.)],
+        )
+        .await?;
+
+        let service = ColumnResampleService::new(&column_factory, &column_repository);
+        let command = ColumnResampleCommand {
+            x_column_id: x.clone_value(),
+            y_column_id: y.clone_value(),
+            target: ResampleTargetInCommand::Linspace {
+                start: 0.,
+                end: 3.,
+                count: 4,
+            },
+            method: InterpolationMethodInCommand::CubicSpline,
+            extrapolation: ExtrapolationInCommand::Clamp,
+        };
+        let output_data = service.handle(command).await?;
+        let resampled = values(&output_data);
+        assert!((resampled[1].unwrap() - 3.).abs() < 1e-12);
+        assert_eq!(resampled[3], Some(5.));
+
+        // 等間隔の x が Y カラムと同じディレクトリに保存されている
+        let x_column = column_repository
+            .find(&ColumnId::new(output_data.x_column_id)?)
+            .await?
+            .unwrap();
+        assert_eq!(x_column.name().value(), "x resampled");
+        assert_eq!(x_column.directory_id().value(), "4");
+        let grid = ColumnValuesService::new(&column_repository)
+            .find_values(&x_column)
+            .await?;
+        assert_eq!(grid, vec![Some(0.), Some(1.), Some(2.), Some(3.)]);
+
+        let command = ColumnResampleCommand {
+            x_column_id: x.clone_value(),
+            y_column_id: y.clone_value(),
+            target: ResampleTargetInCommand::Linspace {
+                start: 0.,
+                end: 3.,
+                count: 0,
+            },
+            method: InterpolationMethodInCommand::Akima,
+            extrapolation: ExtrapolationInCommand::Missing,
+        };
+        assert!(matches!(
+            service.handle(command).await,
+            Err(ColumnResampleServiceError::InterpolationError(
+                InterpolationError::InvalidGrid
+            ))
+        ));
+        Ok(())
+    }
+}
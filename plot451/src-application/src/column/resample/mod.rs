@@ -0,0 +1,10 @@
+/* 補間による再標本化用アプリケーションサービス */
+// コマンドオブジェクト
+pub mod column_resample_command;
+
+// アプリケーションサービス
+pub mod column_resample_service;
+pub mod column_resample_service_impl;
+
+// DTO
+pub mod column_resample_output_data;
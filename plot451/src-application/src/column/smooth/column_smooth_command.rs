@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+
+use src_domain::models::column::column_smoothing::{EdgeHandling, SmoothingFilter};
+
+#[derive(Deserialize, Serialize)]
+pub struct ColumnSmoothCommand {
+    pub(super) column_id: String,
+    pub(super) filter: SmoothingFilterInCommand,
+    #[serde(default)]
+    pub(super) edge_handling: EdgeHandlingInCommand,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SmoothingFilterInCommand {
+    MovingAverage {
+        window: usize,
+    },
+    SavitzkyGolay {
+        window: usize,
+        polynomial_order: usize,
+    },
+    Gaussian {
+        sigma: f64,
+    },
+    Median {
+        window: usize,
+    },
+}
+
+impl SmoothingFilterInCommand {
+    pub(super) fn to_smoothing_filter(self) -> SmoothingFilter {
+        match self {
+            SmoothingFilterInCommand::MovingAverage { window } => {
+                SmoothingFilter::MovingAverage { window }
+            }
+            SmoothingFilterInCommand::SavitzkyGolay {
+                window,
+                polynomial_order,
+            } => SmoothingFilter::SavitzkyGolay {
+                window,
+                polynomial_order,
+            },
+            SmoothingFilterInCommand::Gaussian { sigma } => SmoothingFilter::Gaussian { sigma },
+            SmoothingFilterInCommand::Median { window } => SmoothingFilter::Median { window },
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum EdgeHandlingInCommand {
+    Missing,
+    #[default]
+    Shrink,
+    Nearest,
+    Reflect,
+}
+
+impl EdgeHandlingInCommand {
+    pub(super) fn to_edge_handling(self) -> EdgeHandling {
+        match self {
+            EdgeHandlingInCommand::Missing => EdgeHandling::Missing,
+            EdgeHandlingInCommand::Shrink => EdgeHandling::Shrink,
+            EdgeHandlingInCommand::Nearest => EdgeHandling::Nearest,
+            EdgeHandlingInCommand::Reflect => EdgeHandling::Reflect,
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use thiserror::Error;
+
+use src_domain::{
+    models::column::{
+        column_id::{ColumnId, ColumnIdError},
+        column_name::ColumnNameError,
+        column_repository::ColumnRepositoryError,
+        column_smoothing::ColumnSmoothingError,
+    },
+    services::{column_creation_service::ColumnCreationServiceError, smoothing::SmoothingError},
+};
+
+use super::{
+    column_smooth_command::ColumnSmoothCommand, column_smooth_output_data::ColumnSmoothOutputData,
+};
+
+pub type ColumnSmoothServiceResult<T> = anyhow::Result<T, ColumnSmoothServiceError>;
+
+pub trait IColumnSmoothService {
+    fn handle(
+        &self,
+        command: ColumnSmoothCommand,
+    ) -> impl std::future::Future<Output = ColumnSmoothServiceResult<ColumnSmoothOutputData>> + Send;
+}
+
+#[derive(Debug, Error)]
+pub enum ColumnSmoothServiceError {
+    // repository errors
+    #[error("ColumnRepositoryError: [{0}]")]
+    ColumnRepositoryError(ColumnRepositoryError),
+
+    // value object errors
+    #[error("ColumnIdError: [{0}]")]
+    ColumnIdError(ColumnIdError),
+    #[error("ColumnNameError: [{0}]")]
+    ColumnNameError(ColumnNameError),
+    #[error("ColumnSmoothingError: [{0}]")]
+    ColumnSmoothingError(ColumnSmoothingError),
+
+    // domain service errors
+    #[error("SmoothingError: [{0}]")]
+    SmoothingError(SmoothingError),
+    #[error("ColumnCreationServiceError: [{0}]")]
+    ColumnCreationServiceError(ColumnCreationServiceError),
+
+    // not found errors
+    #[error("Column not found, column_id: {0:?}")]
+    ColumnNotFound(ColumnId),
+}
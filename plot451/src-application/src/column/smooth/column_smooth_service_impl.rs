@@ -0,0 +1,214 @@
+use src_domain::{
+    models::column::{
+        column_factory::IColumnFactory, column_id::ColumnId, column_name::ColumnName,
+        column_provenance::ColumnProvenance, column_repository::IColumnRepository,
+        column_smoothing::ColumnSmoothing,
+    },
+    services::{
+        column_creation_service::ColumnCreationService, column_values_service::ColumnValuesService,
+        smoothing::smooth,
+    },
+    shared::value_object::ValueObject,
+};
+
+use super::{
+    column_smooth_command::ColumnSmoothCommand,
+    column_smooth_output_data::ColumnSmoothOutputData,
+    column_smooth_service::{
+        ColumnSmoothServiceError, ColumnSmoothServiceResult, IColumnSmoothService,
+    },
+};
+
+pub struct ColumnSmoothService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory,
+    CR: IColumnRepository,
+{
+    column_factory: &'a CF,
+    column_repository: &'b CR,
+}
+
+impl<'a, 'b, CF, CR> ColumnSmoothService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+{
+    pub fn new(column_factory: &'a CF, column_repository: &'b CR) -> Self {
+        Self {
+            column_factory,
+            column_repository,
+        }
+    }
+}
+
+impl<'a, 'b, CF, CR> IColumnSmoothService for ColumnSmoothService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+{
+    async fn handle(
+        &self,
+        command: ColumnSmoothCommand,
+    ) -> ColumnSmoothServiceResult<ColumnSmoothOutputData> {
+        let ColumnSmoothCommand {
+            column_id,
+            filter,
+            edge_handling,
+        } = command;
+
+        // 値オブジェクトのインスタンス化
+        let column_id =
+            ColumnId::new(column_id).map_err(ColumnSmoothServiceError::ColumnIdError)?;
+        let smoothing = ColumnSmoothing::new(
+            column_id.clone(),
+            filter.to_smoothing_filter(),
+            edge_handling.to_edge_handling(),
+        )
+        .map_err(ColumnSmoothServiceError::ColumnSmoothingError)?;
+
+        // 元のカラムの取得
+        let (source, values) = ColumnValuesService::new(self.column_repository)
+            .find_column_values(&column_id)
+            .await
+            .map_err(ColumnSmoothServiceError::ColumnRepositoryError)?
+            .ok_or(ColumnSmoothServiceError::ColumnNotFound(column_id.clone()))?;
+
+        // 平滑化したカラムを元のカラムと同じディレクトリに保存し、生成元を記録する
+        let smoothed = smooth(&values, smoothing.filter(), smoothing.edge_handling())
+            .map_err(ColumnSmoothServiceError::SmoothingError)?;
+        let name = ColumnName::new(format!("{} smoothed", source.name()))
+            .map_err(ColumnSmoothServiceError::ColumnNameError)?;
+        let (_, column_with_cells) =
+            ColumnCreationService::new(self.column_factory, self.column_repository)
+                .create_column_from(
+                    name,
+                    source.directory_id().clone(),
+                    smoothed,
+                    ColumnProvenance::Smoothing(smoothing.clone()),
+                )
+                .await
+                .map_err(ColumnSmoothServiceError::ColumnCreationServiceError)?;
+
+        Ok(ColumnSmoothOutputData::new(&smoothing, column_with_cells))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use src_domain::models::column::column_smoothing::{
+        ColumnSmoothingError, EdgeHandling, SmoothingFilter,
+    };
+    use src_domain::services::smoothing::SmoothingError;
+    use src_in_memory_infrastructure::column::{
+        in_memory_column_factory::InMemoryColumnFactory,
+        in_memory_column_repository::InMemoryColumnRepository,
+    };
+
+    use crate::column::smooth::column_smooth_command::{
+        EdgeHandlingInCommand, SmoothingFilterInCommand,
+    };
+    use crate::test_utils::save_column_in_directory;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let source = save_column_in_directory(
+            &column_repository,
+            "signal",
+            "5",
+            vec![Some(1.), Some(2.), Some(3.), None, Some(5.)],
+        )
+        .await?;
+
+        let service = ColumnSmoothService::new(&column_factory, &column_repository);
+        let command = ColumnSmoothCommand {
+            column_id: source.clone_value(),
+            filter: SmoothingFilterInCommand::MovingAverage { window: 3 },
+            edge_handling: EdgeHandlingInCommand::Missing,
+        };
+        let output_data = service.handle(command).await?;
+        assert_eq!(output_data.column_name, "signal smoothed");
+        assert_eq!(output_data.source_column_id, source.clone_value());
+        assert_eq!(
+            output_data.smoothing,
+            "moving average (window 3), edge: missing"
+        );
+        let values: Vec<Option<f64>> = output_data
+            .cells
+            .iter()
+            .map(|cell| cell.cell_value)
+            .collect();
+        assert_eq!(values, vec![None, Some(2.), Some(2.5), None, None]);
+
+        // 作成したカラムに生成元のフィルタが記録されている
+        let column = column_repository
+            .find(&ColumnId::new(output_data.column_id)?)
+            .await?
+            .unwrap();
+        assert_eq!(column.directory_id().value(), "5");
+        let Some(ColumnProvenance::Smoothing(smoothing)) = column.provenance() else {
+            panic!("smoothing is not recorded");
+        };
+        assert_eq!(smoothing.source(), &source);
+        assert_eq!(
+            smoothing.filter(),
+            &SmoothingFilter::MovingAverage { window: 3 }
+        );
+        assert_eq!(smoothing.edge_handling(), EdgeHandling::Missing);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handle_invalid_filter() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let source =
+            save_column_in_directory(&column_repository, "signal", "5", vec![Some(1.)]).await?;
+
+        let service = ColumnSmoothService::new(&column_factory, &column_repository);
+        let command = ColumnSmoothCommand {
+            column_id: source.clone_value(),
+            filter: SmoothingFilterInCommand::SavitzkyGolay {
+                window: 5,
+                polynomial_order: 5,
+            },
+            edge_handling: EdgeHandlingInCommand::Shrink,
+        };
+        assert!(matches!(
+            service.handle(command).await,
+            Err(ColumnSmoothServiceError::ColumnSmoothingError(
+                ColumnSmoothingError::InvalidPolynomialOrder(5, 5)
+            ))
+        ));
+
+        let command = ColumnSmoothCommand {
+            column_id: source.clone_value(),
+            filter: SmoothingFilterInCommand::Median { window: 4 },
+            edge_handling: EdgeHandlingInCommand::Shrink,
+        };
+        assert!(matches!(
+            service.handle(command).await,
+            Err(ColumnSmoothServiceError::ColumnSmoothingError(
+                ColumnSmoothingError::InvalidWindow(4)
+            ))
+        ));
+
+        // カラムより長い窓
+        let command = ColumnSmoothCommand {
+            column_id: source.clone_value(),
+            filter: SmoothingFilterInCommand::MovingAverage { window: 3 },
+            edge_handling: EdgeHandlingInCommand::Shrink,
+        };
+        assert!(matches!(
+            service.handle(command).await,
+            Err(ColumnSmoothServiceError::SmoothingError(
+                SmoothingError::WindowTooLong(3, 1)
+            ))
+        ));
+        assert_eq!(column_repository.find_all().await?.len(), 1);
+        Ok(())
+    }
+}
@@ -0,0 +1,10 @@
+/* 平滑化用アプリケーションサービス */
+// コマンドオブジェクト
+pub mod column_smooth_command;
+
+// アプリケーションサービス
+pub mod column_smooth_service;
+pub mod column_smooth_service_impl;
+
+// DTO
+pub mod column_smooth_output_data;
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize)]
+pub struct ColumnStatisticsCommand {
+    pub(super) target: StatisticsTargetInCommand,
+}
+
+// 統計量を計算する対象 (単一のカラム、またはテーブル内のすべてのカラム)
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum StatisticsTargetInCommand {
+    Column { column_id: String },
+    Table { table_id: String },
+}
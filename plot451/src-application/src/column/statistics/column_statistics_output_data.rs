@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+use src_domain::{
+    models::column::column_with_cells::ColumnWithCells,
+    services::descriptive_statistics::DescriptiveStatistics, shared::value_object::ValueObject,
+};
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+pub struct ColumnStatisticsOutputData {
+    pub(super) columns: Vec<ColumnStatisticsInOutputData>,
+}
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+pub(super) struct ColumnStatisticsInOutputData {
+    pub(super) column_id: String,
+    pub(super) column_name: String,
+    pub(super) count: usize,
+    pub(super) none_count: usize,
+    pub(super) mean: Option<f64>,
+    pub(super) standard_deviation: Option<f64>,
+    pub(super) standard_error: Option<f64>,
+    pub(super) min: Option<f64>,
+    pub(super) max: Option<f64>,
+    pub(super) median: Option<f64>,
+    pub(super) first_quartile: Option<f64>,
+    pub(super) third_quartile: Option<f64>,
+    pub(super) skewness: Option<f64>,
+    pub(super) kurtosis: Option<f64>,
+}
+
+impl ColumnStatisticsOutputData {
+    pub(super) fn new(source: Vec<(ColumnWithCells, DescriptiveStatistics)>) -> Self {
+        Self {
+            columns: source
+                .iter()
+                .map(|(column, statistics)| ColumnStatisticsInOutputData {
+                    column_id: column.id().clone_value(),
+                    column_name: column.name().clone_value(),
+                    count: statistics.count(),
+                    none_count: statistics.none_count(),
+                    mean: statistics.mean(),
+                    standard_deviation: statistics.standard_deviation(),
+                    standard_error: statistics.standard_error(),
+                    min: statistics.min(),
+                    max: statistics.max(),
+                    median: statistics.median(),
+                    first_quartile: statistics.first_quartile(),
+                    third_quartile: statistics.third_quartile(),
+                    skewness: statistics.skewness(),
+                    kurtosis: statistics.kurtosis(),
+                })
+                .collect(),
+        }
+    }
+}
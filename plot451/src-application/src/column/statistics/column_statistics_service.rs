@@ -0,0 +1,48 @@
+use thiserror::Error;
+
+use src_domain::models::{
+    column::{
+        column_id::{ColumnId, ColumnIdError},
+        column_repository::ColumnRepositoryError,
+    },
+    table::{
+        table_id::{TableId, TableIdError},
+        table_repository::TableRepositoryError,
+    },
+};
+
+use super::{
+    column_statistics_command::ColumnStatisticsCommand,
+    column_statistics_output_data::ColumnStatisticsOutputData,
+};
+
+pub type ColumnStatisticsServiceResult<T> = anyhow::Result<T, ColumnStatisticsServiceError>;
+
+pub trait IColumnStatisticsService {
+    fn handle(
+        &self,
+        command: ColumnStatisticsCommand,
+    ) -> impl std::future::Future<Output = ColumnStatisticsServiceResult<ColumnStatisticsOutputData>>
+           + Send;
+}
+
+#[derive(Debug, Error)]
+pub enum ColumnStatisticsServiceError {
+    // repository errors
+    #[error("ColumnRepositoryError: [{0}]")]
+    ColumnRepositoryError(ColumnRepositoryError),
+    #[error("TableRepositoryError: [{0}]")]
+    TableRepositoryError(TableRepositoryError),
+
+    // value object errors
+    #[error("ColumnIdError: [{0}]")]
+    ColumnIdError(ColumnIdError),
+    #[error("TableIdError: [{0}]")]
+    TableIdError(TableIdError),
+
+    // not found errors
+    #[error("Column not found, column_id: {0:?}")]
+    ColumnNotFound(ColumnId),
+    #[error("Table not found, table_id: {0:?}")]
+    TableNotFound(TableId),
+}
@@ -0,0 +1,194 @@
+use src_domain::{
+    models::{
+        column::{
+            column_id::ColumnId, column_repository::IColumnRepository,
+            column_with_cells::ColumnWithCells,
+        },
+        table::{table_id::TableId, table_repository::ITableRepository},
+    },
+    services::descriptive_statistics::DescriptiveStatistics,
+    shared::value_object::ValueObject,
+};
+
+use super::{
+    column_statistics_command::{ColumnStatisticsCommand, StatisticsTargetInCommand},
+    column_statistics_output_data::ColumnStatisticsOutputData,
+    column_statistics_service::{
+        ColumnStatisticsServiceError, ColumnStatisticsServiceResult, IColumnStatisticsService,
+    },
+};
+
+pub struct ColumnStatisticsService<'a, 'b, CR, TR>
+where
+    CR: IColumnRepository,
+    TR: ITableRepository,
+{
+    column_repository: &'a CR,
+    table_repository: &'b TR,
+}
+
+impl<'a, 'b, CR, TR> ColumnStatisticsService<'a, 'b, CR, TR>
+where
+    CR: IColumnRepository,
+    TR: ITableRepository,
+{
+    pub fn new(column_repository: &'a CR, table_repository: &'b TR) -> Self {
+        Self {
+            column_repository,
+            table_repository,
+        }
+    }
+}
+
+impl<'a, 'b, CR, TR> IColumnStatisticsService for ColumnStatisticsService<'a, 'b, CR, TR>
+where
+    CR: IColumnRepository + Sync,
+    TR: ITableRepository + Sync,
+{
+    async fn handle(
+        &self,
+        command: ColumnStatisticsCommand,
+    ) -> ColumnStatisticsServiceResult<ColumnStatisticsOutputData> {
+        // 対象のカラムの取得
+        let columns = match command.target {
+            StatisticsTargetInCommand::Column { column_id } => {
+                let column_id = ColumnId::new(column_id)
+                    .map_err(ColumnStatisticsServiceError::ColumnIdError)?;
+                let column = self
+                    .column_repository
+                    .find(&column_id)
+                    .await
+                    .map_err(ColumnStatisticsServiceError::ColumnRepositoryError)?
+                    .ok_or(ColumnStatisticsServiceError::ColumnNotFound(column_id))?;
+                vec![column]
+            }
+            StatisticsTargetInCommand::Table { table_id } => {
+                let table_id =
+                    TableId::new(table_id).map_err(ColumnStatisticsServiceError::TableIdError)?;
+                let table = self
+                    .table_repository
+                    .find(&table_id)
+                    .await
+                    .map_err(ColumnStatisticsServiceError::TableRepositoryError)?
+                    .ok_or(ColumnStatisticsServiceError::TableNotFound(table_id))?;
+                self.column_repository
+                    .find_by_ids(table.columns())
+                    .await
+                    .map_err(ColumnStatisticsServiceError::ColumnRepositoryError)?
+            }
+        };
+
+        // カラムごとに統計量を計算
+        let mut statistics = vec![];
+        for column in columns {
+            let cells = self
+                .column_repository
+                .find_cells_by_ids(column.cells())
+                .await
+                .map_err(ColumnStatisticsServiceError::ColumnRepositoryError)?;
+            let column_with_cells = ColumnWithCells::new(&column, cells);
+            let descriptive_statistics =
+                DescriptiveStatistics::from_column_with_cells(&column_with_cells);
+            statistics.push((column_with_cells, descriptive_statistics));
+        }
+
+        Ok(ColumnStatisticsOutputData::new(statistics))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use src_domain::models::table::{table::Table, table_name::TableName};
+    use src_in_memory_infrastructure::{
+        column::in_memory_column_repository::InMemoryColumnRepository,
+        table::in_memory_table_repository::InMemoryTableRepository,
+    };
+
+    use crate::test_utils::save_column;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_column() -> anyhow::Result<()> {
+        let column_repository = InMemoryColumnRepository::new();
+        let table_repository = InMemoryTableRepository::new();
+        let column_id = save_column(
+            &column_repository,
+            "x",
+            vec![Some(3.), None, Some(1.), Some(2.)],
+        )
+        .await?;
+
+        let service = ColumnStatisticsService::new(&column_repository, &table_repository);
+        let command = ColumnStatisticsCommand {
+            target: StatisticsTargetInCommand::Column {
+                column_id: column_id.clone_value(),
+            },
+        };
+        let ColumnStatisticsOutputData { columns } = service.handle(command).await?;
+        assert_eq!(columns.len(), 1);
+        assert_eq!(columns[0].column_name, "x");
+        assert_eq!(columns[0].count, 3);
+        assert_eq!(columns[0].none_count, 1);
+        assert_eq!(columns[0].mean, Some(2.));
+        assert_eq!(columns[0].standard_deviation, Some(1.));
+        assert_eq!(columns[0].median, Some(2.));
+        assert_eq!(columns[0].min, Some(1.));
+        assert_eq!(columns[0].max, Some(3.));
+        assert_eq!(columns[0].skewness, Some(0.));
+        assert_eq!(columns[0].kurtosis, None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handle_table() -> anyhow::Result<()> {
+        let column_repository = InMemoryColumnRepository::new();
+        let table_repository = InMemoryTableRepository::new();
+        let x = save_column(&column_repository, "x", vec![Some(1.), Some(2.)]).await?;
+        let y = save_column(&column_repository, "y", vec![None, None]).await?;
+        let table = Table::new(
+            Some(TableId::new("table_id_1".to_string())?),
+            TableName::new("table".to_string())?,
+            vec![y, x],
+        )?;
+        table_repository.save(&table).await?;
+
+        let service = ColumnStatisticsService::new(&column_repository, &table_repository);
+        let command = ColumnStatisticsCommand {
+            target: StatisticsTargetInCommand::Table {
+                table_id: "table_id_1".to_string(),
+            },
+        };
+        let ColumnStatisticsOutputData { columns } = service.handle(command).await?;
+
+        // テーブル内のカラムの順序で返す
+        assert_eq!(
+            columns
+                .iter()
+                .map(|column| column.column_name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["y", "x"]
+        );
+        assert_eq!(columns[0].count, 0);
+        assert_eq!(columns[0].mean, None);
+        assert_eq!(columns[1].mean, Some(1.5));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handle_not_found() -> anyhow::Result<()> {
+        let column_repository = InMemoryColumnRepository::new();
+        let table_repository = InMemoryTableRepository::new();
+
+        let service = ColumnStatisticsService::new(&column_repository, &table_repository);
+        let command = ColumnStatisticsCommand {
+            target: StatisticsTargetInCommand::Table {
+                table_id: "table_id_1".to_string(),
+            },
+        };
+        match service.handle(command).await {
+            Err(ColumnStatisticsServiceError::TableNotFound(_)) => Ok(()),
+            _ => panic!("unexpected result"),
+        }
+    }
+}
@@ -0,0 +1,10 @@
+/* 記述統計量計算用アプリケーションサービス */
+// コマンドオブジェクト
+pub mod column_statistics_command;
+
+// アプリケーションサービス
+pub mod column_statistics_service;
+pub mod column_statistics_service_impl;
+
+// DTO
+pub mod column_statistics_output_data;
@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+
+use src_domain::{
+    models::column::{
+        column_id::{ColumnId, ColumnIdError},
+        column_transformation::Transformation,
+    },
+    shared::value_object::ValueObject,
+};
+
+#[derive(Deserialize, Serialize)]
+pub struct ColumnTransformCommand {
+    pub(super) target: TransformTargetInCommand,
+    pub(super) transformation: TransformationInCommand,
+}
+
+// 変換するカラム (ディレクトリを指定した場合は直下のすべてのカラム)
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TransformTargetInCommand {
+    Column { column_id: String },
+    Directory { directory_id: String },
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TransformationInCommand {
+    ZScore,
+    MinMax,
+    NormalizeToMax,
+    NormalizeToArea { x_column_id: Option<String> },
+    Log10,
+    Ln,
+    Exp,
+    Reciprocal,
+    Offset { value: f64 },
+    Scale { factor: f64 },
+    ScaleByColumn { column_id: String },
+}
+
+impl TransformationInCommand {
+    pub(super) fn to_transformation(&self) -> Result<Transformation, ColumnIdError> {
+        Ok(match self {
+            TransformationInCommand::ZScore => Transformation::ZScore,
+            TransformationInCommand::MinMax => Transformation::MinMax,
+            TransformationInCommand::NormalizeToMax => Transformation::NormalizeToMax,
+            TransformationInCommand::NormalizeToArea { x_column_id } => {
+                Transformation::NormalizeToArea {
+                    x: x_column_id.clone().map(ColumnId::new).transpose()?,
+                }
+            }
+            TransformationInCommand::Log10 => Transformation::Log10,
+            TransformationInCommand::Ln => Transformation::Ln,
+            TransformationInCommand::Exp => Transformation::Exp,
+            TransformationInCommand::Reciprocal => Transformation::Reciprocal,
+            TransformationInCommand::Offset { value } => Transformation::Offset { value: *value },
+            TransformationInCommand::Scale { factor } => Transformation::Scale { factor: *factor },
+            TransformationInCommand::ScaleByColumn { column_id } => Transformation::ScaleByColumn {
+                column: ColumnId::new(column_id.clone())?,
+            },
+        })
+    }
+}
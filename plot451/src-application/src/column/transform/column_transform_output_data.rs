@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+
+use src_domain::{
+    models::column::{
+        column_transformation::ColumnTransformation, column_with_cells::ColumnWithCells,
+    },
+    shared::value_object::ValueObject,
+};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct ColumnTransformOutputData {
+    // 適用した変換
+    pub(super) transformation: String,
+    pub(super) columns: Vec<ColumnInOutputData>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub(super) struct ColumnInOutputData {
+    pub(super) column_id: String,
+    pub(super) column_name: String,
+    pub(super) source_column_id: String,
+    pub(super) cells: Vec<ColumnCellInOutputData>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub(super) struct ColumnCellInOutputData {
+    pub(super) cell_id: String,
+    pub(super) cell_value: Option<f64>,
+}
+
+impl ColumnTransformOutputData {
+    pub(super) fn new(
+        transformation: String,
+        columns: Vec<(ColumnTransformation, ColumnWithCells)>,
+    ) -> Self {
+        Self {
+            transformation,
+            columns: columns
+                .into_iter()
+                .map(|(column_transformation, column)| ColumnInOutputData {
+                    column_id: column.id().clone_value(),
+                    column_name: column.name().clone_value(),
+                    source_column_id: column_transformation.source().clone_value(),
+                    cells: column
+                        .cells()
+                        .iter()
+                        .map(|cell| ColumnCellInOutputData {
+                            cell_id: cell.id().clone_value(),
+                            cell_value: cell.cell_value().clone_value(),
+                        })
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+}
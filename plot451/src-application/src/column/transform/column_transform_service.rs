@@ -0,0 +1,55 @@
+use thiserror::Error;
+
+use src_domain::{
+    models::column::{
+        column_directory::column_directory_id::ColumnDirectoryIdError,
+        column_id::{ColumnId, ColumnIdError},
+        column_name::ColumnNameError,
+        column_repository::ColumnRepositoryError,
+        column_transformation::ColumnTransformationError,
+    },
+    services::{
+        column_creation_service::ColumnCreationServiceError, transformation::TransformationError,
+    },
+};
+
+use super::{
+    column_transform_command::ColumnTransformCommand,
+    column_transform_output_data::ColumnTransformOutputData,
+};
+
+pub type ColumnTransformServiceResult<T> = anyhow::Result<T, ColumnTransformServiceError>;
+
+pub trait IColumnTransformService {
+    fn handle(
+        &self,
+        command: ColumnTransformCommand,
+    ) -> impl std::future::Future<Output = ColumnTransformServiceResult<ColumnTransformOutputData>> + Send;
+}
+
+#[derive(Debug, Error)]
+pub enum ColumnTransformServiceError {
+    // repository errors
+    #[error("ColumnRepositoryError: [{0}]")]
+    ColumnRepositoryError(ColumnRepositoryError),
+
+    // value object errors
+    #[error("ColumnIdError: [{0}]")]
+    ColumnIdError(ColumnIdError),
+    #[error("ColumnDirectoryIdError: [{0}]")]
+    ColumnDirectoryIdError(ColumnDirectoryIdError),
+    #[error("ColumnNameError: [{0}]")]
+    ColumnNameError(ColumnNameError),
+    #[error("ColumnTransformationError: [{0}]")]
+    ColumnTransformationError(ColumnTransformationError),
+
+    // domain service errors
+    #[error("TransformationError: [{0}]")]
+    TransformationError(TransformationError),
+    #[error("ColumnCreationServiceError: [{0}]")]
+    ColumnCreationServiceError(ColumnCreationServiceError),
+
+    // not found errors
+    #[error("Column not found, column_id: {0:?}")]
+    ColumnNotFound(ColumnId),
+}
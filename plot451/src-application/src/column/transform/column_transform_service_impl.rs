@@ -0,0 +1,290 @@
+use src_domain::{
+    models::column::{
+        column::Column, column_cell::column_cell_value::CellRawValue,
+        column_directory::column_directory_id::ColumnDirectoryId, column_factory::IColumnFactory,
+        column_id::ColumnId, column_name::ColumnName, column_provenance::ColumnProvenance,
+        column_repository::IColumnRepository, column_transformation::ColumnTransformation,
+    },
+    services::{
+        column_creation_service::ColumnCreationService, column_values_service::ColumnValuesService,
+        transformation::transform,
+    },
+    shared::value_object::ValueObject,
+};
+
+use super::{
+    column_transform_command::{ColumnTransformCommand, TransformTargetInCommand},
+    column_transform_output_data::ColumnTransformOutputData,
+    column_transform_service::{
+        ColumnTransformServiceError, ColumnTransformServiceResult, IColumnTransformService,
+    },
+};
+
+pub struct ColumnTransformService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory,
+    CR: IColumnRepository,
+{
+    column_factory: &'a CF,
+    column_repository: &'b CR,
+}
+
+impl<'a, 'b, CF, CR> ColumnTransformService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+{
+    pub fn new(column_factory: &'a CF, column_repository: &'b CR) -> Self {
+        Self {
+            column_factory,
+            column_repository,
+        }
+    }
+
+    async fn find_column(&self, column_id: &ColumnId) -> ColumnTransformServiceResult<Column> {
+        self.column_repository
+            .find(column_id)
+            .await
+            .map_err(ColumnTransformServiceError::ColumnRepositoryError)?
+            .ok_or(ColumnTransformServiceError::ColumnNotFound(
+                column_id.clone(),
+            ))
+    }
+
+    async fn find_values(
+        &self,
+        column: &Column,
+    ) -> ColumnTransformServiceResult<Vec<CellRawValue>> {
+        ColumnValuesService::new(self.column_repository)
+            .find_values(column)
+            .await
+            .map_err(ColumnTransformServiceError::ColumnRepositoryError)
+    }
+}
+
+impl<'a, 'b, CF, CR> IColumnTransformService for ColumnTransformService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+{
+    // TODO: トランザクション処理を追加する
+    async fn handle(
+        &self,
+        command: ColumnTransformCommand,
+    ) -> ColumnTransformServiceResult<ColumnTransformOutputData> {
+        let ColumnTransformCommand {
+            target,
+            transformation,
+        } = command;
+
+        // 値オブジェクトのインスタンス化
+        let transformation = transformation
+            .to_transformation()
+            .map_err(ColumnTransformServiceError::ColumnIdError)?;
+
+        // 変換するカラムの取得
+        // ディレクトリを指定した場合は、変換に使う別のカラムと値のないカラムを除く
+        let is_batch = matches!(target, TransformTargetInCommand::Directory { .. });
+        let sources = match target {
+            TransformTargetInCommand::Column { column_id } => {
+                let column_id =
+                    ColumnId::new(column_id).map_err(ColumnTransformServiceError::ColumnIdError)?;
+                vec![self.find_column(&column_id).await?]
+            }
+            TransformTargetInCommand::Directory { directory_id } => {
+                let directory_id = ColumnDirectoryId::new(directory_id)
+                    .map_err(ColumnTransformServiceError::ColumnDirectoryIdError)?;
+                self.column_repository
+                    .find_by_directory_id(&directory_id)
+                    .await
+                    .map_err(ColumnTransformServiceError::ColumnRepositoryError)?
+                    .into_iter()
+                    .filter(|column| transformation.reference() != Some(column.id()))
+                    .collect()
+            }
+        };
+        let reference = match transformation.reference() {
+            Some(column_id) => {
+                let column = self.find_column(column_id).await?;
+                Some(self.find_values(&column).await?)
+            }
+            None => None,
+        };
+
+        // すべてのカラムを変換できることを確かめてから永続化する
+        let mut transformed = vec![];
+        for source in sources {
+            let values = self.find_values(&source).await?;
+            if is_batch && values.iter().all(|value| value.is_none_or(f64::is_nan)) {
+                continue;
+            }
+            let column_transformation =
+                ColumnTransformation::new(source.id().clone(), transformation.clone())
+                    .map_err(ColumnTransformServiceError::ColumnTransformationError)?;
+            let values = transform(&values, &transformation, reference.as_deref())
+                .map_err(ColumnTransformServiceError::TransformationError)?;
+            let name = ColumnName::new(format!("{} {}", source.name(), transformation))
+                .map_err(ColumnTransformServiceError::ColumnNameError)?;
+            transformed.push((source, column_transformation, name, values));
+        }
+
+        // 変換したカラムを元のカラムと同じディレクトリに保存し、生成元を記録する
+        let column_creation_service =
+            ColumnCreationService::new(self.column_factory, self.column_repository);
+        let mut columns = vec![];
+        for (source, column_transformation, name, values) in transformed {
+            let (_, column_with_cells) = column_creation_service
+                .create_column_from(
+                    name,
+                    source.directory_id().clone(),
+                    values,
+                    ColumnProvenance::Transformation(column_transformation.clone()),
+                )
+                .await
+                .map_err(ColumnTransformServiceError::ColumnCreationServiceError)?;
+            columns.push((column_transformation, column_with_cells));
+        }
+
+        Ok(ColumnTransformOutputData::new(
+            transformation.to_string(),
+            columns,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use src_domain::{
+        models::column::column_transformation::Transformation,
+        services::transformation::TransformationError,
+    };
+    use src_in_memory_infrastructure::column::{
+        in_memory_column_factory::InMemoryColumnFactory,
+        in_memory_column_repository::InMemoryColumnRepository,
+    };
+
+    use crate::column::transform::column_transform_command::TransformationInCommand;
+    use crate::test_utils::save_column_in_directory;
+
+    use super::*;
+
+    fn values(output_data: &ColumnTransformOutputData) -> Vec<(String, Vec<Option<f64>>)> {
+        output_data
+            .columns
+            .iter()
+            .map(|column| {
+                (
+                    column.column_name.clone(),
+                    column.cells.iter().map(|cell| cell.cell_value).collect(),
+                )
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_handle_column() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let source = save_column_in_directory(
+            &column_repository,
+            "signal",
+            "3",
+            vec![Some(2.), Some(4.), None, Some(-8.)],
+        )
+        .await?;
+
+        let service = ColumnTransformService::new(&column_factory, &column_repository);
+        let command = ColumnTransformCommand {
+            target: TransformTargetInCommand::Column {
+                column_id: source.clone_value(),
+            },
+            transformation: TransformationInCommand::NormalizeToMax,
+        };
+        let output_data = service.handle(command).await?;
+        assert_eq!(output_data.transformation, "normalized to max");
+        assert_eq!(
+            output_data.columns[0].source_column_id,
+            source.clone_value()
+        );
+        assert_eq!(
+            values(&output_data),
+            vec![(
+                "signal normalized to max".to_string(),
+                vec![Some(0.25), Some(0.5), None, Some(-1.)]
+            )]
+        );
+
+        // 作成したカラムに生成元の変換が記録されている
+        let column = column_repository
+            .find(&ColumnId::new(output_data.columns[0].column_id.clone())?)
+            .await?
+            .unwrap();
+        assert_eq!(column.directory_id().value(), "3");
+        let Some(ColumnProvenance::Transformation(transformation)) = column.provenance() else {
+            panic!("transformation is not recorded");
+        };
+        assert_eq!(transformation.source(), &source);
+        assert_eq!(
+            transformation.transformation(),
+            &Transformation::NormalizeToMax
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handle_directory() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        save_column_in_directory(&column_repository, "a", "3", vec![Some(1.), Some(2.)]).await?;
+        save_column_in_directory(&column_repository, "b", "3", vec![Some(3.), None]).await?;
+        save_column_in_directory(&column_repository, "empty", "3", vec![None]).await?;
+        let factor = save_column_in_directory(
+            &column_repository,
+            "factor",
+            "3",
+            vec![Some(10.), Some(100.)],
+        )
+        .await?;
+        save_column_in_directory(&column_repository, "other", "4", vec![Some(5.)]).await?;
+
+        let service = ColumnTransformService::new(&column_factory, &column_repository);
+        let command = ColumnTransformCommand {
+            target: TransformTargetInCommand::Directory {
+                directory_id: "3".to_string(),
+            },
+            transformation: TransformationInCommand::ScaleByColumn {
+                column_id: factor.clone_value(),
+            },
+        };
+        let output_data = service.handle(command).await?;
+        // リポジトリから取得する順序は決まっていない
+        let mut transformed = values(&output_data);
+        transformed.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(
+            transformed,
+            vec![
+                (
+                    "a scaled by column".to_string(),
+                    vec![Some(10.), Some(200.)]
+                ),
+                ("b scaled by column".to_string(), vec![Some(30.), None]),
+            ]
+        );
+
+        // 1 つでも変換できないカラムがあれば何も作成しない
+        let command = ColumnTransformCommand {
+            target: TransformTargetInCommand::Directory {
+                directory_id: "3".to_string(),
+            },
+            transformation: TransformationInCommand::ZScore,
+        };
+        assert!(matches!(
+            service.handle(command).await,
+            Err(ColumnTransformServiceError::TransformationError(
+                TransformationError::InsufficientValues
+            ))
+        ));
+        assert_eq!(column_repository.find_all().await?.len(), 7);
+        Ok(())
+    }
+}
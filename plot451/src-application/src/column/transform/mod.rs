@@ -0,0 +1,10 @@
+/* カラムの変換・正規化用アプリケーションサービス */
+// コマンドオブジェクト
+pub mod column_transform_command;
+
+// アプリケーションサービス
+pub mod column_transform_service;
+pub mod column_transform_service_impl;
+
+// DTO
+pub mod column_transform_output_data;
@@ -1,3 +1,6 @@
 pub mod column;
 pub mod table;
-pub mod plot_2d;
\ No newline at end of file
+pub mod plot_2d;
+
+#[cfg(test)]
+pub(crate) mod test_utils;
@@ -0,0 +1,10 @@
+/* グラフの SVG 出力用アプリケーションサービス */
+// コマンドオブジェクト
+pub mod plot_2d_export_svg_command;
+
+// アプリケーションサービス
+pub mod plot_2d_export_svg_service;
+pub mod plot_2d_export_svg_service_impl;
+
+// DTO
+pub mod plot_2d_export_svg_output_data;
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize)]
+pub struct Plot2DExportSvgCommand {
+    pub(super) plot_id: String,
+    // 省略した場合は 640 x 480 (px)
+    #[serde(default)]
+    pub(super) width: Option<f64>,
+    #[serde(default)]
+    pub(super) height: Option<f64>,
+}
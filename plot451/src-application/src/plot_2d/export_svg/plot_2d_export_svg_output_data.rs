@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+use src_domain::{models::plot_2d::plot_2d_id::Plot2DId, shared::value_object::ValueObject};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct Plot2DExportSvgOutputData {
+    pub(super) plot_id: String,
+    // 単独の SVG 文書
+    pub(super) svg: String,
+}
+
+impl Plot2DExportSvgOutputData {
+    pub(super) fn new(plot_id: &Plot2DId, svg: String) -> Self {
+        Self {
+            plot_id: plot_id.clone_value(),
+            svg,
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use thiserror::Error;
+
+use src_domain::{
+    models::{
+        column::column_repository::ColumnRepositoryError,
+        plot_2d::{
+            plot_2d_id::{Plot2DId, Plot2DIdError},
+            plot_2d_repository::Plot2DRepositoryError,
+        },
+    },
+    services::plot_2d_svg::Plot2DSvgError,
+};
+
+use super::{
+    plot_2d_export_svg_command::Plot2DExportSvgCommand,
+    plot_2d_export_svg_output_data::Plot2DExportSvgOutputData,
+};
+
+pub type Plot2DExportSvgServiceResult<T> = anyhow::Result<T, Plot2DExportSvgServiceError>;
+
+pub trait IPlot2DExportSvgService {
+    fn handle(
+        &self,
+        command: Plot2DExportSvgCommand,
+    ) -> impl std::future::Future<Output = Plot2DExportSvgServiceResult<Plot2DExportSvgOutputData>> + Send;
+}
+
+#[derive(Debug, Error)]
+pub enum Plot2DExportSvgServiceError {
+    // repository errors
+    #[error("ColumnRepositoryError: [{0}]")]
+    ColumnRepositoryError(ColumnRepositoryError),
+    #[error("Plot2DRepositoryError: [{0}]")]
+    Plot2DRepositoryError(Plot2DRepositoryError),
+
+    // value object errors
+    #[error("Plot2DIdError: [{0}]")]
+    Plot2DIdError(Plot2DIdError),
+
+    // domain service errors
+    #[error("Plot2DSvgError: [{0}]")]
+    Plot2DSvgError(Plot2DSvgError),
+
+    // not found errors
+    #[error("Plot not found, plot_id: {0:?}")]
+    Plot2DNotFound(Plot2DId),
+}
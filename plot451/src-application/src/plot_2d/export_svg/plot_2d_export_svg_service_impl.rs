@@ -0,0 +1,224 @@
+use src_domain::{
+    models::{
+        column::{column_repository::IColumnRepository, column_with_cells::ColumnWithCells},
+        plot_2d::{plot_2d_id::Plot2DId, plot_2d_repository::IPlot2DRepository},
+    },
+    services::plot_2d_svg::Plot2DSvgRenderer,
+    shared::value_object::ValueObject,
+};
+
+use super::{
+    plot_2d_export_svg_command::Plot2DExportSvgCommand,
+    plot_2d_export_svg_output_data::Plot2DExportSvgOutputData,
+    plot_2d_export_svg_service::{
+        IPlot2DExportSvgService, Plot2DExportSvgServiceError, Plot2DExportSvgServiceResult,
+    },
+};
+
+const DEFAULT_WIDTH: f64 = 640.;
+const DEFAULT_HEIGHT: f64 = 480.;
+
+pub struct Plot2DExportSvgService<'a, 'b, CR, PR>
+where
+    CR: IColumnRepository,
+    PR: IPlot2DRepository,
+{
+    column_repository: &'a CR,
+    plot_2d_repository: &'b PR,
+}
+
+impl<'a, 'b, CR, PR> Plot2DExportSvgService<'a, 'b, CR, PR>
+where
+    CR: IColumnRepository,
+    PR: IPlot2DRepository,
+{
+    pub fn new(column_repository: &'a CR, plot_2d_repository: &'b PR) -> Self {
+        Self {
+            column_repository,
+            plot_2d_repository,
+        }
+    }
+}
+
+impl<'a, 'b, CR, PR> IPlot2DExportSvgService for Plot2DExportSvgService<'a, 'b, CR, PR>
+where
+    CR: IColumnRepository + Sync,
+    PR: IPlot2DRepository + Sync,
+{
+    async fn handle(
+        &self,
+        command: Plot2DExportSvgCommand,
+    ) -> Plot2DExportSvgServiceResult<Plot2DExportSvgOutputData> {
+        let Plot2DExportSvgCommand {
+            plot_id,
+            width,
+            height,
+        } = command;
+
+        // 値オブジェクトのインスタンス化
+        let plot_id = Plot2DId::new(plot_id).map_err(Plot2DExportSvgServiceError::Plot2DIdError)?;
+        let renderer = Plot2DSvgRenderer::new(
+            width.unwrap_or(DEFAULT_WIDTH),
+            height.unwrap_or(DEFAULT_HEIGHT),
+        )
+        .map_err(Plot2DExportSvgServiceError::Plot2DSvgError)?;
+
+        // グラフと系列が参照するカラムの取得
+        let plot = self
+            .plot_2d_repository
+            .find(&plot_id)
+            .await
+            .map_err(Plot2DExportSvgServiceError::Plot2DRepositoryError)?
+            .ok_or(Plot2DExportSvgServiceError::Plot2DNotFound(plot_id.clone()))?;
+        let columns = self
+            .column_repository
+            .find_by_ids(&plot.columns())
+            .await
+            .map_err(Plot2DExportSvgServiceError::ColumnRepositoryError)?;
+        let mut columns_with_cells = vec![];
+        for column in columns {
+            let cells = self
+                .column_repository
+                .find_cells_by_ids(column.cells())
+                .await
+                .map_err(Plot2DExportSvgServiceError::ColumnRepositoryError)?;
+            columns_with_cells.push(ColumnWithCells::new(&column, cells));
+        }
+
+        // 描画
+        let svg = renderer
+            .render(&plot, &columns_with_cells)
+            .map_err(Plot2DExportSvgServiceError::Plot2DSvgError)?;
+
+        Ok(Plot2DExportSvgOutputData::new(&plot_id, svg))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use src_domain::{
+        models::{
+            column::{column_id::ColumnId, column_repository::ColumnRepositoryError},
+            plot_2d::{
+                plot_2d::Plot2D, plot_2d_axis::Plot2DAxis, plot_2d_series::Plot2DSeries,
+                plot_2d_series_style::SeriesStyle, plot_2d_title::Plot2DTitle,
+            },
+        },
+        services::plot_2d_svg::Plot2DSvgError,
+    };
+    use src_in_memory_infrastructure::{
+        column::in_memory_column_repository::InMemoryColumnRepository,
+        plot_2d::in_memory_plot_2d_repository::InMemoryPlot2DRepository,
+    };
+
+    use crate::test_utils::save_column;
+
+    use super::*;
+
+    async fn save_plot(
+        plot_2d_repository: &InMemoryPlot2DRepository,
+        x: ColumnId,
+        y: ColumnId,
+    ) -> anyhow::Result<Plot2DId> {
+        let plot = Plot2D::new(
+            None,
+            Plot2DTitle::new("growth".to_string())?,
+            vec![Plot2DSeries::new(
+                x,
+                y,
+                String::new(),
+                SeriesStyle::default(),
+            )],
+            Plot2DAxis::default(),
+            Plot2DAxis::default(),
+        );
+        Ok(plot_2d_repository.save(&plot).await?)
+    }
+
+    #[tokio::test]
+    async fn test_handle() -> anyhow::Result<()> {
+        let column_repository = InMemoryColumnRepository::new();
+        let plot_2d_repository = InMemoryPlot2DRepository::new();
+        let x = save_column(
+            &column_repository,
+            "time",
+            vec![Some(0.), Some(1.), Some(2.)],
+        )
+        .await?;
+        let y = save_column(&column_repository, "size", vec![Some(1.), None, Some(4.)]).await?;
+        let plot_id = save_plot(&plot_2d_repository, x, y).await?;
+
+        let service = Plot2DExportSvgService::new(&column_repository, &plot_2d_repository);
+        let command = Plot2DExportSvgCommand {
+            plot_id: plot_id.clone_value(),
+            width: None,
+            height: None,
+        };
+        let output_data = service.handle(command).await?;
+        assert_eq!(output_data.plot_id, plot_id.clone_value());
+        assert!(output_data
+            .svg
+            .starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"640\" height=\"480\""));
+        assert!(output_data.svg.contains(">growth</text>"));
+        // 凡例には y のカラム名を使う
+        assert!(output_data.svg.contains(">size</text>"));
+
+        let command = Plot2DExportSvgCommand {
+            plot_id: plot_id.clone_value(),
+            width: Some(300.),
+            height: Some(200.),
+        };
+        let output_data = service.handle(command).await?;
+        assert!(output_data.svg.contains("viewBox=\"0 0 300 200\""));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handle_errors() -> anyhow::Result<()> {
+        let column_repository = InMemoryColumnRepository::new();
+        let plot_2d_repository = InMemoryPlot2DRepository::new();
+        let x = save_column(&column_repository, "time", vec![Some(0.), Some(1.)]).await?;
+        let plot_id = save_plot(
+            &plot_2d_repository,
+            x,
+            ColumnId::new("missing".to_string())?,
+        )
+        .await?;
+
+        let service = Plot2DExportSvgService::new(&column_repository, &plot_2d_repository);
+        let command = Plot2DExportSvgCommand {
+            plot_id: plot_id.clone_value(),
+            width: None,
+            height: None,
+        };
+        assert!(matches!(
+            service.handle(command).await,
+            Err(Plot2DExportSvgServiceError::ColumnRepositoryError(
+                ColumnRepositoryError::NotAllColumnsFound(_)
+            ))
+        ));
+
+        let command = Plot2DExportSvgCommand {
+            plot_id: "unknown".to_string(),
+            width: None,
+            height: None,
+        };
+        assert!(matches!(
+            service.handle(command).await,
+            Err(Plot2DExportSvgServiceError::Plot2DNotFound(_))
+        ));
+
+        let command = Plot2DExportSvgCommand {
+            plot_id: plot_id.clone_value(),
+            width: Some(10.),
+            height: None,
+        };
+        assert!(matches!(
+            service.handle(command).await,
+            Err(Plot2DExportSvgServiceError::Plot2DSvgError(
+                Plot2DSvgError::InvalidSize(_, _)
+            ))
+        ));
+        Ok(())
+    }
+}
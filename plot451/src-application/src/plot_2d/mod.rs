@@ -0,0 +1,2 @@
+// グラフの SVG 出力用アプリケーションサービス
+pub mod export_svg;
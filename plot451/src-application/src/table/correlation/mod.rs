@@ -0,0 +1,10 @@
+/* カラム間の相関行列・共分散行列用アプリケーションサービス */
+// コマンドオブジェクト
+pub mod table_correlation_command;
+
+// アプリケーションサービス
+pub mod table_correlation_service;
+pub mod table_correlation_service_impl;
+
+// DTO
+pub mod table_correlation_output_data;
@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+use src_domain::services::correlation::CorrelationMeasure;
+
+#[derive(Deserialize, Serialize)]
+pub struct TableCorrelationCommand {
+    pub(super) table_id: String,
+    // テーブルとして保存する行列
+    #[serde(default)]
+    pub(super) measure: CorrelationMeasureInCommand,
+    // 省略した場合は "{元のテーブル名} {行列の種類}"
+    #[serde(default)]
+    pub(super) table_name: Option<String>,
+    // 新しいカラムを保存するディレクトリ
+    pub(super) directory_id: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum CorrelationMeasureInCommand {
+    #[default]
+    Pearson,
+    Spearman,
+    Covariance,
+}
+
+impl CorrelationMeasureInCommand {
+    pub(super) fn to_correlation_measure(self) -> CorrelationMeasure {
+        match self {
+            CorrelationMeasureInCommand::Pearson => CorrelationMeasure::Pearson,
+            CorrelationMeasureInCommand::Spearman => CorrelationMeasure::Spearman,
+            CorrelationMeasureInCommand::Covariance => CorrelationMeasure::Covariance,
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+
+use src_domain::{
+    models::{
+        column::column::Column, table::table_with_columns_and_cells::TableWithColumnsAndCells,
+    },
+    services::correlation::{CorrelationMatrices, CorrelationMeasure},
+    shared::value_object::ValueObject,
+};
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+pub struct TableCorrelationOutputData {
+    pub(super) table_id: String,
+    pub(super) table_name: String,
+    pub(super) source_table_id: String,
+    // 行列の行と列に対応する元のカラム
+    pub(super) source_columns: Vec<SourceColumnInOutputData>,
+    pub(super) pearson: Vec<Vec<Option<f64>>>,
+    pub(super) spearman: Vec<Vec<Option<f64>>>,
+    pub(super) covariance: Vec<Vec<Option<f64>>>,
+    // 各成分の計算に使った行数
+    pub(super) pair_counts: Vec<Vec<usize>>,
+    // 保存した行列の各列
+    pub(super) columns: Vec<ColumnInOutputData>,
+}
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+pub(super) struct SourceColumnInOutputData {
+    pub(super) column_id: String,
+    pub(super) column_name: String,
+}
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+pub(super) struct ColumnInOutputData {
+    pub(super) column_id: String,
+    pub(super) column_name: String,
+    pub(super) cells: Vec<ColumnCellInOutputData>,
+}
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+pub(super) struct ColumnCellInOutputData {
+    pub(super) cell_id: String,
+    pub(super) cell_value: Option<f64>,
+}
+
+impl TableCorrelationOutputData {
+    pub(super) fn new(
+        source_table_id: String,
+        source_columns: &[Column],
+        matrices: &CorrelationMatrices,
+        table: TableWithColumnsAndCells,
+    ) -> Self {
+        Self {
+            table_id: table.id().clone_value(),
+            table_name: table.name().clone_value(),
+            source_table_id,
+            source_columns: source_columns
+                .iter()
+                .map(|column| SourceColumnInOutputData {
+                    column_id: column.id().clone_value(),
+                    column_name: column.name().clone_value(),
+                })
+                .collect(),
+            pearson: matrices.matrix(CorrelationMeasure::Pearson).clone(),
+            spearman: matrices.matrix(CorrelationMeasure::Spearman).clone(),
+            covariance: matrices.matrix(CorrelationMeasure::Covariance).clone(),
+            pair_counts: matrices.pair_counts().clone(),
+            columns: table
+                .columns()
+                .iter()
+                .map(|column| ColumnInOutputData {
+                    column_id: column.id().clone_value(),
+                    column_name: column.name().clone_value(),
+                    cells: column
+                        .cells()
+                        .iter()
+                        .map(|cell| ColumnCellInOutputData {
+                            cell_id: cell.id().clone_value(),
+                            cell_value: cell.cell_value().clone_value(),
+                        })
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use thiserror::Error;
+
+use src_domain::{
+    models::{
+        column::{
+            column_directory::column_directory_id::ColumnDirectoryIdError,
+            column_repository::ColumnRepositoryError,
+        },
+        table::{
+            table_factory::TableFactoryError,
+            table_id::{TableId, TableIdError},
+            table_name::TableNameError,
+            table_repository::TableRepositoryError,
+        },
+    },
+    services::column_creation_service::ColumnCreationServiceError,
+};
+
+use super::{
+    table_correlation_command::TableCorrelationCommand,
+    table_correlation_output_data::TableCorrelationOutputData,
+};
+
+pub type TableCorrelationServiceResult<T> = anyhow::Result<T, TableCorrelationServiceError>;
+
+pub trait ITableCorrelationService {
+    fn handle(
+        &self,
+        command: TableCorrelationCommand,
+    ) -> impl std::future::Future<Output = TableCorrelationServiceResult<TableCorrelationOutputData>>
+           + Send;
+}
+
+#[derive(Debug, Error)]
+pub enum TableCorrelationServiceError {
+    // repository errors
+    #[error("TableRepositoryError: [{0}]")]
+    TableRepositoryError(TableRepositoryError),
+    #[error("ColumnRepositoryError: [{0}]")]
+    ColumnRepositoryError(ColumnRepositoryError),
+
+    // value object errors
+    #[error("TableIdError: [{0}]")]
+    TableIdError(TableIdError),
+    #[error("TableNameError: [{0}]")]
+    TableNameError(TableNameError),
+    #[error("ColumnDirectoryIdError: [{0}]")]
+    ColumnDirectoryIdError(ColumnDirectoryIdError),
+
+    // factory errors
+    #[error("TableFactoryError: [{0}]")]
+    TableFactoryError(TableFactoryError),
+
+    // domain service errors
+    #[error("ColumnCreationServiceError: [{0}]")]
+    ColumnCreationServiceError(ColumnCreationServiceError),
+
+    // not found errors
+    #[error("Table not found, table_id: {0:?}")]
+    TableNotFound(TableId),
+}
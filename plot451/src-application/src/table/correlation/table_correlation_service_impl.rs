@@ -0,0 +1,289 @@
+use src_domain::{
+    models::{
+        column::{
+            column_cell::column_cell_value::CellRawValue,
+            column_directory::column_directory_id::ColumnDirectoryId,
+            column_factory::IColumnFactory, column_repository::IColumnRepository,
+        },
+        table::{
+            table_factory::ITableFactory, table_id::TableId, table_name::TableName,
+            table_repository::ITableRepository,
+            table_with_columns_and_cells::TableWithColumnsAndCells,
+        },
+    },
+    services::{
+        column_creation_service::ColumnCreationService, column_values_service::ColumnValuesService,
+        correlation::CorrelationMatrices,
+    },
+    shared::value_object::ValueObject,
+};
+
+use super::{
+    table_correlation_command::TableCorrelationCommand,
+    table_correlation_output_data::TableCorrelationOutputData,
+    table_correlation_service::{
+        ITableCorrelationService, TableCorrelationServiceError, TableCorrelationServiceResult,
+    },
+};
+
+pub struct TableCorrelationService<'a, 'b, 'c, 'd, CF, CR, TF, TR>
+where
+    CF: IColumnFactory,
+    CR: IColumnRepository,
+    TF: ITableFactory,
+    TR: ITableRepository,
+{
+    column_factory: &'a CF,
+    column_repository: &'b CR,
+    table_factory: &'c TF,
+    table_repository: &'d TR,
+}
+
+impl<'a, 'b, 'c, 'd, CF, CR, TF, TR> TableCorrelationService<'a, 'b, 'c, 'd, CF, CR, TF, TR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+    TF: ITableFactory + Sync,
+    TR: ITableRepository + Sync,
+{
+    pub fn new(
+        column_factory: &'a CF,
+        column_repository: &'b CR,
+        table_factory: &'c TF,
+        table_repository: &'d TR,
+    ) -> Self {
+        Self {
+            column_factory,
+            column_repository,
+            table_factory,
+            table_repository,
+        }
+    }
+}
+
+impl<'a, 'b, 'c, 'd, CF, CR, TF, TR> ITableCorrelationService
+    for TableCorrelationService<'a, 'b, 'c, 'd, CF, CR, TF, TR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+    TF: ITableFactory + Sync,
+    TR: ITableRepository + Sync,
+{
+    // TODO: トランザクション処理を追加する
+    async fn handle(
+        &self,
+        command: TableCorrelationCommand,
+    ) -> TableCorrelationServiceResult<TableCorrelationOutputData> {
+        let TableCorrelationCommand {
+            table_id,
+            measure,
+            table_name,
+            directory_id,
+        } = command;
+
+        // 値オブジェクトのインスタンス化
+        let table_id =
+            TableId::new(table_id).map_err(TableCorrelationServiceError::TableIdError)?;
+        let directory_id = ColumnDirectoryId::new(directory_id)
+            .map_err(TableCorrelationServiceError::ColumnDirectoryIdError)?;
+        let measure = measure.to_correlation_measure();
+
+        // 元のテーブルとカラムの値の取得
+        let source = self
+            .table_repository
+            .find(&table_id)
+            .await
+            .map_err(TableCorrelationServiceError::TableRepositoryError)?
+            .ok_or(TableCorrelationServiceError::TableNotFound(
+                table_id.clone(),
+            ))?;
+        let table_name = TableName::new(
+            table_name.unwrap_or_else(|| format!("{} {}", source.name().value(), measure)),
+        )
+        .map_err(TableCorrelationServiceError::TableNameError)?;
+        let columns = self
+            .column_repository
+            .find_by_ids(source.columns())
+            .await
+            .map_err(TableCorrelationServiceError::ColumnRepositoryError)?;
+
+        // 数値を 1 つも含まないカラムは除く
+        let mut numeric_columns = vec![];
+        let mut values: Vec<Vec<CellRawValue>> = vec![];
+        for column in columns {
+            let column_values: Vec<CellRawValue> = ColumnValuesService::new(self.column_repository)
+                .find_values(&column)
+                .await
+                .map_err(TableCorrelationServiceError::ColumnRepositoryError)?;
+            if column_values
+                .iter()
+                .any(|value| value.is_some_and(|value| !value.is_nan()))
+            {
+                numeric_columns.push(column);
+                values.push(column_values);
+            }
+        }
+        let matrices = CorrelationMatrices::compute(&values);
+
+        // 行列の各列を元のカラムと同じ名前のカラムとして保存する
+        let column_creation_service =
+            ColumnCreationService::new(self.column_factory, self.column_repository);
+        let mut column_ids = vec![];
+        let mut columns_with_cells = vec![];
+        for (column, matrix_column) in numeric_columns.iter().zip(matrices.matrix(measure)) {
+            let (new_column, column_with_cells) = column_creation_service
+                .create_column(
+                    column.name().clone(),
+                    directory_id.clone(),
+                    matrix_column.clone(),
+                )
+                .await
+                .map_err(TableCorrelationServiceError::ColumnCreationServiceError)?;
+            column_ids.push(new_column.id().clone());
+            columns_with_cells.push(column_with_cells);
+        }
+
+        // 結果のテーブルの作成と永続化
+        let mut table = self
+            .table_factory
+            .create_table(table_name, column_ids)
+            .await
+            .map_err(TableCorrelationServiceError::TableFactoryError)?;
+        let new_table_id = self
+            .table_repository
+            .save(&table)
+            .await
+            .map_err(TableCorrelationServiceError::TableRepositoryError)?;
+        table.set_id(new_table_id);
+
+        Ok(TableCorrelationOutputData::new(
+            table_id.clone_value(),
+            &numeric_columns,
+            &matrices,
+            TableWithColumnsAndCells::new(&table, columns_with_cells),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use src_in_memory_infrastructure::{
+        column::{
+            in_memory_column_factory::InMemoryColumnFactory,
+            in_memory_column_repository::InMemoryColumnRepository,
+        },
+        table::{
+            in_memory_table_factory::InMemoryTableFactory,
+            in_memory_table_repository::InMemoryTableRepository,
+        },
+    };
+
+    use crate::{
+        table::correlation::table_correlation_command::CorrelationMeasureInCommand,
+        test_utils::save_table,
+    };
+
+    use super::*;
+
+    // x: [1, 2, 3, 4], y: [2, 4, 6, None], empty: [None, None] のテーブルを作成する
+    async fn prepare(
+        column_repository: &InMemoryColumnRepository,
+        table_repository: &InMemoryTableRepository,
+    ) -> anyhow::Result<TableId> {
+        let columns = vec![
+            ("x", vec![Some(1.), Some(2.), Some(3.), Some(4.)]),
+            ("y", vec![Some(2.), Some(4.), Some(6.), None]),
+            ("empty", vec![None, None]),
+        ];
+        let (table_id, _) =
+            save_table(column_repository, table_repository, "data", columns).await?;
+        Ok(table_id)
+    }
+
+    #[tokio::test]
+    async fn test_handle() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let table_factory = InMemoryTableFactory::new();
+        let table_repository = InMemoryTableRepository::new();
+        let source_id = prepare(&column_repository, &table_repository).await?;
+
+        let service = TableCorrelationService::new(
+            &column_factory,
+            &column_repository,
+            &table_factory,
+            &table_repository,
+        );
+        let command = TableCorrelationCommand {
+            table_id: source_id.clone_value(),
+            measure: CorrelationMeasureInCommand::Covariance,
+            table_name: None,
+            directory_id: "3".to_string(),
+        };
+        let output_data = service.handle(command).await?;
+        assert_eq!(output_data.table_name, "data covariance");
+        assert_eq!(output_data.source_table_id, source_id.clone_value());
+        let names: Vec<&str> = output_data
+            .source_columns
+            .iter()
+            .map(|column| column.column_name.as_str())
+            .collect();
+        assert_eq!(names, vec!["x", "y"]);
+        // y の None の行はペアごとに除かれる
+        assert_eq!(output_data.pair_counts, vec![vec![4, 3], vec![3, 3]]);
+        assert_eq!(output_data.spearman[0][1], Some(1.));
+
+        // 保存したテーブルの各カラムは共分散行列の列
+        let values: Vec<(String, Vec<Option<f64>>)> = output_data
+            .columns
+            .iter()
+            .map(|column| {
+                (
+                    column.column_name.clone(),
+                    column.cells.iter().map(|cell| cell.cell_value).collect(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            values,
+            vec![
+                ("x".to_string(), vec![Some(5. / 3.), Some(2.)]),
+                ("y".to_string(), vec![Some(2.), Some(4.)]),
+            ]
+        );
+        let table = table_repository
+            .find(&TableId::new(output_data.table_id)?)
+            .await?
+            .unwrap();
+        for column in column_repository.find_by_ids(table.columns()).await? {
+            assert_eq!(column.directory_id().value(), "3");
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handle_table_not_found() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let table_factory = InMemoryTableFactory::new();
+        let table_repository = InMemoryTableRepository::new();
+
+        let service = TableCorrelationService::new(
+            &column_factory,
+            &column_repository,
+            &table_factory,
+            &table_repository,
+        );
+        let command = TableCorrelationCommand {
+            table_id: "100".to_string(),
+            measure: CorrelationMeasureInCommand::Pearson,
+            table_name: None,
+            directory_id: "3".to_string(),
+        };
+        assert!(matches!(
+            service.handle(command).await,
+            Err(TableCorrelationServiceError::TableNotFound(_))
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,10 @@
+/* テーブルの行編集 (挿入・削除・移動) 用アプリケーションサービス */
+// コマンドオブジェクト
+pub mod table_rows_edit_command;
+
+// アプリケーションサービス
+pub mod table_rows_edit_service;
+pub mod table_rows_edit_service_impl;
+
+// DTO
+pub mod table_rows_edit_output_data;
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize)]
+pub struct TableRowsEditCommand {
+    pub(super) table_id: String,
+    pub(super) operation: TableRowsEditOperation,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TableRowsEditOperation {
+    // index 行目の前に count 行の空行を挿入
+    Insert {
+        index: usize,
+        count: usize,
+    },
+    // index 行目から count 行を削除
+    Delete {
+        index: usize,
+        count: usize,
+    },
+    // from 行目から count 行を、移動後に to 行目から始まるように移動
+    Move {
+        from: usize,
+        count: usize,
+        to: usize,
+    },
+}
@@ -0,0 +1,64 @@
+use thiserror::Error;
+
+use src_domain::{
+    models::{
+        column::{
+            column_constraint_specification::ColumnConstraintSpecificationError,
+            column_repository::ColumnRepositoryError,
+        },
+        table::{
+            table_id::{TableId, TableIdError},
+            table_repository::TableRepositoryError,
+            table_rows::TableRowsError,
+        },
+    },
+    services::{
+        derived_column_service::DerivedColumnServiceError,
+        table_rows_padding_service::TableRowsPaddingServiceError,
+    },
+};
+
+use super::{
+    table_rows_edit_command::TableRowsEditCommand,
+    table_rows_edit_output_data::TableRowsEditOutputData,
+};
+
+pub type TableRowsEditServiceResult<T> = anyhow::Result<T, TableRowsEditServiceError>;
+
+pub trait ITableRowsEditService {
+    fn handle(
+        &self,
+        command: TableRowsEditCommand,
+    ) -> impl std::future::Future<Output = TableRowsEditServiceResult<TableRowsEditOutputData>> + Send;
+}
+
+#[derive(Debug, Error)]
+pub enum TableRowsEditServiceError {
+    // repository errors
+    #[error("TableRepositoryError: [{0}]")]
+    TableRepositoryError(TableRepositoryError),
+    #[error("ColumnRepositoryError: [{0}]")]
+    ColumnRepositoryError(ColumnRepositoryError),
+
+    // value object errors
+    #[error("TableIdError: [{0}]")]
+    TableIdError(TableIdError),
+
+    // first class collection errors
+    #[error("TableRowsError: [{0}]")]
+    TableRowsError(TableRowsError),
+
+    // specification errors
+    #[error("ColumnConstraintSpecificationError: [{0}]")]
+    ColumnConstraintSpecificationError(ColumnConstraintSpecificationError),
+
+    // domain service errors
+    #[error("TableRowsPaddingServiceError: [{0}]")]
+    TableRowsPaddingServiceError(TableRowsPaddingServiceError),
+    #[error("DerivedColumnServiceError: [{0}]")]
+    DerivedColumnServiceError(DerivedColumnServiceError),
+
+    // not found errors
+    #[error("Table not found, table_id: {0:?}")]
+    TableNotFound(TableId),
+}
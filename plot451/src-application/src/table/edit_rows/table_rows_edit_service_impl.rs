@@ -0,0 +1,605 @@
+use src_domain::{
+    models::{
+        column::{
+            column_constraint_specification::ColumnConstraintsSpecification,
+            column_factory::IColumnFactory, column_repository::IColumnRepository,
+            column_with_cells::ColumnWithCells,
+        },
+        table::{
+            table_id::TableId, table_repository::ITableRepository, table_rows::TableRows,
+            table_with_columns_and_cells::TableWithColumnsAndCells,
+        },
+    },
+    services::{
+        derived_column_service::{DerivedColumnService, RecomputedColumn},
+        table_rows_padding_service::TableRowsPaddingService,
+    },
+    shared::{specification::Specification, value_object::ValueObject},
+};
+
+use super::{
+    table_rows_edit_command::{TableRowsEditCommand, TableRowsEditOperation},
+    table_rows_edit_output_data::TableRowsEditOutputData,
+    table_rows_edit_service::{
+        ITableRowsEditService, TableRowsEditServiceError, TableRowsEditServiceResult,
+    },
+};
+
+pub struct TableRowsEditService<'a, 'b, 'c, CF, CR, TR>
+where
+    CF: IColumnFactory,
+    CR: IColumnRepository,
+    TR: ITableRepository,
+{
+    column_factory: &'a CF,
+    column_repository: &'b CR,
+    table_repository: &'c TR,
+}
+
+impl<'a, 'b, 'c, CF, CR, TR> TableRowsEditService<'a, 'b, 'c, CF, CR, TR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+    TR: ITableRepository + Sync,
+{
+    pub fn new(
+        column_factory: &'a CF,
+        column_repository: &'b CR,
+        table_repository: &'c TR,
+    ) -> Self {
+        Self {
+            column_factory,
+            column_repository,
+            table_repository,
+        }
+    }
+
+    // カラムに設定された制約のチェック (派生カラムは再計算時にチェックする) と
+    // 派生カラムとそれらを参照する派生カラムの再計算 (永続化はしない)
+    async fn check_rows(
+        &self,
+        table_rows: &TableRows,
+        derived_column_service: &DerivedColumnService<'_, '_, CF, CR>,
+    ) -> TableRowsEditServiceResult<Vec<RecomputedColumn>> {
+        let mut changed = vec![];
+        for column in table_rows.columns() {
+            let cells = self
+                .column_repository
+                .find_cells_by_ids(column.cells())
+                .await
+                .map_err(TableRowsEditServiceError::ColumnRepositoryError)?;
+            let values = cells
+                .iter()
+                .map(|cell| cell.cell_value().clone_value())
+                .collect();
+            if !column.is_derived() {
+                let column_with_cells = ColumnWithCells::new(column, cells);
+                ColumnConstraintsSpecification::new(column.constraints().clone())
+                    .is_satisfied_by(&column_with_cells)
+                    .map_err(TableRowsEditServiceError::ColumnConstraintSpecificationError)?;
+            }
+            changed.push((column.clone(), values));
+        }
+        derived_column_service
+            .compute_dependents(changed)
+            .await
+            .map_err(TableRowsEditServiceError::DerivedColumnServiceError)
+    }
+}
+
+impl<'a, 'b, 'c, CF, CR, TR> ITableRowsEditService for TableRowsEditService<'a, 'b, 'c, CF, CR, TR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+    TR: ITableRepository + Sync,
+{
+    // TODO: トランザクション処理を追加する
+    async fn handle(
+        &self,
+        command: TableRowsEditCommand,
+    ) -> TableRowsEditServiceResult<TableRowsEditOutputData> {
+        let TableRowsEditCommand {
+            table_id,
+            operation,
+        } = command;
+        let table_id = TableId::new(table_id).map_err(TableRowsEditServiceError::TableIdError)?;
+
+        // テーブルの取得
+        let table = self
+            .table_repository
+            .find(&table_id)
+            .await
+            .map_err(TableRowsEditServiceError::TableRepositoryError)?
+            .ok_or(TableRowsEditServiceError::TableNotFound(table_id))?;
+
+        let columns = self
+            .column_repository
+            .find_by_ids(table.columns())
+            .await
+            .map_err(TableRowsEditServiceError::ColumnRepositoryError)?;
+
+        // ファーストクラスコレクションに詰め替え
+        let mut table_rows = TableRows::new(&table, columns);
+
+        // セルを作成する前に操作の範囲を確認する
+        match &operation {
+            TableRowsEditOperation::Insert { index, count } => {
+                table_rows.check_insert(*index, *count)
+            }
+            TableRowsEditOperation::Delete { index, count } => {
+                table_rows.check_delete(*index, *count)
+            }
+            TableRowsEditOperation::Move { from, count, to } => {
+                table_rows.check_move(*from, *count, *to)
+            }
+        }
+        .map_err(TableRowsEditServiceError::TableRowsError)?;
+
+        // 長さの足りないカラムを空セルで埋める
+        let padding_service =
+            TableRowsPaddingService::new(self.column_factory, self.column_repository);
+        let mut created_cell_ids = padding_service
+            .pad(&mut table_rows)
+            .await
+            .map_err(TableRowsEditServiceError::TableRowsPaddingServiceError)?;
+
+        // 行の操作をすべてのカラムに適用
+        let mut removed_cell_ids = vec![];
+        match operation {
+            TableRowsEditOperation::Insert { index, count } => {
+                let mut new_cells = vec![];
+                for _ in table_rows.columns() {
+                    let cell_ids = padding_service
+                        .create_empty_cells(count)
+                        .await
+                        .map_err(TableRowsEditServiceError::TableRowsPaddingServiceError)?;
+                    created_cell_ids.extend(cell_ids.iter().cloned());
+                    new_cells.push(cell_ids);
+                }
+                table_rows
+                    .insert_rows(index, new_cells)
+                    .map_err(TableRowsEditServiceError::TableRowsError)?;
+            }
+            TableRowsEditOperation::Delete { index, count } => {
+                removed_cell_ids = table_rows
+                    .delete_rows(index, count)
+                    .map_err(TableRowsEditServiceError::TableRowsError)?;
+            }
+            TableRowsEditOperation::Move { from, count, to } => {
+                table_rows
+                    .move_rows(from, count, to)
+                    .map_err(TableRowsEditServiceError::TableRowsError)?;
+            }
+        }
+
+        // 永続化する前に制約のチェックと派生カラムの再計算を行う
+        // 失敗した場合は作成した空セルを削除する
+        let derived_column_service =
+            DerivedColumnService::new(self.column_factory, self.column_repository);
+        let recomputed_columns = match self.check_rows(&table_rows, &derived_column_service).await {
+            Ok(recomputed_columns) => recomputed_columns,
+            Err(error) => {
+                let created_cells = self
+                    .column_repository
+                    .find_cells_by_ids(&created_cell_ids)
+                    .await
+                    .map_err(TableRowsEditServiceError::ColumnRepositoryError)?;
+                for cell in created_cells {
+                    self.column_repository
+                        .delete_cell(cell)
+                        .await
+                        .map_err(TableRowsEditServiceError::ColumnRepositoryError)?;
+                }
+                return Err(error);
+            }
+        };
+
+        // カラムの永続化
+        for column in table_rows.columns() {
+            self.column_repository
+                .save(column)
+                .await
+                .map_err(TableRowsEditServiceError::ColumnRepositoryError)?;
+        }
+
+        // 削除された行のセルを削除
+        let removed_cells = self
+            .column_repository
+            .find_cells_by_ids(&removed_cell_ids)
+            .await
+            .map_err(TableRowsEditServiceError::ColumnRepositoryError)?;
+        for cell in removed_cells {
+            self.column_repository
+                .delete_cell(cell)
+                .await
+                .map_err(TableRowsEditServiceError::ColumnRepositoryError)?;
+        }
+
+        // 再計算した派生カラムの永続化
+        derived_column_service
+            .save_all(recomputed_columns)
+            .await
+            .map_err(TableRowsEditServiceError::DerivedColumnServiceError)?;
+
+        // 編集後の行を OutputData として返す
+        let columns = self
+            .column_repository
+            .find_by_ids(table.columns())
+            .await
+            .map_err(TableRowsEditServiceError::ColumnRepositoryError)?;
+        let mut columns_with_cells = vec![];
+        for column in &columns {
+            let cells = self
+                .column_repository
+                .find_cells_by_ids(column.cells())
+                .await
+                .map_err(TableRowsEditServiceError::ColumnRepositoryError)?;
+            columns_with_cells.push(ColumnWithCells::new(column, cells));
+        }
+        let table_with_columns_and_cells =
+            TableWithColumnsAndCells::new(&table, columns_with_cells);
+
+        let output_data = TableRowsEditOutputData::new(table_with_columns_and_cells);
+        Ok(output_data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use src_domain::models::{
+        column::{
+            column::Column,
+            column_cell::{
+                column_cell::ColumnCell, column_cell_id::ColumnCellId,
+                column_cell_value::ColumnCellValue,
+            },
+            column_constraint::ColumnConstraint,
+            column_directory::column_directory_id::ColumnDirectoryId,
+            column_formula::column_formula::ColumnFormula,
+            column_id::ColumnId,
+            column_name::ColumnName,
+        },
+        table::{table::Table, table_name::TableName},
+    };
+    use src_domain::services::column_values_service::ColumnValuesService;
+    use src_in_memory_infrastructure::{
+        column::{
+            in_memory_column_factory::InMemoryColumnFactory,
+            in_memory_column_repository::InMemoryColumnRepository,
+        },
+        table::in_memory_table_repository::InMemoryTableRepository,
+    };
+
+    use crate::table::edit_rows::table_rows_edit_output_data::RowInOutputData;
+
+    use super::*;
+
+    // カラム1: [1, 2, 3], カラム2: [10] のテーブルを作成する
+    async fn prepare(
+        column_repository: &InMemoryColumnRepository,
+        table_repository: &InMemoryTableRepository,
+    ) -> anyhow::Result<()> {
+        let values = [
+            ("cell_id_1", 1.0),
+            ("cell_id_2", 2.0),
+            ("cell_id_3", 3.0),
+            ("cell_id_4", 10.0),
+        ];
+        for (cell_id, value) in values {
+            let cell = ColumnCell::new(
+                Some(ColumnCellId::new(cell_id.to_string())?),
+                ColumnCellValue::new(Some(value))?,
+            );
+            column_repository.save_cell(&cell).await?;
+        }
+
+        let column1 = Column::new(
+            Some(ColumnId::new("column_id_1".to_string())?),
+            ColumnName::new("column_name_1".to_string())?,
+            ColumnDirectoryId::new("0".to_string())?,
+            vec![
+                ColumnCellId::new("cell_id_1".to_string())?,
+                ColumnCellId::new("cell_id_2".to_string())?,
+                ColumnCellId::new("cell_id_3".to_string())?,
+            ],
+        );
+        let column2 = Column::new(
+            Some(ColumnId::new("column_id_2".to_string())?),
+            ColumnName::new("column_name_2".to_string())?,
+            ColumnDirectoryId::new("0".to_string())?,
+            vec![ColumnCellId::new("cell_id_4".to_string())?],
+        );
+        column_repository.save(&column1).await?;
+        column_repository.save(&column2).await?;
+
+        let table = Table::new(
+            Some(TableId::new("table_id_1".to_string())?),
+            TableName::new("table_name_1".to_string())?,
+            vec![column1.id().clone(), column2.id().clone()],
+        )?;
+        table_repository.save(&table).await?;
+        Ok(())
+    }
+
+    fn values(rows: &[RowInOutputData]) -> Vec<Vec<Option<f64>>> {
+        rows.iter()
+            .map(|row| row.cells.iter().map(|cell| cell.cell_value).collect())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_insert_rows() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let table_repository = InMemoryTableRepository::new();
+        prepare(&column_repository, &table_repository).await?;
+
+        let service =
+            TableRowsEditService::new(&column_factory, &column_repository, &table_repository);
+        let command = TableRowsEditCommand {
+            table_id: "table_id_1".to_string(),
+            operation: TableRowsEditOperation::Insert { index: 1, count: 1 },
+        };
+        let TableRowsEditOutputData { rows, .. } = service.handle(command).await?;
+
+        assert_eq!(
+            values(&rows),
+            vec![
+                vec![Some(1.0), Some(10.0)],
+                vec![None, None],
+                vec![Some(2.0), None],
+                vec![Some(3.0), None],
+            ]
+        );
+
+        // パディング・挿入したセルも永続化され、すべてのカラムの長さが揃っている
+        assert!(rows
+            .iter()
+            .all(|row| row.cells.iter().all(|cell| cell.cell_id.is_some())));
+        let column2 = column_repository
+            .find(&ColumnId::new("column_id_2".to_string())?)
+            .await?
+            .unwrap();
+        assert_eq!(column2.cells().len(), 4);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_delete_rows() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let table_repository = InMemoryTableRepository::new();
+        prepare(&column_repository, &table_repository).await?;
+
+        let service =
+            TableRowsEditService::new(&column_factory, &column_repository, &table_repository);
+        let command = TableRowsEditCommand {
+            table_id: "table_id_1".to_string(),
+            operation: TableRowsEditOperation::Delete { index: 0, count: 2 },
+        };
+        let TableRowsEditOutputData { rows, .. } = service.handle(command).await?;
+
+        assert_eq!(values(&rows), vec![vec![Some(3.0), None]]);
+
+        // 削除された行のセルはリポジトリからも削除されている
+        assert!(column_repository
+            .find_cell(&ColumnCellId::new("cell_id_1".to_string())?)
+            .await?
+            .is_none());
+        assert!(column_repository
+            .find_cell(&ColumnCellId::new("cell_id_4".to_string())?)
+            .await?
+            .is_none());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_move_rows() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let table_repository = InMemoryTableRepository::new();
+        prepare(&column_repository, &table_repository).await?;
+
+        let service =
+            TableRowsEditService::new(&column_factory, &column_repository, &table_repository);
+        let command = TableRowsEditCommand {
+            table_id: "table_id_1".to_string(),
+            operation: TableRowsEditOperation::Move {
+                from: 0,
+                count: 1,
+                to: 2,
+            },
+        };
+        let TableRowsEditOutputData { rows, .. } = service.handle(command).await?;
+
+        assert_eq!(
+            values(&rows),
+            vec![
+                vec![Some(2.0), None],
+                vec![Some(3.0), None],
+                vec![Some(1.0), Some(10.0)],
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_out_of_range() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let table_repository = InMemoryTableRepository::new();
+        prepare(&column_repository, &table_repository).await?;
+
+        let service =
+            TableRowsEditService::new(&column_factory, &column_repository, &table_repository);
+        let command = TableRowsEditCommand {
+            table_id: "table_id_1".to_string(),
+            operation: TableRowsEditOperation::Delete { index: 2, count: 2 },
+        };
+        match service.handle(command).await {
+            Err(TableRowsEditServiceError::TableRowsError(_)) => Ok(()),
+            _ => panic!("unexpected result"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_insert_rows_constraint_violated() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let table_repository = InMemoryTableRepository::new();
+        prepare(&column_repository, &table_repository).await?;
+        let mut column1 = column_repository
+            .find(&ColumnId::new("column_id_1".to_string())?)
+            .await?
+            .unwrap();
+        column1.change_constraints(vec![ColumnConstraint::NonNull]);
+        column_repository.save(&column1).await?;
+
+        // 空セルの挿入は空セルを許可しないカラムの制約に違反する
+        let service =
+            TableRowsEditService::new(&column_factory, &column_repository, &table_repository);
+        let command = TableRowsEditCommand {
+            table_id: "table_id_1".to_string(),
+            operation: TableRowsEditOperation::Insert { index: 1, count: 1 },
+        };
+        assert!(matches!(
+            service.handle(command).await,
+            Err(TableRowsEditServiceError::ColumnConstraintSpecificationError(_))
+        ));
+
+        // 作成した空セルは削除され、カラムは変更されていない
+        for cell_id in ["1", "2", "3", "4"] {
+            assert!(column_repository
+                .find_cell(&ColumnCellId::new(cell_id.to_string())?)
+                .await?
+                .is_none());
+        }
+        let column1 = column_repository.find(column1.id()).await?.unwrap();
+        assert_eq!(column1.cells().len(), 3);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_rejected_insert_creates_no_cells() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let table_repository = InMemoryTableRepository::new();
+        prepare(&column_repository, &table_repository).await?;
+
+        let service =
+            TableRowsEditService::new(&column_factory, &column_repository, &table_repository);
+        for (index, count) in [(4, 1), (0, usize::MAX)] {
+            let command = TableRowsEditCommand {
+                table_id: "table_id_1".to_string(),
+                operation: TableRowsEditOperation::Insert { index, count },
+            };
+            assert!(matches!(
+                service.handle(command).await,
+                Err(TableRowsEditServiceError::TableRowsError(_))
+            ));
+        }
+
+        // パディング・挿入用のセルは保存されていない (最初に採番される id は "1")
+        assert!(column_repository
+            .find_cell(&ColumnCellId::new("1".to_string())?)
+            .await?
+            .is_none());
+        let column2 = column_repository
+            .find(&ColumnId::new("column_id_2".to_string())?)
+            .await?
+            .unwrap();
+        assert_eq!(column2.cells().len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_recompute_derived_column() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let table_repository = InMemoryTableRepository::new();
+        prepare(&column_repository, &table_repository).await?;
+
+        // テーブル外に column_id_1 の派生カラム (+1) を作成する
+        let mut derived = Column::new(
+            None,
+            ColumnName::new("derived".to_string())?,
+            ColumnDirectoryId::new("0".to_string())?,
+            vec![],
+        );
+        derived.change_formula(Some(ColumnFormula::new(
+            "col_id(\"column_id_1\") + 1".to_string(),
+        )?));
+        let derived_id = column_repository.save(&derived).await?;
+
+        let service =
+            TableRowsEditService::new(&column_factory, &column_repository, &table_repository);
+        let command = TableRowsEditCommand {
+            table_id: "table_id_1".to_string(),
+            operation: TableRowsEditOperation::Delete { index: 0, count: 1 },
+        };
+        service.handle(command).await?;
+
+        // 行の削除に連動して派生カラムが再計算されている
+        let derived = column_repository.find(&derived_id).await?.unwrap();
+        let values = ColumnValuesService::new(&column_repository)
+            .find_values(&derived)
+            .await?;
+        assert_eq!(values, vec![Some(3.0), Some(4.0)]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_recompute_constraint_violated() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let table_repository = InMemoryTableRepository::new();
+        prepare(&column_repository, &table_repository).await?;
+
+        // テーブル外に空セルを許可しない column_id_1 の派生カラム (+1) を作成する
+        let mut derived = Column::new(
+            None,
+            ColumnName::new("derived".to_string())?,
+            ColumnDirectoryId::new("0".to_string())?,
+            vec![],
+        );
+        derived.change_formula(Some(ColumnFormula::new(
+            "col_id(\"column_id_1\") + 1".to_string(),
+        )?));
+        derived.change_constraints(vec![ColumnConstraint::NonNull]);
+        let derived_id = column_repository.save(&derived).await?;
+        let mut derived = column_repository.find(&derived_id).await?.unwrap();
+        DerivedColumnService::new(&column_factory, &column_repository)
+            .recompute(&mut derived)
+            .await?;
+
+        // 空行の挿入で派生カラムに空セルができるので失敗する
+        let service =
+            TableRowsEditService::new(&column_factory, &column_repository, &table_repository);
+        let command = TableRowsEditCommand {
+            table_id: "table_id_1".to_string(),
+            operation: TableRowsEditOperation::Insert { index: 0, count: 1 },
+        };
+        assert!(matches!(
+            service.handle(command).await,
+            Err(TableRowsEditServiceError::DerivedColumnServiceError(_))
+        ));
+
+        // テーブルのカラムも派生カラムも変更されていない
+        let column1 = column_repository
+            .find(&ColumnId::new("column_id_1".to_string())?)
+            .await?
+            .unwrap();
+        assert_eq!(column1.cells().len(), 3);
+        let column2 = column_repository
+            .find(&ColumnId::new("column_id_2".to_string())?)
+            .await?
+            .unwrap();
+        assert_eq!(column2.cells().len(), 1);
+        let derived = column_repository.find(&derived_id).await?.unwrap();
+        let values = ColumnValuesService::new(&column_repository)
+            .find_values(&derived)
+            .await?;
+        assert_eq!(values, vec![Some(2.0), Some(3.0), Some(4.0)]);
+        Ok(())
+    }
+}
@@ -0,0 +1,10 @@
+/* 条件に合う行を抽出した新しいテーブルの作成用アプリケーションサービス */
+// コマンドオブジェクト
+pub mod table_filter_command;
+
+// アプリケーションサービス
+pub mod table_filter_service;
+pub mod table_filter_service_impl;
+
+// DTO
+pub mod table_filter_output_data;
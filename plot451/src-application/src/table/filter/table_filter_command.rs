@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize)]
+pub struct TableFilterCommand {
+    // 抽出元のテーブル
+    pub(super) table_id: String,
+    // 例: col("T") > 300 && col("flag") == 1
+    pub(super) predicate: String,
+    pub(super) table_name: String,
+    // 新しいカラムを保存するディレクトリ
+    pub(super) directory_id: String,
+}
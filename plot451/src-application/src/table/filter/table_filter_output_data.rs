@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+use src_domain::{
+    models::table::{
+        table_filter::TableFilter, table_with_columns_and_cells::TableWithColumnsAndCells,
+    },
+    shared::value_object::ValueObject,
+};
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+pub struct TableFilterOutputData {
+    pub(super) table_id: String,
+    pub(super) table_name: String,
+    pub(super) source_table_id: String,
+    pub(super) predicate: String,
+    pub(super) columns: Vec<ColumnInOutputData>,
+}
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+pub(super) struct ColumnInOutputData {
+    pub(super) column_id: String,
+    pub(super) column_name: String,
+    pub(super) cells: Vec<ColumnCellInOutputData>,
+}
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+pub(super) struct ColumnCellInOutputData {
+    pub(super) cell_id: String,
+    pub(super) cell_value: Option<f64>,
+}
+
+impl TableFilterOutputData {
+    pub(super) fn new(filter: &TableFilter, source: TableWithColumnsAndCells) -> Self {
+        Self {
+            table_id: source.id().clone_value(),
+            table_name: source.name().clone_value(),
+            source_table_id: filter.source().clone_value(),
+            predicate: filter.value().clone(),
+            columns: source
+                .columns()
+                .iter()
+                .map(|column| ColumnInOutputData {
+                    column_id: column.id().clone_value(),
+                    column_name: column.name().clone_value(),
+                    cells: column
+                        .cells()
+                        .iter()
+                        .map(|cell| ColumnCellInOutputData {
+                            cell_id: cell.id().clone_value(),
+                            cell_value: cell.cell_value().clone_value(),
+                        })
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+}
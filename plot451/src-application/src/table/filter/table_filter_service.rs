@@ -0,0 +1,62 @@
+use thiserror::Error;
+
+use src_domain::{
+    models::{
+        column::{
+            column_directory::column_directory_id::ColumnDirectoryIdError,
+            column_formula::formula_expression::FormulaExpressionError,
+            column_repository::ColumnRepositoryError,
+        },
+        table::{
+            table_factory::TableFactoryError,
+            table_id::{TableId, TableIdError},
+            table_name::TableNameError,
+            table_repository::TableRepositoryError,
+        },
+    },
+    services::column_creation_service::ColumnCreationServiceError,
+};
+
+use super::{
+    table_filter_command::TableFilterCommand, table_filter_output_data::TableFilterOutputData,
+};
+
+pub type TableFilterServiceResult<T> = anyhow::Result<T, TableFilterServiceError>;
+
+pub trait ITableFilterService {
+    fn handle(
+        &self,
+        command: TableFilterCommand,
+    ) -> impl std::future::Future<Output = TableFilterServiceResult<TableFilterOutputData>> + Send;
+}
+
+#[derive(Debug, Error)]
+pub enum TableFilterServiceError {
+    // repository errors
+    #[error("TableRepositoryError: [{0}]")]
+    TableRepositoryError(TableRepositoryError),
+    #[error("ColumnRepositoryError: [{0}]")]
+    ColumnRepositoryError(ColumnRepositoryError),
+
+    // value object errors
+    #[error("TableIdError: [{0}]")]
+    TableIdError(TableIdError),
+    #[error("TableNameError: [{0}]")]
+    TableNameError(TableNameError),
+    #[error("ColumnDirectoryIdError: [{0}]")]
+    ColumnDirectoryIdError(ColumnDirectoryIdError),
+    #[error("FormulaExpressionError: [{0}]")]
+    FormulaExpressionError(FormulaExpressionError),
+
+    // factory errors
+    #[error("TableFactoryError: [{0}]")]
+    TableFactoryError(TableFactoryError),
+
+    // domain service errors
+    #[error("ColumnCreationServiceError: [{0}]")]
+    ColumnCreationServiceError(ColumnCreationServiceError),
+
+    // not found errors
+    #[error("Table not found, table_id: {0:?}")]
+    TableNotFound(TableId),
+}
@@ -0,0 +1,292 @@
+use std::collections::HashMap;
+
+use src_domain::{
+    models::{
+        column::{
+            column_cell::column_cell_value::CellRawValue,
+            column_directory::column_directory_id::ColumnDirectoryId,
+            column_factory::IColumnFactory, column_id::ColumnId,
+            column_repository::IColumnRepository,
+        },
+        table::{
+            table_factory::ITableFactory, table_filter::TableFilter, table_id::TableId,
+            table_name::TableName, table_repository::ITableRepository,
+            table_with_columns_and_cells::TableWithColumnsAndCells,
+        },
+    },
+    services::{
+        column_creation_service::ColumnCreationService, column_values_service::ColumnValuesService,
+    },
+    shared::value_object::ValueObject,
+};
+
+use super::{
+    table_filter_command::TableFilterCommand,
+    table_filter_output_data::TableFilterOutputData,
+    table_filter_service::{
+        ITableFilterService, TableFilterServiceError, TableFilterServiceResult,
+    },
+};
+
+pub struct TableFilterService<'a, 'b, 'c, 'd, CF, CR, TF, TR>
+where
+    CF: IColumnFactory,
+    CR: IColumnRepository,
+    TF: ITableFactory,
+    TR: ITableRepository,
+{
+    column_factory: &'a CF,
+    column_repository: &'b CR,
+    table_factory: &'c TF,
+    table_repository: &'d TR,
+}
+
+impl<'a, 'b, 'c, 'd, CF, CR, TF, TR> TableFilterService<'a, 'b, 'c, 'd, CF, CR, TF, TR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+    TF: ITableFactory + Sync,
+    TR: ITableRepository + Sync,
+{
+    pub fn new(
+        column_factory: &'a CF,
+        column_repository: &'b CR,
+        table_factory: &'c TF,
+        table_repository: &'d TR,
+    ) -> Self {
+        Self {
+            column_factory,
+            column_repository,
+            table_factory,
+            table_repository,
+        }
+    }
+}
+
+impl<'a, 'b, 'c, 'd, CF, CR, TF, TR> ITableFilterService
+    for TableFilterService<'a, 'b, 'c, 'd, CF, CR, TF, TR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+    TF: ITableFactory + Sync,
+    TR: ITableRepository + Sync,
+{
+    // TODO: トランザクション処理を追加する
+    async fn handle(
+        &self,
+        command: TableFilterCommand,
+    ) -> TableFilterServiceResult<TableFilterOutputData> {
+        let TableFilterCommand {
+            table_id,
+            predicate,
+            table_name,
+            directory_id,
+        } = command;
+
+        // 値オブジェクトのインスタンス化
+        let table_id = TableId::new(table_id).map_err(TableFilterServiceError::TableIdError)?;
+        let table_name =
+            TableName::new(table_name).map_err(TableFilterServiceError::TableNameError)?;
+        let directory_id = ColumnDirectoryId::new(directory_id)
+            .map_err(TableFilterServiceError::ColumnDirectoryIdError)?;
+        let filter = TableFilter::new(table_id.clone(), predicate)
+            .map_err(TableFilterServiceError::FormulaExpressionError)?;
+
+        // 抽出元のテーブルとカラムの値の取得
+        let source = self
+            .table_repository
+            .find(&table_id)
+            .await
+            .map_err(TableFilterServiceError::TableRepositoryError)?
+            .ok_or(TableFilterServiceError::TableNotFound(table_id))?;
+        let columns = self
+            .column_repository
+            .find_by_ids(source.columns())
+            .await
+            .map_err(TableFilterServiceError::ColumnRepositoryError)?;
+        let mut values: HashMap<ColumnId, Vec<CellRawValue>> = HashMap::new();
+        for column in &columns {
+            let column_values = ColumnValuesService::new(self.column_repository)
+                .find_values(column)
+                .await
+                .map_err(TableFilterServiceError::ColumnRepositoryError)?;
+            values.insert(column.id().clone(), column_values);
+        }
+
+        // 条件式のカラム名を抽出元のテーブルのカラムで解決して行ごとに判定する
+        // (長さの足りないカラムの行は None として扱う)
+        let row_count = values.values().map(Vec::len).max().unwrap_or(0);
+        let selected = filter
+            .predicate()
+            .resolve(&columns)
+            .and_then(|predicate| predicate.evaluate(&values, row_count))
+            .map_err(TableFilterServiceError::FormulaExpressionError)?;
+
+        // 条件に合う行の値で新しいカラムを作成する
+        let column_creation_service =
+            ColumnCreationService::new(self.column_factory, self.column_repository);
+        let mut column_ids = vec![];
+        let mut columns_with_cells = vec![];
+        for column in &columns {
+            let column_values = &values[column.id()];
+            let filtered = selected
+                .iter()
+                .enumerate()
+                .filter(|(_, &selected)| selected)
+                .map(|(row, _)| column_values.get(row).copied().flatten())
+                .collect();
+            let (new_column, column_with_cells) = column_creation_service
+                .create_column(column.name().clone(), directory_id.clone(), filtered)
+                .await
+                .map_err(TableFilterServiceError::ColumnCreationServiceError)?;
+            column_ids.push(new_column.id().clone());
+            columns_with_cells.push(column_with_cells);
+        }
+
+        // 生成元を記録したテーブルの作成と永続化
+        let mut table = self
+            .table_factory
+            .create_table(table_name, column_ids)
+            .await
+            .map_err(TableFilterServiceError::TableFactoryError)?;
+        table.change_filter(Some(filter.clone()));
+        let new_table_id = self
+            .table_repository
+            .save(&table)
+            .await
+            .map_err(TableFilterServiceError::TableRepositoryError)?;
+        table.set_id(new_table_id);
+
+        let table_with_columns_and_cells =
+            TableWithColumnsAndCells::new(&table, columns_with_cells);
+        Ok(TableFilterOutputData::new(
+            &filter,
+            table_with_columns_and_cells,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use src_domain::models::column::column_formula::formula_expression::FormulaExpressionError;
+    use src_in_memory_infrastructure::{
+        column::{
+            in_memory_column_factory::InMemoryColumnFactory,
+            in_memory_column_repository::InMemoryColumnRepository,
+        },
+        table::{
+            in_memory_table_factory::InMemoryTableFactory,
+            in_memory_table_repository::InMemoryTableRepository,
+        },
+    };
+
+    use crate::test_utils::save_table;
+
+    use super::*;
+
+    // T: [310, 290, 320, 305], flag: [1, 1, 0] のテーブルを作成する
+    async fn prepare(
+        column_repository: &InMemoryColumnRepository,
+        table_repository: &InMemoryTableRepository,
+    ) -> anyhow::Result<TableId> {
+        let columns = vec![
+            ("T", vec![Some(310.), Some(290.), Some(320.), Some(305.)]),
+            ("flag", vec![Some(1.), Some(1.), Some(0.)]),
+        ];
+        let (table_id, _) =
+            save_table(column_repository, table_repository, "source", columns).await?;
+        Ok(table_id)
+    }
+
+    #[tokio::test]
+    async fn test_handle() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let table_factory = InMemoryTableFactory::new();
+        let table_repository = InMemoryTableRepository::new();
+        let source_id = prepare(&column_repository, &table_repository).await?;
+
+        let service = TableFilterService::new(
+            &column_factory,
+            &column_repository,
+            &table_factory,
+            &table_repository,
+        );
+        let command = TableFilterCommand {
+            table_id: source_id.clone_value(),
+            predicate: "col(\"T\") > 300 || col(\"flag\") == 0".to_string(),
+            table_name: "hot".to_string(),
+            directory_id: "7".to_string(),
+        };
+        let output_data = service.handle(command).await?;
+        assert_eq!(output_data.table_name, "hot");
+        assert_eq!(output_data.source_table_id, source_id.clone_value());
+        let values: Vec<(String, Vec<Option<f64>>)> = output_data
+            .columns
+            .iter()
+            .map(|column| {
+                (
+                    column.column_name.clone(),
+                    column.cells.iter().map(|cell| cell.cell_value).collect(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            values,
+            vec![
+                ("T".to_string(), vec![Some(310.), Some(320.), Some(305.)]),
+                ("flag".to_string(), vec![Some(1.), Some(0.), None]),
+            ]
+        );
+
+        // 新しいカラムは指定したディレクトリに作成され、テーブルに生成元が記録されている
+        let table = table_repository
+            .find(&TableId::new(output_data.table_id)?)
+            .await?
+            .unwrap();
+        let filter = table.filter().as_ref().unwrap();
+        assert_eq!(filter.source(), &source_id);
+        assert_eq!(filter.value(), "col(\"T\") > 300 || col(\"flag\") == 0");
+        for column in column_repository.find_by_ids(table.columns()).await? {
+            assert_eq!(column.directory_id().value(), "7");
+        }
+        assert_eq!(column_repository.find_all().await?.len(), 4);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handle_errors() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let table_factory = InMemoryTableFactory::new();
+        let table_repository = InMemoryTableRepository::new();
+        let source_id = prepare(&column_repository, &table_repository).await?;
+
+        let service = TableFilterService::new(
+            &column_factory,
+            &column_repository,
+            &table_factory,
+            &table_repository,
+        );
+        let command = |predicate: &str| TableFilterCommand {
+            table_id: source_id.clone_value(),
+            predicate: predicate.to_string(),
+            table_name: "hot".to_string(),
+            directory_id: "7".to_string(),
+        };
+        assert!(matches!(
+            service.handle(command("col(\"T\") >")).await,
+            Err(TableFilterServiceError::FormulaExpressionError(
+                FormulaExpressionError::UnexpectedEnd
+            ))
+        ));
+        assert!(matches!(
+            service.handle(command("col(\"pressure\") > 1")).await,
+            Err(TableFilterServiceError::FormulaExpressionError(
+                FormulaExpressionError::UnresolvedColumnName(_)
+            ))
+        ));
+        assert_eq!(table_repository.find_all().await?.len(), 1);
+        Ok(())
+    }
+}
@@ -0,0 +1,10 @@
+/* グループ化による集計・ピボットテーブル作成用アプリケーションサービス */
+// コマンドオブジェクト
+pub mod table_group_by_command;
+
+// アプリケーションサービス
+pub mod table_group_by_service;
+pub mod table_group_by_service_impl;
+
+// DTO
+pub mod table_group_by_output_data;
@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+
+use src_domain::services::group_by::Aggregation;
+
+#[derive(Deserialize, Serialize)]
+pub struct TableGroupByCommand {
+    // 集計元のテーブル
+    pub(super) table_id: String,
+    // 省略した場合はすべての行を 1 つのグループにする
+    #[serde(default)]
+    pub(super) key_column_ids: Vec<String>,
+    pub(super) mode: GroupByModeInCommand,
+    pub(super) table_name: String,
+    // 新しいカラムを保存するディレクトリ
+    pub(super) directory_id: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum GroupByModeInCommand {
+    // キーカラムと、指定したカラムの集計値のカラムを作成する
+    Aggregate {
+        aggregations: Vec<AggregationSpecInCommand>,
+    },
+    // キーカラムと、ピボットカラムの値ごとの集計値のカラムを作成する
+    Pivot {
+        pivot_column_id: String,
+        value_column_id: String,
+        aggregation: AggregationInCommand,
+    },
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct AggregationSpecInCommand {
+    pub(super) column_id: String,
+    pub(super) aggregation: AggregationInCommand,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AggregationInCommand {
+    Count,
+    Sum,
+    Mean,
+    StandardDeviation,
+    Min,
+    Max,
+    First,
+    Last,
+}
+
+impl AggregationInCommand {
+    pub(super) fn to_aggregation(self) -> Aggregation {
+        match self {
+            AggregationInCommand::Count => Aggregation::Count,
+            AggregationInCommand::Sum => Aggregation::Sum,
+            AggregationInCommand::Mean => Aggregation::Mean,
+            AggregationInCommand::StandardDeviation => Aggregation::StandardDeviation,
+            AggregationInCommand::Min => Aggregation::Min,
+            AggregationInCommand::Max => Aggregation::Max,
+            AggregationInCommand::First => Aggregation::First,
+            AggregationInCommand::Last => Aggregation::Last,
+        }
+    }
+}
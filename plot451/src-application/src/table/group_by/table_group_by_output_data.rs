@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+
+use src_domain::{
+    models::table::{table_id::TableId, table_with_columns_and_cells::TableWithColumnsAndCells},
+    shared::value_object::ValueObject,
+};
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+pub struct TableGroupByOutputData {
+    pub(super) table_id: String,
+    pub(super) table_name: String,
+    pub(super) source_table_id: String,
+    // グループの数 (作成したテーブルの行数)
+    pub(super) group_count: usize,
+    pub(super) columns: Vec<ColumnInOutputData>,
+}
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+pub(super) struct ColumnInOutputData {
+    pub(super) column_id: String,
+    pub(super) column_name: String,
+    pub(super) cells: Vec<ColumnCellInOutputData>,
+}
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+pub(super) struct ColumnCellInOutputData {
+    pub(super) cell_id: String,
+    pub(super) cell_value: Option<f64>,
+}
+
+impl TableGroupByOutputData {
+    pub(super) fn new(
+        source_table_id: &TableId,
+        group_count: usize,
+        table: TableWithColumnsAndCells,
+    ) -> Self {
+        Self {
+            table_id: table.id().clone_value(),
+            table_name: table.name().clone_value(),
+            source_table_id: source_table_id.clone_value(),
+            group_count,
+            columns: table
+                .columns()
+                .iter()
+                .map(|column| ColumnInOutputData {
+                    column_id: column.id().clone_value(),
+                    column_name: column.name().clone_value(),
+                    cells: column
+                        .cells()
+                        .iter()
+                        .map(|cell| ColumnCellInOutputData {
+                            cell_id: cell.id().clone_value(),
+                            cell_value: cell.cell_value().clone_value(),
+                        })
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+}
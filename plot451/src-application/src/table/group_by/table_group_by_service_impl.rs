@@ -0,0 +1,440 @@
+use std::collections::HashMap;
+
+use src_domain::{
+    models::{
+        column::{
+            column::Column, column_cell::column_cell_value::CellRawValue,
+            column_directory::column_directory_id::ColumnDirectoryId,
+            column_factory::IColumnFactory, column_id::ColumnId, column_name::ColumnName,
+            column_repository::IColumnRepository,
+        },
+        table::{
+            no_duplicated_column_names_specification::NoDuplicatedColumnNamesInListSpecification,
+            table_factory::ITableFactory, table_id::TableId, table_name::TableName,
+            table_repository::ITableRepository,
+            table_with_columns_and_cells::TableWithColumnsAndCells,
+        },
+    },
+    services::{
+        column_creation_service::ColumnCreationService,
+        column_values_service::ColumnValuesService,
+        group_by::{PivotTable, RowGroups},
+    },
+    shared::{specification::Specification, value_object::ValueObject},
+};
+
+use super::{
+    table_group_by_command::{GroupByModeInCommand, TableGroupByCommand},
+    table_group_by_output_data::TableGroupByOutputData,
+    table_group_by_service::{
+        ITableGroupByService, TableGroupByServiceError, TableGroupByServiceResult,
+    },
+};
+
+pub struct TableGroupByService<'a, 'b, 'c, 'd, CF, CR, TF, TR>
+where
+    CF: IColumnFactory,
+    CR: IColumnRepository,
+    TF: ITableFactory,
+    TR: ITableRepository,
+{
+    column_factory: &'a CF,
+    column_repository: &'b CR,
+    table_factory: &'c TF,
+    table_repository: &'d TR,
+}
+
+impl<'a, 'b, 'c, 'd, CF, CR, TF, TR> TableGroupByService<'a, 'b, 'c, 'd, CF, CR, TF, TR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+    TF: ITableFactory + Sync,
+    TR: ITableRepository + Sync,
+{
+    pub fn new(
+        column_factory: &'a CF,
+        column_repository: &'b CR,
+        table_factory: &'c TF,
+        table_repository: &'d TR,
+    ) -> Self {
+        Self {
+            column_factory,
+            column_repository,
+            table_factory,
+            table_repository,
+        }
+    }
+}
+
+// 集計元のテーブルのカラムとその値
+struct SourceColumns {
+    columns: Vec<Column>,
+    values: HashMap<ColumnId, Vec<CellRawValue>>,
+}
+
+impl SourceColumns {
+    // id に対応するテーブル内のカラム
+    fn find(&self, column_id: String) -> TableGroupByServiceResult<&Column> {
+        let column_id =
+            ColumnId::new(column_id).map_err(TableGroupByServiceError::ColumnIdError)?;
+        self.columns
+            .iter()
+            .find(|column| column.id() == &column_id)
+            .ok_or(TableGroupByServiceError::ColumnNotFound(column_id))
+    }
+
+    fn values(&self, column: &Column) -> &Vec<CellRawValue> {
+        &self.values[column.id()]
+    }
+
+    fn row_count(&self) -> usize {
+        self.values.values().map(Vec::len).max().unwrap_or(0)
+    }
+}
+
+fn column_name(name: String) -> TableGroupByServiceResult<ColumnName> {
+    ColumnName::new(name).map_err(TableGroupByServiceError::ColumnNameError)
+}
+
+impl<'a, 'b, 'c, 'd, CF, CR, TF, TR> ITableGroupByService
+    for TableGroupByService<'a, 'b, 'c, 'd, CF, CR, TF, TR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+    TF: ITableFactory + Sync,
+    TR: ITableRepository + Sync,
+{
+    // TODO: トランザクション処理を追加する
+    async fn handle(
+        &self,
+        command: TableGroupByCommand,
+    ) -> TableGroupByServiceResult<TableGroupByOutputData> {
+        let TableGroupByCommand {
+            table_id,
+            key_column_ids,
+            mode,
+            table_name,
+            directory_id,
+        } = command;
+
+        // 値オブジェクトのインスタンス化
+        let table_id = TableId::new(table_id).map_err(TableGroupByServiceError::TableIdError)?;
+        let table_name =
+            TableName::new(table_name).map_err(TableGroupByServiceError::TableNameError)?;
+        let directory_id = ColumnDirectoryId::new(directory_id)
+            .map_err(TableGroupByServiceError::ColumnDirectoryIdError)?;
+
+        // 集計元のテーブルとカラムの値の取得
+        let source = self
+            .table_repository
+            .find(&table_id)
+            .await
+            .map_err(TableGroupByServiceError::TableRepositoryError)?
+            .ok_or(TableGroupByServiceError::TableNotFound(table_id.clone()))?;
+        let columns = self
+            .column_repository
+            .find_by_ids(source.columns())
+            .await
+            .map_err(TableGroupByServiceError::ColumnRepositoryError)?;
+        let mut values = HashMap::new();
+        for column in &columns {
+            let column_values: Vec<CellRawValue> = ColumnValuesService::new(self.column_repository)
+                .find_values(column)
+                .await
+                .map_err(TableGroupByServiceError::ColumnRepositoryError)?;
+            values.insert(column.id().clone(), column_values);
+        }
+        let source_columns = SourceColumns { columns, values };
+        let row_count = source_columns.row_count();
+
+        // キーカラム (長さの足りないカラムの行は None として扱う)
+        let key_columns = key_column_ids
+            .into_iter()
+            .map(|column_id| source_columns.find(column_id))
+            .collect::<TableGroupByServiceResult<Vec<&Column>>>()?;
+        let key_values: Vec<Vec<CellRawValue>> = key_columns
+            .iter()
+            .map(|column| source_columns.values(column).clone())
+            .collect();
+
+        // 作成するカラムの名前と値 (キーカラム → 集計値の順)
+        let (groups, aggregated): (RowGroups, Vec<(ColumnName, Vec<CellRawValue>)>) = match mode {
+            GroupByModeInCommand::Aggregate { aggregations } => {
+                let groups = RowGroups::new(&key_values, row_count);
+                let mut aggregated = vec![];
+                for spec in aggregations {
+                    let column = source_columns.find(spec.column_id)?;
+                    let aggregation = spec.aggregation.to_aggregation();
+                    aggregated.push((
+                        column_name(format!("{} {}", column.name(), aggregation))?,
+                        groups.aggregate(source_columns.values(column), aggregation),
+                    ));
+                }
+                (groups, aggregated)
+            }
+            GroupByModeInCommand::Pivot {
+                pivot_column_id,
+                value_column_id,
+                aggregation,
+            } => {
+                let pivot_column = source_columns.find(pivot_column_id)?;
+                let value_column = source_columns.find(value_column_id)?;
+                let pivot = PivotTable::new(
+                    &key_values,
+                    source_columns.values(pivot_column),
+                    source_columns.values(value_column),
+                    aggregation.to_aggregation(),
+                    row_count,
+                );
+                let mut aggregated = vec![];
+                for (pivot_value, values) in pivot.pivot_values().iter().zip(pivot.columns()) {
+                    aggregated.push((
+                        column_name(format!(
+                            "{} {}={}",
+                            value_column.name(),
+                            pivot_column.name(),
+                            pivot_value
+                        ))?,
+                        values.clone(),
+                    ));
+                }
+                (pivot.groups().clone(), aggregated)
+            }
+        };
+        let mut new_columns = vec![];
+        for (index, column) in key_columns.iter().enumerate() {
+            new_columns.push((column.name().clone(), groups.key_column(index)));
+        }
+        new_columns.extend(aggregated);
+
+        // カラム名の重複チェック (カラムを作成する前に行う)
+        let names = new_columns.iter().map(|(name, _)| name.clone()).collect();
+        NoDuplicatedColumnNamesInListSpecification::new()
+            .is_satisfied_by(&names)
+            .map_err(TableGroupByServiceError::DuplicatedColumnNameError)?;
+
+        // 集計結果のカラムの作成
+        let column_creation_service =
+            ColumnCreationService::new(self.column_factory, self.column_repository);
+        let mut columns = vec![];
+        let mut columns_with_cells = vec![];
+        for (name, values) in new_columns {
+            let (new_column, column_with_cells) = column_creation_service
+                .create_column(name, directory_id.clone(), values)
+                .await
+                .map_err(TableGroupByServiceError::ColumnCreationServiceError)?;
+            columns.push(new_column);
+            columns_with_cells.push(column_with_cells);
+        }
+
+        // 結果のテーブルの作成
+        let column_ids = columns.iter().map(|column| column.id().clone()).collect();
+        let mut table = self
+            .table_factory
+            .create_table(table_name, column_ids)
+            .await
+            .map_err(TableGroupByServiceError::TableFactoryError)?;
+
+        // テーブルの永続化
+        let new_table_id = self
+            .table_repository
+            .save(&table)
+            .await
+            .map_err(TableGroupByServiceError::TableRepositoryError)?;
+        table.set_id(new_table_id);
+
+        Ok(TableGroupByOutputData::new(
+            &table_id,
+            groups.len(),
+            TableWithColumnsAndCells::new(&table, columns_with_cells),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use src_in_memory_infrastructure::{
+        column::{
+            in_memory_column_factory::InMemoryColumnFactory,
+            in_memory_column_repository::InMemoryColumnRepository,
+        },
+        table::{
+            in_memory_table_factory::InMemoryTableFactory,
+            in_memory_table_repository::InMemoryTableRepository,
+        },
+    };
+
+    use crate::{
+        table::group_by::table_group_by_command::{AggregationInCommand, AggregationSpecInCommand},
+        test_utils::save_table,
+    };
+
+    use super::*;
+
+    // sample: [1, 2, 1, 2, 1], T: [300, 300, 310, 310, 300], value: [1, 2, 3, 4, 5]
+    // のテーブルを作成し、テーブルとカラムの id を返す
+    async fn prepare(
+        column_repository: &InMemoryColumnRepository,
+        table_repository: &InMemoryTableRepository,
+    ) -> anyhow::Result<(TableId, Vec<ColumnId>)> {
+        let columns = vec![
+            (
+                "sample",
+                vec![Some(1.), Some(2.), Some(1.), Some(2.), Some(1.)],
+            ),
+            (
+                "T",
+                vec![Some(300.), Some(300.), Some(310.), Some(310.), Some(300.)],
+            ),
+            (
+                "value",
+                vec![Some(1.), Some(2.), Some(3.), Some(4.), Some(5.)],
+            ),
+        ];
+        save_table(column_repository, table_repository, "source", columns).await
+    }
+
+    fn column_values(output_data: &TableGroupByOutputData) -> Vec<(String, Vec<Option<f64>>)> {
+        output_data
+            .columns
+            .iter()
+            .map(|column| {
+                (
+                    column.column_name.clone(),
+                    column.cells.iter().map(|cell| cell.cell_value).collect(),
+                )
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_handle_aggregate() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let table_factory = InMemoryTableFactory::new();
+        let table_repository = InMemoryTableRepository::new();
+        let (source_id, column_ids) = prepare(&column_repository, &table_repository).await?;
+
+        let service = TableGroupByService::new(
+            &column_factory,
+            &column_repository,
+            &table_factory,
+            &table_repository,
+        );
+        let aggregation = |aggregation| AggregationSpecInCommand {
+            column_id: column_ids[2].clone_value(),
+            aggregation,
+        };
+        let command = TableGroupByCommand {
+            table_id: source_id.clone_value(),
+            key_column_ids: vec![column_ids[0].clone_value()],
+            mode: GroupByModeInCommand::Aggregate {
+                aggregations: vec![
+                    aggregation(AggregationInCommand::Count),
+                    aggregation(AggregationInCommand::Mean),
+                    aggregation(AggregationInCommand::Max),
+                ],
+            },
+            table_name: "by sample".to_string(),
+            directory_id: "2".to_string(),
+        };
+        let output_data = service.handle(command).await?;
+        assert_eq!(output_data.table_name, "by sample");
+        assert_eq!(output_data.source_table_id, source_id.clone_value());
+        assert_eq!(output_data.group_count, 2);
+        assert_eq!(
+            column_values(&output_data),
+            vec![
+                ("sample".to_string(), vec![Some(1.), Some(2.)]),
+                ("value count".to_string(), vec![Some(3.), Some(2.)]),
+                ("value mean".to_string(), vec![Some(3.), Some(3.)]),
+                ("value max".to_string(), vec![Some(5.), Some(4.)]),
+            ]
+        );
+        let table = table_repository
+            .find(&TableId::new(output_data.table_id)?)
+            .await?
+            .unwrap();
+        for column in column_repository.find_by_ids(table.columns()).await? {
+            assert_eq!(column.directory_id().value(), "2");
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handle_pivot() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let table_factory = InMemoryTableFactory::new();
+        let table_repository = InMemoryTableRepository::new();
+        let (source_id, column_ids) = prepare(&column_repository, &table_repository).await?;
+
+        let service = TableGroupByService::new(
+            &column_factory,
+            &column_repository,
+            &table_factory,
+            &table_repository,
+        );
+        let command = TableGroupByCommand {
+            table_id: source_id.clone_value(),
+            key_column_ids: vec![column_ids[0].clone_value()],
+            mode: GroupByModeInCommand::Pivot {
+                pivot_column_id: column_ids[1].clone_value(),
+                value_column_id: column_ids[2].clone_value(),
+                aggregation: AggregationInCommand::Sum,
+            },
+            table_name: "pivot".to_string(),
+            directory_id: "2".to_string(),
+        };
+        let output_data = service.handle(command).await?;
+        assert_eq!(
+            column_values(&output_data),
+            vec![
+                ("sample".to_string(), vec![Some(1.), Some(2.)]),
+                ("value T=300".to_string(), vec![Some(6.), Some(2.)]),
+                ("value T=310".to_string(), vec![Some(3.), Some(4.)]),
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handle_errors() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let table_factory = InMemoryTableFactory::new();
+        let table_repository = InMemoryTableRepository::new();
+        let (source_id, column_ids) = prepare(&column_repository, &table_repository).await?;
+
+        let service = TableGroupByService::new(
+            &column_factory,
+            &column_repository,
+            &table_factory,
+            &table_repository,
+        );
+        let command = |aggregations: Vec<AggregationSpecInCommand>| TableGroupByCommand {
+            table_id: source_id.clone_value(),
+            key_column_ids: vec![column_ids[0].clone_value()],
+            mode: GroupByModeInCommand::Aggregate { aggregations },
+            table_name: "by sample".to_string(),
+            directory_id: "2".to_string(),
+        };
+        let spec = |column_id: &str| AggregationSpecInCommand {
+            column_id: column_id.to_string(),
+            aggregation: AggregationInCommand::Sum,
+        };
+        assert!(matches!(
+            service.handle(command(vec![spec("100")])).await,
+            Err(TableGroupByServiceError::ColumnNotFound(_))
+        ));
+        let value = column_ids[2].clone_value();
+        assert!(matches!(
+            service
+                .handle(command(vec![spec(&value), spec(&value)]))
+                .await,
+            Err(TableGroupByServiceError::DuplicatedColumnNameError(_))
+        ));
+        assert_eq!(column_repository.find_all().await?.len(), 3);
+        Ok(())
+    }
+}
@@ -0,0 +1,10 @@
+/* テーブルの結合用アプリケーションサービス */
+// コマンドオブジェクト
+pub mod table_join_command;
+
+// アプリケーションサービス
+pub mod table_join_service;
+pub mod table_join_service_impl;
+
+// DTO
+pub mod table_join_output_data;
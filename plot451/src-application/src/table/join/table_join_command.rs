@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+use src_domain::services::table_join::JoinKind;
+
+#[derive(Deserialize, Serialize)]
+pub struct TableJoinCommand {
+    pub(super) left_table_id: String,
+    pub(super) right_table_id: String,
+    // 左右で同じ順に対応させるキーカラム
+    pub(super) left_key_column_ids: Vec<String>,
+    pub(super) right_key_column_ids: Vec<String>,
+    pub(super) kind: JoinKindInCommand,
+    pub(super) table_name: String,
+    // 新しいカラムを保存するディレクトリ
+    pub(super) directory_id: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum JoinKindInCommand {
+    Inner,
+    Left,
+    Outer,
+    Nearest { tolerance: f64 },
+}
+
+impl JoinKindInCommand {
+    pub(super) fn to_join_kind(self) -> JoinKind {
+        match self {
+            JoinKindInCommand::Inner => JoinKind::Inner,
+            JoinKindInCommand::Left => JoinKind::Left,
+            JoinKindInCommand::Outer => JoinKind::Outer,
+            JoinKindInCommand::Nearest { tolerance } => JoinKind::Nearest { tolerance },
+        }
+    }
+}
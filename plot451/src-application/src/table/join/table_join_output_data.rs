@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+use src_domain::{
+    models::table::{table_id::TableId, table_with_columns_and_cells::TableWithColumnsAndCells},
+    shared::value_object::ValueObject,
+};
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+pub struct TableJoinOutputData {
+    pub(super) table_id: String,
+    pub(super) table_name: String,
+    pub(super) left_table_id: String,
+    pub(super) right_table_id: String,
+    pub(super) row_count: usize,
+    pub(super) columns: Vec<ColumnInOutputData>,
+}
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+pub(super) struct ColumnInOutputData {
+    pub(super) column_id: String,
+    pub(super) column_name: String,
+    pub(super) cells: Vec<ColumnCellInOutputData>,
+}
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+pub(super) struct ColumnCellInOutputData {
+    pub(super) cell_id: String,
+    pub(super) cell_value: Option<f64>,
+}
+
+impl TableJoinOutputData {
+    pub(super) fn new(
+        left_table_id: &TableId,
+        right_table_id: &TableId,
+        row_count: usize,
+        table: TableWithColumnsAndCells,
+    ) -> Self {
+        Self {
+            table_id: table.id().clone_value(),
+            table_name: table.name().clone_value(),
+            left_table_id: left_table_id.clone_value(),
+            right_table_id: right_table_id.clone_value(),
+            row_count,
+            columns: table
+                .columns()
+                .iter()
+                .map(|column| ColumnInOutputData {
+                    column_id: column.id().clone_value(),
+                    column_name: column.name().clone_value(),
+                    cells: column
+                        .cells()
+                        .iter()
+                        .map(|cell| ColumnCellInOutputData {
+                            cell_id: cell.id().clone_value(),
+                            cell_value: cell.cell_value().clone_value(),
+                        })
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use thiserror::Error;
+
+use src_domain::{
+    models::{
+        column::{
+            column_directory::column_directory_id::ColumnDirectoryIdError,
+            column_id::{ColumnId, ColumnIdError},
+            column_name::ColumnNameError,
+            column_repository::ColumnRepositoryError,
+        },
+        table::{
+            no_duplicated_column_names_specification::NoDuplicateColumnNameSpecificationError,
+            table_factory::TableFactoryError,
+            table_id::{TableId, TableIdError},
+            table_name::TableNameError,
+            table_repository::TableRepositoryError,
+        },
+    },
+    services::{column_creation_service::ColumnCreationServiceError, table_join::TableJoinError},
+};
+
+use super::{table_join_command::TableJoinCommand, table_join_output_data::TableJoinOutputData};
+
+pub type TableJoinServiceResult<T> = anyhow::Result<T, TableJoinServiceError>;
+
+pub trait ITableJoinService {
+    fn handle(
+        &self,
+        command: TableJoinCommand,
+    ) -> impl std::future::Future<Output = TableJoinServiceResult<TableJoinOutputData>> + Send;
+}
+
+#[derive(Debug, Error)]
+pub enum TableJoinServiceError {
+    // repository errors
+    #[error("TableRepositoryError: [{0}]")]
+    TableRepositoryError(TableRepositoryError),
+    #[error("ColumnRepositoryError: [{0}]")]
+    ColumnRepositoryError(ColumnRepositoryError),
+
+    // value object errors
+    #[error("TableIdError: [{0}]")]
+    TableIdError(TableIdError),
+    #[error("TableNameError: [{0}]")]
+    TableNameError(TableNameError),
+    #[error("ColumnIdError: [{0}]")]
+    ColumnIdError(ColumnIdError),
+    #[error("ColumnNameError: [{0}]")]
+    ColumnNameError(ColumnNameError),
+    #[error("ColumnDirectoryIdError: [{0}]")]
+    ColumnDirectoryIdError(ColumnDirectoryIdError),
+
+    // first class collection errors
+    #[error("DuplicatedColumnNameError: [{0}]")]
+    DuplicatedColumnNameError(NoDuplicateColumnNameSpecificationError),
+
+    // factory errors
+    #[error("TableFactoryError: [{0}]")]
+    TableFactoryError(TableFactoryError),
+
+    // domain service errors
+    #[error("TableJoinError: [{0}]")]
+    TableJoinError(TableJoinError),
+    #[error("ColumnCreationServiceError: [{0}]")]
+    ColumnCreationServiceError(ColumnCreationServiceError),
+
+    // not found errors
+    #[error("Table not found, table_id: {0:?}")]
+    TableNotFound(TableId),
+    #[error("Column not found in the table, column_id: {0:?}")]
+    ColumnNotFound(ColumnId),
+}
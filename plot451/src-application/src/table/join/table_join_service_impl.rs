@@ -0,0 +1,450 @@
+use src_domain::{
+    models::{
+        column::{
+            column::Column, column_cell::column_cell_value::CellRawValue,
+            column_directory::column_directory_id::ColumnDirectoryId,
+            column_factory::IColumnFactory, column_id::ColumnId, column_name::ColumnName,
+            column_repository::IColumnRepository,
+        },
+        table::{
+            no_duplicated_column_names_specification::NoDuplicatedColumnNamesInListSpecification,
+            table::Table, table_factory::ITableFactory, table_id::TableId, table_name::TableName,
+            table_repository::ITableRepository,
+            table_with_columns_and_cells::TableWithColumnsAndCells,
+        },
+    },
+    services::{
+        column_creation_service::ColumnCreationService,
+        column_values_service::ColumnValuesService,
+        table_join::{join_rows, resolve_name_clashes, JoinKind},
+    },
+    shared::{specification::Specification, value_object::ValueObject},
+};
+
+use super::{
+    table_join_command::TableJoinCommand,
+    table_join_output_data::TableJoinOutputData,
+    table_join_service::{ITableJoinService, TableJoinServiceError, TableJoinServiceResult},
+};
+
+pub struct TableJoinService<'a, 'b, 'c, 'd, CF, CR, TF, TR>
+where
+    CF: IColumnFactory,
+    CR: IColumnRepository,
+    TF: ITableFactory,
+    TR: ITableRepository,
+{
+    column_factory: &'a CF,
+    column_repository: &'b CR,
+    table_factory: &'c TF,
+    table_repository: &'d TR,
+}
+
+// 結合するテーブルのカラムとその値 (テーブル内の順序)
+struct JoinSource {
+    table: Table,
+    columns: Vec<Column>,
+    values: Vec<Vec<CellRawValue>>,
+}
+
+impl JoinSource {
+    fn row_count(&self) -> usize {
+        self.values.iter().map(Vec::len).max().unwrap_or(0)
+    }
+
+    // キーカラムのテーブル内での位置
+    fn key_indices(&self, column_ids: Vec<String>) -> TableJoinServiceResult<Vec<usize>> {
+        column_ids
+            .into_iter()
+            .map(|column_id| {
+                let column_id =
+                    ColumnId::new(column_id).map_err(TableJoinServiceError::ColumnIdError)?;
+                self.columns
+                    .iter()
+                    .position(|column| column.id() == &column_id)
+                    .ok_or(TableJoinServiceError::ColumnNotFound(column_id))
+            })
+            .collect()
+    }
+
+    // 結合後の行ごとの値 (対応する行がなければ None)
+    fn joined_values(&self, index: usize, rows: &[Option<usize>]) -> Vec<CellRawValue> {
+        rows.iter()
+            .map(|row| row.and_then(|row| self.values[index].get(row).copied().flatten()))
+            .collect()
+    }
+}
+
+impl<'a, 'b, 'c, 'd, CF, CR, TF, TR> TableJoinService<'a, 'b, 'c, 'd, CF, CR, TF, TR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+    TF: ITableFactory + Sync,
+    TR: ITableRepository + Sync,
+{
+    pub fn new(
+        column_factory: &'a CF,
+        column_repository: &'b CR,
+        table_factory: &'c TF,
+        table_repository: &'d TR,
+    ) -> Self {
+        Self {
+            column_factory,
+            column_repository,
+            table_factory,
+            table_repository,
+        }
+    }
+
+    async fn find_source(&self, table_id: &TableId) -> TableJoinServiceResult<JoinSource> {
+        let table = self
+            .table_repository
+            .find(table_id)
+            .await
+            .map_err(TableJoinServiceError::TableRepositoryError)?
+            .ok_or(TableJoinServiceError::TableNotFound(table_id.clone()))?;
+        let columns = self
+            .column_repository
+            .find_by_ids(table.columns())
+            .await
+            .map_err(TableJoinServiceError::ColumnRepositoryError)?;
+        let mut values = vec![];
+        for column in &columns {
+            let column_values = ColumnValuesService::new(self.column_repository)
+                .find_values(column)
+                .await
+                .map_err(TableJoinServiceError::ColumnRepositoryError)?;
+            values.push(column_values);
+        }
+        Ok(JoinSource {
+            table,
+            columns,
+            values,
+        })
+    }
+}
+
+impl<'a, 'b, 'c, 'd, CF, CR, TF, TR> ITableJoinService
+    for TableJoinService<'a, 'b, 'c, 'd, CF, CR, TF, TR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+    TF: ITableFactory + Sync,
+    TR: ITableRepository + Sync,
+{
+    // TODO: トランザクション処理を追加する
+    async fn handle(
+        &self,
+        command: TableJoinCommand,
+    ) -> TableJoinServiceResult<TableJoinOutputData> {
+        let TableJoinCommand {
+            left_table_id,
+            right_table_id,
+            left_key_column_ids,
+            right_key_column_ids,
+            kind,
+            table_name,
+            directory_id,
+        } = command;
+
+        // 値オブジェクトのインスタンス化
+        let left_table_id =
+            TableId::new(left_table_id).map_err(TableJoinServiceError::TableIdError)?;
+        let right_table_id =
+            TableId::new(right_table_id).map_err(TableJoinServiceError::TableIdError)?;
+        let table_name =
+            TableName::new(table_name).map_err(TableJoinServiceError::TableNameError)?;
+        let directory_id = ColumnDirectoryId::new(directory_id)
+            .map_err(TableJoinServiceError::ColumnDirectoryIdError)?;
+        let kind = kind.to_join_kind();
+
+        // 左右のテーブルの取得と行の対応付け
+        let left = self.find_source(&left_table_id).await?;
+        let right = self.find_source(&right_table_id).await?;
+        let left_keys = left.key_indices(left_key_column_ids)?;
+        let right_keys = right.key_indices(right_key_column_ids)?;
+        let key_values = |source: &JoinSource, keys: &[usize]| -> Vec<Vec<CellRawValue>> {
+            keys.iter()
+                .map(|index| source.values[*index].clone())
+                .collect()
+        };
+        let joined = join_rows(
+            &key_values(&left, &left_keys),
+            &key_values(&right, &right_keys),
+            left.row_count(),
+            right.row_count(),
+            kind,
+        )
+        .map_err(TableJoinServiceError::TableJoinError)?;
+        let (left_rows, right_rows): (Vec<Option<usize>>, Vec<Option<usize>>) =
+            joined.into_iter().unzip();
+
+        // 作成するカラムの名前と値
+        // 完全一致の結合ではキーカラムを 1 つにまとめて先頭に置き (右だけの行は右の値)、
+        // 最も近い行との結合では左右のキーカラムをそのまま残す
+        let mut left_columns: Vec<(String, Vec<CellRawValue>)> = vec![];
+        let merge_keys = !matches!(kind, JoinKind::Nearest { .. });
+        if merge_keys {
+            for (left_index, right_index) in left_keys.iter().zip(&right_keys) {
+                let right_values = right.joined_values(*right_index, &right_rows);
+                let values = left
+                    .joined_values(*left_index, &left_rows)
+                    .into_iter()
+                    .zip(right_values)
+                    .zip(&left_rows)
+                    .map(|((left_value, right_value), left_row)| match left_row {
+                        Some(_) => left_value,
+                        None => right_value,
+                    })
+                    .collect();
+                left_columns.push((left.columns[*left_index].name().clone_value(), values));
+            }
+        }
+        for (index, column) in left.columns.iter().enumerate() {
+            if !merge_keys || !left_keys.contains(&index) {
+                left_columns.push((
+                    column.name().clone_value(),
+                    left.joined_values(index, &left_rows),
+                ));
+            }
+        }
+        let right_indices: Vec<usize> = (0..right.columns.len())
+            .filter(|index| !merge_keys || !right_keys.contains(index))
+            .collect();
+        let left_names: Vec<String> = left_columns.iter().map(|(name, _)| name.clone()).collect();
+        let right_names: Vec<String> = right_indices
+            .iter()
+            .map(|index| right.columns[*index].name().clone_value())
+            .collect();
+        let right_names =
+            resolve_name_clashes(&left_names, &right_names, right.table.name().value());
+        let mut new_columns = left_columns;
+        for (index, name) in right_indices.iter().zip(right_names) {
+            new_columns.push((name, right.joined_values(*index, &right_rows)));
+        }
+
+        let new_columns = new_columns
+            .into_iter()
+            .map(|(name, values)| Ok((ColumnName::new(name)?, values)))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(TableJoinServiceError::ColumnNameError)?;
+
+        // カラム名の重複チェック (カラムを作成する前に行う)
+        let names = new_columns.iter().map(|(name, _)| name.clone()).collect();
+        NoDuplicatedColumnNamesInListSpecification::new()
+            .is_satisfied_by(&names)
+            .map_err(TableJoinServiceError::DuplicatedColumnNameError)?;
+
+        // 結合したカラムの作成
+        let column_creation_service =
+            ColumnCreationService::new(self.column_factory, self.column_repository);
+        let mut columns = vec![];
+        let mut columns_with_cells = vec![];
+        for (name, values) in new_columns {
+            let (column, column_with_cells) = column_creation_service
+                .create_column(name, directory_id.clone(), values)
+                .await
+                .map_err(TableJoinServiceError::ColumnCreationServiceError)?;
+            columns.push(column);
+            columns_with_cells.push(column_with_cells);
+        }
+
+        // エンティティのインスタンス化
+        let column_ids = columns.iter().map(|column| column.id().clone()).collect();
+        let mut table = self
+            .table_factory
+            .create_table(table_name, column_ids)
+            .await
+            .map_err(TableJoinServiceError::TableFactoryError)?;
+
+        // テーブルの永続化
+        let table_id = self
+            .table_repository
+            .save(&table)
+            .await
+            .map_err(TableJoinServiceError::TableRepositoryError)?;
+        table.set_id(table_id);
+
+        Ok(TableJoinOutputData::new(
+            &left_table_id,
+            &right_table_id,
+            left_rows.len(),
+            TableWithColumnsAndCells::new(&table, columns_with_cells),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use src_domain::services::table_join::TableJoinError;
+    use src_in_memory_infrastructure::{
+        column::{
+            in_memory_column_factory::InMemoryColumnFactory,
+            in_memory_column_repository::InMemoryColumnRepository,
+        },
+        table::{
+            in_memory_table_factory::InMemoryTableFactory,
+            in_memory_table_repository::InMemoryTableRepository,
+        },
+    };
+
+    use crate::{table::join::table_join_command::JoinKindInCommand, test_utils::save_table};
+
+    use super::*;
+
+    fn column_values(output_data: &TableJoinOutputData) -> Vec<(String, Vec<Option<f64>>)> {
+        output_data
+            .columns
+            .iter()
+            .map(|column| {
+                (
+                    column.column_name.clone(),
+                    column.cells.iter().map(|cell| cell.cell_value).collect(),
+                )
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_handle_outer() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let table_factory = InMemoryTableFactory::new();
+        let table_repository = InMemoryTableRepository::new();
+        let (left_id, left_columns) = save_table(
+            &column_repository,
+            &table_repository,
+            "xrd",
+            vec![
+                ("sample", vec![Some(1.), Some(2.), Some(3.)]),
+                ("value", vec![Some(10.), Some(20.), Some(30.)]),
+            ],
+        )
+        .await?;
+        let (right_id, right_columns) = save_table(
+            &column_repository,
+            &table_repository,
+            "raman",
+            vec![
+                ("id", vec![Some(3.), Some(4.), Some(1.)]),
+                ("value", vec![Some(0.3), Some(0.4), Some(0.1)]),
+            ],
+        )
+        .await?;
+
+        let service = TableJoinService::new(
+            &column_factory,
+            &column_repository,
+            &table_factory,
+            &table_repository,
+        );
+        let command = TableJoinCommand {
+            left_table_id: left_id.clone_value(),
+            right_table_id: right_id.clone_value(),
+            left_key_column_ids: vec![left_columns[0].clone_value()],
+            right_key_column_ids: vec![right_columns[0].clone_value()],
+            kind: JoinKindInCommand::Outer,
+            table_name: "joined".to_string(),
+            directory_id: "8".to_string(),
+        };
+        let output_data = service.handle(command).await?;
+        assert_eq!(output_data.row_count, 4);
+        assert_eq!(output_data.left_table_id, left_id.clone_value());
+        // 右の value はテーブル名を付けて区別する
+        assert_eq!(
+            column_values(&output_data),
+            vec![
+                (
+                    "sample".to_string(),
+                    vec![Some(1.), Some(2.), Some(3.), Some(4.)]
+                ),
+                (
+                    "value".to_string(),
+                    vec![Some(10.), Some(20.), Some(30.), None]
+                ),
+                (
+                    "value (raman)".to_string(),
+                    vec![Some(0.1), None, Some(0.3), Some(0.4)]
+                ),
+            ]
+        );
+        let table = table_repository
+            .find(&TableId::new(output_data.table_id)?)
+            .await?
+            .unwrap();
+        assert_eq!(table.name().value(), "joined");
+        for column in column_repository.find_by_ids(table.columns()).await? {
+            assert_eq!(column.directory_id().value(), "8");
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handle_nearest() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let table_factory = InMemoryTableFactory::new();
+        let table_repository = InMemoryTableRepository::new();
+        let (left_id, left_columns) = save_table(
+            &column_repository,
+            &table_repository,
+            "thermometer",
+            vec![
+                ("time", vec![Some(0.), Some(10.), Some(20.)]),
+                ("T", vec![Some(300.), Some(305.), Some(310.)]),
+            ],
+        )
+        .await?;
+        let (right_id, right_columns) = save_table(
+            &column_repository,
+            &table_repository,
+            "gauge",
+            vec![
+                ("time", vec![Some(9.), Some(21.5)]),
+                ("P", vec![Some(1.), Some(2.)]),
+            ],
+        )
+        .await?;
+
+        let service = TableJoinService::new(
+            &column_factory,
+            &column_repository,
+            &table_factory,
+            &table_repository,
+        );
+        let command = |kind| TableJoinCommand {
+            left_table_id: left_id.clone_value(),
+            right_table_id: right_id.clone_value(),
+            left_key_column_ids: vec![left_columns[0].clone_value()],
+            right_key_column_ids: vec![right_columns[0].clone_value()],
+            kind,
+            table_name: "joined".to_string(),
+            directory_id: "8".to_string(),
+        };
+        let output_data = service
+            .handle(command(JoinKindInCommand::Nearest { tolerance: 1. }))
+            .await?;
+        assert_eq!(
+            column_values(&output_data),
+            vec![
+                ("time".to_string(), vec![Some(0.), Some(10.), Some(20.)]),
+                ("T".to_string(), vec![Some(300.), Some(305.), Some(310.)]),
+                ("time (gauge)".to_string(), vec![None, Some(9.), None]),
+                ("P".to_string(), vec![None, Some(1.), None]),
+            ]
+        );
+
+        assert!(matches!(
+            service
+                .handle(command(JoinKindInCommand::Nearest {
+                    tolerance: f64::NAN
+                }))
+                .await,
+            Err(TableJoinServiceError::TableJoinError(
+                TableJoinError::InvalidTolerance(_)
+            ))
+        ));
+        assert_eq!(table_repository.find_all().await?.len(), 3);
+        Ok(())
+    }
+}
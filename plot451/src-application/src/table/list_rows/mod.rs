@@ -0,0 +1,10 @@
+/* テーブルの行一覧取得用アプリケーションサービス */
+// コマンドオブジェクト
+pub mod table_rows_list_command;
+
+// アプリケーションサービス
+pub mod table_rows_list_service;
+pub mod table_rows_list_service_impl;
+
+// DTO
+pub mod table_rows_list_output_data;
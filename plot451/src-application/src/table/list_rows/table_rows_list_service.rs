@@ -0,0 +1,40 @@
+use thiserror::Error;
+
+use src_domain::models::{
+    column::column_repository::ColumnRepositoryError,
+    table::{
+        table_id::{TableId, TableIdError},
+        table_repository::TableRepositoryError,
+    },
+};
+
+use super::{
+    table_rows_list_command::TableRowsListCommand,
+    table_rows_list_output_data::TableRowsListOutputData,
+};
+
+pub type TableRowsListServiceResult<T> = anyhow::Result<T, TableRowsListServiceError>;
+
+pub trait ITableRowsListService {
+    fn handle(
+        &self,
+        command: TableRowsListCommand,
+    ) -> impl std::future::Future<Output = TableRowsListServiceResult<TableRowsListOutputData>> + Send;
+}
+
+#[derive(Debug, Error)]
+pub enum TableRowsListServiceError {
+    // repository errors
+    #[error("TableRepositoryError: [{0}]")]
+    TableRepositoryError(TableRepositoryError),
+    #[error("ColumnRepositoryError: [{0}]")]
+    ColumnRepositoryError(ColumnRepositoryError),
+
+    // value object errors
+    #[error("TableIdError: [{0}]")]
+    TableIdError(TableIdError),
+
+    // not found errors
+    #[error("Table not found, table_id: {0:?}")]
+    TableNotFound(TableId),
+}
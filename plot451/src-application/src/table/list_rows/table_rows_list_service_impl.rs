@@ -0,0 +1,214 @@
+use src_domain::{
+    models::{
+        column::{column_repository::IColumnRepository, column_with_cells::ColumnWithCells},
+        table::{
+            table_id::TableId, table_repository::ITableRepository,
+            table_with_columns_and_cells::TableWithColumnsAndCells,
+        },
+    },
+    shared::value_object::ValueObject,
+};
+
+use super::{
+    table_rows_list_command::TableRowsListCommand,
+    table_rows_list_output_data::TableRowsListOutputData,
+    table_rows_list_service::{
+        ITableRowsListService, TableRowsListServiceError, TableRowsListServiceResult,
+    },
+};
+
+pub struct TableRowsListService<'a, 'b, CR, TR>
+where
+    CR: IColumnRepository,
+    TR: ITableRepository,
+{
+    column_repository: &'a CR,
+    table_repository: &'b TR,
+}
+
+impl<'a, 'b, CR, TR> TableRowsListService<'a, 'b, CR, TR>
+where
+    CR: IColumnRepository,
+    TR: ITableRepository,
+{
+    pub fn new(column_repository: &'a CR, table_repository: &'b TR) -> Self {
+        Self {
+            column_repository,
+            table_repository,
+        }
+    }
+}
+
+impl<'a, 'b, CR, TR> ITableRowsListService for TableRowsListService<'a, 'b, CR, TR>
+where
+    CR: IColumnRepository + Sync,
+    TR: ITableRepository + Sync,
+{
+    async fn handle(
+        &self,
+        command: TableRowsListCommand,
+    ) -> TableRowsListServiceResult<TableRowsListOutputData> {
+        let TableRowsListCommand { table_id } = command;
+        let table_id = TableId::new(table_id).map_err(TableRowsListServiceError::TableIdError)?;
+
+        // テーブルの取得
+        let table = self
+            .table_repository
+            .find(&table_id)
+            .await
+            .map_err(TableRowsListServiceError::TableRepositoryError)?
+            .ok_or(TableRowsListServiceError::TableNotFound(table_id))?;
+
+        // カラムとセルをテーブル上の順序で取得
+        let columns = self
+            .column_repository
+            .find_by_ids(table.columns())
+            .await
+            .map_err(TableRowsListServiceError::ColumnRepositoryError)?;
+
+        let mut columns_with_cells = vec![];
+        for column in columns.iter() {
+            let cells = self
+                .column_repository
+                .find_cells_by_ids(column.cells())
+                .await
+                .map_err(TableRowsListServiceError::ColumnRepositoryError)?;
+            columns_with_cells.push(ColumnWithCells::new(column, cells));
+        }
+
+        // ファーストクラスコレクションに詰め替え
+        let table_with_columns_and_cells =
+            TableWithColumnsAndCells::new(&table, columns_with_cells);
+
+        let output_data = TableRowsListOutputData::new(table_with_columns_and_cells);
+        Ok(output_data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use src_domain::models::{
+        column::{
+            column::Column,
+            column_cell::{
+                column_cell::ColumnCell, column_cell_id::ColumnCellId,
+                column_cell_value::ColumnCellValue,
+            },
+            column_directory::column_directory_id::ColumnDirectoryId,
+            column_id::ColumnId,
+            column_name::ColumnName,
+        },
+        table::{table::Table, table_name::TableName},
+    };
+    use src_in_memory_infrastructure::{
+        column::in_memory_column_repository::InMemoryColumnRepository,
+        table::in_memory_table_repository::InMemoryTableRepository,
+    };
+
+    use crate::table::list_rows::table_rows_list_output_data::{
+        ColumnCellInOutputData, RowInOutputData,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_handle_with_ragged_columns() -> anyhow::Result<()> {
+        let column_repository = InMemoryColumnRepository::new();
+        let table_repository = InMemoryTableRepository::new();
+
+        // 事前にセルを作成しておく (カラム1 は 2 行、カラム2 は 1 行)
+        let cell1 = ColumnCell::new(
+            Some(ColumnCellId::new("cell_id_1".to_string())?),
+            ColumnCellValue::new(Some(1.0))?,
+        );
+        let cell2 = ColumnCell::new(
+            Some(ColumnCellId::new("cell_id_2".to_string())?),
+            ColumnCellValue::new(Some(2.0))?,
+        );
+        let cell3 = ColumnCell::new(
+            Some(ColumnCellId::new("cell_id_3".to_string())?),
+            ColumnCellValue::new(None)?,
+        );
+        column_repository.save_cell(&cell1).await?;
+        column_repository.save_cell(&cell2).await?;
+        column_repository.save_cell(&cell3).await?;
+
+        // 事前にカラムを作成しておく
+        let column1 = Column::new(
+            Some(ColumnId::new("column_id_1".to_string())?),
+            ColumnName::new("column_name_1".to_string())?,
+            ColumnDirectoryId::new("0".to_string())?,
+            vec![cell1.id().clone(), cell2.id().clone()],
+        );
+        let column2 = Column::new(
+            Some(ColumnId::new("column_id_2".to_string())?),
+            ColumnName::new("column_name_2".to_string())?,
+            ColumnDirectoryId::new("0".to_string())?,
+            vec![cell3.id().clone()],
+        );
+        column_repository.save(&column1).await?;
+        column_repository.save(&column2).await?;
+
+        // 事前にテーブルを作成しておく
+        let table = Table::new(
+            Some(TableId::new("table_id_1".to_string())?),
+            TableName::new("table_name_1".to_string())?,
+            vec![column1.id().clone(), column2.id().clone()],
+        )?;
+        table_repository.save(&table).await?;
+
+        // サービスの実行
+        let service = TableRowsListService::new(&column_repository, &table_repository);
+        let command = TableRowsListCommand {
+            table_id: "table_id_1".to_string(),
+        };
+        let TableRowsListOutputData { columns, rows, .. } = service.handle(command).await?;
+
+        assert_eq!(columns.len(), 2);
+        assert_eq!(
+            rows,
+            vec![
+                RowInOutputData {
+                    cells: vec![
+                        ColumnCellInOutputData {
+                            cell_id: Some("cell_id_1".to_string()),
+                            cell_value: Some(1.0),
+                        },
+                        ColumnCellInOutputData {
+                            cell_id: Some("cell_id_3".to_string()),
+                            cell_value: None,
+                        },
+                    ],
+                },
+                RowInOutputData {
+                    cells: vec![
+                        ColumnCellInOutputData {
+                            cell_id: Some("cell_id_2".to_string()),
+                            cell_value: Some(2.0),
+                        },
+                        ColumnCellInOutputData {
+                            cell_id: None,
+                            cell_value: None,
+                        },
+                    ],
+                },
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handle_not_found() -> anyhow::Result<()> {
+        let column_repository = InMemoryColumnRepository::new();
+        let table_repository = InMemoryTableRepository::new();
+        let service = TableRowsListService::new(&column_repository, &table_repository);
+
+        let command = TableRowsListCommand {
+            table_id: "1".to_string(),
+        };
+        match service.handle(command).await {
+            Err(TableRowsListServiceError::TableNotFound(_)) => Ok(()),
+            _ => panic!("unexpected result"),
+        }
+    }
+}
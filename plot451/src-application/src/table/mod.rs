@@ -5,4 +5,25 @@ pub mod create;
 pub mod list;
 
 // テーブル削除用のアプリケーションサービス
-pub mod delete;
\ No newline at end of file
+pub mod delete;
+
+// テーブルの行一覧取得用のアプリケーションサービス
+pub mod list_rows;
+
+// テーブルの行編集用のアプリケーションサービス
+pub mod edit_rows;
+
+// テーブルの行の並べ替え用のアプリケーションサービス
+pub mod sort;
+
+// 行の抽出による新しいテーブル作成用のアプリケーションサービス
+pub mod filter;
+
+// カラム間の相関行列・共分散行列用のアプリケーションサービス
+pub mod correlation;
+
+// グループ化による集計・ピボットテーブル作成用のアプリケーションサービス
+pub mod group_by;
+
+// テーブルの結合用のアプリケーションサービス
+pub mod join;
@@ -0,0 +1,10 @@
+/* テーブルの行の並べ替え用アプリケーションサービス */
+// コマンドオブジェクト
+pub mod table_sort_command;
+
+// アプリケーションサービス
+pub mod table_sort_service;
+pub mod table_sort_service_impl;
+
+// DTO
+pub mod table_sort_output_data;
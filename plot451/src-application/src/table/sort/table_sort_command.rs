@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+use src_domain::models::table::table_sort_key::{MissingPlacement, SortOrder};
+
+#[derive(Deserialize, Serialize)]
+pub struct TableSortCommand {
+    pub(super) table_id: String,
+    // 先のキーほど優先する
+    pub(super) keys: Vec<SortKeyInCommand>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct SortKeyInCommand {
+    pub(super) column_id: String,
+    #[serde(default)]
+    pub(super) order: SortOrderInCommand,
+    #[serde(default)]
+    pub(super) missing: MissingPlacementInCommand,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SortOrderInCommand {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+impl SortOrderInCommand {
+    pub(super) fn to_sort_order(self) -> SortOrder {
+        match self {
+            SortOrderInCommand::Ascending => SortOrder::Ascending,
+            SortOrderInCommand::Descending => SortOrder::Descending,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum MissingPlacementInCommand {
+    First,
+    #[default]
+    Last,
+}
+
+impl MissingPlacementInCommand {
+    pub(super) fn to_missing_placement(self) -> MissingPlacement {
+        match self {
+            MissingPlacementInCommand::First => MissingPlacement::First,
+            MissingPlacementInCommand::Last => MissingPlacement::Last,
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+use src_domain::{
+    models::table::table_with_columns_and_cells::TableWithColumnsAndCells,
+    shared::value_object::ValueObject,
+};
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+pub struct TableSortOutputData {
+    pub(super) table_id: String,
+    pub(super) table_name: String,
+    pub(super) columns: Vec<ColumnInOutputData>,
+    pub(super) rows: Vec<RowInOutputData>,
+}
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+pub(super) struct ColumnInOutputData {
+    pub(super) column_id: String,
+    pub(super) column_name: String,
+}
+
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+pub(super) struct RowInOutputData {
+    pub(super) cells: Vec<ColumnCellInOutputData>,
+}
+
+// 長さの足りないカラムのセルは cell_id が None になる
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+pub(super) struct ColumnCellInOutputData {
+    pub(super) cell_id: Option<String>,
+    pub(super) cell_value: Option<f64>,
+}
+
+impl TableSortOutputData {
+    pub(super) fn new(source: TableWithColumnsAndCells) -> Self {
+        Self {
+            table_id: source.id().clone_value(),
+            table_name: source.name().clone_value(),
+            columns: source
+                .columns()
+                .iter()
+                .map(|column| ColumnInOutputData {
+                    column_id: column.id().clone_value(),
+                    column_name: column.name().clone_value(),
+                })
+                .collect(),
+            rows: source
+                .rows()
+                .iter()
+                .map(|row| RowInOutputData {
+                    cells: row
+                        .iter()
+                        .map(|cell| ColumnCellInOutputData {
+                            cell_id: cell.map(|cell| cell.id().clone_value()),
+                            cell_value: cell.and_then(|cell| *cell.cell_value().value()),
+                        })
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+}
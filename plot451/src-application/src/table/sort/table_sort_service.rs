@@ -0,0 +1,60 @@
+use thiserror::Error;
+
+use src_domain::{
+    models::{
+        column::{column_id::ColumnIdError, column_repository::ColumnRepositoryError},
+        table::{
+            table_id::{TableId, TableIdError},
+            table_repository::TableRepositoryError,
+            table_rows::TableRowsError,
+        },
+    },
+    services::{
+        derived_column_service::DerivedColumnServiceError,
+        table_rows_padding_service::TableRowsPaddingServiceError,
+    },
+};
+
+use super::{table_sort_command::TableSortCommand, table_sort_output_data::TableSortOutputData};
+
+pub type TableSortServiceResult<T> = anyhow::Result<T, TableSortServiceError>;
+
+pub trait ITableSortService {
+    fn handle(
+        &self,
+        command: TableSortCommand,
+    ) -> impl std::future::Future<Output = TableSortServiceResult<TableSortOutputData>> + Send;
+}
+
+#[derive(Debug, Error)]
+pub enum TableSortServiceError {
+    // repository errors
+    #[error("TableRepositoryError: [{0}]")]
+    TableRepositoryError(TableRepositoryError),
+    #[error("ColumnRepositoryError: [{0}]")]
+    ColumnRepositoryError(ColumnRepositoryError),
+
+    // value object errors
+    #[error("TableIdError: [{0}]")]
+    TableIdError(TableIdError),
+    #[error("ColumnIdError: [{0}]")]
+    ColumnIdError(ColumnIdError),
+
+    // first class collection errors
+    #[error("TableRowsError: [{0}]")]
+    TableRowsError(TableRowsError),
+
+    // domain service errors
+    #[error("TableRowsPaddingServiceError: [{0}]")]
+    TableRowsPaddingServiceError(TableRowsPaddingServiceError),
+    #[error("DerivedColumnServiceError: [{0}]")]
+    DerivedColumnServiceError(DerivedColumnServiceError),
+
+    // not found errors
+    #[error("Table not found, table_id: {0:?}")]
+    TableNotFound(TableId),
+
+    // command errors
+    #[error("At least one sort key is required")]
+    EmptySortKeys,
+}
@@ -0,0 +1,331 @@
+use src_domain::{
+    models::{
+        column::{
+            column_cell::column_cell_value::CellRawValue, column_factory::IColumnFactory,
+            column_id::ColumnId, column_repository::IColumnRepository,
+            column_with_cells::ColumnWithCells,
+        },
+        table::{
+            table_id::TableId, table_repository::ITableRepository, table_rows::TableRows,
+            table_sort_key::TableSortKey, table_with_columns_and_cells::TableWithColumnsAndCells,
+        },
+    },
+    services::{
+        column_values_service::ColumnValuesService, derived_column_service::DerivedColumnService,
+        table_rows_padding_service::TableRowsPaddingService,
+    },
+    shared::value_object::ValueObject,
+};
+
+use super::{
+    table_sort_command::TableSortCommand,
+    table_sort_output_data::TableSortOutputData,
+    table_sort_service::{ITableSortService, TableSortServiceError, TableSortServiceResult},
+};
+
+pub struct TableSortService<'a, 'b, 'c, CF, CR, TR>
+where
+    CF: IColumnFactory,
+    CR: IColumnRepository,
+    TR: ITableRepository,
+{
+    column_factory: &'a CF,
+    column_repository: &'b CR,
+    table_repository: &'c TR,
+}
+
+impl<'a, 'b, 'c, CF, CR, TR> TableSortService<'a, 'b, 'c, CF, CR, TR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+    TR: ITableRepository + Sync,
+{
+    pub fn new(
+        column_factory: &'a CF,
+        column_repository: &'b CR,
+        table_repository: &'c TR,
+    ) -> Self {
+        Self {
+            column_factory,
+            column_repository,
+            table_repository,
+        }
+    }
+}
+
+impl<'a, 'b, 'c, CF, CR, TR> ITableSortService for TableSortService<'a, 'b, 'c, CF, CR, TR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+    TR: ITableRepository + Sync,
+{
+    // TODO: トランザクション処理を追加する
+    async fn handle(
+        &self,
+        command: TableSortCommand,
+    ) -> TableSortServiceResult<TableSortOutputData> {
+        let TableSortCommand { table_id, keys } = command;
+        if keys.is_empty() {
+            return Err(TableSortServiceError::EmptySortKeys);
+        }
+
+        // 値オブジェクトのインスタンス化
+        let table_id = TableId::new(table_id).map_err(TableSortServiceError::TableIdError)?;
+        let keys = keys
+            .into_iter()
+            .map(|key| {
+                Ok(TableSortKey::new(
+                    ColumnId::new(key.column_id).map_err(TableSortServiceError::ColumnIdError)?,
+                    key.order.to_sort_order(),
+                    key.missing.to_missing_placement(),
+                ))
+            })
+            .collect::<TableSortServiceResult<Vec<_>>>()?;
+
+        // テーブルの取得
+        let table = self
+            .table_repository
+            .find(&table_id)
+            .await
+            .map_err(TableSortServiceError::TableRepositoryError)?
+            .ok_or(TableSortServiceError::TableNotFound(table_id))?;
+
+        let columns = self
+            .column_repository
+            .find_by_ids(table.columns())
+            .await
+            .map_err(TableSortServiceError::ColumnRepositoryError)?;
+
+        // ファーストクラスコレクションに詰め替え
+        let mut table_rows = TableRows::new(&table, columns);
+
+        // 空セルを作成する前にキーのカラムを確認する
+        table_rows
+            .check_sort_keys(&keys)
+            .map_err(TableSortServiceError::TableRowsError)?;
+
+        // 長さの足りないカラムを空セルで埋める
+        let created_cell_ids =
+            TableRowsPaddingService::new(self.column_factory, self.column_repository)
+                .pad(&mut table_rows)
+                .await
+                .map_err(TableSortServiceError::TableRowsPaddingServiceError)?;
+
+        // キーのカラムの値
+        let mut keys_with_values = vec![];
+        for key in keys {
+            let values: Vec<CellRawValue> = match table_rows
+                .columns()
+                .iter()
+                .find(|column| column.id() == key.column_id())
+            {
+                Some(column) => ColumnValuesService::new(self.column_repository)
+                    .find_values(column)
+                    .await
+                    .map_err(TableSortServiceError::ColumnRepositoryError)?,
+                None => vec![],
+            };
+            keys_with_values.push((key, values));
+        }
+
+        // 行の並べ替えをすべてのカラムに適用 (セルの id は変わらない)
+        table_rows
+            .sort_rows(&keys_with_values)
+            .map_err(TableSortServiceError::TableRowsError)?;
+
+        // 派生カラムとそれらを参照する派生カラムの再計算 (永続化する前に制約までチェックする)
+        // 失敗した場合は作成した空セルを削除する
+        let mut changed = vec![];
+        for column in table_rows.columns() {
+            let values = ColumnValuesService::new(self.column_repository)
+                .find_values(column)
+                .await
+                .map_err(TableSortServiceError::ColumnRepositoryError)?;
+            changed.push((column.clone(), values));
+        }
+        let derived_column_service =
+            DerivedColumnService::new(self.column_factory, self.column_repository);
+        let recomputed_columns = match derived_column_service.compute_dependents(changed).await {
+            Ok(recomputed_columns) => recomputed_columns,
+            Err(error) => {
+                let created_cells = self
+                    .column_repository
+                    .find_cells_by_ids(&created_cell_ids)
+                    .await
+                    .map_err(TableSortServiceError::ColumnRepositoryError)?;
+                for cell in created_cells {
+                    self.column_repository
+                        .delete_cell(cell)
+                        .await
+                        .map_err(TableSortServiceError::ColumnRepositoryError)?;
+                }
+                return Err(TableSortServiceError::DerivedColumnServiceError(error));
+            }
+        };
+
+        // カラムの永続化
+        for column in table_rows.columns() {
+            self.column_repository
+                .save(column)
+                .await
+                .map_err(TableSortServiceError::ColumnRepositoryError)?;
+        }
+        derived_column_service
+            .save_all(recomputed_columns)
+            .await
+            .map_err(TableSortServiceError::DerivedColumnServiceError)?;
+
+        // 並べ替え後の行を OutputData として返す
+        let columns = self
+            .column_repository
+            .find_by_ids(table.columns())
+            .await
+            .map_err(TableSortServiceError::ColumnRepositoryError)?;
+        let mut columns_with_cells = vec![];
+        for column in &columns {
+            let cells = self
+                .column_repository
+                .find_cells_by_ids(column.cells())
+                .await
+                .map_err(TableSortServiceError::ColumnRepositoryError)?;
+            columns_with_cells.push(ColumnWithCells::new(column, cells));
+        }
+        let table_with_columns_and_cells =
+            TableWithColumnsAndCells::new(&table, columns_with_cells);
+
+        Ok(TableSortOutputData::new(table_with_columns_and_cells))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use src_domain::models::{
+        column::column_cell::column_cell_id::ColumnCellId, table::table_rows::TableRowsError,
+    };
+    use src_in_memory_infrastructure::{
+        column::{
+            in_memory_column_factory::InMemoryColumnFactory,
+            in_memory_column_repository::InMemoryColumnRepository,
+        },
+        table::in_memory_table_repository::InMemoryTableRepository,
+    };
+
+    use crate::{
+        table::sort::{
+            table_sort_command::{MissingPlacementInCommand, SortKeyInCommand, SortOrderInCommand},
+            table_sort_output_data::RowInOutputData,
+        },
+        test_utils::save_table,
+    };
+
+    use super::*;
+
+    // group: [1, 2, 1, None], value: [30, 10, 20] のテーブルを作成し、テーブルとカラムの id を返す
+    async fn prepare(
+        column_repository: &InMemoryColumnRepository,
+        table_repository: &InMemoryTableRepository,
+    ) -> anyhow::Result<(TableId, Vec<ColumnId>)> {
+        let columns = vec![
+            ("group", vec![Some(1.), Some(2.), Some(1.), None]),
+            ("value", vec![Some(30.), Some(10.), Some(20.)]),
+        ];
+        save_table(column_repository, table_repository, "table_name_1", columns).await
+    }
+
+    fn values(rows: &[RowInOutputData]) -> Vec<Vec<Option<f64>>> {
+        rows.iter()
+            .map(|row| row.cells.iter().map(|cell| cell.cell_value).collect())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_handle() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let table_repository = InMemoryTableRepository::new();
+        let (table_id, column_ids) = prepare(&column_repository, &table_repository).await?;
+        let group = column_repository.find(&column_ids[0]).await?.unwrap();
+
+        let service = TableSortService::new(&column_factory, &column_repository, &table_repository);
+        let command = TableSortCommand {
+            table_id: table_id.clone_value(),
+            keys: vec![
+                SortKeyInCommand {
+                    column_id: column_ids[0].clone_value(),
+                    order: SortOrderInCommand::Ascending,
+                    missing: MissingPlacementInCommand::First,
+                },
+                SortKeyInCommand {
+                    column_id: column_ids[1].clone_value(),
+                    order: SortOrderInCommand::Descending,
+                    missing: MissingPlacementInCommand::Last,
+                },
+            ],
+        };
+        let TableSortOutputData { rows, .. } = service.handle(command).await?;
+        assert_eq!(
+            values(&rows),
+            vec![
+                vec![None, None],
+                vec![Some(1.), Some(30.)],
+                vec![Some(1.), Some(20.)],
+                vec![Some(2.), Some(10.)],
+            ]
+        );
+
+        // セルの id は並べ替え前のものが使われている
+        let sorted = column_repository.find(&column_ids[0]).await?.unwrap();
+        assert_eq!(
+            sorted.cells(),
+            &vec![
+                group.cells()[3].clone(),
+                group.cells()[0].clone(),
+                group.cells()[2].clone(),
+                group.cells()[1].clone(),
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handle_errors() -> anyhow::Result<()> {
+        let column_factory = InMemoryColumnFactory::new();
+        let column_repository = InMemoryColumnRepository::new();
+        let table_repository = InMemoryTableRepository::new();
+        let (table_id, column_ids) = prepare(&column_repository, &table_repository).await?;
+
+        let service = TableSortService::new(&column_factory, &column_repository, &table_repository);
+        let command = TableSortCommand {
+            table_id: table_id.clone_value(),
+            keys: vec![],
+        };
+        assert!(matches!(
+            service.handle(command).await,
+            Err(TableSortServiceError::EmptySortKeys)
+        ));
+
+        let command = TableSortCommand {
+            table_id: table_id.clone_value(),
+            keys: vec![SortKeyInCommand {
+                column_id: "other".to_string(),
+                order: SortOrderInCommand::Ascending,
+                missing: MissingPlacementInCommand::Last,
+            }],
+        };
+        assert!(matches!(
+            service.handle(command).await,
+            Err(TableSortServiceError::TableRowsError(
+                TableRowsError::KeyColumnNotFound(_)
+            ))
+        ));
+
+        // 長さの足りない value カラムの空セルは作成されていない (7 個のセルのあとに採番される id は "8")
+        let value = column_repository.find(&column_ids[1]).await?.unwrap();
+        assert_eq!(value.cells().len(), 3);
+        assert!(column_repository
+            .find_cell(&ColumnCellId::new("8".to_string())?)
+            .await?
+            .is_none());
+        Ok(())
+    }
+}
@@ -0,0 +1,66 @@
+use src_domain::{
+    models::{
+        column::{
+            column::Column,
+            column_cell::{column_cell::ColumnCell, column_cell_value::ColumnCellValue},
+            column_directory::column_directory_id::ColumnDirectoryId,
+            column_id::ColumnId,
+            column_name::ColumnName,
+            column_repository::IColumnRepository,
+        },
+        table::{
+            table::Table, table_id::TableId, table_name::TableName,
+            table_repository::ITableRepository,
+        },
+    },
+    shared::value_object::ValueObject,
+};
+use src_in_memory_infrastructure::{
+    column::in_memory_column_repository::InMemoryColumnRepository,
+    table::in_memory_table_repository::InMemoryTableRepository,
+};
+
+// テスト用: 値からセルとカラムを作成して保存する
+pub async fn save_column(
+    column_repository: &InMemoryColumnRepository,
+    name: &str,
+    values: Vec<Option<f64>>,
+) -> anyhow::Result<ColumnId> {
+    save_column_in_directory(column_repository, name, "0", values).await
+}
+
+// テスト用: ディレクトリを指定してカラムを保存する
+pub async fn save_column_in_directory(
+    column_repository: &InMemoryColumnRepository,
+    name: &str,
+    directory_id: &str,
+    values: Vec<Option<f64>>,
+) -> anyhow::Result<ColumnId> {
+    let mut cell_ids = vec![];
+    for value in values {
+        let cell = ColumnCell::new(None, ColumnCellValue::new(value)?);
+        cell_ids.push(column_repository.save_cell(&cell).await?);
+    }
+    let column = Column::new(
+        None,
+        ColumnName::new(name.to_string())?,
+        ColumnDirectoryId::new(directory_id.to_string())?,
+        cell_ids,
+    );
+    Ok(column_repository.save(&column).await?)
+}
+
+// テスト用: カラムを保存し、それらからなるテーブルを保存してテーブルとカラムの id を返す
+pub async fn save_table(
+    column_repository: &InMemoryColumnRepository,
+    table_repository: &InMemoryTableRepository,
+    name: &str,
+    columns: Vec<(&str, Vec<Option<f64>>)>,
+) -> anyhow::Result<(TableId, Vec<ColumnId>)> {
+    let mut column_ids = vec![];
+    for (column_name, values) in columns {
+        column_ids.push(save_column(column_repository, column_name, values).await?);
+    }
+    let table = Table::new(None, TableName::new(name.to_string())?, column_ids.clone())?;
+    Ok((table_repository.save(&table).await?, column_ids))
+}
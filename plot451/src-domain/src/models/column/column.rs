@@ -3,9 +3,12 @@ use std::collections::HashSet;
 use thiserror::Error;
 
 use super::column_cell::column_cell_id::ColumnCellId;
+use super::column_constraint::ColumnConstraint;
 use super::column_directory::column_directory_id::ColumnDirectoryId;
+use super::column_formula::column_formula::ColumnFormula;
 use super::column_id::ColumnId;
 use super::column_name::ColumnName;
+use super::column_provenance::ColumnProvenance;
 use crate::shared::entity::Entity;
 
 // entity
@@ -15,6 +18,9 @@ pub struct Column {
     name: ColumnName,
     directory: ColumnDirectoryId,
     cells: Vec<ColumnCellId>,
+    constraints: Vec<ColumnConstraint>,
+    formula: Option<ColumnFormula>,
+    provenance: Option<ColumnProvenance>,
 }
 
 impl Column {
@@ -30,6 +36,9 @@ impl Column {
             name,
             directory,
             cells,
+            constraints: vec![],
+            formula: None,
+            provenance: None,
         }
     }
 
@@ -61,6 +70,23 @@ impl Column {
         &self.cells
     }
 
+    pub fn constraints(&self) -> &Vec<ColumnConstraint> {
+        &self.constraints
+    }
+
+    pub fn formula(&self) -> &Option<ColumnFormula> {
+        &self.formula
+    }
+
+    pub fn provenance(&self) -> &Option<ColumnProvenance> {
+        &self.provenance
+    }
+
+    // 数式から値が計算される派生カラムか
+    pub fn is_derived(&self) -> bool {
+        self.formula.is_some()
+    }
+
     // カラム名の変更
     pub fn change_name(&mut self, new_name: ColumnName) {
         self.name = new_name;
@@ -76,6 +102,34 @@ impl Column {
         self.cells.retain(|c| c != cell_id);
     }
 
+    // 指定位置へのセルの挿入
+    pub fn insert_cells_at(
+        &mut self,
+        index: usize,
+        cell_ids: Vec<ColumnCellId>,
+    ) -> anyhow::Result<(), ColumnEntityError> {
+        if index > self.cells.len() {
+            return Err(ColumnEntityError::IndexOutOfRange(index));
+        }
+        self.cells.splice(index..index, cell_ids);
+        Ok(())
+    }
+
+    // 指定位置からのセルの削除
+    pub fn remove_cells_at(
+        &mut self,
+        index: usize,
+        count: usize,
+    ) -> anyhow::Result<Vec<ColumnCellId>, ColumnEntityError> {
+        let end = index
+            .checked_add(count)
+            .ok_or(ColumnEntityError::IndexOutOfRange(index))?;
+        if end > self.cells.len() {
+            return Err(ColumnEntityError::IndexOutOfRange(end));
+        }
+        Ok(self.cells.drain(index..end).collect())
+    }
+
     // セルの順序変更
     pub fn change_order(
         &mut self,
@@ -93,6 +147,27 @@ impl Column {
         Ok(())
     }
 
+    // 制約の変更
+    pub fn change_constraints(&mut self, constraints: Vec<ColumnConstraint>) {
+        let mut unique_constraints = vec![];
+        for constraint in constraints {
+            if !unique_constraints.contains(&constraint) {
+                unique_constraints.push(constraint);
+            }
+        }
+        self.constraints = unique_constraints;
+    }
+
+    // 数式の変更 (None で通常のカラムに戻す)
+    pub fn change_formula(&mut self, formula: Option<ColumnFormula>) {
+        self.formula = formula;
+    }
+
+    // 生成元の変更
+    pub fn change_provenance(&mut self, provenance: Option<ColumnProvenance>) {
+        self.provenance = provenance;
+    }
+
     // ディレクトリの移動
     pub fn move_to(&mut self, new_directory: ColumnDirectoryId) {
         self.directory = new_directory;
@@ -117,6 +192,8 @@ impl PartialEq for Column {
 pub enum ColumnEntityError {
     #[error("invalid order")]
     InvalidOrder,
+    #[error("index out of range, index: {0}")]
+    IndexOutOfRange(usize),
 }
 
 #[cfg(test)]
@@ -128,7 +205,7 @@ mod tests {
 
     use super::super::column_cell::column_cell_id::ColumnCellId;
 
-    use super::Column;
+    use super::{Column, ColumnEntityError};
 
     #[test]
     fn test_change_order() {
@@ -159,4 +236,36 @@ mod tests {
         let new_order = vec![cell_id3.clone(), cell_id1.clone(), cell_id4.clone()];
         assert!(column.change_order(new_order).is_err());
     }
+
+    #[test]
+    fn test_insert_and_remove_cells_at() {
+        let column_id = ColumnId::new("column_id".to_string()).unwrap();
+        let column_name = ColumnName::new("column_name".to_string()).unwrap();
+        let directory_id = ColumnDirectoryId::new("0".to_string()).unwrap();
+        let cell_id1 = ColumnCellId::new("cell_id1".to_string()).unwrap();
+        let cell_id2 = ColumnCellId::new("cell_id2".to_string()).unwrap();
+        let cell_id3 = ColumnCellId::new("cell_id3".to_string()).unwrap();
+        let mut column = Column::new(
+            Some(column_id),
+            column_name,
+            directory_id,
+            vec![cell_id1.clone(), cell_id3.clone()],
+        );
+
+        assert!(column.insert_cells_at(1, vec![cell_id2.clone()]).is_ok());
+        assert_eq!(
+            column.cells(),
+            &vec![cell_id1.clone(), cell_id2.clone(), cell_id3.clone()]
+        );
+        assert!(column.insert_cells_at(4, vec![]).is_err());
+
+        let removed = column.remove_cells_at(0, 2).unwrap();
+        assert_eq!(removed, vec![cell_id1.clone(), cell_id2.clone()]);
+        assert_eq!(column.cells(), &vec![cell_id3.clone()]);
+        assert!(column.remove_cells_at(0, 2).is_err());
+        assert!(matches!(
+            column.remove_cells_at(1, usize::MAX),
+            Err(ColumnEntityError::IndexOutOfRange(1))
+        ));
+    }
 }
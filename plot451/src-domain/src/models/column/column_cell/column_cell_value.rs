@@ -1,6 +1,8 @@
 use crate::shared::value_object::ValueObject;
 use thiserror::Error;
 
+use super::column_cell_value_parser::ColumnCellValueParser;
+
 pub type CellRawValue = Option<f64>;
 
 // value object
@@ -13,6 +15,10 @@ pub struct ColumnCellValue {
 pub enum ColumnCellValueError {
     #[error("ColumnCellValueParseError: [{0}]")]
     ParseError(String),
+    #[error("invalid character '{1}' at position {2}: [{0}]")]
+    InvalidCharacter(String, char, usize),
+    #[error("unexpected end of input: [{0}]")]
+    UnexpectedEnd(String),
 }
 
 impl ValueObject for ColumnCellValue {
@@ -34,15 +40,9 @@ impl ValueObject for ColumnCellValue {
 }
 
 impl ColumnCellValue {
+    // 既定の設定でパースする (設定を変える場合は ColumnCellValueParser を使う)
     pub fn parse(str: &str) -> Result<Self, ColumnCellValueError> {
-        let str = str.trim();
-        if str.is_empty() {
-            return Ok(Self { value: None });
-        }
-        let value = str
-            .parse::<f64>()
-            .map_err(|e| ColumnCellValueError::ParseError(e.to_string()))?;
-        Ok(Self { value: Some(value) })
+        ColumnCellValueParser::default().parse(str)
     }
 }
 
@@ -86,4 +86,10 @@ mod tests {
         let cell_value = ColumnCellValue::parse("a");
         assert!(cell_value.is_err());
     }
+
+    #[test]
+    fn test_parse_full_width_digits() {
+        let cell_value = ColumnCellValue::parse("１．５").unwrap();
+        assert_eq!(cell_value.value(), &Some(1.5));
+    }
 }
@@ -0,0 +1,378 @@
+use crate::shared::value_object::ValueObject;
+
+use super::column_cell_value::{ColumnCellValue, ColumnCellValueError};
+
+// 数値表記のロケール
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumberLocale {
+    // 小数点 '.', 桁区切り ',' (例: 1,234.5)
+    Standard,
+    // 小数点 ',', 桁区切り '.' (例: 1.234,5)
+    CommaDecimal,
+}
+
+impl NumberLocale {
+    pub fn decimal_separator(&self) -> char {
+        match self {
+            NumberLocale::Standard => '.',
+            NumberLocale::CommaDecimal => ',',
+        }
+    }
+
+    pub fn thousands_separator(&self) -> char {
+        match self {
+            NumberLocale::Standard => ',',
+            NumberLocale::CommaDecimal => '.',
+        }
+    }
+}
+
+// セルの値のパーサ
+#[derive(Debug, Clone)]
+pub struct ColumnCellValueParser {
+    locale: NumberLocale,
+    missing_value_tokens: Vec<String>,
+    accept_si_suffix: bool,
+}
+
+impl Default for ColumnCellValueParser {
+    fn default() -> Self {
+        Self::new(NumberLocale::Standard, vec![], false)
+    }
+}
+
+impl ColumnCellValueParser {
+    pub fn new(
+        locale: NumberLocale,
+        missing_value_tokens: Vec<String>,
+        accept_si_suffix: bool,
+    ) -> Self {
+        Self {
+            locale,
+            missing_value_tokens: missing_value_tokens
+                .iter()
+                .map(|token| normalize(token.trim()).to_lowercase())
+                .collect(),
+            accept_si_suffix,
+        }
+    }
+
+    pub fn locale(&self) -> NumberLocale {
+        self.locale
+    }
+
+    pub fn missing_value_tokens(&self) -> &Vec<String> {
+        &self.missing_value_tokens
+    }
+
+    pub fn accept_si_suffix(&self) -> bool {
+        self.accept_si_suffix
+    }
+
+    pub fn parse(&self, str: &str) -> Result<ColumnCellValue, ColumnCellValueError> {
+        let input = str.trim();
+        let normalized = normalize(input);
+
+        // 空文字列・欠損値トークンは None として扱う
+        if normalized.is_empty()
+            || self
+                .missing_value_tokens
+                .contains(&normalized.to_lowercase())
+        {
+            return ColumnCellValue::new(None);
+        }
+
+        // NaN・無限大の表記揺れ
+        if let Some(value) = parse_special_value(&normalized) {
+            return ColumnCellValue::new(Some(value));
+        }
+
+        let value = self.parse_number(input, &normalized)?;
+        ColumnCellValue::new(Some(value))
+    }
+
+    fn parse_number(&self, input: &str, normalized: &str) -> Result<f64, ColumnCellValueError> {
+        let chars: Vec<char> = normalized.chars().collect();
+        let original_chars: Vec<char> = input.chars().collect();
+        let decimal_separator = self.locale.decimal_separator();
+        let thousands_separator = self.locale.thousands_separator();
+        let invalid = |position: usize| {
+            ColumnCellValueError::InvalidCharacter(
+                input.to_string(),
+                original_chars[position],
+                position,
+            )
+        };
+
+        let mut mantissa = String::new();
+        let mut i = 0;
+
+        // 符号
+        if let Some(&c) = chars.get(i) {
+            if c == '+' || c == '-' {
+                if c == '-' {
+                    mantissa.push('-');
+                }
+                i += 1;
+            }
+        }
+
+        // 整数部 (桁区切りは 3 桁ごとのみ許可する)
+        let mut integer_digits = 0;
+        let mut group_length = 0;
+        let mut last_separator: Option<usize> = None;
+        while let Some(&c) = chars.get(i) {
+            if c.is_ascii_digit() {
+                if last_separator.is_some() && group_length == 3 {
+                    return Err(invalid(i));
+                }
+                mantissa.push(c);
+                integer_digits += 1;
+                group_length += 1;
+            } else if c == thousands_separator {
+                let valid_group = match last_separator {
+                    Some(_) => group_length == 3,
+                    None => (1..=3).contains(&group_length),
+                };
+                if !valid_group {
+                    return Err(invalid(i));
+                }
+                last_separator = Some(i);
+                group_length = 0;
+            } else {
+                break;
+            }
+            i += 1;
+        }
+        if let Some(separator_position) = last_separator {
+            if group_length != 3 {
+                return Err(invalid(separator_position));
+            }
+        }
+
+        // 小数部
+        let mut fraction_digits = 0;
+        if chars.get(i) == Some(&decimal_separator) {
+            mantissa.push('.');
+            i += 1;
+            while let Some(&c) = chars.get(i) {
+                if !c.is_ascii_digit() {
+                    break;
+                }
+                mantissa.push(c);
+                fraction_digits += 1;
+                i += 1;
+            }
+        }
+        if integer_digits + fraction_digits == 0 {
+            return match chars.get(i) {
+                Some(_) => Err(invalid(i)),
+                None => Err(ColumnCellValueError::UnexpectedEnd(input.to_string())),
+            };
+        }
+
+        // 指数部
+        let mut exponent: i64 = 0;
+        if matches!(chars.get(i), Some('e') | Some('E')) && starts_exponent(&chars[i + 1..]) {
+            i += 1;
+            let mut negative = false;
+            if let Some(&c) = chars.get(i) {
+                if c == '+' || c == '-' {
+                    negative = c == '-';
+                    i += 1;
+                }
+            }
+            while let Some(&c) = chars.get(i) {
+                let Some(digit) = c.to_digit(10) else {
+                    break;
+                };
+                exponent = exponent.saturating_mul(10).saturating_add(digit as i64);
+                i += 1;
+            }
+            if negative {
+                exponent = -exponent;
+            }
+        }
+
+        // SI 接頭辞
+        if self.accept_si_suffix {
+            if let Some(si_exponent) = chars.get(i).and_then(|&c| si_prefix_exponent(c)) {
+                exponent = exponent.saturating_add(si_exponent);
+                i += 1;
+            }
+        }
+
+        if i < chars.len() {
+            return Err(invalid(i));
+        }
+
+        format!("{}e{}", mantissa, exponent)
+            .parse::<f64>()
+            .map_err(|e| ColumnCellValueError::ParseError(e.to_string()))
+    }
+}
+
+// 全角英数字・記号を半角に変換する
+fn normalize(str: &str) -> String {
+    str.chars()
+        .map(|c| match c {
+            '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+            '\u{2212}' => '-',
+            _ => c,
+        })
+        .collect()
+}
+
+fn parse_special_value(normalized: &str) -> Option<f64> {
+    let (sign, body) = match normalized.strip_prefix('-') {
+        Some(body) => (-1., body),
+        None => (1., normalized.strip_prefix('+').unwrap_or(normalized)),
+    };
+    match body.to_lowercase().as_str() {
+        "nan" | "1.#qnan" | "1.#snan" | "1.#ind" => Some(f64::NAN),
+        "inf" | "infinity" | "∞" | "1.#inf" => Some(sign * f64::INFINITY),
+        _ => None,
+    }
+}
+
+fn starts_exponent(rest: &[char]) -> bool {
+    match rest {
+        [c, ..] if c.is_ascii_digit() => true,
+        ['+' | '-', c, ..] if c.is_ascii_digit() => true,
+        _ => false,
+    }
+}
+
+fn si_prefix_exponent(c: char) -> Option<i64> {
+    match c {
+        'y' => Some(-24),
+        'z' => Some(-21),
+        'a' => Some(-18),
+        'f' => Some(-15),
+        'p' => Some(-12),
+        'n' => Some(-9),
+        'u' | 'µ' | 'μ' => Some(-6),
+        'm' => Some(-3),
+        'k' => Some(3),
+        'M' => Some(6),
+        'G' => Some(9),
+        'T' => Some(12),
+        'P' => Some(15),
+        'E' => Some(18),
+        'Z' => Some(21),
+        'Y' => Some(24),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(parser: &ColumnCellValueParser, str: &str) -> Option<f64> {
+        parser.parse(str).unwrap().clone_value()
+    }
+
+    #[test]
+    fn test_parse_standard_locale() {
+        let parser = ColumnCellValueParser::default();
+        assert_eq!(parse(&parser, "1.5"), Some(1.5));
+        assert_eq!(parse(&parser, "-1,234.5"), Some(-1234.5));
+        assert_eq!(parse(&parser, "1,234,567"), Some(1234567.));
+        assert_eq!(parse(&parser, ".5"), Some(0.5));
+        assert_eq!(parse(&parser, "1.5e-3"), Some(1.5e-3));
+        assert_eq!(parse(&parser, "+2E3"), Some(2000.));
+    }
+
+    #[test]
+    fn test_parse_comma_decimal_locale() {
+        let parser = ColumnCellValueParser::new(NumberLocale::CommaDecimal, vec![], false);
+        assert_eq!(parse(&parser, "1,5"), Some(1.5));
+        assert_eq!(parse(&parser, "1,5E-3"), Some(1.5e-3));
+        assert_eq!(parse(&parser, "1.234,5"), Some(1234.5));
+    }
+
+    #[test]
+    fn test_parse_full_width() {
+        let parser = ColumnCellValueParser::default();
+        assert_eq!(parse(&parser, "１．５"), Some(1.5));
+        assert_eq!(parse(&parser, "－１，０００"), Some(-1000.));
+        assert_eq!(parse(&parser, "　２Ｅ３　"), Some(2000.));
+    }
+
+    #[test]
+    fn test_parse_special_values() {
+        let parser = ColumnCellValueParser::default();
+        assert!(parse(&parser, "NaN").unwrap().is_nan());
+        assert!(parse(&parser, "1.#QNAN").unwrap().is_nan());
+        assert_eq!(parse(&parser, "Inf"), Some(f64::INFINITY));
+        assert_eq!(parse(&parser, "-Infinity"), Some(f64::NEG_INFINITY));
+        assert_eq!(parse(&parser, "∞"), Some(f64::INFINITY));
+        assert_eq!(parse(&parser, "-1.#INF"), Some(f64::NEG_INFINITY));
+    }
+
+    #[test]
+    fn test_parse_si_suffix() {
+        let parser = ColumnCellValueParser::new(NumberLocale::Standard, vec![], true);
+        assert_eq!(parse(&parser, "3.3k"), Some(3300.));
+        assert_eq!(parse(&parser, "10m"), Some(0.01));
+        assert_eq!(parse(&parser, "2.2µ"), Some(2.2e-6));
+        assert_eq!(parse(&parser, "1M"), Some(1e6));
+        assert_eq!(parse(&parser, "1E"), Some(1e18));
+        assert_eq!(parse(&parser, "1e3k"), Some(1e6));
+
+        // SI 接頭辞を許可しない場合はエラー
+        let parser = ColumnCellValueParser::default();
+        assert!(parser.parse("3.3k").is_err());
+    }
+
+    #[test]
+    fn test_parse_missing_value_tokens() {
+        let parser = ColumnCellValueParser::new(
+            NumberLocale::Standard,
+            vec!["NA".to_string(), "-".to_string(), "#N/A".to_string()],
+            false,
+        );
+        assert_eq!(parse(&parser, "na"), None);
+        assert_eq!(parse(&parser, " - "), None);
+        assert_eq!(parse(&parser, "#N/A"), None);
+        assert_eq!(parse(&parser, ""), None);
+        assert_eq!(parse(&parser, "1"), Some(1.));
+    }
+
+    #[test]
+    fn test_parse_error_reports_character() {
+        let parser = ColumnCellValueParser::default();
+
+        match parser.parse("1.2x") {
+            Err(ColumnCellValueError::InvalidCharacter(input, character, position)) => {
+                assert_eq!(input, "1.2x");
+                assert_eq!(character, 'x');
+                assert_eq!(position, 3);
+            }
+            _ => panic!("unexpected result"),
+        }
+
+        // 3 桁区切りになっていない桁区切り記号
+        match parser.parse("1,5") {
+            Err(ColumnCellValueError::InvalidCharacter(_, character, position)) => {
+                assert_eq!(character, ',');
+                assert_eq!(position, 1);
+            }
+            _ => panic!("unexpected result"),
+        }
+
+        match parser.parse("1,2345") {
+            Err(ColumnCellValueError::InvalidCharacter(_, character, position)) => {
+                assert_eq!(character, '5');
+                assert_eq!(position, 5);
+            }
+            _ => panic!("unexpected result"),
+        }
+
+        match parser.parse("-") {
+            Err(ColumnCellValueError::UnexpectedEnd(_)) => {}
+            _ => panic!("unexpected result"),
+        }
+    }
+}
@@ -3,4 +3,7 @@ pub mod column_cell;
 
 // 値オブジェクト
 pub mod column_cell_id;
-pub mod column_cell_value;
\ No newline at end of file
+pub mod column_cell_value;
+
+// パーサ
+pub mod column_cell_value_parser;
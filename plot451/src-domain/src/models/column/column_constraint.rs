@@ -0,0 +1,76 @@
+use std::{fmt::Display, hash::Hash};
+
+use super::column_cell::column_cell_value::CellRawValue;
+
+// value object
+// カラムに設定する値の制約
+#[derive(Debug, Clone)]
+pub enum ColumnConstraint {
+    // 空セルを許可しない
+    NonNull,
+    // 下限 (境界値を含む)
+    Min(f64),
+    // 上限 (境界値を含む)
+    Max(f64),
+    // 空でないセルの値が狭義単調増加
+    StrictlyIncreasing,
+    // 整数のみ
+    IntegerOnly,
+}
+
+impl ColumnConstraint {
+    // 単一セルの値が制約を満たすか
+    // (StrictlyIncreasing のようにセル同士の比較が必要な制約は常に true を返す)
+    pub fn accepts(&self, value: &CellRawValue) -> bool {
+        match (self, value) {
+            (ColumnConstraint::NonNull, None) => false,
+            (ColumnConstraint::NonNull, Some(value)) => !value.is_nan(),
+            (_, None) => true,
+            (ColumnConstraint::Min(min), Some(value)) => *value >= *min,
+            (ColumnConstraint::Max(max), Some(value)) => *value <= *max,
+            (ColumnConstraint::StrictlyIncreasing, Some(_)) => true,
+            (ColumnConstraint::IntegerOnly, Some(value)) => {
+                value.is_finite() && value.fract() == 0.
+            }
+        }
+    }
+}
+
+impl PartialEq for ColumnConstraint {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (ColumnConstraint::NonNull, ColumnConstraint::NonNull) => true,
+            (ColumnConstraint::Min(a), ColumnConstraint::Min(b)) => a.to_bits() == b.to_bits(),
+            (ColumnConstraint::Max(a), ColumnConstraint::Max(b)) => a.to_bits() == b.to_bits(),
+            (ColumnConstraint::StrictlyIncreasing, ColumnConstraint::StrictlyIncreasing) => true,
+            (ColumnConstraint::IntegerOnly, ColumnConstraint::IntegerOnly) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for ColumnConstraint {}
+
+impl Hash for ColumnConstraint {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            ColumnConstraint::Min(value) | ColumnConstraint::Max(value) => {
+                value.to_bits().hash(state)
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Display for ColumnConstraint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColumnConstraint::NonNull => write!(f, "non null"),
+            ColumnConstraint::Min(min) => write!(f, "min {}", min),
+            ColumnConstraint::Max(max) => write!(f, "max {}", max),
+            ColumnConstraint::StrictlyIncreasing => write!(f, "strictly increasing"),
+            ColumnConstraint::IntegerOnly => write!(f, "integer only"),
+        }
+    }
+}
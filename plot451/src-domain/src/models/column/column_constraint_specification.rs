@@ -0,0 +1,343 @@
+use thiserror::Error;
+
+use crate::shared::{specification::Specification, value_object::ValueObject};
+
+use super::{
+    column_cell::{column_cell_id::ColumnCellId, column_cell_value::CellRawValue},
+    column_constraint::ColumnConstraint,
+    column_with_cells::ColumnWithCells,
+};
+
+// 1 つの制約に対する仕様
+pub struct ColumnConstraintSpecification {
+    constraint: ColumnConstraint,
+}
+
+impl ColumnConstraintSpecification {
+    pub fn new(constraint: ColumnConstraint) -> Self {
+        Self { constraint }
+    }
+
+    // 制約に違反した行の番号
+    fn violated_rows<'v>(&self, values: impl Iterator<Item = &'v CellRawValue>) -> Vec<usize> {
+        match self.constraint {
+            // 直前の空でないセルの値以下であれば違反
+            ColumnConstraint::StrictlyIncreasing => {
+                let mut previous: Option<f64> = None;
+                let mut rows = vec![];
+                for (row, value) in values.enumerate() {
+                    let Some(value) = *value else {
+                        continue;
+                    };
+                    if let Some(previous) = previous {
+                        if value.is_nan() || value <= previous {
+                            rows.push(row);
+                        }
+                    }
+                    previous = Some(value);
+                }
+                rows
+            }
+            _ => values
+                .enumerate()
+                .filter(|(_, value)| !self.constraint.accepts(value))
+                .map(|(row, _)| row)
+                .collect(),
+        }
+    }
+}
+
+impl Specification for ColumnConstraintSpecification {
+    type T = ColumnWithCells;
+    type Error = ColumnConstraintViolation;
+
+    fn is_satisfied_by(
+        &self,
+        column_with_cells: &ColumnWithCells,
+    ) -> Result<(), ColumnConstraintViolation> {
+        let cells = column_with_cells.cells();
+        let cell_ids: Vec<ColumnCellId> = self
+            .violated_rows(cells.iter().map(|cell| cell.cell_value().value()))
+            .into_iter()
+            .map(|row| cells[row].id().clone())
+            .collect();
+        if cell_ids.is_empty() {
+            Ok(())
+        } else {
+            Err(ColumnConstraintViolation::new(
+                self.constraint.clone(),
+                cell_ids,
+            ))
+        }
+    }
+}
+
+// カラムに設定されたすべての制約に対する仕様
+pub struct ColumnConstraintsSpecification {
+    constraints: Vec<ColumnConstraint>,
+}
+
+impl ColumnConstraintsSpecification {
+    pub fn new(constraints: Vec<ColumnConstraint>) -> Self {
+        Self { constraints }
+    }
+}
+
+impl Specification for ColumnConstraintsSpecification {
+    type T = ColumnWithCells;
+    type Error = ColumnConstraintSpecificationError;
+
+    fn is_satisfied_by(
+        &self,
+        column_with_cells: &ColumnWithCells,
+    ) -> Result<(), ColumnConstraintSpecificationError> {
+        let violations: Vec<ColumnConstraintViolation> = self
+            .constraints
+            .iter()
+            .filter_map(|constraint| {
+                ColumnConstraintSpecification::new(constraint.clone())
+                    .is_satisfied_by(column_with_cells)
+                    .err()
+            })
+            .collect();
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(ColumnConstraintSpecificationError::ConstraintsViolated(
+                violations,
+            ))
+        }
+    }
+}
+
+// 永続化前の値 (セルの id がまだない) に対するすべての制約の仕様
+// 違反した場合は行番号で報告する
+pub struct ColumnValuesConstraintsSpecification {
+    constraints: Vec<ColumnConstraint>,
+}
+
+impl ColumnValuesConstraintsSpecification {
+    pub fn new(constraints: Vec<ColumnConstraint>) -> Self {
+        Self { constraints }
+    }
+}
+
+impl Specification for ColumnValuesConstraintsSpecification {
+    type T = Vec<CellRawValue>;
+    type Error = ColumnConstraintSpecificationError;
+
+    fn is_satisfied_by(
+        &self,
+        values: &Vec<CellRawValue>,
+    ) -> Result<(), ColumnConstraintSpecificationError> {
+        let violations: Vec<ColumnConstraintRowViolation> = self
+            .constraints
+            .iter()
+            .filter_map(|constraint| {
+                let rows = ColumnConstraintSpecification::new(constraint.clone())
+                    .violated_rows(values.iter());
+                (!rows.is_empty()).then(|| ColumnConstraintRowViolation {
+                    constraint: constraint.clone(),
+                    rows,
+                })
+            })
+            .collect();
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(ColumnConstraintSpecificationError::RowsViolated(violations))
+        }
+    }
+}
+
+// 制約違反 (違反した制約と違反したセルの id)
+#[derive(Debug, Clone, PartialEq, Error)]
+#[error("constraint '{constraint}' is violated, {} cells", cell_ids.len())]
+pub struct ColumnConstraintViolation {
+    constraint: ColumnConstraint,
+    cell_ids: Vec<ColumnCellId>,
+}
+
+impl ColumnConstraintViolation {
+    pub fn new(constraint: ColumnConstraint, cell_ids: Vec<ColumnCellId>) -> Self {
+        Self {
+            constraint,
+            cell_ids,
+        }
+    }
+
+    pub fn constraint(&self) -> &ColumnConstraint {
+        &self.constraint
+    }
+
+    pub fn cell_ids(&self) -> &Vec<ColumnCellId> {
+        &self.cell_ids
+    }
+}
+
+// 永続化前の値の制約違反 (違反した制約と違反した行番号)
+#[derive(Debug, Clone, PartialEq, Error)]
+#[error("constraint '{constraint}' is violated, {} rows", rows.len())]
+pub struct ColumnConstraintRowViolation {
+    constraint: ColumnConstraint,
+    rows: Vec<usize>,
+}
+
+impl ColumnConstraintRowViolation {
+    pub fn constraint(&self) -> &ColumnConstraint {
+        &self.constraint
+    }
+
+    pub fn rows(&self) -> &Vec<usize> {
+        &self.rows
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ColumnConstraintSpecificationError {
+    #[error("column constraints are violated: {0:?}")]
+    ConstraintsViolated(Vec<ColumnConstraintViolation>),
+    #[error("column constraints are violated: {0:?}")]
+    RowsViolated(Vec<ColumnConstraintRowViolation>),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::column::column::Column;
+    use crate::models::column::column_cell::column_cell::ColumnCell;
+    use crate::models::column::column_cell::column_cell_value::ColumnCellValue;
+    use crate::models::column::column_directory::column_directory_id::ColumnDirectoryId;
+    use crate::models::column::column_id::ColumnId;
+    use crate::models::column::column_name::ColumnName;
+    use crate::shared::value_object::ValueObject;
+
+    fn column_with_cells(values: Vec<Option<f64>>) -> anyhow::Result<ColumnWithCells> {
+        let mut cells = vec![];
+        for (i, value) in values.into_iter().enumerate() {
+            cells.push(ColumnCell::new(
+                Some(ColumnCellId::new(format!("cell_id_{}", i))?),
+                ColumnCellValue::new(value)?,
+            ));
+        }
+        let column = Column::new(
+            Some(ColumnId::new("column_id".to_string())?),
+            ColumnName::new("column".to_string())?,
+            ColumnDirectoryId::new("0".to_string())?,
+            cells.iter().map(|cell| cell.id().clone()).collect(),
+        );
+        Ok(ColumnWithCells::new(&column, cells))
+    }
+
+    fn violated_cell_ids(
+        constraint: ColumnConstraint,
+        column_with_cells: &ColumnWithCells,
+    ) -> Vec<String> {
+        match ColumnConstraintSpecification::new(constraint).is_satisfied_by(column_with_cells) {
+            Ok(()) => vec![],
+            Err(violation) => violation
+                .cell_ids()
+                .iter()
+                .map(|cell_id| cell_id.clone_value())
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_non_null() -> anyhow::Result<()> {
+        let column = column_with_cells(vec![Some(1.), None, Some(f64::NAN)])?;
+        assert_eq!(
+            violated_cell_ids(ColumnConstraint::NonNull, &column),
+            vec!["cell_id_1", "cell_id_2"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_bounds() -> anyhow::Result<()> {
+        let column = column_with_cells(vec![Some(-1.), Some(0.), Some(10.), None, Some(11.)])?;
+        assert_eq!(
+            violated_cell_ids(ColumnConstraint::Min(0.), &column),
+            vec!["cell_id_0"]
+        );
+        assert_eq!(
+            violated_cell_ids(ColumnConstraint::Max(10.), &column),
+            vec!["cell_id_4"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_strictly_increasing() -> anyhow::Result<()> {
+        let column = column_with_cells(vec![
+            Some(1.),
+            None,
+            Some(2.),
+            Some(2.),
+            Some(0.5),
+            Some(3.),
+        ])?;
+        assert_eq!(
+            violated_cell_ids(ColumnConstraint::StrictlyIncreasing, &column),
+            vec!["cell_id_3", "cell_id_4"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_integer_only() -> anyhow::Result<()> {
+        let column = column_with_cells(vec![Some(1.), Some(1.5), None, Some(-3.)])?;
+        assert_eq!(
+            violated_cell_ids(ColumnConstraint::IntegerOnly, &column),
+            vec!["cell_id_1"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_constraints_specification() -> anyhow::Result<()> {
+        let column = column_with_cells(vec![Some(1.), None, Some(0.5)])?;
+
+        let specification = ColumnConstraintsSpecification::new(vec![
+            ColumnConstraint::NonNull,
+            ColumnConstraint::Min(0.),
+            ColumnConstraint::IntegerOnly,
+        ]);
+        match specification.is_satisfied_by(&column) {
+            Err(ColumnConstraintSpecificationError::ConstraintsViolated(violations)) => {
+                assert_eq!(violations.len(), 2);
+                assert_eq!(violations[0].constraint(), &ColumnConstraint::NonNull);
+                assert_eq!(violations[1].constraint(), &ColumnConstraint::IntegerOnly);
+            }
+            _ => panic!("unexpected result"),
+        }
+
+        let specification = ColumnConstraintsSpecification::new(vec![ColumnConstraint::Max(1.)]);
+        assert!(specification.is_satisfied_by(&column).is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn test_values_constraints_specification() {
+        let specification = ColumnValuesConstraintsSpecification::new(vec![
+            ColumnConstraint::NonNull,
+            ColumnConstraint::StrictlyIncreasing,
+        ]);
+        match specification.is_satisfied_by(&vec![Some(1.), None, Some(3.), Some(2.)]) {
+            Err(ColumnConstraintSpecificationError::RowsViolated(violations)) => {
+                assert_eq!(violations.len(), 2);
+                assert_eq!(violations[0].constraint(), &ColumnConstraint::NonNull);
+                assert_eq!(violations[0].rows(), &vec![1]);
+                assert_eq!(
+                    violations[1].constraint(),
+                    &ColumnConstraint::StrictlyIncreasing
+                );
+                assert_eq!(violations[1].rows(), &vec![3]);
+            }
+            _ => panic!("unexpected result"),
+        }
+        assert!(specification
+            .is_satisfied_by(&vec![Some(1.), Some(2.)])
+            .is_ok());
+    }
+}
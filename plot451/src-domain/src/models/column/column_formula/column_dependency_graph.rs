@@ -0,0 +1,223 @@
+use std::collections::{HashMap, HashSet};
+
+use thiserror::Error;
+
+use crate::{
+    models::column::{column::Column, column_id::ColumnId},
+    shared::value_object::ValueObject,
+};
+
+// ファーストクラスコレクション
+// 派生カラムとその参照先カラムの依存関係
+pub struct ColumnDependencyGraph {
+    // 派生カラム -> 参照先カラム
+    dependencies: HashMap<ColumnId, Vec<ColumnId>>,
+}
+
+impl ColumnDependencyGraph {
+    pub fn new(columns: &[Column]) -> Self {
+        let dependencies = columns
+            .iter()
+            .filter_map(|column| {
+                column
+                    .formula()
+                    .as_ref()
+                    .map(|formula| (column.id().clone(), formula.dependencies()))
+            })
+            .collect();
+        Self { dependencies }
+    }
+
+    pub fn dependencies_of(&self, column_id: &ColumnId) -> &[ColumnId] {
+        self.dependencies
+            .get(column_id)
+            .map(|dependencies| dependencies.as_slice())
+            .unwrap_or(&[])
+    }
+
+    // column_id の参照先を new_dependencies に置き換えたときに循環が生じるか
+    // 循環が生じる場合は column_id から column_id に戻るまでの経路を返す
+    pub fn find_cycle(
+        &self,
+        column_id: &ColumnId,
+        new_dependencies: &[ColumnId],
+    ) -> Option<Vec<ColumnId>> {
+        let mut visited = HashSet::new();
+        for dependency in new_dependencies {
+            let mut path = vec![column_id.clone()];
+            if self.find_path(dependency, column_id, &mut visited, &mut path) {
+                return Some(path);
+            }
+        }
+        None
+    }
+
+    // from から to への参照の経路を深さ優先で探索する
+    fn find_path(
+        &self,
+        from: &ColumnId,
+        to: &ColumnId,
+        visited: &mut HashSet<ColumnId>,
+        path: &mut Vec<ColumnId>,
+    ) -> bool {
+        path.push(from.clone());
+        if from == to {
+            return true;
+        }
+        if visited.insert(from.clone()) {
+            for dependency in self.dependencies_of(from) {
+                if self.find_path(dependency, to, visited, path) {
+                    return true;
+                }
+            }
+        }
+        path.pop();
+        false
+    }
+
+    // changed を直接または間接に参照する派生カラムを再計算すべき順に返す
+    // changed のうち派生カラムであるものも再計算の対象に含める
+    pub fn dependents_in_order(
+        &self,
+        changed: &[ColumnId],
+    ) -> Result<Vec<ColumnId>, ColumnDependencyGraphError> {
+        // 逆向きの辺 (参照先 -> 派生カラム)
+        let mut dependents: HashMap<&ColumnId, Vec<&ColumnId>> = HashMap::new();
+        for (column_id, dependencies) in &self.dependencies {
+            for dependency in dependencies {
+                dependents.entry(dependency).or_default().push(column_id);
+            }
+        }
+
+        // changed から到達可能な派生カラム
+        let mut affected: HashSet<&ColumnId> = changed
+            .iter()
+            .filter(|column_id| self.dependencies.contains_key(*column_id))
+            .collect();
+        let mut stack: Vec<&ColumnId> = changed.iter().collect();
+        while let Some(column_id) = stack.pop() {
+            for dependent in dependents.get(column_id).into_iter().flatten() {
+                if affected.insert(dependent) {
+                    stack.push(dependent);
+                }
+            }
+        }
+
+        // 影響を受ける派生カラムのみでトポロジカルソート
+        let mut order = vec![];
+        let mut done: HashSet<&ColumnId> = HashSet::new();
+        let mut remaining: Vec<&ColumnId> = affected.iter().copied().collect();
+        remaining.sort_by(|a, b| a.value().cmp(b.value()));
+        while !remaining.is_empty() {
+            let (ready, blocked): (Vec<&ColumnId>, Vec<&ColumnId>) =
+                remaining.into_iter().partition(|column_id| {
+                    self.dependencies_of(column_id).iter().all(|dependency| {
+                        !affected.contains(dependency) || done.contains(dependency)
+                    })
+                });
+            if ready.is_empty() {
+                return Err(ColumnDependencyGraphError::CyclicDependency(
+                    blocked.into_iter().cloned().collect(),
+                ));
+            }
+            for column_id in ready {
+                done.insert(column_id);
+                order.push(column_id.clone());
+            }
+            remaining = blocked;
+        }
+        Ok(order)
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ColumnDependencyGraphError {
+    #[error("cyclic dependency among columns: {0:?}")]
+    CyclicDependency(Vec<ColumnId>),
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::column::{
+        column_directory::column_directory_id::ColumnDirectoryId,
+        column_formula::column_formula::ColumnFormula, column_name::ColumnName,
+    };
+
+    use super::*;
+
+    fn id(id: &str) -> ColumnId {
+        ColumnId::new(id.to_string()).unwrap()
+    }
+
+    fn column(column_id: &str, formula: Option<&str>) -> Column {
+        let mut column = Column::new(
+            Some(id(column_id)),
+            ColumnName::new(format!("column_{}", column_id)).unwrap(),
+            ColumnDirectoryId::new("0".to_string()).unwrap(),
+            vec![],
+        );
+        column.change_formula(
+            formula.map(|formula| ColumnFormula::new(formula.to_string()).unwrap()),
+        );
+        column
+    }
+
+    // 1 <- 2 <- 4, 1 <- 3 <- 4, 5 は独立
+    fn graph() -> ColumnDependencyGraph {
+        ColumnDependencyGraph::new(&[
+            column("1", None),
+            column("2", Some("col_id('1') * 2")),
+            column("3", Some("col_id('1') + 1")),
+            column("4", Some("col_id('3') - col_id('2')")),
+            column("5", None),
+        ])
+    }
+
+    #[test]
+    fn test_dependents_in_order() -> anyhow::Result<()> {
+        let graph = graph();
+        assert_eq!(
+            graph.dependents_in_order(&[id("1")])?,
+            vec![id("2"), id("3"), id("4")]
+        );
+        assert_eq!(
+            graph.dependents_in_order(&[id("3")])?,
+            vec![id("3"), id("4")]
+        );
+        assert!(graph.dependents_in_order(&[id("5")])?.is_empty());
+        assert_eq!(
+            graph.dependents_in_order(&[id("5"), id("2")])?,
+            vec![id("2"), id("4")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let graph = graph();
+        // 1 が 4 を参照すると 1 -> 4 -> 3 -> 1 の循環になる
+        assert_eq!(
+            graph.find_cycle(&id("1"), &[id("5"), id("4")]),
+            Some(vec![id("1"), id("4"), id("3"), id("1")])
+        );
+        // 自己参照
+        assert_eq!(
+            graph.find_cycle(&id("5"), &[id("5")]),
+            Some(vec![id("5"), id("5")])
+        );
+        assert_eq!(graph.find_cycle(&id("5"), &[id("4")]), None);
+    }
+
+    #[test]
+    fn test_cyclic_dependency() {
+        let graph = ColumnDependencyGraph::new(&[
+            column("1", None),
+            column("2", Some("col_id('1') + col_id('3')")),
+            column("3", Some("col_id('2')")),
+        ]);
+        assert!(matches!(
+            graph.dependents_in_order(&[id("1")]),
+            Err(ColumnDependencyGraphError::CyclicDependency(_))
+        ));
+    }
+}
@@ -0,0 +1,108 @@
+use std::{collections::HashMap, fmt::Display, hash::Hash};
+
+use crate::{
+    models::column::{
+        column::Column, column_cell::column_cell_value::CellRawValue, column_id::ColumnId,
+    },
+    shared::value_object::ValueObject,
+};
+
+use super::formula_expression::{ColumnReference, FormulaExpression, FormulaExpressionError};
+
+// value object
+// 派生カラムの数式 (入力された文字列と構文木を保持する)
+#[derive(Debug, Clone)]
+pub struct ColumnFormula {
+    value: String,
+    expression: FormulaExpression,
+}
+
+impl ColumnFormula {
+    // 名前による参照を id による参照に解決する
+    pub fn resolve(&self, candidates: &[Column]) -> Result<Self, FormulaExpressionError> {
+        Ok(Self {
+            value: self.value.clone(),
+            expression: self.expression.resolve(candidates)?,
+        })
+    }
+
+    pub fn expression(&self) -> &FormulaExpression {
+        &self.expression
+    }
+
+    // 参照しているカラムの id (重複なし、出現順)
+    pub fn dependencies(&self) -> Vec<ColumnId> {
+        let mut dependencies = vec![];
+        for reference in self.expression.references() {
+            if let ColumnReference::Id(column_id) = reference {
+                if !dependencies.contains(column_id) {
+                    dependencies.push(column_id.clone());
+                }
+            }
+        }
+        dependencies
+    }
+
+    // すべての参照が id で解決済みか
+    pub fn is_resolved(&self) -> bool {
+        self.expression
+            .references()
+            .iter()
+            .all(|reference| matches!(reference, ColumnReference::Id(_)))
+    }
+
+    pub fn evaluate(
+        &self,
+        values: &HashMap<ColumnId, Vec<CellRawValue>>,
+    ) -> Result<Vec<CellRawValue>, FormulaExpressionError> {
+        self.expression.evaluate(values)
+    }
+}
+
+impl ValueObject for ColumnFormula {
+    type Value = String;
+    type Error = FormulaExpressionError;
+
+    fn new(value: String) -> Result<Self, FormulaExpressionError> {
+        let value = value.trim().to_string();
+        let expression = FormulaExpression::parse(&value)?;
+        // カラムの数式では変数を使用できない
+        if let Some((name, position)) = expression.variables_with_positions().first() {
+            return Err(FormulaExpressionError::UndefinedVariable(
+                name.to_string(),
+                *position,
+            ));
+        }
+        Ok(Self { value, expression })
+    }
+
+    fn value(&self) -> &Self::Value {
+        &self.value
+    }
+
+    fn clone_value(&self) -> Self::Value {
+        self.value.clone()
+    }
+}
+
+// 同じ文字列でも参照の解決先が異なれば別の数式とみなす
+impl PartialEq for ColumnFormula {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value && self.dependencies() == other.dependencies()
+    }
+}
+
+impl Eq for ColumnFormula {}
+
+impl Hash for ColumnFormula {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+        self.dependencies().hash(state);
+    }
+}
+
+impl Display for ColumnFormula {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
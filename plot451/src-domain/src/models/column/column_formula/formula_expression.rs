@@ -0,0 +1,875 @@
+use std::collections::HashMap;
+
+use thiserror::Error;
+
+use crate::{
+    models::column::{
+        column::Column, column_cell::column_cell_value::CellRawValue, column_id::ColumnId,
+    },
+    shared::value_object::ValueObject,
+};
+
+// 数式中のカラム参照
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColumnReference {
+    // col("名前")
+    Name(String),
+    // col_id("id")
+    Id(ColumnId),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOperator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Power,
+}
+
+impl BinaryOperator {
+    fn apply(&self, left: f64, right: f64) -> f64 {
+        match self {
+            BinaryOperator::Add => left + right,
+            BinaryOperator::Subtract => left - right,
+            BinaryOperator::Multiply => left * right,
+            BinaryOperator::Divide => left / right,
+            BinaryOperator::Power => left.powf(right),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormulaFunction {
+    Log10,
+    Ln,
+    Exp,
+    Sqrt,
+    Abs,
+    Sin,
+    Cos,
+    Tan,
+    Asin,
+    Acos,
+    Atan,
+    Pow,
+    Min,
+    Max,
+}
+
+impl FormulaFunction {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "log10" => Some(FormulaFunction::Log10),
+            "ln" | "log" => Some(FormulaFunction::Ln),
+            "exp" => Some(FormulaFunction::Exp),
+            "sqrt" => Some(FormulaFunction::Sqrt),
+            "abs" => Some(FormulaFunction::Abs),
+            "sin" => Some(FormulaFunction::Sin),
+            "cos" => Some(FormulaFunction::Cos),
+            "tan" => Some(FormulaFunction::Tan),
+            "asin" => Some(FormulaFunction::Asin),
+            "acos" => Some(FormulaFunction::Acos),
+            "atan" => Some(FormulaFunction::Atan),
+            "pow" => Some(FormulaFunction::Pow),
+            "min" => Some(FormulaFunction::Min),
+            "max" => Some(FormulaFunction::Max),
+            _ => None,
+        }
+    }
+
+    fn arity(&self) -> usize {
+        match self {
+            FormulaFunction::Pow | FormulaFunction::Min | FormulaFunction::Max => 2,
+            _ => 1,
+        }
+    }
+
+    fn apply(&self, args: &[f64]) -> f64 {
+        match self {
+            FormulaFunction::Log10 => args[0].log10(),
+            FormulaFunction::Ln => args[0].ln(),
+            FormulaFunction::Exp => args[0].exp(),
+            FormulaFunction::Sqrt => args[0].sqrt(),
+            FormulaFunction::Abs => args[0].abs(),
+            FormulaFunction::Sin => args[0].sin(),
+            FormulaFunction::Cos => args[0].cos(),
+            FormulaFunction::Tan => args[0].tan(),
+            FormulaFunction::Asin => args[0].asin(),
+            FormulaFunction::Acos => args[0].acos(),
+            FormulaFunction::Atan => args[0].atan(),
+            FormulaFunction::Pow => args[0].powf(args[1]),
+            FormulaFunction::Min => args[0].min(args[1]),
+            FormulaFunction::Max => args[0].max(args[1]),
+        }
+    }
+}
+
+// 数式の構文木
+#[derive(Debug, Clone, PartialEq)]
+pub enum FormulaExpression {
+    Number(f64),
+    Column(ColumnReference),
+    // 変数 (当てはめモデルのパラメータなど。カラムの数式では使用できない)
+    // 名前と数式中の位置
+    Variable(String, usize),
+    Negate(Box<FormulaExpression>),
+    Binary(
+        BinaryOperator,
+        Box<FormulaExpression>,
+        Box<FormulaExpression>,
+    ),
+    Function(FormulaFunction, Vec<FormulaExpression>),
+}
+
+impl FormulaExpression {
+    pub fn parse(source: &str) -> Result<Self, FormulaExpressionError> {
+        let tokens = tokenize(source)?;
+        let mut parser = Parser::new(tokens);
+        let expression = parser.parse_expression()?;
+        match parser.peek() {
+            None => Ok(expression),
+            Some((token, position)) => Err(FormulaExpressionError::UnexpectedToken(
+                token.to_string(),
+                *position,
+            )),
+        }
+    }
+
+    // 参照しているカラム (出現順)
+    pub fn references(&self) -> Vec<&ColumnReference> {
+        let mut references = vec![];
+        self.collect_references(&mut references);
+        references
+    }
+
+    fn collect_references<'a>(&'a self, references: &mut Vec<&'a ColumnReference>) {
+        match self {
+            FormulaExpression::Number(_) | FormulaExpression::Variable(..) => {}
+            FormulaExpression::Column(reference) => references.push(reference),
+            FormulaExpression::Negate(operand) => operand.collect_references(references),
+            FormulaExpression::Binary(_, left, right) => {
+                left.collect_references(references);
+                right.collect_references(references);
+            }
+            FormulaExpression::Function(_, args) => args
+                .iter()
+                .for_each(|arg| arg.collect_references(references)),
+        }
+    }
+
+    // 名前による参照を id による参照に置き換える
+    pub fn resolve(&self, candidates: &[Column]) -> Result<Self, FormulaExpressionError> {
+        Ok(match self {
+            FormulaExpression::Column(ColumnReference::Name(name)) => {
+                let found: Vec<&Column> = candidates
+                    .iter()
+                    .filter(|column| column.name().value() == name)
+                    .collect();
+                match found.as_slice() {
+                    [column] => FormulaExpression::Column(ColumnReference::Id(column.id().clone())),
+                    [] => return Err(FormulaExpressionError::UnresolvedColumnName(name.clone())),
+                    _ => return Err(FormulaExpressionError::AmbiguousColumnName(name.clone())),
+                }
+            }
+            FormulaExpression::Number(_)
+            | FormulaExpression::Column(_)
+            | FormulaExpression::Variable(..) => self.clone(),
+            FormulaExpression::Negate(operand) => {
+                FormulaExpression::Negate(Box::new(operand.resolve(candidates)?))
+            }
+            FormulaExpression::Binary(operator, left, right) => FormulaExpression::Binary(
+                *operator,
+                Box::new(left.resolve(candidates)?),
+                Box::new(right.resolve(candidates)?),
+            ),
+            FormulaExpression::Function(function, args) => FormulaExpression::Function(
+                *function,
+                args.iter()
+                    .map(|arg| arg.resolve(candidates))
+                    .collect::<Result<_, _>>()?,
+            ),
+        })
+    }
+
+    // 使用している変数 (重複なし、出現順)
+    pub fn variables(&self) -> Vec<&str> {
+        self.variables_with_positions()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect()
+    }
+
+    // 使用している変数と最初に現れる位置
+    pub(super) fn variables_with_positions(&self) -> Vec<(&str, usize)> {
+        let mut variables = vec![];
+        self.collect_variables(&mut variables);
+        variables
+    }
+
+    fn collect_variables<'a>(&'a self, variables: &mut Vec<(&'a str, usize)>) {
+        match self {
+            FormulaExpression::Number(_) | FormulaExpression::Column(_) => {}
+            FormulaExpression::Variable(name, position) => {
+                if !variables.iter().any(|(variable, _)| variable == name) {
+                    variables.push((name, *position));
+                }
+            }
+            FormulaExpression::Negate(operand) => operand.collect_variables(variables),
+            FormulaExpression::Binary(_, left, right) => {
+                left.collect_variables(variables);
+                right.collect_variables(variables);
+            }
+            FormulaExpression::Function(_, args) => {
+                args.iter().for_each(|arg| arg.collect_variables(variables))
+            }
+        }
+    }
+
+    // 変数に値を代入して評価する (カラムを参照する式は評価できない)
+    pub fn evaluate_with_variables(
+        &self,
+        variables: &HashMap<String, f64>,
+    ) -> Result<f64, FormulaExpressionError> {
+        Ok(match self {
+            FormulaExpression::Number(value) => *value,
+            FormulaExpression::Column(_) => {
+                return Err(FormulaExpressionError::UnexpectedColumnReference)
+            }
+            FormulaExpression::Variable(name, position) => {
+                *variables
+                    .get(name)
+                    .ok_or(FormulaExpressionError::UndefinedVariable(
+                        name.clone(),
+                        *position,
+                    ))?
+            }
+            FormulaExpression::Negate(operand) => -operand.evaluate_with_variables(variables)?,
+            FormulaExpression::Binary(operator, left, right) => {
+                let left = left.evaluate_with_variables(variables)?;
+                let right = right.evaluate_with_variables(variables)?;
+                operator.apply(left, right)
+            }
+            FormulaExpression::Function(function, args) => {
+                let args = args
+                    .iter()
+                    .map(|arg| arg.evaluate_with_variables(variables))
+                    .collect::<Result<Vec<f64>, _>>()?;
+                function.apply(&args)
+            }
+        })
+    }
+
+    // 行ごとに評価する (参照先のいずれかが None の行は None)
+    pub fn evaluate(
+        &self,
+        values: &HashMap<ColumnId, Vec<CellRawValue>>,
+    ) -> Result<Vec<CellRawValue>, FormulaExpressionError> {
+        if let Some((name, position)) = self.variables_with_positions().first() {
+            return Err(FormulaExpressionError::UndefinedVariable(
+                name.to_string(),
+                *position,
+            ));
+        }
+        let mut row_count = None;
+        for reference in self.references() {
+            match reference {
+                ColumnReference::Id(column_id) => {
+                    let column_values = values.get(column_id).ok_or(
+                        FormulaExpressionError::MissingColumnValues(column_id.clone()),
+                    )?;
+                    row_count = Some(row_count.unwrap_or(0).max(column_values.len()));
+                }
+                ColumnReference::Name(name) => {
+                    return Err(FormulaExpressionError::UnresolvedColumnName(name.clone()));
+                }
+            }
+        }
+        let row_count = row_count.ok_or(FormulaExpressionError::NoColumnReference)?;
+        Ok((0..row_count)
+            .map(|row| self.evaluate_row(values, row))
+            .collect())
+    }
+
+    pub(super) fn evaluate_row(
+        &self,
+        values: &HashMap<ColumnId, Vec<CellRawValue>>,
+        row: usize,
+    ) -> CellRawValue {
+        let value = match self {
+            FormulaExpression::Number(value) => *value,
+            FormulaExpression::Column(ColumnReference::Id(column_id)) => {
+                values.get(column_id)?.get(row).copied().flatten()?
+            }
+            FormulaExpression::Column(ColumnReference::Name(_))
+            | FormulaExpression::Variable(..) => return None,
+            FormulaExpression::Negate(operand) => -operand.evaluate_row(values, row)?,
+            FormulaExpression::Binary(operator, left, right) => {
+                let left = left.evaluate_row(values, row)?;
+                let right = right.evaluate_row(values, row)?;
+                operator.apply(left, right)
+            }
+            FormulaExpression::Function(function, args) => {
+                let args = args
+                    .iter()
+                    .map(|arg| arg.evaluate_row(values, row))
+                    .collect::<Option<Vec<f64>>>()?;
+                function.apply(&args)
+            }
+        };
+        // 0 除算や定義域外の計算結果は空セルとする
+        if value.is_finite() {
+            Some(value)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum Token {
+    Number(f64),
+    Identifier(String),
+    String(String),
+    Operator(char),
+    // 比較演算子と論理演算子 (条件式でのみ使用する)
+    Comparison(ComparisonOperator),
+    And,
+    Or,
+    Not,
+    LeftParen,
+    RightParen,
+    Comma,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComparisonOperator {
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    Equal,
+    NotEqual,
+}
+
+impl ComparisonOperator {
+    pub(super) fn apply(&self, left: f64, right: f64) -> bool {
+        match self {
+            ComparisonOperator::Less => left < right,
+            ComparisonOperator::LessOrEqual => left <= right,
+            ComparisonOperator::Greater => left > right,
+            ComparisonOperator::GreaterOrEqual => left >= right,
+            ComparisonOperator::Equal => left == right,
+            ComparisonOperator::NotEqual => left != right,
+        }
+    }
+}
+
+impl std::fmt::Display for ComparisonOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ComparisonOperator::Less => write!(f, "<"),
+            ComparisonOperator::LessOrEqual => write!(f, "<="),
+            ComparisonOperator::Greater => write!(f, ">"),
+            ComparisonOperator::GreaterOrEqual => write!(f, ">="),
+            ComparisonOperator::Equal => write!(f, "=="),
+            ComparisonOperator::NotEqual => write!(f, "!="),
+        }
+    }
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Number(value) => write!(f, "{}", value),
+            Token::Identifier(name) => write!(f, "{}", name),
+            Token::String(value) => write!(f, "\"{}\"", value),
+            Token::Operator(operator) => write!(f, "{}", operator),
+            Token::Comparison(operator) => write!(f, "{}", operator),
+            Token::And => write!(f, "&&"),
+            Token::Or => write!(f, "||"),
+            Token::Not => write!(f, "!"),
+            Token::LeftParen => write!(f, "("),
+            Token::RightParen => write!(f, ")"),
+            Token::Comma => write!(f, ","),
+        }
+    }
+}
+
+pub(super) fn tokenize(source: &str) -> Result<Vec<(Token, usize)>, FormulaExpressionError> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = vec![];
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let start = i;
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+        let token = if c.is_ascii_digit() || c == '.' {
+            while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                i += 1;
+            }
+            // 指数表記
+            if i < chars.len() && (chars[i] == 'e' || chars[i] == 'E') {
+                let mut j = i + 1;
+                if j < chars.len() && (chars[j] == '+' || chars[j] == '-') {
+                    j += 1;
+                }
+                if j < chars.len() && chars[j].is_ascii_digit() {
+                    i = j;
+                    while i < chars.len() && chars[i].is_ascii_digit() {
+                        i += 1;
+                    }
+                }
+            }
+            let literal: String = chars[start..i].iter().collect();
+            let value = literal
+                .parse::<f64>()
+                .map_err(|_| FormulaExpressionError::InvalidNumber(literal.clone(), start))?;
+            Token::Number(value)
+        } else if c.is_alphabetic() || c == '_' {
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            Token::Identifier(chars[start..i].iter().collect())
+        } else if c == '"' || c == '\'' {
+            i += 1;
+            while i < chars.len() && chars[i] != c {
+                i += 1;
+            }
+            if i == chars.len() {
+                return Err(FormulaExpressionError::UnterminatedString(start));
+            }
+            i += 1;
+            Token::String(chars[start + 1..i - 1].iter().collect())
+        } else {
+            i += 1;
+            // 2 文字の演算子
+            let next = chars.get(i).copied();
+            let two_chars = match (c, next) {
+                ('<', Some('=')) => Some(Token::Comparison(ComparisonOperator::LessOrEqual)),
+                ('>', Some('=')) => Some(Token::Comparison(ComparisonOperator::GreaterOrEqual)),
+                ('=', Some('=')) => Some(Token::Comparison(ComparisonOperator::Equal)),
+                ('!', Some('=')) => Some(Token::Comparison(ComparisonOperator::NotEqual)),
+                ('&', Some('&')) => Some(Token::And),
+                ('|', Some('|')) => Some(Token::Or),
+                _ => None,
+            };
+            if let Some(token) = two_chars {
+                i += 1;
+                tokens.push((token, start));
+                continue;
+            }
+            match c {
+                '+' | '-' | '*' | '/' | '^' => Token::Operator(c),
+                '<' => Token::Comparison(ComparisonOperator::Less),
+                '>' => Token::Comparison(ComparisonOperator::Greater),
+                '!' => Token::Not,
+                '(' => Token::LeftParen,
+                ')' => Token::RightParen,
+                ',' => Token::Comma,
+                _ => return Err(FormulaExpressionError::UnexpectedCharacter(c, start)),
+            }
+        };
+        tokens.push((token, start));
+    }
+    Ok(tokens)
+}
+
+// 再帰下降パーサ
+// expression := term (('+' | '-') term)*
+// term       := unary (('*' | '/') unary)*
+// unary      := ('-' | '+') unary | power
+// power      := primary ('^' unary)?
+// primary    := number | identifier | identifier '(' arguments ')' | '(' expression ')'
+// 括弧や単項演算子の入れ子の上限 (深い入れ子でスタックを使い切らないようにする)
+const MAX_NESTING_DEPTH: usize = 256;
+
+pub(super) struct Parser {
+    pub(super) tokens: Vec<(Token, usize)>,
+    pub(super) position: usize,
+    depth: usize,
+}
+
+impl Parser {
+    pub(super) fn new(tokens: Vec<(Token, usize)>) -> Self {
+        Self {
+            tokens,
+            position: 0,
+            depth: 0,
+        }
+    }
+
+    // 再帰の入れ子を 1 段深くする (上限を超えたらエラー)
+    pub(super) fn enter(&mut self) -> Result<(), FormulaExpressionError> {
+        if self.depth >= MAX_NESTING_DEPTH {
+            return Err(FormulaExpressionError::NestingTooDeep(MAX_NESTING_DEPTH));
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    pub(super) fn leave(&mut self) {
+        self.depth -= 1;
+    }
+
+    pub(super) fn peek(&self) -> Option<&(Token, usize)> {
+        self.tokens.get(self.position)
+    }
+
+    pub(super) fn next(&mut self) -> Result<(Token, usize), FormulaExpressionError> {
+        let token = self
+            .tokens
+            .get(self.position)
+            .cloned()
+            .ok_or(FormulaExpressionError::UnexpectedEnd)?;
+        self.position += 1;
+        Ok(token)
+    }
+
+    pub(super) fn expect(&mut self, expected: Token) -> Result<(), FormulaExpressionError> {
+        let (token, position) = self.next()?;
+        if token == expected {
+            Ok(())
+        } else {
+            Err(FormulaExpressionError::UnexpectedToken(
+                token.to_string(),
+                position,
+            ))
+        }
+    }
+
+    fn next_operator_in(&mut self, operators: &[char]) -> Option<char> {
+        match self.peek() {
+            Some((Token::Operator(operator), _)) if operators.contains(operator) => {
+                let operator = *operator;
+                self.position += 1;
+                Some(operator)
+            }
+            _ => None,
+        }
+    }
+
+    pub(super) fn parse_expression(&mut self) -> Result<FormulaExpression, FormulaExpressionError> {
+        let mut left = self.parse_term()?;
+        while let Some(operator) = self.next_operator_in(&['+', '-']) {
+            let right = self.parse_term()?;
+            let operator = if operator == '+' {
+                BinaryOperator::Add
+            } else {
+                BinaryOperator::Subtract
+            };
+            left = FormulaExpression::Binary(operator, Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn parse_term(&mut self) -> Result<FormulaExpression, FormulaExpressionError> {
+        let mut left = self.parse_unary()?;
+        while let Some(operator) = self.next_operator_in(&['*', '/']) {
+            let right = self.parse_unary()?;
+            let operator = if operator == '*' {
+                BinaryOperator::Multiply
+            } else {
+                BinaryOperator::Divide
+            };
+            left = FormulaExpression::Binary(operator, Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    // 括弧・関数の引数・指数はすべてここを通って再帰するので、入れ子の深さはここで数える
+    fn parse_unary(&mut self) -> Result<FormulaExpression, FormulaExpressionError> {
+        self.enter()?;
+        let expression = match self.next_operator_in(&['-', '+']) {
+            Some('-') => self
+                .parse_unary()
+                .map(|operand| FormulaExpression::Negate(Box::new(operand))),
+            Some(_) => self.parse_unary(),
+            None => self.parse_power(),
+        };
+        self.leave();
+        expression
+    }
+
+    fn parse_power(&mut self) -> Result<FormulaExpression, FormulaExpressionError> {
+        let base = self.parse_primary()?;
+        if self.next_operator_in(&['^']).is_some() {
+            let exponent = self.parse_unary()?;
+            return Ok(FormulaExpression::Binary(
+                BinaryOperator::Power,
+                Box::new(base),
+                Box::new(exponent),
+            ));
+        }
+        Ok(base)
+    }
+
+    fn parse_primary(&mut self) -> Result<FormulaExpression, FormulaExpressionError> {
+        let (token, position) = self.next()?;
+        match token {
+            Token::Number(value) => Ok(FormulaExpression::Number(value)),
+            Token::LeftParen => {
+                let expression = self.parse_expression()?;
+                self.expect(Token::RightParen)?;
+                Ok(expression)
+            }
+            Token::Identifier(name) => {
+                if !matches!(self.peek(), Some((Token::LeftParen, _))) {
+                    return match name.as_str() {
+                        "pi" => Ok(FormulaExpression::Number(std::f64::consts::PI)),
+                        "e" => Ok(FormulaExpression::Number(std::f64::consts::E)),
+                        _ => Ok(FormulaExpression::Variable(name, position)),
+                    };
+                }
+                self.position += 1;
+                match name.as_str() {
+                    "col" | "col_id" => {
+                        let reference = match self.next()? {
+                            (Token::String(value), _) if name == "col" => {
+                                ColumnReference::Name(value)
+                            }
+                            (Token::String(value), _) => {
+                                ColumnReference::Id(ColumnId::new(value).map_err(|e| {
+                                    FormulaExpressionError::ColumnIdError(e.to_string())
+                                })?)
+                            }
+                            (token, position) => {
+                                return Err(FormulaExpressionError::UnexpectedToken(
+                                    token.to_string(),
+                                    position,
+                                ))
+                            }
+                        };
+                        self.expect(Token::RightParen)?;
+                        Ok(FormulaExpression::Column(reference))
+                    }
+                    _ => {
+                        let function = FormulaFunction::from_name(&name).ok_or(
+                            FormulaExpressionError::UnknownFunction(name.clone(), position),
+                        )?;
+                        let mut args = vec![];
+                        if !matches!(self.peek(), Some((Token::RightParen, _))) {
+                            args.push(self.parse_expression()?);
+                            while matches!(self.peek(), Some((Token::Comma, _))) {
+                                self.position += 1;
+                                args.push(self.parse_expression()?);
+                            }
+                        }
+                        self.expect(Token::RightParen)?;
+                        if args.len() != function.arity() {
+                            return Err(FormulaExpressionError::WrongArgumentCount(
+                                name,
+                                function.arity(),
+                                args.len(),
+                            ));
+                        }
+                        Ok(FormulaExpression::Function(function, args))
+                    }
+                }
+            }
+            token => Err(FormulaExpressionError::UnexpectedToken(
+                token.to_string(),
+                position,
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum FormulaExpressionError {
+    #[error("unexpected character '{0}' at position {1}")]
+    UnexpectedCharacter(char, usize),
+    #[error("invalid number '{0}' at position {1}")]
+    InvalidNumber(String, usize),
+    #[error("unterminated string starting at position {0}")]
+    UnterminatedString(usize),
+    #[error("unexpected token '{0}' at position {1}")]
+    UnexpectedToken(String, usize),
+    #[error("unexpected end of formula")]
+    UnexpectedEnd,
+    #[error("undefined variable '{0}' at position {1}")]
+    UndefinedVariable(String, usize),
+    #[error("column reference is not allowed in this expression")]
+    UnexpectedColumnReference,
+    #[error("unknown function '{0}' at position {1}")]
+    UnknownFunction(String, usize),
+    #[error("function '{0}' takes {1} arguments but {2} were given")]
+    WrongArgumentCount(String, usize, usize),
+    #[error("ColumnIdError: [{0}]")]
+    ColumnIdError(String),
+    #[error("column name could not be resolved: {0}")]
+    UnresolvedColumnName(String),
+    #[error("column name is ambiguous: {0}")]
+    AmbiguousColumnName(String),
+    #[error("values of column are missing, column_id: {0}")]
+    MissingColumnValues(ColumnId),
+    #[error("formula does not refer to any column")]
+    NoColumnReference,
+    #[error("formula is nested more than {0} levels deep")]
+    NestingTooDeep(usize),
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::column::column_directory::column_directory_id::ColumnDirectoryId;
+    use crate::models::column::column_name::ColumnName;
+
+    use super::*;
+
+    fn column(id: &str, name: &str) -> Column {
+        Column::new(
+            Some(ColumnId::new(id.to_string()).unwrap()),
+            ColumnName::new(name.to_string()).unwrap(),
+            ColumnDirectoryId::new("0".to_string()).unwrap(),
+            vec![],
+        )
+    }
+
+    fn id(id: &str) -> ColumnId {
+        ColumnId::new(id.to_string()).unwrap()
+    }
+
+    #[test]
+    fn test_parse_precedence() -> anyhow::Result<()> {
+        let values = HashMap::from([(id("1"), vec![Some(2.)])]);
+        let evaluate = |source: &str| {
+            FormulaExpression::parse(source)
+                .unwrap()
+                .evaluate(&values)
+                .unwrap()[0]
+        };
+        assert_eq!(evaluate("1 + 2 * col_id(\"1\")"), Some(5.));
+        assert_eq!(evaluate("(1 + 2) * col_id('1')"), Some(6.));
+        assert_eq!(evaluate("-col_id('1')^2"), Some(-4.));
+        assert_eq!(evaluate("2^3^2 + 0 * col_id('1')"), Some(512.));
+        assert_eq!(evaluate("col_id('1') / 4 - 1e-1"), Some(0.4));
+        assert_eq!(evaluate("pow(col_id('1'), 3) + max(1, 2)"), Some(10.));
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolve_and_evaluate() -> anyhow::Result<()> {
+        let candidates = vec![column("1", "V"), column("2", "I"), column("3", "f")];
+        let expression =
+            FormulaExpression::parse("col(\"V\") / col(\"I\")")?.resolve(&candidates)?;
+        assert_eq!(
+            expression.references(),
+            vec![&ColumnReference::Id(id("1")), &ColumnReference::Id(id("2"))]
+        );
+
+        let values = HashMap::from([
+            (id("1"), vec![Some(1.), Some(4.), None, Some(1.)]),
+            (id("2"), vec![Some(2.), Some(2.), Some(1.), Some(0.)]),
+        ]);
+        // None を含む行、0 除算の行は None。短いカラムは None で補う
+        assert_eq!(
+            expression.evaluate(&values)?,
+            vec![Some(0.5), Some(2.), None, None]
+        );
+
+        let expression = FormulaExpression::parse("log10(col(\"f\"))")?.resolve(&candidates)?;
+        let values = HashMap::from([(id("3"), vec![Some(100.), Some(-1.)])]);
+        assert_eq!(expression.evaluate(&values)?, vec![Some(2.), None]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolve_errors() -> anyhow::Result<()> {
+        let candidates = vec![column("1", "V"), column("2", "V")];
+        assert!(matches!(
+            FormulaExpression::parse("col(\"V\")")?.resolve(&candidates),
+            Err(FormulaExpressionError::AmbiguousColumnName(_))
+        ));
+        assert!(matches!(
+            FormulaExpression::parse("col(\"I\")")?.resolve(&candidates),
+            Err(FormulaExpressionError::UnresolvedColumnName(_))
+        ));
+        assert!(matches!(
+            FormulaExpression::parse("1 + 2")?.evaluate(&HashMap::new()),
+            Err(FormulaExpressionError::NoColumnReference)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(matches!(
+            FormulaExpression::parse("1 + $"),
+            Err(FormulaExpressionError::UnexpectedCharacter('$', 4))
+        ));
+        assert!(matches!(
+            FormulaExpression::parse("1 +"),
+            Err(FormulaExpressionError::UnexpectedEnd)
+        ));
+        assert!(matches!(
+            FormulaExpression::parse("foo(1)"),
+            Err(FormulaExpressionError::UnknownFunction(_, 0))
+        ));
+        assert!(matches!(
+            FormulaExpression::parse("pow(1)"),
+            Err(FormulaExpressionError::WrongArgumentCount(_, 2, 1))
+        ));
+        assert!(matches!(
+            FormulaExpression::parse("col(\"V\""),
+            Err(FormulaExpressionError::UnexpectedEnd)
+        ));
+        assert!(matches!(
+            FormulaExpression::parse("(1 + 2))"),
+            Err(FormulaExpressionError::UnexpectedToken(_, 7))
+        ));
+    }
+
+    #[test]
+    fn test_evaluate_with_variables() -> anyhow::Result<()> {
+        let expression = FormulaExpression::parse("A * exp(-x / tau) + C")?;
+        assert_eq!(expression.variables(), vec!["A", "x", "tau", "C"]);
+        let variables: HashMap<String, f64> = [("A", 2.), ("x", 1.), ("tau", 1.), ("C", 0.5)]
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value))
+            .collect();
+        let value = expression.evaluate_with_variables(&variables)?;
+        assert!((value - (2. * (-1f64).exp() + 0.5)).abs() < 1e-12);
+
+        assert!(matches!(
+            FormulaExpression::parse("A * x + B")?.evaluate_with_variables(&variables),
+            Err(FormulaExpressionError::UndefinedVariable(name, 8)) if name == "B"
+        ));
+        assert!(matches!(
+            FormulaExpression::parse("A * col(\"x\")")?.evaluate_with_variables(&variables),
+            Err(FormulaExpressionError::UnexpectedColumnReference)
+        ));
+        // 変数を含む式はカラムの値で評価できない
+        assert!(matches!(
+            FormulaExpression::parse("1 + x")?.evaluate(&HashMap::new()),
+            Err(FormulaExpressionError::UndefinedVariable(name, 4)) if name == "x"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_nesting_limit() -> anyhow::Result<()> {
+        // 上限以内の入れ子は読める
+        let source = format!("{}1{}", "(".repeat(200), ")".repeat(200));
+        assert!(matches!(
+            FormulaExpression::parse(&source)?,
+            FormulaExpression::Number(value) if value == 1.
+        ));
+
+        // 深すぎる入れ子はスタックを使い切る前にエラーになる
+        for source in [
+            format!("{}1{}", "(".repeat(200_000), ")".repeat(200_000)),
+            format!("{}1", "-".repeat(200_000)),
+            format!("{}1", "2^".repeat(200_000)),
+            format!("{}1{}", "sqrt(".repeat(200_000), ")".repeat(200_000)),
+        ] {
+            assert!(matches!(
+                FormulaExpression::parse(&source),
+                Err(FormulaExpressionError::NestingTooDeep(256))
+            ));
+        }
+        Ok(())
+    }
+}
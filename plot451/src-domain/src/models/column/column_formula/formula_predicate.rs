@@ -0,0 +1,318 @@
+use std::collections::HashMap;
+
+use crate::models::column::{
+    column::Column, column_cell::column_cell_value::CellRawValue, column_id::ColumnId,
+};
+
+use super::formula_expression::{
+    ColumnReference, ComparisonOperator, FormulaExpression, FormulaExpressionError, Parser, Token,
+};
+
+// 行を選ぶ条件式の構文木
+// 例: col("T") > 300 && col("flag") == 1
+#[derive(Debug, Clone, PartialEq)]
+pub enum FormulaPredicate {
+    Compare(ComparisonOperator, FormulaExpression, FormulaExpression),
+    And(Box<FormulaPredicate>, Box<FormulaPredicate>),
+    Or(Box<FormulaPredicate>, Box<FormulaPredicate>),
+    Not(Box<FormulaPredicate>),
+}
+
+impl FormulaPredicate {
+    pub fn parse(source: &str) -> Result<Self, FormulaExpressionError> {
+        let tokens = super::formula_expression::tokenize(source)?;
+        let mut parser = Parser::new(tokens);
+        let predicate = parse_disjunction(&mut parser)?;
+        match parser.peek() {
+            None => Ok(predicate),
+            Some((token, position)) => Err(FormulaExpressionError::UnexpectedToken(
+                token.to_string(),
+                *position,
+            )),
+        }
+    }
+
+    // 参照しているカラム (出現順)
+    pub fn references(&self) -> Vec<&ColumnReference> {
+        self.expressions()
+            .into_iter()
+            .flat_map(|expression| expression.references())
+            .collect()
+    }
+
+    fn expressions(&self) -> Vec<&FormulaExpression> {
+        match self {
+            FormulaPredicate::Compare(_, left, right) => vec![left, right],
+            FormulaPredicate::And(left, right) | FormulaPredicate::Or(left, right) => {
+                let mut expressions = left.expressions();
+                expressions.extend(right.expressions());
+                expressions
+            }
+            FormulaPredicate::Not(operand) => operand.expressions(),
+        }
+    }
+
+    // 名前による参照を id による参照に解決する
+    pub fn resolve(&self, candidates: &[Column]) -> Result<Self, FormulaExpressionError> {
+        Ok(match self {
+            FormulaPredicate::Compare(operator, left, right) => FormulaPredicate::Compare(
+                *operator,
+                left.resolve(candidates)?,
+                right.resolve(candidates)?,
+            ),
+            FormulaPredicate::And(left, right) => FormulaPredicate::And(
+                Box::new(left.resolve(candidates)?),
+                Box::new(right.resolve(candidates)?),
+            ),
+            FormulaPredicate::Or(left, right) => FormulaPredicate::Or(
+                Box::new(left.resolve(candidates)?),
+                Box::new(right.resolve(candidates)?),
+            ),
+            FormulaPredicate::Not(operand) => {
+                FormulaPredicate::Not(Box::new(operand.resolve(candidates)?))
+            }
+        })
+    }
+
+    // 行ごとに条件を満たすかを判定する
+    // 値が None の行の比較は不定とし、不定のまま残った行は条件を満たさないものとする
+    pub fn evaluate(
+        &self,
+        values: &HashMap<ColumnId, Vec<CellRawValue>>,
+        row_count: usize,
+    ) -> Result<Vec<bool>, FormulaExpressionError> {
+        for expression in self.expressions() {
+            if let Some((name, position)) = expression.variables_with_positions().first() {
+                return Err(FormulaExpressionError::UndefinedVariable(
+                    name.to_string(),
+                    *position,
+                ));
+            }
+        }
+        for reference in self.references() {
+            match reference {
+                ColumnReference::Id(column_id) => {
+                    if !values.contains_key(column_id) {
+                        return Err(FormulaExpressionError::MissingColumnValues(
+                            column_id.clone(),
+                        ));
+                    }
+                }
+                ColumnReference::Name(name) => {
+                    return Err(FormulaExpressionError::UnresolvedColumnName(name.clone()));
+                }
+            }
+        }
+        Ok((0..row_count)
+            .map(|row| self.evaluate_row(values, row) == Some(true))
+            .collect())
+    }
+
+    fn evaluate_row(
+        &self,
+        values: &HashMap<ColumnId, Vec<CellRawValue>>,
+        row: usize,
+    ) -> Option<bool> {
+        match self {
+            FormulaPredicate::Compare(operator, left, right) => {
+                let left = left.evaluate_row(values, row)?;
+                let right = right.evaluate_row(values, row)?;
+                Some(operator.apply(left, right))
+            }
+            FormulaPredicate::And(left, right) => {
+                match (
+                    left.evaluate_row(values, row),
+                    right.evaluate_row(values, row),
+                ) {
+                    (Some(false), _) | (_, Some(false)) => Some(false),
+                    (Some(true), Some(true)) => Some(true),
+                    _ => None,
+                }
+            }
+            FormulaPredicate::Or(left, right) => {
+                match (
+                    left.evaluate_row(values, row),
+                    right.evaluate_row(values, row),
+                ) {
+                    (Some(true), _) | (_, Some(true)) => Some(true),
+                    (Some(false), Some(false)) => Some(false),
+                    _ => None,
+                }
+            }
+            FormulaPredicate::Not(operand) => operand.evaluate_row(values, row).map(|value| !value),
+        }
+    }
+}
+
+// 再帰下降パーサ (算術式は FormulaExpression のパーサで読む)
+// disjunction := conjunction ('||' conjunction)*
+// conjunction := negation ('&&' negation)*
+// negation    := '!' negation | comparison | '(' disjunction ')'
+// comparison  := expression comparison_operator expression
+fn parse_disjunction(parser: &mut Parser) -> Result<FormulaPredicate, FormulaExpressionError> {
+    let mut left = parse_conjunction(parser)?;
+    while matches!(parser.peek(), Some((Token::Or, _))) {
+        parser.position += 1;
+        let right = parse_conjunction(parser)?;
+        left = FormulaPredicate::Or(Box::new(left), Box::new(right));
+    }
+    Ok(left)
+}
+
+fn parse_conjunction(parser: &mut Parser) -> Result<FormulaPredicate, FormulaExpressionError> {
+    let mut left = parse_negation(parser)?;
+    while matches!(parser.peek(), Some((Token::And, _))) {
+        parser.position += 1;
+        let right = parse_negation(parser)?;
+        left = FormulaPredicate::And(Box::new(left), Box::new(right));
+    }
+    Ok(left)
+}
+
+// 入れ子の深さは算術式のパーサと同じカウンタで数える
+fn parse_negation(parser: &mut Parser) -> Result<FormulaPredicate, FormulaExpressionError> {
+    parser.enter()?;
+    let predicate = parse_negation_inner(parser);
+    parser.leave();
+    predicate
+}
+
+fn parse_negation_inner(parser: &mut Parser) -> Result<FormulaPredicate, FormulaExpressionError> {
+    if matches!(parser.peek(), Some((Token::Not, _))) {
+        parser.position += 1;
+        return Ok(FormulaPredicate::Not(Box::new(parse_negation(parser)?)));
+    }
+
+    // "(1 + 2) * x > 3" のような算術式の括弧と区別するため、比較として読めなければ
+    // 括弧で囲まれた条件式として読み直す
+    let start = parser.position;
+    match parse_comparison(parser) {
+        Ok(predicate) => Ok(predicate),
+        Err(error) => {
+            parser.position = start;
+            if !matches!(parser.peek(), Some((Token::LeftParen, _))) {
+                return Err(error);
+            }
+            parser.position += 1;
+            let predicate = parse_disjunction(parser)?;
+            parser.expect(Token::RightParen)?;
+            Ok(predicate)
+        }
+    }
+}
+
+fn parse_comparison(parser: &mut Parser) -> Result<FormulaPredicate, FormulaExpressionError> {
+    let left = parser.parse_expression()?;
+    match parser.next()? {
+        (Token::Comparison(operator), _) => {
+            let right = parser.parse_expression()?;
+            Ok(FormulaPredicate::Compare(operator, left, right))
+        }
+        (token, position) => Err(FormulaExpressionError::UnexpectedToken(
+            token.to_string(),
+            position,
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        models::column::{
+            column_directory::column_directory_id::ColumnDirectoryId, column_name::ColumnName,
+        },
+        shared::value_object::ValueObject,
+    };
+
+    use super::*;
+
+    fn column(id: &str, name: &str) -> Column {
+        Column::new(
+            Some(ColumnId::new(id.to_string()).unwrap()),
+            ColumnName::new(name.to_string()).unwrap(),
+            ColumnDirectoryId::new("0".to_string()).unwrap(),
+            vec![],
+        )
+    }
+
+    #[test]
+    fn test_parse_and_evaluate() -> anyhow::Result<()> {
+        let predicate = FormulaPredicate::parse("col(\"T\") > 300 && col(\"flag\") == 1")?;
+        assert!(matches!(predicate, FormulaPredicate::And(_, _)));
+        let predicate = predicate.resolve(&[column("1", "T"), column("2", "flag")])?;
+
+        let values = HashMap::from([
+            (
+                ColumnId::new("1".to_string())?,
+                vec![Some(310.), Some(290.), Some(320.), None, Some(400.)],
+            ),
+            (
+                ColumnId::new("2".to_string())?,
+                vec![Some(1.), Some(1.), Some(0.), Some(1.)],
+            ),
+        ]);
+        assert_eq!(
+            predicate.evaluate(&values, 5)?,
+            vec![true, false, false, false, false]
+        );
+
+        // 算術式の括弧と条件式の括弧、否定と論理和
+        let predicate =
+            FormulaPredicate::parse("!((col_id(\"1\") - 300) * 2 < 0 || col_id(\"2\") != 1)")?;
+        assert_eq!(
+            predicate.evaluate(&values, 5)?,
+            vec![true, false, false, false, false]
+        );
+        // 片方が偽なら値が None でも論理積は偽
+        let predicate = FormulaPredicate::parse("!(col_id(\"1\") > 0 && col_id(\"2\") > 5)")?;
+        assert_eq!(
+            predicate.evaluate(&values, 5)?,
+            vec![true, true, true, true, false]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(matches!(
+            FormulaPredicate::parse("col(\"T\") + 1"),
+            Err(FormulaExpressionError::UnexpectedEnd)
+        ));
+        assert!(matches!(
+            FormulaPredicate::parse("col(\"T\") > 1 &&"),
+            Err(FormulaExpressionError::UnexpectedEnd)
+        ));
+        assert!(matches!(
+            FormulaPredicate::parse("col(\"T\") = 1"),
+            Err(FormulaExpressionError::UnexpectedCharacter('=', 9))
+        ));
+        // 数式には比較演算子を書けない
+        assert!(matches!(
+            FormulaExpression::parse("col(\"T\") > 1"),
+            Err(FormulaExpressionError::UnexpectedToken(_, 9))
+        ));
+        assert!(matches!(
+            FormulaPredicate::parse("x > 1")
+                .unwrap()
+                .evaluate(&HashMap::new(), 1),
+            Err(FormulaExpressionError::UndefinedVariable(name, 0)) if name == "x"
+        ));
+    }
+
+    #[test]
+    fn test_parse_nesting_limit() -> anyhow::Result<()> {
+        assert!(FormulaPredicate::parse(&format!("{}(1 > 0)", "!".repeat(100))).is_ok());
+        for source in [
+            format!("{}(1 > 0)", "!".repeat(200_000)),
+            format!("{}1 > 0{}", "(".repeat(200_000), ")".repeat(200_000)),
+            // 条件式と算術式の入れ子は合わせて数える
+            format!("{}{}1 > 0", "!(".repeat(150), "-".repeat(150)),
+        ] {
+            assert!(matches!(
+                FormulaPredicate::parse(&source),
+                Err(FormulaExpressionError::NestingTooDeep(256))
+            ));
+        }
+        Ok(())
+    }
+}
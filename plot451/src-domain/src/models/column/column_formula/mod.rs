@@ -0,0 +1,11 @@
+// 値オブジェクト
+pub mod column_formula;
+
+// 数式の構文木とパーサ
+pub mod formula_expression;
+
+// 行を選ぶ条件式
+pub mod formula_predicate;
+
+// ファーストクラスコレクション
+pub mod column_dependency_graph;
@@ -0,0 +1,58 @@
+use super::{
+    column_id::ColumnId, column_smoothing::ColumnSmoothing,
+    column_transformation::ColumnTransformation,
+};
+
+// 解析の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AnalysisKind {
+    LeastSquaresFit,
+    NonlinearFit,
+    Differentiation,
+    Integration,
+    Resampling,
+    FourierTransform,
+    Histogram,
+    PeakDetection,
+    BaselineCorrection,
+}
+
+// value object
+// 解析で作成したカラムの生成元 (解析の種類と入力のカラム)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ColumnAnalysis {
+    kind: AnalysisKind,
+    sources: Vec<ColumnId>,
+}
+
+impl ColumnAnalysis {
+    pub fn new(kind: AnalysisKind, sources: Vec<ColumnId>) -> Self {
+        let mut unique_sources = vec![];
+        for source in sources {
+            if !unique_sources.contains(&source) {
+                unique_sources.push(source);
+            }
+        }
+        Self {
+            kind,
+            sources: unique_sources,
+        }
+    }
+
+    pub fn kind(&self) -> AnalysisKind {
+        self.kind
+    }
+
+    pub fn sources(&self) -> &Vec<ColumnId> {
+        &self.sources
+    }
+}
+
+// value object
+// 元のカラムから計算して作成したカラムの生成元
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ColumnProvenance {
+    Smoothing(ColumnSmoothing),
+    Transformation(ColumnTransformation),
+    Analysis(ColumnAnalysis),
+}
@@ -0,0 +1,212 @@
+use std::{fmt::Display, hash::Hash};
+
+use thiserror::Error;
+
+use super::column_id::ColumnId;
+
+// 平滑化フィルタ (窓幅は点数で、奇数)
+#[derive(Debug, Clone, Copy)]
+pub enum SmoothingFilter {
+    // 移動平均
+    MovingAverage {
+        window: usize,
+    },
+    // Savitzky–Golay フィルタ (窓内の点に多項式を当てはめた中心の値)
+    SavitzkyGolay {
+        window: usize,
+        polynomial_order: usize,
+    },
+    // ガウス核による加重平均 (σ は点数、窓は中心から ±3σ)
+    Gaussian {
+        sigma: f64,
+    },
+    // 移動中央値
+    Median {
+        window: usize,
+    },
+}
+
+impl SmoothingFilter {
+    // 窓の中心から端までの点数
+    pub fn half_width(&self) -> usize {
+        match self {
+            SmoothingFilter::MovingAverage { window }
+            | SmoothingFilter::SavitzkyGolay { window, .. }
+            | SmoothingFilter::Median { window } => window / 2,
+            SmoothingFilter::Gaussian { sigma } => (3. * sigma).ceil() as usize,
+        }
+    }
+}
+
+impl PartialEq for SmoothingFilter {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                SmoothingFilter::MovingAverage { window: a },
+                SmoothingFilter::MovingAverage { window: b },
+            )
+            | (SmoothingFilter::Median { window: a }, SmoothingFilter::Median { window: b }) => {
+                a == b
+            }
+            (
+                SmoothingFilter::SavitzkyGolay {
+                    window: a,
+                    polynomial_order: p,
+                },
+                SmoothingFilter::SavitzkyGolay {
+                    window: b,
+                    polynomial_order: q,
+                },
+            ) => a == b && p == q,
+            (SmoothingFilter::Gaussian { sigma: a }, SmoothingFilter::Gaussian { sigma: b }) => {
+                a.to_bits() == b.to_bits()
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Eq for SmoothingFilter {}
+
+impl Hash for SmoothingFilter {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            SmoothingFilter::MovingAverage { window } | SmoothingFilter::Median { window } => {
+                window.hash(state)
+            }
+            SmoothingFilter::SavitzkyGolay {
+                window,
+                polynomial_order,
+            } => {
+                window.hash(state);
+                polynomial_order.hash(state);
+            }
+            SmoothingFilter::Gaussian { sigma } => sigma.to_bits().hash(state),
+        }
+    }
+}
+
+impl Display for SmoothingFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SmoothingFilter::MovingAverage { window } => {
+                write!(f, "moving average (window {})", window)
+            }
+            SmoothingFilter::SavitzkyGolay {
+                window,
+                polynomial_order,
+            } => write!(
+                f,
+                "Savitzky-Golay (window {}, order {})",
+                window, polynomial_order
+            ),
+            SmoothingFilter::Gaussian { sigma } => write!(f, "Gaussian (sigma {})", sigma),
+            SmoothingFilter::Median { window } => write!(f, "median (window {})", window),
+        }
+    }
+}
+
+// 窓がデータの範囲外にはみ出す点の扱い
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EdgeHandling {
+    // None にする
+    Missing,
+    // 範囲内の点だけを使う
+    Shrink,
+    // 端の値で延長する
+    Nearest,
+    // 端の点を軸に折り返す
+    Reflect,
+}
+
+impl Display for EdgeHandling {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EdgeHandling::Missing => write!(f, "missing"),
+            EdgeHandling::Shrink => write!(f, "shrink"),
+            EdgeHandling::Nearest => write!(f, "nearest"),
+            EdgeHandling::Reflect => write!(f, "reflect"),
+        }
+    }
+}
+
+// value object
+// 平滑化で作成したカラムの生成元 (元のカラムと適用したフィルタ)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ColumnSmoothing {
+    source: ColumnId,
+    filter: SmoothingFilter,
+    edge_handling: EdgeHandling,
+}
+
+impl ColumnSmoothing {
+    pub fn new(
+        source: ColumnId,
+        filter: SmoothingFilter,
+        edge_handling: EdgeHandling,
+    ) -> Result<Self, ColumnSmoothingError> {
+        match filter {
+            SmoothingFilter::MovingAverage { window } | SmoothingFilter::Median { window } => {
+                Self::validate_window(window)?
+            }
+            SmoothingFilter::SavitzkyGolay {
+                window,
+                polynomial_order,
+            } => {
+                Self::validate_window(window)?;
+                if polynomial_order >= window {
+                    return Err(ColumnSmoothingError::InvalidPolynomialOrder(
+                        polynomial_order,
+                        window,
+                    ));
+                }
+            }
+            SmoothingFilter::Gaussian { sigma } => {
+                if !sigma.is_finite() || sigma <= 0. {
+                    return Err(ColumnSmoothingError::InvalidSigma(sigma));
+                }
+            }
+        }
+        Ok(Self {
+            source,
+            filter,
+            edge_handling,
+        })
+    }
+
+    fn validate_window(window: usize) -> Result<(), ColumnSmoothingError> {
+        if window.is_multiple_of(2) {
+            return Err(ColumnSmoothingError::InvalidWindow(window));
+        }
+        Ok(())
+    }
+
+    pub fn source(&self) -> &ColumnId {
+        &self.source
+    }
+
+    pub fn filter(&self) -> &SmoothingFilter {
+        &self.filter
+    }
+
+    pub fn edge_handling(&self) -> EdgeHandling {
+        self.edge_handling
+    }
+}
+
+impl Display for ColumnSmoothing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}, edge: {}", self.filter, self.edge_handling)
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ColumnSmoothingError {
+    #[error("window must be a positive odd number, but {0} is given")]
+    InvalidWindow(usize),
+    #[error("polynomial order {0} must be less than window {1}")]
+    InvalidPolynomialOrder(usize, usize),
+    #[error("sigma must be a positive number, but {0} is given")]
+    InvalidSigma(f64),
+}
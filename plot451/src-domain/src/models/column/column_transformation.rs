@@ -0,0 +1,144 @@
+use std::{fmt::Display, hash::Hash};
+
+use thiserror::Error;
+
+use super::column_id::ColumnId;
+
+// カラムの値の変換
+#[derive(Debug, Clone)]
+pub enum Transformation {
+    // 標準化 ((値 - 平均) / 標本標準偏差)
+    ZScore,
+    // 最小値を 0、最大値を 1 にする
+    MinMax,
+    // 絶対値の最大値で割る
+    NormalizeToMax,
+    // 面積 (台形公式) で割る (x を指定しない場合は行番号を x とする)
+    NormalizeToArea { x: Option<ColumnId> },
+    Log10,
+    Ln,
+    Exp,
+    Reciprocal,
+    // 定数を足す
+    Offset { value: f64 },
+    // 定数を掛ける
+    Scale { factor: f64 },
+    // 同じ行の別のカラムの値を掛ける
+    ScaleByColumn { column: ColumnId },
+}
+
+impl Transformation {
+    // 変換に必要な別のカラム
+    pub fn reference(&self) -> Option<&ColumnId> {
+        match self {
+            Transformation::NormalizeToArea { x } => x.as_ref(),
+            Transformation::ScaleByColumn { column } => Some(column),
+            _ => None,
+        }
+    }
+}
+
+impl PartialEq for Transformation {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                Transformation::NormalizeToArea { x: a },
+                Transformation::NormalizeToArea { x: b },
+            ) => a == b,
+            (Transformation::Offset { value: a }, Transformation::Offset { value: b })
+            | (Transformation::Scale { factor: a }, Transformation::Scale { factor: b }) => {
+                a.to_bits() == b.to_bits()
+            }
+            (
+                Transformation::ScaleByColumn { column: a },
+                Transformation::ScaleByColumn { column: b },
+            ) => a == b,
+            _ => std::mem::discriminant(self) == std::mem::discriminant(other),
+        }
+    }
+}
+
+impl Eq for Transformation {}
+
+impl Hash for Transformation {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Transformation::NormalizeToArea { x } => x.hash(state),
+            Transformation::Offset { value: constant }
+            | Transformation::Scale { factor: constant } => constant.to_bits().hash(state),
+            Transformation::ScaleByColumn { column } => column.hash(state),
+            _ => {}
+        }
+    }
+}
+
+impl Display for Transformation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Transformation::ZScore => write!(f, "z-score"),
+            Transformation::MinMax => write!(f, "min-max"),
+            Transformation::NormalizeToMax => write!(f, "normalized to max"),
+            Transformation::NormalizeToArea { .. } => write!(f, "normalized to area"),
+            Transformation::Log10 => write!(f, "log10"),
+            Transformation::Ln => write!(f, "ln"),
+            Transformation::Exp => write!(f, "exp"),
+            Transformation::Reciprocal => write!(f, "reciprocal"),
+            Transformation::Offset { value } => write!(f, "offset by {}", value),
+            Transformation::Scale { factor } => write!(f, "scaled by {}", factor),
+            Transformation::ScaleByColumn { .. } => write!(f, "scaled by column"),
+        }
+    }
+}
+
+// value object
+// 変換で作成したカラムの生成元 (元のカラムと適用した変換)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ColumnTransformation {
+    source: ColumnId,
+    transformation: Transformation,
+}
+
+impl ColumnTransformation {
+    pub fn new(
+        source: ColumnId,
+        transformation: Transformation,
+    ) -> Result<Self, ColumnTransformationError> {
+        if let Transformation::Offset { value: constant }
+        | Transformation::Scale { factor: constant } = &transformation
+        {
+            if !constant.is_finite() {
+                return Err(ColumnTransformationError::InvalidConstant(*constant));
+            }
+        }
+        if transformation.reference() == Some(&source) {
+            return Err(ColumnTransformationError::SelfReference(source));
+        }
+        Ok(Self {
+            source,
+            transformation,
+        })
+    }
+
+    pub fn source(&self) -> &ColumnId {
+        &self.source
+    }
+
+    pub fn transformation(&self) -> &Transformation {
+        &self.transformation
+    }
+}
+
+impl Display for ColumnTransformation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.transformation)
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ColumnTransformationError {
+    #[error("constant must be a finite number, but {0} is given")]
+    InvalidConstant(f64),
+    #[error("column {0:?} cannot be transformed with itself")]
+    SelfReference(ColumnId),
+}
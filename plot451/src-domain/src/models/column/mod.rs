@@ -3,9 +3,19 @@ pub mod column;
 pub mod column_cell;
 pub mod column_directory;
 
+// 派生カラムの数式
+pub mod column_formula;
+
 // 値オブジェクト
 pub mod column_id;
 pub mod column_name;
+pub mod column_constraint;
+pub mod column_smoothing;
+pub mod column_transformation;
+pub mod column_provenance;
+
+// 仕様
+pub mod column_constraint_specification;
 
 // ファーストクラスコレクション
 pub mod column_with_cells;
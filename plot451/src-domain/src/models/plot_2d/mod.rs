@@ -0,0 +1,14 @@
+// エンティティ
+#[allow(clippy::module_inception)]
+pub mod plot_2d;
+
+// 値オブジェクト
+pub mod plot_2d_id;
+pub mod plot_2d_title;
+pub mod plot_2d_series;
+pub mod plot_2d_series_style;
+pub mod plot_2d_axis;
+
+// リポジトリ
+pub mod plot_2d_factory;
+pub mod plot_2d_repository;
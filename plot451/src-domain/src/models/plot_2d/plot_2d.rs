@@ -0,0 +1,229 @@
+use std::hash::Hash;
+
+use thiserror::Error;
+
+use super::plot_2d_axis::Plot2DAxis;
+use super::plot_2d_id::Plot2DId;
+use super::plot_2d_series::Plot2DSeries;
+use super::plot_2d_series_style::SeriesStyle;
+use super::plot_2d_title::Plot2DTitle;
+use crate::{models::column::column_id::ColumnId, shared::entity::Entity};
+
+// entity
+#[derive(Debug, Clone, Eq)]
+pub struct Plot2D {
+    id: Option<Plot2DId>,
+    title: Plot2DTitle,
+    // 描画順 (後の系列が上に重なる)
+    series: Vec<Plot2DSeries>,
+    x_axis: Plot2DAxis,
+    y_axis: Plot2DAxis,
+}
+
+impl Plot2D {
+    // Plot2D の新規作製は factory で行う
+
+    // Plot2D の再構築
+    pub fn new(
+        id: Option<Plot2DId>,
+        title: Plot2DTitle,
+        series: Vec<Plot2DSeries>,
+        x_axis: Plot2DAxis,
+        y_axis: Plot2DAxis,
+    ) -> Self {
+        Self {
+            id,
+            title,
+            series,
+            x_axis,
+            y_axis,
+        }
+    }
+
+    // getter & setter
+    pub fn id(&self) -> &Plot2DId {
+        self.id.as_ref().expect("id is not set")
+    }
+
+    pub fn id_wrapped(&self) -> &Option<Plot2DId> {
+        &self.id
+    }
+
+    pub fn set_id(&mut self, id: Plot2DId) {
+        if self.id.is_some() {
+            panic!("id cannot be change");
+        }
+        self.id = Some(id);
+    }
+
+    pub fn title(&self) -> &Plot2DTitle {
+        &self.title
+    }
+
+    pub fn series(&self) -> &Vec<Plot2DSeries> {
+        &self.series
+    }
+
+    pub fn x_axis(&self) -> &Plot2DAxis {
+        &self.x_axis
+    }
+
+    pub fn y_axis(&self) -> &Plot2DAxis {
+        &self.y_axis
+    }
+
+    // 系列が参照しているカラム (重複を除き、出現順)
+    pub fn columns(&self) -> Vec<ColumnId> {
+        let mut columns: Vec<ColumnId> = vec![];
+        for series in &self.series {
+            for column_id in [series.x(), series.y()] {
+                if !columns.contains(column_id) {
+                    columns.push(column_id.clone());
+                }
+            }
+        }
+        columns
+    }
+
+    // タイトルの変更
+    pub fn change_title(&mut self, new_title: Plot2DTitle) {
+        self.title = new_title;
+    }
+
+    // 系列の追加 (末尾)
+    pub fn add_series(&mut self, series: Plot2DSeries) {
+        self.series.push(series);
+    }
+
+    // 系列の削除
+    pub fn remove_series(&mut self, index: usize) -> Result<Plot2DSeries, Plot2DEntityError> {
+        if index >= self.series.len() {
+            return Err(Plot2DEntityError::SeriesIndexOutOfRange(index));
+        }
+        Ok(self.series.remove(index))
+    }
+
+    // 系列の見た目の変更
+    pub fn change_series_style(
+        &mut self,
+        index: usize,
+        style: SeriesStyle,
+    ) -> Result<(), Plot2DEntityError> {
+        let series = self
+            .series
+            .get_mut(index)
+            .ok_or(Plot2DEntityError::SeriesIndexOutOfRange(index))?;
+        *series = Plot2DSeries::new(
+            series.x().clone(),
+            series.y().clone(),
+            series.label().clone(),
+            style,
+        );
+        Ok(())
+    }
+
+    // 指定したカラムを参照する系列の削除
+    pub fn remove_series_by_column_id(&mut self, column_id: &ColumnId) {
+        self.series
+            .retain(|series| series.x() != column_id && series.y() != column_id);
+    }
+
+    // 軸の設定の変更
+    pub fn change_x_axis(&mut self, axis: Plot2DAxis) {
+        self.x_axis = axis;
+    }
+
+    pub fn change_y_axis(&mut self, axis: Plot2DAxis) {
+        self.y_axis = axis;
+    }
+}
+
+impl Entity for Plot2D {
+    type Identity = Plot2DId;
+
+    fn identity(&self) -> &Self::Identity {
+        self.id.as_ref().expect("id must be set before comparison")
+    }
+}
+
+impl PartialEq for Plot2D {
+    fn eq(&self, other: &Self) -> bool {
+        Entity::eq(self, other)
+    }
+}
+
+// 等価性と合わせて id だけでハッシュ値を決める
+impl Hash for Plot2D {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum Plot2DEntityError {
+    #[error("series index out of range, index: {0}")]
+    SeriesIndexOutOfRange(usize),
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        models::plot_2d::plot_2d_series_style::{Color, LineDash, MarkerShape, SeriesKind},
+        shared::value_object::ValueObject,
+    };
+
+    use super::*;
+
+    fn series(x: &str, y: &str) -> anyhow::Result<Plot2DSeries> {
+        Ok(Plot2DSeries::new(
+            ColumnId::new(x.to_string())?,
+            ColumnId::new(y.to_string())?,
+            String::new(),
+            SeriesStyle::default(),
+        ))
+    }
+
+    #[test]
+    fn test_series() -> anyhow::Result<()> {
+        let mut plot = Plot2D::new(
+            Some(Plot2DId::new("1".to_string())?),
+            Plot2DTitle::new(" spectra ".to_string())?,
+            vec![series("x", "a")?, series("x", "b")?],
+            Plot2DAxis::default(),
+            Plot2DAxis::default(),
+        );
+        assert_eq!(plot.title().value(), "spectra");
+        plot.add_series(series("t", "a")?);
+        let column_ids: Vec<String> = plot
+            .columns()
+            .iter()
+            .map(|column_id| column_id.clone_value())
+            .collect();
+        assert_eq!(column_ids, vec!["x", "a", "b", "t"]);
+
+        let style = SeriesStyle::new(
+            SeriesKind::Scatter,
+            Color::new(255, 0, 0),
+            1.,
+            LineDash::Dotted,
+            MarkerShape::Triangle,
+            6.,
+        )?;
+        plot.change_series_style(1, style)?;
+        assert_eq!(plot.series()[1].style(), &style);
+        assert_eq!(plot.series()[1].y().value(), "b");
+
+        let removed = plot.remove_series(0)?;
+        assert_eq!(removed.y().value(), "a");
+        assert!(matches!(
+            plot.remove_series(2),
+            Err(Plot2DEntityError::SeriesIndexOutOfRange(2))
+        ));
+
+        // "a" を参照する系列を除くと "b" の系列だけが残る
+        plot.remove_series_by_column_id(&ColumnId::new("a".to_string())?);
+        assert_eq!(plot.series().len(), 1);
+        assert_eq!(plot.series()[0].y().value(), "b");
+        Ok(())
+    }
+}
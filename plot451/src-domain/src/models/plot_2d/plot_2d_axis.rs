@@ -0,0 +1,233 @@
+use std::{fmt::Display, hash::Hash};
+
+use thiserror::Error;
+
+use crate::shared::distributions::{normal_cdf, normal_quantile};
+
+// 軸の目盛りの取り方
+// 値は transform で変換した座標に比例する位置に描く
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AxisScale {
+    Linear,
+    // 常用対数 (正の値のみ)
+    Log10,
+    // 自然対数 (正の値のみ)
+    Ln,
+    // 逆数 (正の値のみ、値が大きいほど原点側になる)
+    Reciprocal,
+    // 正規確率 (0 < p < 1 の値を標準正規分布の分位点の位置に描く)
+    Probability,
+}
+
+impl AxisScale {
+    // 軸に描ける値か
+    pub fn contains(&self, value: f64) -> bool {
+        match self {
+            AxisScale::Linear => value.is_finite(),
+            AxisScale::Log10 | AxisScale::Ln | AxisScale::Reciprocal => {
+                value.is_finite() && value > 0.
+            }
+            AxisScale::Probability => value > 0. && value < 1.,
+        }
+    }
+
+    // 値から軸上の座標への変換 (描けない値は None)
+    pub fn transform(&self, value: f64) -> Option<f64> {
+        if !self.contains(value) {
+            return None;
+        }
+        Some(match self {
+            AxisScale::Linear => value,
+            AxisScale::Log10 => value.log10(),
+            AxisScale::Ln => value.ln(),
+            AxisScale::Reciprocal => 1. / value,
+            AxisScale::Probability => normal_quantile(value),
+        })
+    }
+
+    // 軸上の座標から値への変換
+    pub fn inverse(&self, coordinate: f64) -> f64 {
+        match self {
+            AxisScale::Linear => coordinate,
+            AxisScale::Log10 => 10f64.powf(coordinate),
+            AxisScale::Ln => coordinate.exp(),
+            AxisScale::Reciprocal => 1. / coordinate,
+            AxisScale::Probability => normal_cdf(coordinate),
+        }
+    }
+}
+
+impl Display for AxisScale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AxisScale::Linear => write!(f, "linear"),
+            AxisScale::Log10 => write!(f, "log10"),
+            AxisScale::Ln => write!(f, "ln"),
+            AxisScale::Reciprocal => write!(f, "reciprocal"),
+            AxisScale::Probability => write!(f, "probability"),
+        }
+    }
+}
+
+// value object
+// 軸の表示範囲 (min < max)
+#[derive(Debug, Clone, Copy)]
+pub struct AxisRange {
+    min: f64,
+    max: f64,
+}
+
+impl AxisRange {
+    pub fn new(min: f64, max: f64) -> Result<Self, AxisError> {
+        if !min.is_finite() || !max.is_finite() || min >= max {
+            return Err(AxisError::InvalidRange(min, max));
+        }
+        Ok(Self { min, max })
+    }
+
+    pub fn min(&self) -> f64 {
+        self.min
+    }
+
+    pub fn max(&self) -> f64 {
+        self.max
+    }
+
+    // 軸上の位置 (min を 0、max を 1 とする割合、範囲外や描けない値は None)
+    pub fn fraction(&self, scale: AxisScale, value: f64) -> Option<f64> {
+        let start = scale.transform(self.min)?;
+        let end = scale.transform(self.max)?;
+        let fraction = (scale.transform(value)? - start) / (end - start);
+        (-1e-9..=1. + 1e-9)
+            .contains(&fraction)
+            .then_some(fraction.clamp(0., 1.))
+    }
+}
+
+impl PartialEq for AxisRange {
+    fn eq(&self, other: &Self) -> bool {
+        self.min.to_bits() == other.min.to_bits() && self.max.to_bits() == other.max.to_bits()
+    }
+}
+
+impl Eq for AxisRange {}
+
+impl Hash for AxisRange {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.min.to_bits().hash(state);
+        self.max.to_bits().hash(state);
+    }
+}
+
+// value object
+// 軸の設定 (範囲が None の場合は系列の値から自動で決める)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Plot2DAxis {
+    label: String,
+    scale: AxisScale,
+    range: Option<AxisRange>,
+    show_grid: bool,
+}
+
+impl Plot2DAxis {
+    pub fn new(
+        label: String,
+        scale: AxisScale,
+        range: Option<AxisRange>,
+        show_grid: bool,
+    ) -> Result<Self, AxisError> {
+        if let Some(range) = range {
+            if !scale.contains(range.min()) || !scale.contains(range.max()) {
+                return Err(AxisError::RangeOutsideScale(
+                    scale,
+                    range.min(),
+                    range.max(),
+                ));
+            }
+        }
+        Ok(Self {
+            label: label.trim().to_string(),
+            scale,
+            range,
+            show_grid,
+        })
+    }
+
+    pub fn label(&self) -> &String {
+        &self.label
+    }
+
+    pub fn scale(&self) -> AxisScale {
+        self.scale
+    }
+
+    pub fn range(&self) -> Option<AxisRange> {
+        self.range
+    }
+
+    pub fn show_grid(&self) -> bool {
+        self.show_grid
+    }
+}
+
+impl Default for Plot2DAxis {
+    fn default() -> Self {
+        Self {
+            label: String::new(),
+            scale: AxisScale::Linear,
+            range: None,
+            show_grid: true,
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum AxisError {
+    #[error("axis range must be finite and min < max, but [{0}, {1}] is given")]
+    InvalidRange(f64, f64),
+    #[error("{0} axis cannot show range [{1}, {2}]")]
+    RangeOutsideScale(AxisScale, f64, f64),
+    #[error("no values to show on {0} axis")]
+    NoValues(AxisScale),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scales() -> anyhow::Result<()> {
+        assert_eq!(AxisScale::Log10.transform(100.), Some(2.));
+        assert_eq!(AxisScale::Log10.transform(0.), None);
+        assert_eq!(AxisScale::Reciprocal.transform(4.), Some(0.25));
+        assert_eq!(AxisScale::Probability.transform(1.), None);
+        for scale in [
+            AxisScale::Linear,
+            AxisScale::Log10,
+            AxisScale::Ln,
+            AxisScale::Reciprocal,
+            AxisScale::Probability,
+        ] {
+            let value = 0.3;
+            let coordinate = scale.transform(value).unwrap();
+            assert!(
+                (scale.inverse(coordinate) - value).abs() < 1e-9,
+                "{}",
+                scale
+            );
+        }
+
+        // 逆数軸では値が大きいほど min 側に寄る
+        let range = AxisRange::new(1., 4.)?;
+        assert_eq!(range.fraction(AxisScale::Linear, 2.5), Some(0.5));
+        assert_eq!(range.fraction(AxisScale::Reciprocal, 2.), Some(2. / 3.));
+        assert_eq!(range.fraction(AxisScale::Log10, 2.), Some(0.5));
+        assert_eq!(range.fraction(AxisScale::Linear, 5.), None);
+
+        assert!(matches!(
+            Plot2DAxis::new(String::new(), AxisScale::Probability, Some(range), true),
+            Err(AxisError::RangeOutsideScale(AxisScale::Probability, _, _))
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,24 @@
+use super::{
+    plot_2d::Plot2D, plot_2d_axis::Plot2DAxis, plot_2d_series::Plot2DSeries,
+    plot_2d_title::Plot2DTitle,
+};
+use thiserror::Error;
+
+pub type Plot2DFactoryResult<T> = anyhow::Result<T, Plot2DFactoryError>;
+type Result<T> = Plot2DFactoryResult<T>;
+
+pub trait IPlot2DFactory {
+    fn create_plot_2d(
+        &self,
+        title: Plot2DTitle,
+        series: Vec<Plot2DSeries>,
+        x_axis: Plot2DAxis,
+        y_axis: Plot2DAxis,
+    ) -> impl std::future::Future<Output = Result<Plot2D>> + Send;
+}
+
+#[derive(Debug, Error)]
+pub enum Plot2DFactoryError {
+    #[error("Unexpected error: [{0}]")]
+    Unexpected(String),
+}
@@ -0,0 +1,36 @@
+use std::fmt::Display;
+
+use crate::shared::value_object::ValueObject;
+use thiserror::Error;
+
+// value object
+#[derive(Debug, Eq, Hash, PartialEq, Clone)]
+pub struct Plot2DId {
+    value: String,
+}
+
+#[derive(Debug, Error)]
+pub enum Plot2DIdError {}
+
+impl ValueObject for Plot2DId {
+    type Value = String;
+    type Error = Plot2DIdError;
+
+    fn new(value: String) -> Result<Self, Plot2DIdError> {
+        Ok(Self { value })
+    }
+
+    fn value(&self) -> &Self::Value {
+        &self.value
+    }
+
+    fn clone_value(&self) -> Self::Value {
+        self.value.clone()
+    }
+}
+
+impl Display for Plot2DId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
@@ -0,0 +1,23 @@
+use crate::models::column::column_id::ColumnId;
+
+use super::{plot_2d::Plot2D, plot_2d_id::Plot2DId};
+use thiserror::Error;
+
+pub type Plot2DRepositoryResult<T> = anyhow::Result<T, Plot2DRepositoryError>;
+type Result<T> = Plot2DRepositoryResult<T>;
+
+pub trait IPlot2DRepository {
+    fn save(&self, plot: &Plot2D) -> impl std::future::Future<Output = Result<Plot2DId>> + Send;
+    fn find(&self, id: &Plot2DId) -> impl std::future::Future<Output = Result<Option<Plot2D>>> + Send;
+    fn find_by_column_id(&self, column_id: &ColumnId) -> impl std::future::Future<Output = Result<Vec<Plot2D>>> + Send;
+    fn find_all(&self) -> impl std::future::Future<Output = Result<Vec<Plot2D>>> + Send;
+    fn delete(&self, plot: Plot2D) -> impl std::future::Future<Output = Result<()>> + Send;
+}
+
+#[derive(Debug, Error)]
+pub enum Plot2DRepositoryError {
+    #[error("Plot not found, plot id is {0}")]
+    Plot2DNotFound(Plot2DId),
+    #[error("Unexpected error: [{0}]")]
+    Unexpected(String),
+}
@@ -0,0 +1,40 @@
+use crate::models::column::column_id::ColumnId;
+
+use super::plot_2d_series_style::SeriesStyle;
+
+// value object
+// x, y のカラムの組で表す系列 (凡例のラベルが空の場合は y のカラム名を使う)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Plot2DSeries {
+    x: ColumnId,
+    y: ColumnId,
+    label: String,
+    style: SeriesStyle,
+}
+
+impl Plot2DSeries {
+    pub fn new(x: ColumnId, y: ColumnId, label: String, style: SeriesStyle) -> Self {
+        Self {
+            x,
+            y,
+            label: label.trim().to_string(),
+            style,
+        }
+    }
+
+    pub fn x(&self) -> &ColumnId {
+        &self.x
+    }
+
+    pub fn y(&self) -> &ColumnId {
+        &self.y
+    }
+
+    pub fn label(&self) -> &String {
+        &self.label
+    }
+
+    pub fn style(&self) -> &SeriesStyle {
+        &self.style
+    }
+}
@@ -0,0 +1,238 @@
+use std::{fmt::Display, hash::Hash, str::FromStr};
+
+use thiserror::Error;
+
+// 系列の描き方
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SeriesKind {
+    // 点を線で結ぶ
+    Line,
+    // 点だけを描く
+    Scatter,
+    // 点を描いて線で結ぶ
+    LineAndScatter,
+}
+
+impl SeriesKind {
+    pub fn has_line(&self) -> bool {
+        matches!(self, SeriesKind::Line | SeriesKind::LineAndScatter)
+    }
+
+    pub fn has_marker(&self) -> bool {
+        matches!(self, SeriesKind::Scatter | SeriesKind::LineAndScatter)
+    }
+}
+
+// 線の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LineDash {
+    Solid,
+    Dashed,
+    Dotted,
+}
+
+// マーカーの形
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MarkerShape {
+    Circle,
+    Square,
+    Triangle,
+    Cross,
+}
+
+// RGB の色 ("#rrggbb" 形式で読み書きする)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color {
+    red: u8,
+    green: u8,
+    blue: u8,
+}
+
+impl Color {
+    pub fn new(red: u8, green: u8, blue: u8) -> Self {
+        Self { red, green, blue }
+    }
+
+    pub fn red(&self) -> u8 {
+        self.red
+    }
+
+    pub fn green(&self) -> u8 {
+        self.green
+    }
+
+    pub fn blue(&self) -> u8 {
+        self.blue
+    }
+}
+
+impl FromStr for Color {
+    type Err = SeriesStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || SeriesStyleError::InvalidColor(s.to_string());
+        let hex = s.strip_prefix('#').ok_or_else(invalid)?;
+        if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(invalid());
+        }
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| invalid());
+        Ok(Self::new(channel(0)?, channel(2)?, channel(4)?))
+    }
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.red, self.green, self.blue)
+    }
+}
+
+// value object
+// 系列の見た目 (線の太さとマーカーの大きさは px)
+#[derive(Debug, Clone, Copy)]
+pub struct SeriesStyle {
+    kind: SeriesKind,
+    color: Color,
+    line_width: f64,
+    line_dash: LineDash,
+    marker: MarkerShape,
+    marker_size: f64,
+}
+
+impl SeriesStyle {
+    pub fn new(
+        kind: SeriesKind,
+        color: Color,
+        line_width: f64,
+        line_dash: LineDash,
+        marker: MarkerShape,
+        marker_size: f64,
+    ) -> Result<Self, SeriesStyleError> {
+        if !line_width.is_finite() || line_width <= 0. {
+            return Err(SeriesStyleError::InvalidLineWidth(line_width));
+        }
+        if !marker_size.is_finite() || marker_size <= 0. {
+            return Err(SeriesStyleError::InvalidMarkerSize(marker_size));
+        }
+        Ok(Self {
+            kind,
+            color,
+            line_width,
+            line_dash,
+            marker,
+            marker_size,
+        })
+    }
+
+    pub fn kind(&self) -> SeriesKind {
+        self.kind
+    }
+
+    pub fn color(&self) -> Color {
+        self.color
+    }
+
+    pub fn line_width(&self) -> f64 {
+        self.line_width
+    }
+
+    pub fn line_dash(&self) -> LineDash {
+        self.line_dash
+    }
+
+    pub fn marker(&self) -> MarkerShape {
+        self.marker
+    }
+
+    pub fn marker_size(&self) -> f64 {
+        self.marker_size
+    }
+}
+
+impl Default for SeriesStyle {
+    fn default() -> Self {
+        Self {
+            kind: SeriesKind::Line,
+            color: Color::new(0x1f, 0x77, 0xb4),
+            line_width: 1.5,
+            line_dash: LineDash::Solid,
+            marker: MarkerShape::Circle,
+            marker_size: 4.,
+        }
+    }
+}
+
+impl PartialEq for SeriesStyle {
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind
+            && self.color == other.color
+            && self.line_width.to_bits() == other.line_width.to_bits()
+            && self.line_dash == other.line_dash
+            && self.marker == other.marker
+            && self.marker_size.to_bits() == other.marker_size.to_bits()
+    }
+}
+
+impl Eq for SeriesStyle {}
+
+impl Hash for SeriesStyle {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.kind.hash(state);
+        self.color.hash(state);
+        self.line_width.to_bits().hash(state);
+        self.line_dash.hash(state);
+        self.marker.hash(state);
+        self.marker_size.to_bits().hash(state);
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum SeriesStyleError {
+    #[error("color must be given as #rrggbb, but {0} is given")]
+    InvalidColor(String),
+    #[error("line width must be a positive number, but {0} is given")]
+    InvalidLineWidth(f64),
+    #[error("marker size must be a positive number, but {0} is given")]
+    InvalidMarkerSize(f64),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color() -> anyhow::Result<()> {
+        let color: Color = "#1F77b4".parse()?;
+        assert_eq!(color, Color::new(0x1f, 0x77, 0xb4));
+        assert_eq!(color.to_string(), "#1f77b4");
+        for invalid in ["1f77b4", "#1f77b", "#1f77bg", "#1f77b4ff"] {
+            assert!(matches!(
+                invalid.parse::<Color>(),
+                Err(SeriesStyleError::InvalidColor(_))
+            ));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_new() {
+        let style = |line_width, marker_size| {
+            SeriesStyle::new(
+                SeriesKind::Scatter,
+                Color::new(0, 0, 0),
+                line_width,
+                LineDash::Dashed,
+                MarkerShape::Square,
+                marker_size,
+            )
+        };
+        assert!(style(1., 3.).is_ok());
+        assert!(matches!(
+            style(0., 3.),
+            Err(SeriesStyleError::InvalidLineWidth(_))
+        ));
+        assert!(matches!(
+            style(1., f64::NAN),
+            Err(SeriesStyleError::InvalidMarkerSize(_))
+        ));
+    }
+}
@@ -0,0 +1,31 @@
+use crate::shared::value_object::ValueObject;
+use thiserror::Error;
+
+// value object
+// グラフのタイトル (前後の空白を除き、空のタイトルは表示しない)
+#[derive(Debug, Eq, Hash, PartialEq, Clone)]
+pub struct Plot2DTitle {
+    value: String,
+}
+
+#[derive(Debug, Error)]
+pub enum Plot2DTitleError {}
+
+impl ValueObject for Plot2DTitle {
+    type Value = String;
+    type Error = Plot2DTitleError;
+
+    fn new(value: String) -> Result<Self, Plot2DTitleError> {
+        Ok(Self {
+            value: value.trim().to_string(),
+        })
+    }
+
+    fn value(&self) -> &Self::Value {
+        &self.value
+    }
+
+    fn clone_value(&self) -> Self::Value {
+        self.value.clone()
+    }
+}
@@ -4,6 +4,8 @@ pub mod table;
 // 値オブジェクト
 pub mod table_id;
 pub mod table_name;
+pub mod table_sort_key;
+pub mod table_filter;
 
 // 仕様
 pub mod no_duplicated_column_names_specification;
@@ -11,6 +13,7 @@ pub mod no_duplicated_column_names_specification;
 // ファーストクラスコレクション
 pub mod table_columns;
 pub mod table_with_columns_and_cells;
+pub mod table_rows;
 
 // リポジトリ
 pub mod table_factory;
@@ -22,19 +22,44 @@ impl Specification for NoDuplicatedColumnNamesSpecification {
         &self,
         table_columns: &TableColumns,
     ) -> Result<(), NoDuplicateColumnNameSpecificationError> {
-        let column_names = table_columns.column_names();
-        let mut set = HashSet::new();
-        for column_name in column_names {
-            if !set.insert(column_name) {
-                return Err(
-                    NoDuplicateColumnNameSpecificationError::DuplicatedColumnNames(
-                        column_name.clone(),
-                    ),
-                );
-            }
+        check_duplicated(table_columns.column_names())
+    }
+}
+
+// カラムを作成する前に、作成するカラムの名前の重複をチェックする
+#[derive(Default)]
+pub struct NoDuplicatedColumnNamesInListSpecification {}
+
+impl NoDuplicatedColumnNamesInListSpecification {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Specification for NoDuplicatedColumnNamesInListSpecification {
+    type T = Vec<ColumnName>;
+    type Error = NoDuplicateColumnNameSpecificationError;
+
+    fn is_satisfied_by(
+        &self,
+        column_names: &Vec<ColumnName>,
+    ) -> Result<(), NoDuplicateColumnNameSpecificationError> {
+        check_duplicated(column_names.iter().collect())
+    }
+}
+
+fn check_duplicated(
+    column_names: Vec<&ColumnName>,
+) -> Result<(), NoDuplicateColumnNameSpecificationError> {
+    let mut set = HashSet::new();
+    for column_name in column_names {
+        if !set.insert(column_name) {
+            return Err(
+                NoDuplicateColumnNameSpecificationError::DuplicatedColumnNames(column_name.clone()),
+            );
         }
-        Ok(())
     }
+    Ok(())
 }
 
 #[derive(Debug, Error)]
@@ -135,4 +160,22 @@ mod tests {
         assert!(specification.is_satisfied_by(&table_columns).is_err());
         Ok(())
     }
+
+    #[test]
+    fn test_column_names_in_list() -> anyhow::Result<()> {
+        let names = |names: &[&str]| -> anyhow::Result<Vec<ColumnName>> {
+            Ok(names
+                .iter()
+                .map(|name| ColumnName::new(name.to_string()))
+                .collect::<Result<_, _>>()?)
+        };
+        let specification = NoDuplicatedColumnNamesInListSpecification::new();
+        assert!(specification
+            .is_satisfied_by(&names(&["column1", "column2"])?)
+            .is_ok());
+        assert!(specification
+            .is_satisfied_by(&names(&["column1", "column2", "column1"])?)
+            .is_err());
+        Ok(())
+    }
 }
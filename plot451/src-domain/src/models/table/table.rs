@@ -1,5 +1,6 @@
 use thiserror::Error;
 
+use super::table_filter::TableFilter;
 use super::table_id::TableId;
 use super::table_name::TableName;
 use crate::{models::column::column_id::ColumnId, shared::entity::Entity};
@@ -10,6 +11,8 @@ pub struct Table {
     id: Option<TableId>,
     name: TableName,
     columns: Vec<ColumnId>,
+    // 行の抽出で作成したテーブルの生成元
+    filter: Option<TableFilter>,
 }
 
 impl Table {
@@ -24,7 +27,12 @@ impl Table {
         if columns.is_empty() {
             return Err(TableEntityError::EmptyColumnList);
         }
-        Ok(Self { id, name, columns })
+        Ok(Self {
+            id,
+            name,
+            columns,
+            filter: None,
+        })
     }
 
     // getter & setter
@@ -51,6 +59,10 @@ impl Table {
         &self.columns
     }
 
+    pub fn filter(&self) -> &Option<TableFilter> {
+        &self.filter
+    }
+
     // テーブル名の変更
     pub fn change_name(&mut self, new_name: TableName) {
         self.name = new_name;
@@ -116,6 +128,11 @@ impl Table {
     pub fn remove_column(&mut self, column_id: &ColumnId) {
         self.columns.retain(|id| id != column_id);
     }
+
+    // 抽出の生成元の変更
+    pub fn change_filter(&mut self, filter: Option<TableFilter>) {
+        self.filter = filter;
+    }
 }
 
 impl Entity for Table {
@@ -0,0 +1,62 @@
+use std::{fmt::Display, hash::Hash};
+
+use crate::models::column::column_formula::{
+    formula_expression::FormulaExpressionError, formula_predicate::FormulaPredicate,
+};
+
+use super::table_id::TableId;
+
+// value object
+// 行の抽出で作成したテーブルの生成元 (元のテーブルと入力された条件式)
+// 条件式のカラム名は再生成時に元のテーブルのカラムで解決する
+#[derive(Debug, Clone)]
+pub struct TableFilter {
+    source: TableId,
+    value: String,
+    predicate: FormulaPredicate,
+}
+
+impl TableFilter {
+    pub fn new(source: TableId, value: String) -> Result<Self, FormulaExpressionError> {
+        let value = value.trim().to_string();
+        let predicate = FormulaPredicate::parse(&value)?;
+        Ok(Self {
+            source,
+            value,
+            predicate,
+        })
+    }
+
+    pub fn source(&self) -> &TableId {
+        &self.source
+    }
+
+    pub fn value(&self) -> &String {
+        &self.value
+    }
+
+    pub fn predicate(&self) -> &FormulaPredicate {
+        &self.predicate
+    }
+}
+
+impl PartialEq for TableFilter {
+    fn eq(&self, other: &Self) -> bool {
+        self.source == other.source && self.value == other.value
+    }
+}
+
+impl Eq for TableFilter {}
+
+impl Hash for TableFilter {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.source.hash(state);
+        self.value.hash(state);
+    }
+}
+
+impl Display for TableFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
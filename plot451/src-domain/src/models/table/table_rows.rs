@@ -0,0 +1,432 @@
+use thiserror::Error;
+
+use crate::{
+    models::column::{
+        column::{Column, ColumnEntityError},
+        column_cell::{column_cell_id::ColumnCellId, column_cell_value::CellRawValue},
+        column_id::ColumnId,
+    },
+    shared::limits::MAX_CREATED_CELLS,
+};
+
+use super::{table::Table, table_sort_key::TableSortKey};
+
+// 一度に挿入できる行数の上限
+pub const MAX_INSERTED_ROWS: usize = MAX_CREATED_CELLS;
+
+// ファーストクラスコレクション
+// テーブルに属するすべてのカラムに対して行単位の操作を一括で適用する
+pub struct TableRows {
+    columns: Vec<Column>,
+}
+
+impl TableRows {
+    pub fn new(table: &Table, columns: Vec<Column>) -> Self {
+        assert_eq!(
+            table.columns().iter().collect::<Vec<_>>(),
+            columns.iter().map(|column| column.id()).collect::<Vec<_>>()
+        );
+        Self { columns }
+    }
+
+    pub fn columns(&self) -> &Vec<Column> {
+        &self.columns
+    }
+
+    pub fn into_columns(self) -> Vec<Column> {
+        self.columns
+    }
+
+    // 行数 (最も長いカラムのセル数)
+    pub fn row_count(&self) -> usize {
+        self.columns
+            .iter()
+            .map(|column| column.cells().len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn is_aligned(&self) -> bool {
+        let row_count = self.row_count();
+        self.columns
+            .iter()
+            .all(|column| column.cells().len() == row_count)
+    }
+
+    // 各カラムを行数に揃えるために必要な空セルの数
+    pub fn padding_lengths(&self) -> Vec<usize> {
+        let row_count = self.row_count();
+        self.columns
+            .iter()
+            .map(|column| row_count - column.cells().len())
+            .collect()
+    }
+
+    // 長さの足りないカラムの末尾に空セルを追加する
+    pub fn pad(&mut self, padding: Vec<Vec<ColumnCellId>>) -> Result<(), TableRowsError> {
+        if padding.len() != self.columns.len()
+            || padding
+                .iter()
+                .zip(self.padding_lengths())
+                .any(|(cells, length)| cells.len() != length)
+        {
+            return Err(TableRowsError::InvalidPadding);
+        }
+        for (column, cells) in self.columns.iter_mut().zip(padding) {
+            let length = column.cells().len();
+            column
+                .insert_cells_at(length, cells)
+                .map_err(TableRowsError::ColumnEntityError)?;
+        }
+        Ok(())
+    }
+
+    // 行の挿入が可能か (セルを作成する前に確認する)
+    pub fn check_insert(&self, index: usize, count: usize) -> Result<(), TableRowsError> {
+        if count > MAX_INSERTED_ROWS {
+            return Err(TableRowsError::TooManyRows(count));
+        }
+        if index > self.row_count() {
+            return Err(TableRowsError::RowOutOfRange(index));
+        }
+        Ok(())
+    }
+
+    // キーのカラムがすべてテーブルにあるか (空セルで埋める前に確認する)
+    pub fn check_sort_keys<'a>(
+        &self,
+        keys: impl IntoIterator<Item = &'a TableSortKey>,
+    ) -> Result<(), TableRowsError> {
+        for key in keys {
+            if !self
+                .columns
+                .iter()
+                .any(|column| column.id() == key.column_id())
+            {
+                return Err(TableRowsError::KeyColumnNotFound(key.column_id().clone()));
+            }
+        }
+        Ok(())
+    }
+
+    // 行の削除が可能か
+    pub fn check_delete(&self, index: usize, count: usize) -> Result<(), TableRowsError> {
+        self.check_range(index, count).map(|_| ())
+    }
+
+    // 行の移動が可能か
+    pub fn check_move(&self, from: usize, count: usize, to: usize) -> Result<(), TableRowsError> {
+        self.check_range(from, count)?;
+        self.check_range(to, count).map(|_| ())
+    }
+
+    // 行の挿入
+    pub fn insert_rows(
+        &mut self,
+        index: usize,
+        new_cells: Vec<Vec<ColumnCellId>>,
+    ) -> Result<(), TableRowsError> {
+        self.check_aligned()?;
+        let count = new_cells.first().map(|cells| cells.len()).unwrap_or(0);
+        self.check_insert(index, count)?;
+        if new_cells.len() != self.columns.len()
+            || new_cells.iter().any(|cells| cells.len() != count)
+        {
+            return Err(TableRowsError::CellCountMismatch);
+        }
+        for (column, cells) in self.columns.iter_mut().zip(new_cells) {
+            column
+                .insert_cells_at(index, cells)
+                .map_err(TableRowsError::ColumnEntityError)?;
+        }
+        Ok(())
+    }
+
+    // 行の削除 (削除されたセルの id を返す)
+    pub fn delete_rows(
+        &mut self,
+        index: usize,
+        count: usize,
+    ) -> Result<Vec<ColumnCellId>, TableRowsError> {
+        self.check_aligned()?;
+        self.check_delete(index, count)?;
+        let mut removed = vec![];
+        for column in self.columns.iter_mut() {
+            let cells = column
+                .remove_cells_at(index, count)
+                .map_err(TableRowsError::ColumnEntityError)?;
+            removed.extend(cells);
+        }
+        Ok(removed)
+    }
+
+    // 行の移動 (from から count 行を、移動後に to 行目から始まるように移動する)
+    pub fn move_rows(
+        &mut self,
+        from: usize,
+        count: usize,
+        to: usize,
+    ) -> Result<(), TableRowsError> {
+        self.check_aligned()?;
+        self.check_move(from, count, to)?;
+        for column in self.columns.iter_mut() {
+            let mut new_order = column.cells().clone();
+            let moved: Vec<ColumnCellId> = new_order.drain(from..from + count).collect();
+            new_order.splice(to..to, moved);
+            column
+                .change_order(new_order)
+                .map_err(TableRowsError::ColumnEntityError)?;
+        }
+        Ok(())
+    }
+
+    // キーとそのカラムの値 (現在の行の順序) による行の並べ替え
+    // 先のキーほど優先し、すべてのキーで等しい行は元の順序を保つ
+    pub fn sort_rows(
+        &mut self,
+        keys: &[(TableSortKey, Vec<CellRawValue>)],
+    ) -> Result<(), TableRowsError> {
+        self.check_aligned()?;
+        self.check_sort_keys(keys.iter().map(|(key, _)| key))?;
+        let row_count = self.row_count();
+        for (_, values) in keys {
+            if values.len() != row_count {
+                return Err(TableRowsError::CellCountMismatch);
+            }
+        }
+
+        let mut permutation: Vec<usize> = (0..row_count).collect();
+        permutation.sort_by(|&a, &b| {
+            keys.iter()
+                .map(|(key, values)| key.compare(&values[a], &values[b]))
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        for column in self.columns.iter_mut() {
+            let new_order = permutation
+                .iter()
+                .map(|&row| column.cells()[row].clone())
+                .collect();
+            column
+                .change_order(new_order)
+                .map_err(TableRowsError::ColumnEntityError)?;
+        }
+        Ok(())
+    }
+
+    // start から count 行が行数に収まるか (終端を返す)
+    fn check_range(&self, start: usize, count: usize) -> Result<usize, TableRowsError> {
+        match start.checked_add(count) {
+            Some(end) if end <= self.row_count() => Ok(end),
+            Some(end) => Err(TableRowsError::RowOutOfRange(end)),
+            None => Err(TableRowsError::RowOutOfRange(start)),
+        }
+    }
+
+    fn check_aligned(&self) -> Result<(), TableRowsError> {
+        let row_count = self.row_count();
+        match self
+            .columns
+            .iter()
+            .find(|column| column.cells().len() != row_count)
+        {
+            Some(column) => Err(TableRowsError::RaggedColumn(column.id().clone())),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum TableRowsError {
+    #[error("column length differs from row count, column_id: {0}")]
+    RaggedColumn(ColumnId),
+    #[error("row index out of range, index: {0}")]
+    RowOutOfRange(usize),
+    #[error("too many rows to insert, count: {0}")]
+    TooManyRows(usize),
+    #[error("number of new cells does not match")]
+    CellCountMismatch,
+    #[error("invalid padding")]
+    InvalidPadding,
+    #[error("sort key column is not in the table, column_id: {0}")]
+    KeyColumnNotFound(ColumnId),
+    #[error("ColumnEntityError: [{0}]")]
+    ColumnEntityError(ColumnEntityError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::column::column_directory::column_directory_id::ColumnDirectoryId;
+    use crate::models::column::column_name::ColumnName;
+    use crate::models::table::table_id::TableId;
+    use crate::models::table::table_name::TableName;
+    use crate::models::table::table_sort_key::{MissingPlacement, SortOrder};
+    use crate::shared::value_object::ValueObject;
+
+    fn cell_ids(prefix: &str, count: usize) -> Vec<ColumnCellId> {
+        (0..count)
+            .map(|i| ColumnCellId::new(format!("{}{}", prefix, i)).unwrap())
+            .collect()
+    }
+
+    fn table_rows(lengths: &[usize]) -> anyhow::Result<TableRows> {
+        let mut column_ids = vec![];
+        let mut columns = vec![];
+        for (i, &length) in lengths.iter().enumerate() {
+            let column_id = ColumnId::new(format!("column{}", i))?;
+            column_ids.push(column_id.clone());
+            columns.push(Column::new(
+                Some(column_id),
+                ColumnName::new(format!("column{}", i))?,
+                ColumnDirectoryId::new("0".to_string())?,
+                cell_ids(&format!("c{}_", i), length),
+            ));
+        }
+        let table = Table::new(
+            Some(TableId::new("table".to_string())?),
+            TableName::new("table".to_string())?,
+            column_ids,
+        )?;
+        Ok(TableRows::new(&table, columns))
+    }
+
+    #[test]
+    fn test_pad() -> anyhow::Result<()> {
+        let mut rows = table_rows(&[3, 1, 2])?;
+        assert_eq!(rows.row_count(), 3);
+        assert!(!rows.is_aligned());
+        assert_eq!(rows.padding_lengths(), vec![0, 2, 1]);
+
+        // 行の操作は揃っていないとできない
+        assert!(matches!(
+            rows.delete_rows(0, 1),
+            Err(TableRowsError::RaggedColumn(_))
+        ));
+
+        // パディングの数が合わない場合はエラー
+        assert!(rows.pad(vec![vec![], cell_ids("p1_", 1), vec![]]).is_err());
+
+        rows.pad(vec![vec![], cell_ids("p1_", 2), cell_ids("p2_", 1)])?;
+        assert!(rows.is_aligned());
+        assert_eq!(rows.columns()[1].cells()[1..], cell_ids("p1_", 2)[..]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_insert_rows() -> anyhow::Result<()> {
+        let mut rows = table_rows(&[2, 2])?;
+        let new_cells = vec![cell_ids("n0_", 2), cell_ids("n1_", 2)];
+        rows.insert_rows(1, new_cells)?;
+        assert_eq!(rows.row_count(), 4);
+        let c0 = cell_ids("c0_", 2);
+        let n0 = cell_ids("n0_", 2);
+        assert_eq!(
+            rows.columns()[0].cells(),
+            &vec![c0[0].clone(), n0[0].clone(), n0[1].clone(), c0[1].clone()]
+        );
+
+        // カラムごとの挿入数が揃っていない場合はエラー
+        let result = rows.insert_rows(0, vec![cell_ids("m0_", 1), cell_ids("m1_", 2)]);
+        assert!(matches!(result, Err(TableRowsError::CellCountMismatch)));
+
+        // 範囲外
+        let result = rows.insert_rows(5, vec![cell_ids("m0_", 1), cell_ids("m1_", 1)]);
+        assert!(matches!(result, Err(TableRowsError::RowOutOfRange(5))));
+        assert!(matches!(
+            rows.check_insert(0, MAX_INSERTED_ROWS + 1),
+            Err(TableRowsError::TooManyRows(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_delete_rows() -> anyhow::Result<()> {
+        let mut rows = table_rows(&[3, 3])?;
+        let removed = rows.delete_rows(1, 2)?;
+        assert_eq!(removed.len(), 4);
+        assert_eq!(rows.row_count(), 1);
+        assert_eq!(rows.columns()[1].cells(), &cell_ids("c1_", 1));
+        assert!(rows.delete_rows(1, 1).is_err());
+        // 終端の計算が桁あふれする場合も範囲外
+        assert!(matches!(
+            rows.delete_rows(1, usize::MAX),
+            Err(TableRowsError::RowOutOfRange(1))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_move_rows() -> anyhow::Result<()> {
+        let mut rows = table_rows(&[4, 4])?;
+        let c = cell_ids("c1_", 4);
+
+        // 0 行目を末尾へ
+        rows.move_rows(0, 1, 3)?;
+        assert_eq!(
+            rows.columns()[1].cells(),
+            &vec![c[1].clone(), c[2].clone(), c[3].clone(), c[0].clone()]
+        );
+
+        // 末尾 2 行を先頭へ
+        rows.move_rows(2, 2, 0)?;
+        assert_eq!(
+            rows.columns()[1].cells(),
+            &vec![c[3].clone(), c[0].clone(), c[1].clone(), c[2].clone()]
+        );
+
+        assert!(rows.move_rows(3, 2, 0).is_err());
+        assert!(rows.move_rows(0, 2, 3).is_err());
+        assert!(rows.move_rows(0, 1, usize::MAX).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_sort_rows() -> anyhow::Result<()> {
+        let mut rows = table_rows(&[4, 4])?;
+        let c0 = cell_ids("c0_", 4);
+        let c1 = cell_ids("c1_", 4);
+        let key0 = TableSortKey::new(
+            ColumnId::new("column0".to_string())?,
+            SortOrder::Descending,
+            MissingPlacement::Last,
+        );
+        let key1 = TableSortKey::new(
+            ColumnId::new("column1".to_string())?,
+            SortOrder::Ascending,
+            MissingPlacement::First,
+        );
+
+        // 第 1 キーで同順位の行 (0, 2) は第 2 キーで並ぶ
+        rows.sort_rows(&[
+            (key0.clone(), vec![Some(1.), None, Some(1.), Some(2.)]),
+            (key1.clone(), vec![Some(5.), Some(0.), Some(3.), None]),
+        ])?;
+        let expected = [3, 2, 0, 1];
+        assert_eq!(
+            rows.columns()[0].cells(),
+            &expected.iter().map(|&i| c0[i].clone()).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            rows.columns()[1].cells(),
+            &expected.iter().map(|&i| c1[i].clone()).collect::<Vec<_>>()
+        );
+
+        // 値の数が行数と合わない
+        assert!(matches!(
+            rows.sort_rows(&[(key1, vec![Some(1.)])]),
+            Err(TableRowsError::CellCountMismatch)
+        ));
+        // テーブルにないカラム
+        let key = TableSortKey::new(
+            ColumnId::new("column9".to_string())?,
+            SortOrder::Ascending,
+            MissingPlacement::Last,
+        );
+        assert!(matches!(
+            rows.sort_rows(&[(key, vec![None; 4])]),
+            Err(TableRowsError::KeyColumnNotFound(_))
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+use std::cmp::Ordering;
+
+use crate::models::column::{column_cell::column_cell_value::CellRawValue, column_id::ColumnId};
+
+// 並べ替えの方向
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+// None (と NaN) の置き場所 (並べ替えの方向によらない)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MissingPlacement {
+    First,
+    Last,
+}
+
+// value object
+// テーブルの行の並べ替えに使うキー
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TableSortKey {
+    column_id: ColumnId,
+    order: SortOrder,
+    missing: MissingPlacement,
+}
+
+impl TableSortKey {
+    pub fn new(column_id: ColumnId, order: SortOrder, missing: MissingPlacement) -> Self {
+        Self {
+            column_id,
+            order,
+            missing,
+        }
+    }
+
+    pub fn column_id(&self) -> &ColumnId {
+        &self.column_id
+    }
+
+    pub fn order(&self) -> SortOrder {
+        self.order
+    }
+
+    pub fn missing(&self) -> MissingPlacement {
+        self.missing
+    }
+
+    // このキーでの 2 つの値の比較
+    pub fn compare(&self, a: &CellRawValue, b: &CellRawValue) -> Ordering {
+        let a = a.filter(|value| !value.is_nan());
+        let b = b.filter(|value| !value.is_nan());
+        match (a, b) {
+            (Some(a), Some(b)) => match self.order {
+                SortOrder::Ascending => a.total_cmp(&b),
+                SortOrder::Descending => b.total_cmp(&a),
+            },
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) => match self.missing {
+                MissingPlacement::First => Ordering::Less,
+                MissingPlacement::Last => Ordering::Greater,
+            },
+            (Some(_), None) => match self.missing {
+                MissingPlacement::First => Ordering::Greater,
+                MissingPlacement::Last => Ordering::Less,
+            },
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use std::{collections::HashSet, hash::RandomState};
 
-use crate::models::column::column_with_cells::ColumnWithCells;
+use crate::models::column::{column_cell::column_cell::ColumnCell, column_with_cells::ColumnWithCells};
 
 use super::{table::Table, table_id::TableId, table_name::TableName};
 
@@ -35,4 +35,25 @@ impl TableWithColumnsAndCells {
     pub fn columns(&self) -> &Vec<ColumnWithCells> {
         &self.columns
     }
+
+    // 行数 (最も長いカラムのセル数)
+    pub fn row_count(&self) -> usize {
+        self.columns
+            .iter()
+            .map(|column| column.cells().len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    // 行単位のビュー (長さの足りないカラムは None で埋める)
+    pub fn rows(&self) -> Vec<Vec<Option<&ColumnCell>>> {
+        (0..self.row_count())
+            .map(|row| {
+                self.columns
+                    .iter()
+                    .map(|column| column.cells().get(row))
+                    .collect()
+            })
+            .collect()
+    }
 }
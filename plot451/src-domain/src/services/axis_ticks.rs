@@ -0,0 +1,346 @@
+use crate::models::{
+    column::column_cell::column_cell_value::CellRawValue,
+    plot_2d::plot_2d_axis::{AxisError, AxisRange, AxisScale, Plot2DAxis},
+};
+
+// 自動で決める範囲の余白 (軸上の座標での幅に対する割合)
+pub const DEFAULT_MARGIN: f64 = 0.05;
+
+// 正規確率軸の目盛りの候補 (主目盛りと補助目盛り)
+const PROBABILITY_MAJOR_TICKS: [f64; 9] = [0.0001, 0.001, 0.01, 0.1, 0.5, 0.9, 0.99, 0.999, 0.9999];
+const PROBABILITY_MINOR_TICKS: [f64; 10] = [0.02, 0.05, 0.2, 0.3, 0.4, 0.6, 0.7, 0.8, 0.95, 0.98];
+
+// domain service
+// 系列の値から軸の範囲を決める
+// None と NaN、軸に描けない値 (対数軸の 0 以下の値など) は除き、
+// 軸上の座標での最小値と最大値の外側に margin の割合の余白を付ける
+pub fn auto_range(
+    columns: &[&[CellRawValue]],
+    scale: AxisScale,
+    margin: f64,
+) -> Result<AxisRange, AxisError> {
+    let coordinates: Vec<f64> = columns
+        .iter()
+        .flat_map(|values| values.iter())
+        .filter_map(|value| value.and_then(|value| scale.transform(value)))
+        .collect();
+    let (mut low, mut high) = coordinates
+        .iter()
+        .fold(None, |range: Option<(f64, f64)>, coordinate| {
+            Some(range.map_or((*coordinate, *coordinate), |(low, high)| {
+                (low.min(*coordinate), high.max(*coordinate))
+            }))
+        })
+        .ok_or(AxisError::NoValues(scale))?;
+
+    // 値が 1 つしかない場合は値を中心とする幅を持たせる
+    if low == high {
+        let half_width = match scale {
+            AxisScale::Linear | AxisScale::Reciprocal if low != 0. => low.abs() * 0.1,
+            _ => 0.5,
+        };
+        low -= half_width;
+        high += half_width;
+    }
+    let padding = (high - low) * margin.max(0.);
+    // 逆数軸の座標は正でなければならないため、はみ出す場合は余白を付けない
+    if scale != AxisScale::Reciprocal || low - padding > 0. {
+        low -= padding;
+    }
+    high += padding;
+
+    let (a, b) = (scale.inverse(low), scale.inverse(high));
+    AxisRange::new(a.min(b), a.max(b))
+}
+
+// 軸の範囲 (指定されていない場合は系列の値から自動で決める)
+pub fn resolve_range(
+    axis: &Plot2DAxis,
+    columns: &[&[CellRawValue]],
+) -> Result<AxisRange, AxisError> {
+    match axis.range() {
+        Some(range) => Ok(range),
+        None => auto_range(columns, axis.scale(), DEFAULT_MARGIN),
+    }
+}
+
+// 主目盛り (値とラベル)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tick {
+    value: f64,
+    label: String,
+}
+
+impl Tick {
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    pub fn label(&self) -> &String {
+        &self.label
+    }
+}
+
+// domain service
+// 軸の目盛り
+// 線形軸と逆数軸は 1, 2, 5 × 10^n 刻み、対数軸は 10 (自然対数軸は e) のべき乗、
+// 正規確率軸は 0.01, 0.1, 0.5 などの決まった確率に目盛りを置く
+#[derive(Debug, Clone, PartialEq)]
+pub struct AxisTicks {
+    major: Vec<Tick>,
+    minor: Vec<f64>,
+}
+
+impl AxisTicks {
+    // target_count は主目盛りのおおよその数
+    pub fn new(range: AxisRange, scale: AxisScale, target_count: usize) -> Self {
+        let target_count = target_count.max(2);
+        match scale {
+            AxisScale::Linear | AxisScale::Reciprocal => {
+                Self::linear(range.min(), range.max(), target_count)
+            }
+            AxisScale::Log10 => Self::powers(range, 10., target_count)
+                .unwrap_or_else(|| Self::linear(range.min(), range.max(), target_count)),
+            AxisScale::Ln => Self::powers(range, std::f64::consts::E, target_count)
+                .unwrap_or_else(|| Self::linear(range.min(), range.max(), target_count)),
+            AxisScale::Probability => Self::probability(range),
+        }
+    }
+
+    pub fn major(&self) -> &Vec<Tick> {
+        &self.major
+    }
+
+    pub fn minor(&self) -> &Vec<f64> {
+        &self.minor
+    }
+
+    fn linear(min: f64, max: f64, target_count: usize) -> Self {
+        // 端の目盛りが丸め誤差で落ちないように少し広げた範囲の整数倍を取る
+        let multiples = |step: f64| {
+            let tolerance = 1e-9;
+            let first = (min / step - tolerance).ceil() as i64;
+            let last = (max / step + tolerance).floor() as i64;
+            first..=last
+        };
+
+        // 1, 2, 5 × 10^n のうち、主目盛りが target_count + 1 個以下になる最小の刻み
+        let magnitude = 10f64.powf(((max - min) / target_count as f64).log10().floor());
+        let (step, divisions) = [(1., 5), (2., 4), (5., 5), (10., 5), (20., 4)]
+            .into_iter()
+            .map(|(mantissa, divisions)| (mantissa * magnitude, divisions))
+            .find(|(step, _)| multiples(*step).count() <= target_count + 1)
+            .unwrap_or((50. * magnitude, 5));
+        let minor_step = step / divisions as f64;
+
+        let major = multiples(step)
+            .map(|k| {
+                let value = k as f64 * step;
+                Tick {
+                    value,
+                    label: format_number(value, step),
+                }
+            })
+            .collect();
+        let minor = multiples(minor_step)
+            .filter(|j| j % divisions != 0)
+            .map(|j| j as f64 * minor_step)
+            .collect();
+        Self { major, minor }
+    }
+
+    // base のべき乗の目盛り (範囲内に 2 つ以上ない場合は None)
+    fn powers(range: AxisRange, base: f64, target_count: usize) -> Option<Self> {
+        let tolerance = 1e-9;
+        let first = (range.min().log(base) - tolerance).ceil() as i64;
+        let last = (range.max().log(base) + tolerance).floor() as i64;
+        if last <= first {
+            return None;
+        }
+        // 桁が多い場合は stride 桁ごとにし、間の桁を補助目盛りにする
+        let count = (last - first + 1) as usize;
+        let stride = count.div_ceil(target_count) as i64;
+        let label = |k: i64| {
+            if base == 10. {
+                format_number(10f64.powi(k as i32), 10f64.powi(k as i32))
+            } else {
+                match k {
+                    0 => "1".to_string(),
+                    1 => "e".to_string(),
+                    _ => format!("e^{}", k),
+                }
+            }
+        };
+        let major = (first..=last)
+            .filter(|k| k.rem_euclid(stride) == 0)
+            .map(|k| Tick {
+                value: base.powi(k as i32),
+                label: label(k),
+            })
+            .collect();
+        let minor = if stride > 1 {
+            (first..=last)
+                .filter(|k| k.rem_euclid(stride) != 0)
+                .map(|k| base.powi(k as i32))
+                .collect()
+        } else if base == 10. {
+            // 各桁の 2 〜 9 倍
+            (first - 1..=last)
+                .flat_map(|k| (2..10).map(move |m| m as f64 * 10f64.powi(k as i32)))
+                .filter(|value| range.min() <= *value && *value <= range.max())
+                .collect()
+        } else {
+            vec![]
+        };
+        Some(Self { major, minor })
+    }
+
+    fn probability(range: AxisRange) -> Self {
+        let within = |value: &&f64| range.min() <= **value && **value <= range.max();
+        let mut major: Vec<f64> = PROBABILITY_MAJOR_TICKS
+            .iter()
+            .filter(within)
+            .copied()
+            .collect();
+        let mut minor: Vec<f64> = PROBABILITY_MINOR_TICKS
+            .iter()
+            .filter(within)
+            .copied()
+            .collect();
+        // 範囲が狭く主目盛りが足りない場合はすべての候補を主目盛りにする
+        if major.len() < 2 {
+            major.append(&mut minor);
+            major.sort_by(|a, b| a.total_cmp(b));
+        }
+        Self {
+            major: major
+                .into_iter()
+                .map(|value| Tick {
+                    value,
+                    label: value.to_string(),
+                })
+                .collect(),
+            minor,
+        }
+    }
+}
+
+// 目盛りのラベル
+// 刻み step の桁まで表示し、絶対値が 10^6 以上か 10^-4 未満の値は指数表記にする
+pub fn format_number(value: f64, step: f64) -> String {
+    // 丸め誤差で -0 や 0.30000000000000004 のようにならないよう、刻みの桁で丸める
+    let step_exponent = step.abs().log10().floor() as i32;
+    let scale = 10f64.powi(-step_exponent);
+    let value = (value * scale).round() / scale;
+    if value == 0. {
+        return "0".to_string();
+    }
+    let exponent = value.abs().log10().floor() as i32;
+    if !(-4..6).contains(&exponent) {
+        let digits = (exponent - step_exponent).max(0) as usize;
+        let formatted = format!("{:.*e}", digits, value);
+        // 仮数部の末尾の 0 を除く ("1.50e6" → "1.5e6")
+        let (mantissa, exponent) = formatted.split_once('e').unwrap_or((&formatted, "0"));
+        let mantissa = if mantissa.contains('.') {
+            mantissa.trim_end_matches('0').trim_end_matches('.')
+        } else {
+            mantissa
+        };
+        return format!("{}e{}", mantissa, exponent);
+    }
+    format!("{:.*}", (-step_exponent).max(0) as usize, value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(ticks: &AxisTicks) -> Vec<&str> {
+        ticks
+            .major()
+            .iter()
+            .map(|tick| tick.label().as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_auto_range() -> anyhow::Result<()> {
+        let x = vec![Some(0.), None, Some(4.)];
+        let y = vec![Some(10.), Some(f64::NAN)];
+        let range = auto_range(&[&x, &y], AxisScale::Linear, 0.05)?;
+        assert_eq!((range.min(), range.max()), (-0.5, 10.5));
+
+        // 対数軸では 0 以下の値を除く (座標 0 〜 2 に 0.1 ずつ余白を付ける)
+        let values = vec![Some(-1.), Some(0.), Some(1.), Some(100.)];
+        let range = auto_range(&[&values], AxisScale::Log10, 0.05)?;
+        assert!((range.min() - 10f64.powf(-0.1)).abs() < 1e-12);
+        assert!((range.max() - 10f64.powf(2.1)).abs() < 1e-9);
+
+        // 逆数軸の範囲は正のまま
+        let range = auto_range(&[&values], AxisScale::Reciprocal, 0.05)?;
+        assert!(range.min() > 0.);
+
+        let range = auto_range(&[&[Some(2.)]], AxisScale::Linear, 0.)?;
+        assert_eq!((range.min(), range.max()), (1.8, 2.2));
+        assert!(matches!(
+            auto_range(&[&[Some(0.), None]], AxisScale::Ln, 0.05),
+            Err(AxisError::NoValues(AxisScale::Ln))
+        ));
+
+        // 範囲を指定した軸はその範囲を使う
+        let axis = Plot2DAxis::new(
+            String::new(),
+            AxisScale::Linear,
+            Some(AxisRange::new(0., 1.)?),
+            true,
+        )?;
+        assert_eq!(resolve_range(&axis, &[&x])?, AxisRange::new(0., 1.)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_linear_ticks() -> anyhow::Result<()> {
+        let ticks = AxisTicks::new(AxisRange::new(-0.5, 10.5)?, AxisScale::Linear, 5);
+        assert_eq!(labels(&ticks), vec!["0", "2", "4", "6", "8", "10"]);
+        // 2 刻みの主目盛りの間を 4 等分する
+        assert_eq!(&ticks.minor()[..4], &[-0.5, 0.5, 1., 1.5]);
+        assert_eq!(ticks.minor().len(), 17);
+
+        let ticks = AxisTicks::new(AxisRange::new(-0.3, 0.3)?, AxisScale::Linear, 6);
+        assert_eq!(
+            labels(&ticks),
+            vec!["-0.3", "-0.2", "-0.1", "0", "0.1", "0.2", "0.3"]
+        );
+        assert_eq!(ticks.major()[3].value(), 0.);
+
+        let ticks = AxisTicks::new(AxisRange::new(0., 3e6)?, AxisScale::Linear, 5);
+        assert_eq!(labels(&ticks), vec!["0", "1e6", "2e6", "3e6"]);
+        let ticks = AxisTicks::new(AxisRange::new(1e-5, 4e-5)?, AxisScale::Linear, 3);
+        assert_eq!(labels(&ticks), vec!["1e-5", "2e-5", "3e-5", "4e-5"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_power_and_probability_ticks() -> anyhow::Result<()> {
+        let ticks = AxisTicks::new(AxisRange::new(1., 1000.)?, AxisScale::Log10, 5);
+        assert_eq!(labels(&ticks), vec!["1", "10", "100", "1000"]);
+        assert_eq!(ticks.minor().len(), 24);
+
+        // 13 桁は 3 桁ごと
+        let ticks = AxisTicks::new(AxisRange::new(1e-3, 1e9)?, AxisScale::Log10, 5);
+        assert_eq!(labels(&ticks), vec!["0.001", "1", "1000", "1e6", "1e9"]);
+        assert_eq!(ticks.minor().len(), 8);
+
+        // 1 桁に収まる場合は線形の刻み
+        let ticks = AxisTicks::new(AxisRange::new(2., 8.)?, AxisScale::Log10, 4);
+        assert_eq!(labels(&ticks), vec!["2", "4", "6", "8"]);
+
+        let ticks = AxisTicks::new(AxisRange::new(1., 100.)?, AxisScale::Ln, 6);
+        assert_eq!(labels(&ticks), vec!["1", "e", "e^2", "e^3", "e^4"]);
+
+        let ticks = AxisTicks::new(AxisRange::new(0.005, 0.995)?, AxisScale::Probability, 5);
+        assert_eq!(labels(&ticks), vec!["0.01", "0.1", "0.5", "0.9", "0.99"]);
+        assert_eq!(ticks.minor().len(), 10);
+        Ok(())
+    }
+}
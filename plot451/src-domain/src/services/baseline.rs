@@ -0,0 +1,397 @@
+use thiserror::Error;
+
+use crate::models::column::column_cell::column_cell_value::CellRawValue;
+
+use super::least_squares::{
+    LeastSquaresError, LeastSquaresFit, LeastSquaresModel, MAX_POLYNOMIAL_DEGREE,
+};
+
+// ベースラインの推定方法
+#[derive(Debug, Clone, PartialEq)]
+pub enum BaselineMethod {
+    // アンカー領域 (x の閉区間) 内の点に当てはめた多項式
+    Polynomial {
+        degree: usize,
+        anchors: Vec<(f64, f64)>,
+    },
+    // 非対称最小二乗法 (Eilers & Boelens)
+    // smoothness は二階差分の罰則の重み、asymmetry はベースラインより上の点の重み
+    AsymmetricLeastSquares {
+        smoothness: f64,
+        asymmetry: f64,
+        iterations: usize,
+    },
+    // 半径 radius の円を曲線の下から転がした軌跡 (x と y は同じ尺度とみなす)
+    RollingBall {
+        radius: f64,
+    },
+}
+
+// domain service
+// ベースラインとそれを差し引いた値
+// x, y のいずれかが None (または NaN) の行は除き、その行の結果は None にする
+// x は狭義単調増加でなければならない
+#[derive(Debug, Clone, PartialEq)]
+pub struct Baseline {
+    baseline: Vec<CellRawValue>,
+    corrected: Vec<CellRawValue>,
+}
+
+impl Baseline {
+    pub fn estimate(
+        x: &[CellRawValue],
+        y: &[CellRawValue],
+        method: &BaselineMethod,
+    ) -> Result<Self, BaselineError> {
+        let row_count = x.len().max(y.len());
+        let value_at = |values: &[CellRawValue], row: usize| {
+            values
+                .get(row)
+                .copied()
+                .flatten()
+                .filter(|value| !value.is_nan())
+        };
+        let mut rows = vec![];
+        let mut xs: Vec<f64> = vec![];
+        let mut ys = vec![];
+        for row in 0..row_count {
+            if let (Some(x), Some(y)) = (value_at(x, row), value_at(y, row)) {
+                if xs.last().is_some_and(|last_x| x <= *last_x) {
+                    return Err(BaselineError::NonIncreasingX(row));
+                }
+                rows.push(row);
+                xs.push(x);
+                ys.push(y);
+            }
+        }
+
+        let values = match method {
+            BaselineMethod::Polynomial { degree, anchors } => {
+                Self::polynomial(&xs, &ys, *degree, anchors)?
+            }
+            BaselineMethod::AsymmetricLeastSquares {
+                smoothness,
+                asymmetry,
+                iterations,
+            } => Self::asymmetric_least_squares(&ys, *smoothness, *asymmetry, *iterations)?,
+            BaselineMethod::RollingBall { radius } => Self::rolling_ball(&xs, &ys, *radius)?,
+        };
+
+        let mut baseline = vec![None; row_count];
+        let mut corrected = vec![None; row_count];
+        for ((row, y), value) in rows.into_iter().zip(ys).zip(values) {
+            baseline[row] = Some(value);
+            corrected[row] = Some(y - value);
+        }
+        Ok(Self {
+            baseline,
+            corrected,
+        })
+    }
+
+    fn polynomial(
+        xs: &[f64],
+        ys: &[f64],
+        degree: usize,
+        anchors: &[(f64, f64)],
+    ) -> Result<Vec<f64>, BaselineError> {
+        if anchors.is_empty() {
+            return Err(BaselineError::NoAnchorRegions);
+        }
+        if let Some((start, end)) = anchors
+            .iter()
+            .find(|(start, end)| !start.is_finite() || !end.is_finite() || start > end)
+        {
+            return Err(BaselineError::InvalidAnchorRegion(*start, *end));
+        }
+        let (anchor_x, anchor_y): (Vec<CellRawValue>, Vec<CellRawValue>) = xs
+            .iter()
+            .zip(ys)
+            .filter(|(x, _)| anchors.iter().any(|(start, end)| start <= *x && *x <= end))
+            .map(|(x, y)| (Some(*x), Some(*y)))
+            .unzip();
+        let fit = LeastSquaresFit::fit(LeastSquaresModel::Polynomial(degree), &anchor_x, &anchor_y)
+            .map_err(|error| match error {
+                LeastSquaresError::InsufficientPoints(required, given) => {
+                    BaselineError::InsufficientPoints(required, given)
+                }
+                LeastSquaresError::SingularMatrix => BaselineError::SingularMatrix,
+                LeastSquaresError::InvalidDegree(degree) => BaselineError::InvalidDegree(degree),
+            })?;
+        Ok(xs.iter().map(|x| fit.evaluate(*x)).collect())
+    }
+
+    // 重み付きの (W + λ DᵀD) z = W y を解き、z より上の点の重みを asymmetry、下の点の重みを
+    // 1 - asymmetry として繰り返す
+    fn asymmetric_least_squares(
+        ys: &[f64],
+        smoothness: f64,
+        asymmetry: f64,
+        iterations: usize,
+    ) -> Result<Vec<f64>, BaselineError> {
+        if !smoothness.is_finite() || smoothness <= 0. {
+            return Err(BaselineError::InvalidSmoothness(smoothness));
+        }
+        if !(asymmetry > 0. && asymmetry < 1.) {
+            return Err(BaselineError::InvalidAsymmetry(asymmetry));
+        }
+        if iterations == 0 {
+            return Err(BaselineError::InvalidIterations);
+        }
+        let n = ys.len();
+        if n < 3 {
+            return Err(BaselineError::InsufficientPoints(3, n));
+        }
+
+        // λ DᵀD (D は二階差分行列) の帯 (band[i][d] は (i, i + d) 成分)
+        let mut penalty = vec![[0.; 3]; n];
+        let difference = [1., -2., 1.];
+        for k in 0..n - 2 {
+            for a in 0..3 {
+                for b in a..3 {
+                    penalty[k + a][b - a] += smoothness * difference[a] * difference[b];
+                }
+            }
+        }
+
+        let mut weights = vec![1.; n];
+        let mut baseline = vec![0.; n];
+        for _ in 0..iterations {
+            let mut band = penalty.clone();
+            for (row, weight) in band.iter_mut().zip(&weights) {
+                row[0] += weight;
+            }
+            let rhs: Vec<f64> = weights.iter().zip(ys).map(|(w, y)| w * y).collect();
+            baseline = solve_pentadiagonal(&band, &rhs).ok_or(BaselineError::SingularMatrix)?;
+            weights = ys
+                .iter()
+                .zip(&baseline)
+                .map(|(y, z)| if y > z { asymmetry } else { 1. - asymmetry })
+                .collect();
+        }
+        Ok(baseline)
+    }
+
+    // 各点を中心とする円の上端が曲線を越えない最高の位置を求め (収縮)、
+    // それらの円の上端の包絡線をベースラインとする (膨張)
+    fn rolling_ball(xs: &[f64], ys: &[f64], radius: f64) -> Result<Vec<f64>, BaselineError> {
+        if !radius.is_finite() || radius <= 0. {
+            return Err(BaselineError::InvalidRadius(radius));
+        }
+        let arc = |distance: f64| (radius * radius - distance * distance).max(0.).sqrt();
+        let window = |center: usize| {
+            let start = xs.partition_point(|x| *x < xs[center] - radius);
+            let end = xs.partition_point(|x| *x <= xs[center] + radius);
+            start..end
+        };
+        let centers: Vec<f64> = (0..xs.len())
+            .map(|center| {
+                window(center)
+                    .map(|i| ys[i] - arc(xs[i] - xs[center]))
+                    .fold(f64::INFINITY, f64::min)
+            })
+            .collect();
+        Ok((0..xs.len())
+            .map(|point| {
+                window(point)
+                    .map(|center| centers[center] + arc(xs[point] - xs[center]))
+                    .fold(f64::NEG_INFINITY, f64::max)
+            })
+            .collect())
+    }
+
+    pub fn baseline(&self) -> &Vec<CellRawValue> {
+        &self.baseline
+    }
+
+    pub fn corrected(&self) -> &Vec<CellRawValue> {
+        &self.corrected
+    }
+}
+
+// 帯幅 2 の対称正定値行列 (band[i][d] は (i, i + d) 成分) についてコレスキー分解で a x = b を解く
+fn solve_pentadiagonal(band: &[[f64; 3]], b: &[f64]) -> Option<Vec<f64>> {
+    let n = b.len();
+    // lower[i][d] は L の (i, i - d) 成分
+    let mut lower = vec![[0.; 3]; n];
+    for i in 0..n {
+        for j in i.saturating_sub(2)..=i {
+            let mut sum = band[j][i - j];
+            for k in i.saturating_sub(2)..j {
+                sum -= lower[i][i - k] * lower[j][j - k];
+            }
+            if i == j {
+                if sum <= 0. {
+                    return None;
+                }
+                lower[i][0] = sum.sqrt();
+            } else {
+                lower[i][i - j] = sum / lower[j][0];
+            }
+        }
+    }
+    // L y = b, Lᵀ x = y
+    let mut x = vec![0.; n];
+    for i in 0..n {
+        let sum: f64 = (i.saturating_sub(2)..i)
+            .map(|k| lower[i][i - k] * x[k])
+            .sum();
+        x[i] = (b[i] - sum) / lower[i][0];
+    }
+    for i in (0..n).rev() {
+        let sum: f64 = (i + 1..n.min(i + 3)).map(|k| lower[k][k - i] * x[k]).sum();
+        x[i] = (x[i] - sum) / lower[i][0];
+    }
+    Some(x)
+}
+
+#[derive(Debug, Error)]
+pub enum BaselineError {
+    #[error("x must be strictly increasing, at row {0}")]
+    NonIncreasingX(usize),
+    #[error("at least {0} points are required, but {1} points are given")]
+    InsufficientPoints(usize, usize),
+    #[error("normal matrix is singular")]
+    SingularMatrix,
+    #[error(
+        "polynomial degree must be between 1 and {}, but {0} is given",
+        MAX_POLYNOMIAL_DEGREE
+    )]
+    InvalidDegree(usize),
+    #[error("at least one anchor region is required")]
+    NoAnchorRegions,
+    #[error("invalid anchor region: [{0}, {1}]")]
+    InvalidAnchorRegion(f64, f64),
+    #[error("smoothness must be a positive number, but {0} is given")]
+    InvalidSmoothness(f64),
+    #[error("asymmetry must be between 0 and 1 exclusive, but {0} is given")]
+    InvalidAsymmetry(f64),
+    #[error("iterations must be positive")]
+    InvalidIterations,
+    #[error("radius must be a positive number, but {0} is given")]
+    InvalidRadius(f64),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 位置 5、標準偏差 0.3 のガウス関数のピーク + 背景
+    fn spectrum(background: impl Fn(f64) -> f64) -> (Vec<CellRawValue>, Vec<CellRawValue>) {
+        let x: Vec<f64> = (0..=200).map(|i| i as f64 * 0.05).collect();
+        let y = x
+            .iter()
+            .map(|x| Some(background(*x) + 3. * (-(x - 5.).powi(2) / (2. * 0.3 * 0.3)).exp()))
+            .collect();
+        (x.into_iter().map(Some).collect(), y)
+    }
+
+    fn max_error(
+        actual: &[CellRawValue],
+        expected: impl Fn(f64) -> f64,
+        x: &[CellRawValue],
+    ) -> f64 {
+        actual
+            .iter()
+            .zip(x)
+            .map(|(actual, x)| (actual.unwrap() - expected(x.unwrap())).abs())
+            .fold(0., f64::max)
+    }
+
+    #[test]
+    fn test_polynomial() -> anyhow::Result<()> {
+        let background = |x: f64| 1. + 0.5 * x;
+        let (x, y) = spectrum(background);
+        let method = BaselineMethod::Polynomial {
+            degree: 1,
+            anchors: vec![(0., 2.), (8., 10.)],
+        };
+        let baseline = Baseline::estimate(&x, &y, &method)?;
+        assert!(max_error(baseline.baseline(), background, &x) < 1e-9);
+        // 差し引いた値はピークだけになる
+        let corrected = baseline.corrected();
+        assert!((corrected[100].unwrap() - 3.).abs() < 1e-9);
+
+        assert!(matches!(
+            Baseline::estimate(
+                &x,
+                &y,
+                &BaselineMethod::Polynomial {
+                    degree: 3,
+                    anchors: vec![(0., 0.1)],
+                }
+            ),
+            Err(BaselineError::InsufficientPoints(4, 3))
+        ));
+        assert!(matches!(
+            Baseline::estimate(
+                &x,
+                &y,
+                &BaselineMethod::Polynomial {
+                    degree: 1,
+                    anchors: vec![],
+                }
+            ),
+            Err(BaselineError::NoAnchorRegions)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_asymmetric_least_squares() -> anyhow::Result<()> {
+        let background = |x: f64| 2. + 0.1 * x;
+        let (x, y) = spectrum(background);
+        let method = BaselineMethod::AsymmetricLeastSquares {
+            smoothness: 1e5,
+            asymmetry: 0.001,
+            iterations: 10,
+        };
+        let baseline = Baseline::estimate(&x, &y, &method)?;
+        assert!(max_error(baseline.baseline(), background, &x) < 0.05);
+
+        assert!(matches!(
+            Baseline::estimate(
+                &x,
+                &y,
+                &BaselineMethod::AsymmetricLeastSquares {
+                    smoothness: 1e5,
+                    asymmetry: 1.,
+                    iterations: 10,
+                }
+            ),
+            Err(BaselineError::InvalidAsymmetry(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_rolling_ball() -> anyhow::Result<()> {
+        let background = |_: f64| 1.;
+        let (mut x, mut y) = spectrum(background);
+        x[10] = None;
+        y[20] = Some(f64::NAN);
+        let method = BaselineMethod::RollingBall { radius: 50. };
+        let baseline = Baseline::estimate(&x, &y, &method)?;
+        assert_eq!(baseline.baseline()[10], None);
+        assert_eq!(baseline.corrected()[20], None);
+        // ピークから離れた点では背景と一致し、ピークの下ではわずかに浮く
+        for (x, value) in x.iter().zip(baseline.baseline()) {
+            if let (Some(x), Some(value)) = (x, value) {
+                let tolerance = if (x - 5.).abs() > 2. { 1e-9 } else { 0.02 };
+                assert!(
+                    (value - 1.).abs() < tolerance,
+                    "x: {}, baseline: {}",
+                    x,
+                    value
+                );
+            }
+        }
+
+        x[30] = Some(0.);
+        assert!(matches!(
+            Baseline::estimate(&x, &y, &BaselineMethod::RollingBall { radius: 50. }),
+            Err(BaselineError::NonIncreasingX(30))
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,128 @@
+use thiserror::Error;
+
+use crate::{
+    models::column::{
+        column::Column,
+        column_cell::{
+            column_cell::ColumnCell,
+            column_cell_value::{CellRawValue, ColumnCellValue, ColumnCellValueError},
+        },
+        column_directory::column_directory_id::ColumnDirectoryId,
+        column_factory::{ColumnFactoryError, IColumnFactory},
+        column_name::ColumnName,
+        column_provenance::ColumnProvenance,
+        column_repository::{ColumnRepositoryError, IColumnRepository},
+        column_with_cells::ColumnWithCells,
+    },
+    shared::value_object::ValueObject,
+};
+
+pub type ColumnCreationServiceResult<T> = anyhow::Result<T, ColumnCreationServiceError>;
+
+// domain service
+// 解析結果などの値の列から新しいカラムを作成して永続化する
+pub struct ColumnCreationService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory,
+    CR: IColumnRepository,
+{
+    column_factory: &'a CF,
+    column_repository: &'b CR,
+}
+
+impl<'a, 'b, CF, CR> ColumnCreationService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+{
+    pub fn new(column_factory: &'a CF, column_repository: &'b CR) -> Self {
+        Self {
+            column_factory,
+            column_repository,
+        }
+    }
+
+    // 永続化したカラムと、そのファーストクラスコレクションを返す
+    pub async fn create_column(
+        &self,
+        name: ColumnName,
+        directory_id: ColumnDirectoryId,
+        values: Vec<CellRawValue>,
+    ) -> ColumnCreationServiceResult<(Column, ColumnWithCells)> {
+        self.create_column_with_provenance(name, directory_id, values, None)
+            .await
+    }
+
+    // 生成元を記録したカラムを作成する
+    pub async fn create_column_from(
+        &self,
+        name: ColumnName,
+        directory_id: ColumnDirectoryId,
+        values: Vec<CellRawValue>,
+        provenance: ColumnProvenance,
+    ) -> ColumnCreationServiceResult<(Column, ColumnWithCells)> {
+        self.create_column_with_provenance(name, directory_id, values, Some(provenance))
+            .await
+    }
+
+    async fn create_column_with_provenance(
+        &self,
+        name: ColumnName,
+        directory_id: ColumnDirectoryId,
+        values: Vec<CellRawValue>,
+        provenance: Option<ColumnProvenance>,
+    ) -> ColumnCreationServiceResult<(Column, ColumnWithCells)> {
+        // セルのインスタンス化～永続化
+        let mut cells: Vec<ColumnCell> = vec![];
+        for value in values {
+            let cell_value = ColumnCellValue::new(value)
+                .map_err(ColumnCreationServiceError::ColumnCellValueError)?;
+            let mut cell = self
+                .column_factory
+                .create_cell(cell_value)
+                .await
+                .map_err(ColumnCreationServiceError::ColumnFactoryError)?;
+            let cell_id = self
+                .column_repository
+                .save_cell(&cell)
+                .await
+                .map_err(ColumnCreationServiceError::ColumnRepositoryError)?;
+            cell.set_id(cell_id);
+            cells.push(cell);
+        }
+
+        // カラムのインスタンス化～永続化
+        let mut column = self
+            .column_factory
+            .create_column(
+                name,
+                directory_id,
+                cells.iter().map(|cell| cell.id().clone()).collect(),
+            )
+            .await
+            .map_err(ColumnCreationServiceError::ColumnFactoryError)?;
+        column.change_provenance(provenance);
+        let column_id = self
+            .column_repository
+            .save(&column)
+            .await
+            .map_err(ColumnCreationServiceError::ColumnRepositoryError)?;
+        column.set_id(column_id);
+
+        let column_with_cells = ColumnWithCells::new(&column, cells);
+        Ok((column, column_with_cells))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ColumnCreationServiceError {
+    // repository errors
+    #[error("ColumnRepositoryError: [{0}]")]
+    ColumnRepositoryError(ColumnRepositoryError),
+    // factory errors
+    #[error("ColumnFactoryError: [{0}]")]
+    ColumnFactoryError(ColumnFactoryError),
+    // value object errors
+    #[error("ColumnCellValueError: [{0}]")]
+    ColumnCellValueError(ColumnCellValueError),
+}
@@ -0,0 +1,401 @@
+use std::fmt::Display;
+
+use thiserror::Error;
+
+use crate::shared::{distributions::ln_gamma, limits::MAX_CREATED_CELLS};
+
+use super::interpolation::{linspace, InterpolationError};
+
+// 乱数の分布
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RandomDistribution {
+    // [low, high) の一様分布
+    Uniform { low: f64, high: f64 },
+    Normal { mean: f64, standard_deviation: f64 },
+    Poisson { mean: f64 },
+    Exponential { rate: f64 },
+}
+
+impl Display for RandomDistribution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RandomDistribution::Uniform { low, high } => write!(f, "uniform({}, {})", low, high),
+            RandomDistribution::Normal {
+                mean,
+                standard_deviation,
+            } => write!(f, "normal({}, {})", mean, standard_deviation),
+            RandomDistribution::Poisson { mean } => write!(f, "Poisson({})", mean),
+            RandomDistribution::Exponential { rate } => write!(f, "exponential({})", rate),
+        }
+    }
+}
+
+// カラムの値の生成方法
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColumnGenerator {
+    // start から step ずつ増える count 個の等差数列
+    Sequence {
+        start: f64,
+        step: f64,
+        count: usize,
+    },
+    // start から end までを count 等分した点列 (両端を含む)
+    Linspace {
+        start: f64,
+        end: f64,
+        count: usize,
+    },
+    // start から end までを対数軸上で count 等分した点列 (両端を含む、いずれも正の値)
+    Logspace {
+        start: f64,
+        end: f64,
+        count: usize,
+    },
+    // 分布に従う count 個の乱数 (同じ seed からは同じ値が得られる)
+    Random {
+        distribution: RandomDistribution,
+        count: usize,
+        seed: u64,
+    },
+}
+
+impl ColumnGenerator {
+    // 生成する値の数
+    pub fn count(&self) -> usize {
+        match *self {
+            ColumnGenerator::Sequence { count, .. }
+            | ColumnGenerator::Linspace { count, .. }
+            | ColumnGenerator::Logspace { count, .. }
+            | ColumnGenerator::Random { count, .. } => count,
+        }
+    }
+
+    // domain service
+    // 生成方法に従って値を作る
+    pub fn generate(&self) -> Result<Vec<f64>, ColumnGeneratorError> {
+        if self.count() > MAX_CREATED_CELLS {
+            return Err(ColumnGeneratorError::TooManyValues(self.count()));
+        }
+        match *self {
+            ColumnGenerator::Sequence { start, step, count } => {
+                if !start.is_finite() || !step.is_finite() {
+                    return Err(ColumnGeneratorError::InvalidParameter(self.to_string()));
+                }
+                Ok((0..count).map(|i| start + step * i as f64).collect())
+            }
+            ColumnGenerator::Linspace { start, end, count } => {
+                linspace(start, end, count).map_err(ColumnGeneratorError::InterpolationError)
+            }
+            ColumnGenerator::Logspace { start, end, count } => {
+                if !(start > 0. && end > 0.) {
+                    return Err(ColumnGeneratorError::InvalidParameter(self.to_string()));
+                }
+                let exponents = linspace(start.log10(), end.log10(), count)
+                    .map_err(ColumnGeneratorError::InterpolationError)?;
+                // 両端は丸め誤差を避けて指定した値にする
+                Ok(exponents
+                    .iter()
+                    .enumerate()
+                    .map(|(i, exponent)| match i {
+                        0 => start,
+                        _ if i == count - 1 => end,
+                        _ => 10f64.powf(*exponent),
+                    })
+                    .collect())
+            }
+            ColumnGenerator::Random {
+                distribution,
+                count,
+                seed,
+            } => {
+                let valid = match distribution {
+                    RandomDistribution::Uniform { low, high } => {
+                        low.is_finite() && high.is_finite() && low < high
+                    }
+                    RandomDistribution::Normal {
+                        mean,
+                        standard_deviation,
+                    } => {
+                        mean.is_finite()
+                            && standard_deviation.is_finite()
+                            && standard_deviation >= 0.
+                    }
+                    RandomDistribution::Poisson { mean } => mean.is_finite() && mean >= 0.,
+                    RandomDistribution::Exponential { rate } => rate.is_finite() && rate > 0.,
+                };
+                if !valid {
+                    return Err(ColumnGeneratorError::InvalidParameter(self.to_string()));
+                }
+                let mut random = Xoshiro256::new(seed);
+                Ok((0..count).map(|_| random.sample(distribution)).collect())
+            }
+        }
+    }
+}
+
+impl Display for ColumnGenerator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColumnGenerator::Sequence { start, step, count } => {
+                write!(
+                    f,
+                    "sequence (start {}, step {}, {} values)",
+                    start, step, count
+                )
+            }
+            ColumnGenerator::Linspace { start, end, count } => {
+                write!(f, "linspace ({} to {}, {} values)", start, end, count)
+            }
+            ColumnGenerator::Logspace { start, end, count } => {
+                write!(f, "logspace ({} to {}, {} values)", start, end, count)
+            }
+            ColumnGenerator::Random {
+                distribution,
+                count,
+                seed,
+            } => write!(
+                f,
+                "random {} ({} values, seed {})",
+                distribution, count, seed
+            ),
+        }
+    }
+}
+
+// 擬似乱数生成器 (xoshiro256**、状態は SplitMix64 で seed から初期化する)
+struct Xoshiro256 {
+    state: [u64; 4],
+}
+
+impl Xoshiro256 {
+    fn new(seed: u64) -> Self {
+        let mut seed = seed;
+        let mut split_mix = || {
+            seed = seed.wrapping_add(0x9e3779b97f4a7c15);
+            let mut z = seed;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+            z ^ (z >> 31)
+        };
+        Self {
+            state: [split_mix(), split_mix(), split_mix(), split_mix()],
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let result = self.state[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = self.state[1] << 17;
+        self.state[2] ^= self.state[0];
+        self.state[3] ^= self.state[1];
+        self.state[1] ^= self.state[2];
+        self.state[0] ^= self.state[3];
+        self.state[2] ^= t;
+        self.state[3] = self.state[3].rotate_left(45);
+        result
+    }
+
+    // [0, 1) の一様乱数 (上位 53 ビットを使う)
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn sample(&mut self, distribution: RandomDistribution) -> f64 {
+        match distribution {
+            RandomDistribution::Uniform { low, high } => low + (high - low) * self.next_f64(),
+            // Box–Muller 法 (対数の引数が 0 にならないよう 1 - u を使う)
+            RandomDistribution::Normal {
+                mean,
+                standard_deviation,
+            } => {
+                let radius = (-2. * (1. - self.next_f64()).ln()).sqrt();
+                let angle = 2. * std::f64::consts::PI * self.next_f64();
+                mean + standard_deviation * radius * angle.cos()
+            }
+            RandomDistribution::Poisson { mean } => self.poisson(mean),
+            RandomDistribution::Exponential { rate } => -(1. - self.next_f64()).ln() / rate,
+        }
+    }
+
+    // 平均が小さい場合は一様乱数の積による方法、大きい場合は Hörmann の PTRS 法
+    fn poisson(&mut self, mean: f64) -> f64 {
+        if mean < 10. {
+            let limit = (-mean).exp();
+            let mut product = self.next_f64();
+            let mut k = 0.;
+            while product > limit {
+                product *= self.next_f64();
+                k += 1.;
+            }
+            return k;
+        }
+        let sqrt_mean = mean.sqrt();
+        let b = 0.931 + 2.53 * sqrt_mean;
+        let a = -0.059 + 0.02483 * b;
+        let inverse_alpha = 1.1239 + 1.1328 / (b - 3.4);
+        let v_r = 0.9277 - 3.6224 / (b - 2.);
+        loop {
+            let u = self.next_f64() - 0.5;
+            let v = self.next_f64();
+            let u_s = 0.5 - u.abs();
+            let k = ((2. * a / u_s + b) * u + mean + 0.43).floor();
+            if u_s >= 0.07 && v <= v_r {
+                return k;
+            }
+            if k < 0. || (u_s < 0.013 && v > u_s) {
+                continue;
+            }
+            if (v * inverse_alpha / (a / (u_s * u_s) + b)).ln()
+                <= -mean + k * mean.ln() - ln_gamma(k + 1.)
+            {
+                return k;
+            }
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ColumnGeneratorError {
+    #[error("invalid parameters for {0}")]
+    InvalidParameter(String),
+    #[error("too many values: {0} (at most {MAX_CREATED_CELLS})")]
+    TooManyValues(usize),
+    #[error("InterpolationError: [{0}]")]
+    InterpolationError(InterpolationError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sequences() -> anyhow::Result<()> {
+        let generator = ColumnGenerator::Sequence {
+            start: 1.,
+            step: 0.5,
+            count: 4,
+        };
+        assert_eq!(generator.generate()?, vec![1., 1.5, 2., 2.5]);
+        let generator = ColumnGenerator::Linspace {
+            start: 0.,
+            end: 1.,
+            count: 5,
+        };
+        assert_eq!(generator.generate()?, vec![0., 0.25, 0.5, 0.75, 1.]);
+        let generator = ColumnGenerator::Logspace {
+            start: 1.,
+            end: 1000.,
+            count: 4,
+        };
+        let values = generator.generate()?;
+        assert_eq!(values.len(), 4);
+        assert!((values[1] - 10.).abs() < 1e-12 && (values[2] - 100.).abs() < 1e-12);
+        assert_eq!(values[3], 1000.);
+
+        assert!(matches!(
+            ColumnGenerator::Logspace {
+                start: 0.,
+                end: 1.,
+                count: 3
+            }
+            .generate(),
+            Err(ColumnGeneratorError::InvalidParameter(_))
+        ));
+        assert!(matches!(
+            ColumnGenerator::Linspace {
+                start: 0.,
+                end: 1.,
+                count: 0
+            }
+            .generate(),
+            Err(ColumnGeneratorError::InterpolationError(_))
+        ));
+
+        // 生成する値の数には上限がある
+        for count in [MAX_CREATED_CELLS + 1, usize::MAX] {
+            assert!(matches!(
+                ColumnGenerator::Random {
+                    distribution: RandomDistribution::Uniform { low: 0., high: 1. },
+                    count,
+                    seed: 0,
+                }
+                .generate(),
+                Err(ColumnGeneratorError::TooManyValues(_))
+            ));
+        }
+        assert_eq!(
+            ColumnGenerator::Sequence {
+                start: 0.,
+                step: 1.,
+                count: MAX_CREATED_CELLS,
+            }
+            .generate()?
+            .len(),
+            MAX_CREATED_CELLS
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_random() -> anyhow::Result<()> {
+        let random = |distribution, seed| ColumnGenerator::Random {
+            distribution,
+            count: 20000,
+            seed,
+        };
+        let mean_and_variance = |values: &[f64]| {
+            let n = values.len() as f64;
+            let mean = values.iter().sum::<f64>() / n;
+            let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.);
+            (mean, variance)
+        };
+
+        // 同じ seed からは同じ値、違う seed からは違う値
+        let uniform = RandomDistribution::Uniform { low: 2., high: 4. };
+        let values = random(uniform, 42).generate()?;
+        assert_eq!(values, random(uniform, 42).generate()?);
+        assert_ne!(values, random(uniform, 43).generate()?);
+        assert!(values.iter().all(|value| (2. ..4.).contains(value)));
+
+        // 標本平均と標本分散が理論値に近い
+        let cases: [(RandomDistribution, f64, f64); 5] = [
+            (uniform, 3., 1. / 3.),
+            (
+                RandomDistribution::Normal {
+                    mean: -1.,
+                    standard_deviation: 2.,
+                },
+                -1.,
+                4.,
+            ),
+            (RandomDistribution::Poisson { mean: 3. }, 3., 3.),
+            (RandomDistribution::Poisson { mean: 50. }, 50., 50.),
+            (RandomDistribution::Exponential { rate: 2. }, 0.5, 0.25),
+        ];
+        for (distribution, mean, variance) in cases {
+            let (sample_mean, sample_variance) =
+                mean_and_variance(&random(distribution, 7).generate()?);
+            assert!(
+                (sample_mean - mean).abs() < 0.05 * variance.sqrt().max(1.),
+                "{}: mean {}",
+                distribution,
+                sample_mean
+            );
+            assert!(
+                (sample_variance / variance - 1.).abs() < 0.05,
+                "{}: variance {}",
+                distribution,
+                sample_variance
+            );
+        }
+        let poisson = random(RandomDistribution::Poisson { mean: 50. }, 1).generate()?;
+        assert!(poisson
+            .iter()
+            .all(|value| value.fract() == 0. && *value >= 0.));
+
+        assert!(matches!(
+            random(RandomDistribution::Exponential { rate: 0. }, 1).generate(),
+            Err(ColumnGeneratorError::InvalidParameter(_))
+        ));
+        Ok(())
+    }
+}
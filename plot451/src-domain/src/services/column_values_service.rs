@@ -0,0 +1,50 @@
+use crate::{
+    models::column::{
+        column::Column,
+        column_cell::column_cell_value::CellRawValue,
+        column_id::ColumnId,
+        column_repository::{ColumnRepositoryResult, IColumnRepository},
+    },
+    shared::value_object::ValueObject,
+};
+
+// domain service
+// 解析の入力となるカラムとその値 (セルの順) を取得する
+pub struct ColumnValuesService<'a, CR>
+where
+    CR: IColumnRepository,
+{
+    column_repository: &'a CR,
+}
+
+impl<'a, CR> ColumnValuesService<'a, CR>
+where
+    CR: IColumnRepository + Sync,
+{
+    pub fn new(column_repository: &'a CR) -> Self {
+        Self { column_repository }
+    }
+
+    // カラムが存在しない場合は None を返す
+    pub async fn find_column_values(
+        &self,
+        column_id: &ColumnId,
+    ) -> ColumnRepositoryResult<Option<(Column, Vec<CellRawValue>)>> {
+        let Some(column) = self.column_repository.find(column_id).await? else {
+            return Ok(None);
+        };
+        let values = self.find_values(&column).await?;
+        Ok(Some((column, values)))
+    }
+
+    // 取得済みのカラムの値 (セルの順)
+    pub async fn find_values(&self, column: &Column) -> ColumnRepositoryResult<Vec<CellRawValue>> {
+        Ok(self
+            .column_repository
+            .find_cells_by_ids(column.cells())
+            .await?
+            .iter()
+            .map(|cell| cell.cell_value().clone_value())
+            .collect())
+    }
+}
@@ -0,0 +1,178 @@
+use crate::models::column::column_cell::column_cell_value::CellRawValue;
+
+// 相関行列・共分散行列の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CorrelationMeasure {
+    Pearson,
+    Spearman,
+    Covariance,
+}
+
+impl std::fmt::Display for CorrelationMeasure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CorrelationMeasure::Pearson => write!(f, "Pearson correlation"),
+            CorrelationMeasure::Spearman => write!(f, "Spearman correlation"),
+            CorrelationMeasure::Covariance => write!(f, "covariance"),
+        }
+    }
+}
+
+// domain service
+// カラム間の相関行列と共分散行列
+// 欠損値はペアごとに除外する (2 つのカラムの両方に値がある行だけを使う)
+// 値が 2 個未満、または分散が 0 で求まらない成分は None になる
+#[derive(Debug, Clone, PartialEq)]
+pub struct CorrelationMatrices {
+    pearson: Vec<Vec<Option<f64>>>,
+    spearman: Vec<Vec<Option<f64>>>,
+    covariance: Vec<Vec<Option<f64>>>,
+    // 各成分の計算に使った行数
+    pair_counts: Vec<Vec<usize>>,
+}
+
+impl CorrelationMatrices {
+    pub fn compute(columns: &[Vec<CellRawValue>]) -> Self {
+        let n = columns.len();
+        let mut pearson = vec![vec![None; n]; n];
+        let mut spearman = vec![vec![None; n]; n];
+        let mut covariance = vec![vec![None; n]; n];
+        let mut pair_counts = vec![vec![0; n]; n];
+        for i in 0..n {
+            for j in i..n {
+                let (x, y) = complete_pairs(&columns[i], &columns[j]);
+                let values = [
+                    (&mut pearson, pearson_correlation(&x, &y)),
+                    (&mut spearman, pearson_correlation(&ranks(&x), &ranks(&y))),
+                    (&mut covariance, sample_covariance(&x, &y)),
+                ];
+                for (matrix, value) in values {
+                    matrix[i][j] = value;
+                    matrix[j][i] = value;
+                }
+                pair_counts[i][j] = x.len();
+                pair_counts[j][i] = x.len();
+            }
+        }
+        Self {
+            pearson,
+            spearman,
+            covariance,
+            pair_counts,
+        }
+    }
+
+    pub fn matrix(&self, measure: CorrelationMeasure) -> &Vec<Vec<Option<f64>>> {
+        match measure {
+            CorrelationMeasure::Pearson => &self.pearson,
+            CorrelationMeasure::Spearman => &self.spearman,
+            CorrelationMeasure::Covariance => &self.covariance,
+        }
+    }
+
+    pub fn pair_counts(&self) -> &Vec<Vec<usize>> {
+        &self.pair_counts
+    }
+}
+
+// 両方に値 (None と NaN 以外) がある行の値の組
+fn complete_pairs(x: &[CellRawValue], y: &[CellRawValue]) -> (Vec<f64>, Vec<f64>) {
+    x.iter()
+        .zip(y)
+        .filter_map(|(x, y)| match (x, y) {
+            (Some(x), Some(y)) if !x.is_nan() && !y.is_nan() => Some((*x, *y)),
+            _ => None,
+        })
+        .unzip()
+}
+
+// 標本共分散 (n - 1 で割る)
+fn sample_covariance(x: &[f64], y: &[f64]) -> Option<f64> {
+    let n = x.len();
+    if n < 2 {
+        return None;
+    }
+    let mean_x = x.iter().sum::<f64>() / n as f64;
+    let mean_y = y.iter().sum::<f64>() / n as f64;
+    let sum: f64 = x
+        .iter()
+        .zip(y)
+        .map(|(x, y)| (x - mean_x) * (y - mean_y))
+        .sum();
+    Some(sum / (n - 1) as f64)
+}
+
+fn pearson_correlation(x: &[f64], y: &[f64]) -> Option<f64> {
+    let covariance = sample_covariance(x, y)?;
+    let variance_x = sample_covariance(x, x)?;
+    let variance_y = sample_covariance(y, y)?;
+    if variance_x <= 0. || variance_y <= 0. {
+        return None;
+    }
+    // 丸め誤差で [-1, 1] を超えないようにする
+    Some((covariance / (variance_x * variance_y).sqrt()).clamp(-1., 1.))
+}
+
+// 1 から始まる順位 (同順位は平均順位)
+pub fn ranks(values: &[f64]) -> Vec<f64> {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|a, b| values[*a].total_cmp(&values[*b]));
+    let mut ranks = vec![0.; values.len()];
+    let mut start = 0;
+    while start < order.len() {
+        let mut end = start + 1;
+        while end < order.len() && values[order[end]] == values[order[start]] {
+            end += 1;
+        }
+        // start + 1 位から end 位までの平均
+        let rank = (start + 1 + end) as f64 / 2.;
+        for index in &order[start..end] {
+            ranks[*index] = rank;
+        }
+        start = end;
+    }
+    ranks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ranks() {
+        assert_eq!(ranks(&[3., 1., 4., 1., 5.]), vec![3., 1.5, 4., 1.5, 5.]);
+        assert_eq!(ranks(&[]), Vec::<f64>::new());
+    }
+
+    #[test]
+    fn test_matrices() {
+        let columns = vec![
+            vec![Some(1.), Some(2.), Some(3.), Some(4.), None],
+            // 単調増加だが線形ではない
+            vec![Some(1.), Some(4.), Some(9.), Some(100.), Some(7.)],
+            vec![Some(4.), Some(3.), None, Some(1.), Some(0.)],
+            vec![Some(2.), Some(2.), Some(2.), Some(2.), Some(2.)],
+        ];
+        let matrices = CorrelationMatrices::compute(&columns);
+
+        let pearson = matrices.matrix(CorrelationMeasure::Pearson);
+        assert_eq!(pearson[0][0], Some(1.));
+        assert!(pearson[0][1].unwrap() < 1.);
+        assert_eq!(pearson[0][1], pearson[1][0]);
+        // 1, 2, 4 行目だけを使う: x = [1, 2, 4], y = [4, 3, 1] は完全に線形
+        assert!((pearson[0][2].unwrap() + 1.).abs() < 1e-12);
+        // 分散が 0 のカラムとの相関は求まらない
+        assert_eq!(pearson[0][3], None);
+
+        let spearman = matrices.matrix(CorrelationMeasure::Spearman);
+        assert_eq!(spearman[0][1], Some(1.));
+        assert_eq!(spearman[0][2], Some(-1.));
+
+        let covariance = matrices.matrix(CorrelationMeasure::Covariance);
+        assert!((covariance[0][0].unwrap() - 5. / 3.).abs() < 1e-12);
+        assert_eq!(covariance[0][3], Some(0.));
+
+        assert_eq!(matrices.pair_counts()[0][2], 3);
+        assert_eq!(matrices.pair_counts()[1][3], 5);
+    }
+}
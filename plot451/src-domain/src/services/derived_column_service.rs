@@ -0,0 +1,284 @@
+use std::collections::HashMap;
+
+use thiserror::Error;
+
+use crate::{
+    models::column::{
+        column::{Column, ColumnEntityError},
+        column_cell::{
+            column_cell::ColumnCell,
+            column_cell_value::{CellRawValue, ColumnCellValue, ColumnCellValueError},
+        },
+        column_constraint_specification::{
+            ColumnConstraintSpecificationError, ColumnValuesConstraintsSpecification,
+        },
+        column_factory::{ColumnFactoryError, IColumnFactory},
+        column_formula::{
+            column_dependency_graph::{ColumnDependencyGraph, ColumnDependencyGraphError},
+            formula_expression::FormulaExpressionError,
+        },
+        column_id::ColumnId,
+        column_repository::{ColumnRepositoryError, IColumnRepository},
+    },
+    shared::{specification::Specification, value_object::ValueObject},
+};
+
+use super::column_values_service::ColumnValuesService;
+
+pub type DerivedColumnServiceResult<T> = anyhow::Result<T, DerivedColumnServiceError>;
+
+// 再計算した派生カラムとその値 (まだ永続化していない)
+pub struct RecomputedColumn {
+    column: Column,
+    values: Vec<CellRawValue>,
+}
+
+impl RecomputedColumn {
+    pub fn column(&self) -> &Column {
+        &self.column
+    }
+
+    pub fn values(&self) -> &Vec<CellRawValue> {
+        &self.values
+    }
+}
+
+// domain service
+// 派生カラムの値を参照先カラムから再計算する
+// 再計算と制約のチェックをすべて終えてから永続化する
+pub struct DerivedColumnService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory,
+    CR: IColumnRepository,
+{
+    column_factory: &'a CF,
+    column_repository: &'b CR,
+}
+
+impl<'a, 'b, CF, CR> DerivedColumnService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+{
+    pub fn new(column_factory: &'a CF, column_repository: &'b CR) -> Self {
+        Self {
+            column_factory,
+            column_repository,
+        }
+    }
+
+    // 派生カラムのセルを再計算して永続化し、再計算後のセルを返す
+    // 既存のセルは id を保ったまま値を更新し、行数の増減に応じてセルを追加・削除する
+    pub async fn recompute(
+        &self,
+        column: &mut Column,
+    ) -> DerivedColumnServiceResult<Vec<ColumnCell>> {
+        let mut values = HashMap::new();
+        let recomputed = self.compute(column.clone(), &mut values).await?;
+        *column = recomputed.column.clone();
+        self.save(column, recomputed.values).await
+    }
+
+    // 値を変更するカラム (変更後のカラムと値、まだ永続化していない) を参照する派生カラムを
+    // 参照順に再計算して制約をチェックする。changed のうち派生カラムであるものも再計算する
+    // 何も永続化しないので、エラーの場合はそのまま中断してよい
+    pub async fn compute_dependents(
+        &self,
+        changed: Vec<(Column, Vec<CellRawValue>)>,
+    ) -> DerivedColumnServiceResult<Vec<RecomputedColumn>> {
+        let mut columns: HashMap<ColumnId, Column> = self
+            .column_repository
+            .find_all()
+            .await
+            .map_err(DerivedColumnServiceError::ColumnRepositoryError)?
+            .into_iter()
+            .map(|column| (column.id().clone(), column))
+            .collect();
+        let mut values = HashMap::new();
+        let mut changed_ids = vec![];
+        for (column, column_values) in changed {
+            changed_ids.push(column.id().clone());
+            values.insert(column.id().clone(), column_values);
+            columns.insert(column.id().clone(), column);
+        }
+
+        let all_columns: Vec<Column> = columns.values().cloned().collect();
+        let order = ColumnDependencyGraph::new(&all_columns)
+            .dependents_in_order(&changed_ids)
+            .map_err(DerivedColumnServiceError::ColumnDependencyGraphError)?;
+
+        let mut recomputed_columns = vec![];
+        for column_id in &order {
+            let column = columns
+                .get(column_id)
+                .ok_or(DerivedColumnServiceError::ColumnNotFound(column_id.clone()))?;
+            let recomputed = self.compute(column.clone(), &mut values).await?;
+            values.insert(column_id.clone(), recomputed.values.clone());
+            recomputed_columns.push(recomputed);
+        }
+        Ok(recomputed_columns)
+    }
+
+    // compute_dependents の結果を永続化し、再計算したカラムの id を返す
+    pub async fn save_all(
+        &self,
+        recomputed_columns: Vec<RecomputedColumn>,
+    ) -> DerivedColumnServiceResult<Vec<ColumnId>> {
+        let mut column_ids = vec![];
+        for RecomputedColumn { mut column, values } in recomputed_columns {
+            self.save(&mut column, values).await?;
+            column_ids.push(column.id().clone());
+        }
+        Ok(column_ids)
+    }
+
+    // 数式を評価して制約をチェックする (values にない参照先はリポジトリから取得する)
+    async fn compute(
+        &self,
+        column: Column,
+        values: &mut HashMap<ColumnId, Vec<CellRawValue>>,
+    ) -> DerivedColumnServiceResult<RecomputedColumn> {
+        let Some(formula) = column.formula().clone() else {
+            return Err(DerivedColumnServiceError::NotDerivedColumn(
+                column.id().clone(),
+            ));
+        };
+
+        // 参照先カラムの値を取得
+        let missing: Vec<ColumnId> = formula
+            .dependencies()
+            .into_iter()
+            .filter(|column_id| !values.contains_key(column_id))
+            .collect();
+        let sources = self
+            .column_repository
+            .find_by_ids(&missing)
+            .await
+            .map_err(DerivedColumnServiceError::ColumnRepositoryError)?;
+        let column_values_service = ColumnValuesService::new(self.column_repository);
+        for source in sources {
+            let source_values = column_values_service
+                .find_values(&source)
+                .await
+                .map_err(DerivedColumnServiceError::ColumnRepositoryError)?;
+            values.insert(source.id().clone(), source_values);
+        }
+        let new_values = formula
+            .evaluate(values)
+            .map_err(DerivedColumnServiceError::FormulaExpressionError)?;
+
+        // カラムに設定された制約のチェック
+        ColumnValuesConstraintsSpecification::new(column.constraints().clone())
+            .is_satisfied_by(&new_values)
+            .map_err(DerivedColumnServiceError::ColumnConstraintSpecificationError)?;
+
+        Ok(RecomputedColumn {
+            column,
+            values: new_values,
+        })
+    }
+
+    // 再計算した値でセルを更新・追加・削除してカラムを永続化する
+    async fn save(
+        &self,
+        column: &mut Column,
+        new_values: Vec<CellRawValue>,
+    ) -> DerivedColumnServiceResult<Vec<ColumnCell>> {
+        // 既存のセルの更新
+        let mut cells = self
+            .column_repository
+            .find_cells_by_ids(column.cells())
+            .await
+            .map_err(DerivedColumnServiceError::ColumnRepositoryError)?;
+        for (cell, value) in cells.iter_mut().zip(new_values.iter()) {
+            if cell.cell_value().value() != value {
+                cell.edit_cell_value(
+                    ColumnCellValue::new(*value)
+                        .map_err(DerivedColumnServiceError::ColumnCellValueError)?,
+                );
+                self.column_repository
+                    .save_cell(cell)
+                    .await
+                    .map_err(DerivedColumnServiceError::ColumnRepositoryError)?;
+            }
+        }
+
+        // 余剰のセルの削除
+        let excess_cells = if cells.len() > new_values.len() {
+            cells.split_off(new_values.len())
+        } else {
+            vec![]
+        };
+        if !excess_cells.is_empty() {
+            column
+                .remove_cells_at(new_values.len(), excess_cells.len())
+                .map_err(DerivedColumnServiceError::ColumnEntityError)?;
+        }
+        for cell in excess_cells {
+            self.column_repository
+                .delete_cell(cell)
+                .await
+                .map_err(DerivedColumnServiceError::ColumnRepositoryError)?;
+        }
+
+        // 不足するセルの追加
+        let mut new_cell_ids = vec![];
+        for value in new_values.iter().skip(cells.len()) {
+            let cell_value = ColumnCellValue::new(*value)
+                .map_err(DerivedColumnServiceError::ColumnCellValueError)?;
+            let mut cell = self
+                .column_factory
+                .create_cell(cell_value)
+                .await
+                .map_err(DerivedColumnServiceError::ColumnFactoryError)?;
+            let cell_id = self
+                .column_repository
+                .save_cell(&cell)
+                .await
+                .map_err(DerivedColumnServiceError::ColumnRepositoryError)?;
+            cell.set_id(cell_id.clone());
+            new_cell_ids.push(cell_id);
+            cells.push(cell);
+        }
+        column
+            .insert_cells_at(column.cells().len(), new_cell_ids)
+            .map_err(DerivedColumnServiceError::ColumnEntityError)?;
+
+        // カラムの永続化
+        self.column_repository
+            .save(column)
+            .await
+            .map_err(DerivedColumnServiceError::ColumnRepositoryError)?;
+
+        Ok(cells)
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum DerivedColumnServiceError {
+    // repository errors
+    #[error("ColumnRepositoryError: [{0}]")]
+    ColumnRepositoryError(ColumnRepositoryError),
+    // factory errors
+    #[error("ColumnFactoryError: [{0}]")]
+    ColumnFactoryError(ColumnFactoryError),
+    // value object errors
+    #[error("ColumnCellValueError: [{0}]")]
+    ColumnCellValueError(ColumnCellValueError),
+    #[error("FormulaExpressionError: [{0}]")]
+    FormulaExpressionError(FormulaExpressionError),
+    // entity errors
+    #[error("ColumnEntityError: [{0}]")]
+    ColumnEntityError(ColumnEntityError),
+    // specification errors
+    #[error("ColumnConstraintSpecificationError: [{0}]")]
+    ColumnConstraintSpecificationError(ColumnConstraintSpecificationError),
+    // first class collection errors
+    #[error("ColumnDependencyGraphError: [{0}]")]
+    ColumnDependencyGraphError(ColumnDependencyGraphError),
+    // not found errors
+    #[error("ColumnNotFound: [{0}]")]
+    ColumnNotFound(ColumnId),
+    #[error("NotDerivedColumn: [{0}]")]
+    NotDerivedColumn(ColumnId),
+}
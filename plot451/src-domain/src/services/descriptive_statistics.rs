@@ -0,0 +1,214 @@
+use crate::{
+    models::column::{
+        column_cell::column_cell_value::CellRawValue, column_with_cells::ColumnWithCells,
+    },
+    shared::value_object::ValueObject,
+};
+
+// domain service
+// カラムの記述統計量
+// None と NaN は欠損値として扱い、統計量の計算から除外する
+// 計算に必要な個数に満たない統計量は None になる
+#[derive(Debug, Clone, PartialEq)]
+pub struct DescriptiveStatistics {
+    count: usize,
+    none_count: usize,
+    mean: Option<f64>,
+    standard_deviation: Option<f64>,
+    standard_error: Option<f64>,
+    min: Option<f64>,
+    max: Option<f64>,
+    median: Option<f64>,
+    first_quartile: Option<f64>,
+    third_quartile: Option<f64>,
+    skewness: Option<f64>,
+    kurtosis: Option<f64>,
+}
+
+impl DescriptiveStatistics {
+    pub fn from_column_with_cells(column_with_cells: &ColumnWithCells) -> Self {
+        let values: Vec<CellRawValue> = column_with_cells
+            .cells()
+            .iter()
+            .map(|cell| cell.cell_value().clone_value())
+            .collect();
+        Self::from_values(&values)
+    }
+
+    pub fn from_values(values: &[CellRawValue]) -> Self {
+        let mut sorted: Vec<f64> = values
+            .iter()
+            .filter_map(|value| value.filter(|value| !value.is_nan()))
+            .collect();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        let count = sorted.len();
+        let none_count = values.len() - count;
+        let n = count as f64;
+
+        let mean = (count >= 1).then(|| sorted.iter().sum::<f64>() / n);
+        // 平均からの偏差の k 乗和
+        let central_moment_sum = |k: i32| {
+            let mean = mean.unwrap_or(0.);
+            sorted
+                .iter()
+                .map(|value| (value - mean).powi(k))
+                .sum::<f64>()
+        };
+
+        // 標本標準偏差 (不偏分散の平方根)
+        let standard_deviation = (count >= 2).then(|| (central_moment_sum(2) / (n - 1.)).sqrt());
+        let standard_error = standard_deviation.map(|sd| sd / n.sqrt());
+
+        // 歪度・尖度は標本数で補正した推定量 (Excel の SKEW, KURT と同じ定義)
+        let skewness = standard_deviation
+            .filter(|sd| count >= 3 && *sd > 0.)
+            .map(|sd| n / ((n - 1.) * (n - 2.)) * central_moment_sum(3) / sd.powi(3));
+        let kurtosis = standard_deviation
+            .filter(|sd| count >= 4 && *sd > 0.)
+            .map(|sd| {
+                n * (n + 1.) / ((n - 1.) * (n - 2.) * (n - 3.)) * central_moment_sum(4) / sd.powi(4)
+                    - 3. * (n - 1.).powi(2) / ((n - 2.) * (n - 3.))
+            });
+
+        Self {
+            count,
+            none_count,
+            mean,
+            standard_deviation,
+            standard_error,
+            min: sorted.first().copied(),
+            max: sorted.last().copied(),
+            median: quantile(&sorted, 0.5),
+            first_quartile: quantile(&sorted, 0.25),
+            third_quartile: quantile(&sorted, 0.75),
+            skewness,
+            kurtosis,
+        }
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn none_count(&self) -> usize {
+        self.none_count
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        self.mean
+    }
+
+    pub fn standard_deviation(&self) -> Option<f64> {
+        self.standard_deviation
+    }
+
+    pub fn standard_error(&self) -> Option<f64> {
+        self.standard_error
+    }
+
+    pub fn min(&self) -> Option<f64> {
+        self.min
+    }
+
+    pub fn max(&self) -> Option<f64> {
+        self.max
+    }
+
+    pub fn median(&self) -> Option<f64> {
+        self.median
+    }
+
+    pub fn first_quartile(&self) -> Option<f64> {
+        self.first_quartile
+    }
+
+    pub fn third_quartile(&self) -> Option<f64> {
+        self.third_quartile
+    }
+
+    pub fn skewness(&self) -> Option<f64> {
+        self.skewness
+    }
+
+    pub fn kurtosis(&self) -> Option<f64> {
+        self.kurtosis
+    }
+}
+
+// 昇順にソート済みの値の分位数 (隣接する順序統計量の線形補間)
+pub fn quantile(sorted: &[f64], p: f64) -> Option<f64> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let position = p.clamp(0., 1.) * (sorted.len() - 1) as f64;
+    let lower = position.floor() as usize;
+    let upper = position.ceil() as usize;
+    let fraction = position - lower as f64;
+    Some(sorted[lower] + (sorted[upper] - sorted[lower]) * fraction)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: Option<f64>, expected: f64) {
+        let actual = actual.expect("value is None");
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "actual: {}, expected: {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_from_values() {
+        let values = vec![
+            Some(2.),
+            None,
+            Some(4.),
+            Some(4.),
+            Some(4.),
+            Some(f64::NAN),
+            Some(5.),
+            Some(5.),
+            Some(7.),
+            Some(9.),
+        ];
+        let statistics = DescriptiveStatistics::from_values(&values);
+        assert_eq!(statistics.count(), 8);
+        assert_eq!(statistics.none_count(), 2);
+        assert_close(statistics.mean(), 5.);
+        assert_close(statistics.standard_deviation(), (32f64 / 7.).sqrt());
+        assert_close(
+            statistics.standard_error(),
+            (32f64 / 7.).sqrt() / 8f64.sqrt(),
+        );
+        assert_eq!(statistics.min(), Some(2.));
+        assert_eq!(statistics.max(), Some(9.));
+        assert_close(statistics.median(), 4.5);
+        assert_close(statistics.first_quartile(), 4.);
+        assert_close(statistics.third_quartile(), 5.5);
+        // Excel: SKEW = 0.818487553, KURT = 0.940625
+        assert_close(
+            statistics.skewness().map(|v| (v * 1e6).round() / 1e6),
+            0.818488,
+        );
+        assert_close(statistics.kurtosis(), 0.940625);
+    }
+
+    #[test]
+    fn test_insufficient_values() {
+        let statistics = DescriptiveStatistics::from_values(&[None, Some(3.)]);
+        assert_eq!(statistics.count(), 1);
+        assert_eq!(statistics.mean(), Some(3.));
+        assert_eq!(statistics.median(), Some(3.));
+        assert_eq!(statistics.standard_deviation(), None);
+        assert_eq!(statistics.skewness(), None);
+
+        let statistics = DescriptiveStatistics::from_values(&[]);
+        assert_eq!(statistics.count(), 0);
+        assert_eq!(statistics.mean(), None);
+        assert_eq!(statistics.min(), None);
+    }
+}
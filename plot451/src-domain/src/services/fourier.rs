@@ -0,0 +1,560 @@
+use std::f64::consts::PI;
+
+use thiserror::Error;
+
+use crate::{
+    models::column::column_cell::column_cell_value::CellRawValue, shared::limits::MAX_CREATED_CELLS,
+};
+
+// 等間隔とみなす x の間隔の相対誤差
+const UNIFORM_SAMPLING_TOLERANCE: f64 = 1e-6;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Complex {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl Complex {
+    pub fn new(re: f64, im: f64) -> Self {
+        Self { re, im }
+    }
+
+    // e^(iθ)
+    fn from_angle(theta: f64) -> Self {
+        Self::new(theta.cos(), theta.sin())
+    }
+
+    pub fn norm(&self) -> f64 {
+        self.re.hypot(self.im)
+    }
+
+    pub fn arg(&self) -> f64 {
+        self.im.atan2(self.re)
+    }
+
+    fn conj(self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.re + other.re, self.im + other.im)
+    }
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.re - other.re, self.im - other.im)
+    }
+
+    fn mul(self, other: Self) -> Self {
+        Self::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re,
+        )
+    }
+
+    fn scale(self, factor: f64) -> Self {
+        Self::new(self.re * factor, self.im * factor)
+    }
+}
+
+// 窓関数 (対称窓)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowFunction {
+    Rectangular,
+    Hann,
+    Hamming,
+    Blackman,
+}
+
+impl WindowFunction {
+    pub fn coefficients(&self, length: usize) -> Vec<f64> {
+        if length == 1 {
+            return vec![1.];
+        }
+        let denominator = (length - 1) as f64;
+        (0..length)
+            .map(|i| {
+                let phase = 2. * PI * i as f64 / denominator;
+                match self {
+                    WindowFunction::Rectangular => 1.,
+                    WindowFunction::Hann => 0.5 - 0.5 * phase.cos(),
+                    WindowFunction::Hamming => 0.54 - 0.46 * phase.cos(),
+                    WindowFunction::Blackman => {
+                        0.42 - 0.5 * phase.cos() + 0.08 * (2. * phase).cos()
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+// ゼロ詰めの方法
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZeroPadding {
+    None,
+    // 2 のべき乗の長さまで
+    NextPowerOfTwo,
+    // 指定した長さまで (データ長以上、作成できるセルの数の上限か
+    // データ長以上の最小の 2 のべき乗のうち大きい方まで)
+    Length(usize),
+}
+
+// 高速フーリエ変換 (inverse の場合は 1/n を掛けない)
+// 長さが 2 のべき乗でない場合は Bluestein のアルゴリズムを使う
+pub fn fft(input: &[Complex], inverse: bool) -> Vec<Complex> {
+    let n = input.len();
+    if n <= 1 {
+        return input.to_vec();
+    }
+    if n.is_power_of_two() {
+        let mut data = input.to_vec();
+        radix2_fft(&mut data, inverse);
+        return data;
+    }
+
+    // Bluestein: X_k = conj(w_k) Σ (x_j conj(w_j)) w_(k-j),  w_j = e^(±iπj²/n)
+    let sign = if inverse { 1. } else { -1. };
+    let chirp: Vec<Complex> = (0..n)
+        .map(|j| {
+            // j² は 2n で割った余りを使って桁落ちを避ける
+            let j2 = (j * j) % (2 * n);
+            Complex::from_angle(sign * PI * j2 as f64 / n as f64)
+        })
+        .collect();
+    let m = (2 * n - 1).next_power_of_two();
+    let mut a = vec![Complex::new(0., 0.); m];
+    for (j, (x, w)) in input.iter().zip(&chirp).enumerate() {
+        a[j] = x.mul(*w);
+    }
+    let mut b = vec![Complex::new(0., 0.); m];
+    b[0] = chirp[0].conj();
+    for j in 1..n {
+        b[j] = chirp[j].conj();
+        b[m - j] = chirp[j].conj();
+    }
+    radix2_fft(&mut a, false);
+    radix2_fft(&mut b, false);
+    let mut product: Vec<Complex> = a.iter().zip(&b).map(|(a, b)| a.mul(*b)).collect();
+    radix2_fft(&mut product, true);
+    product
+        .iter()
+        .zip(&chirp)
+        .map(|(value, w)| value.scale(1. / m as f64).mul(*w))
+        .collect()
+}
+
+// 反復型の基数 2 FFT
+fn radix2_fft(data: &mut [Complex], inverse: bool) {
+    let n = data.len();
+    // ビット反転の並べ替え
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+
+    let sign = if inverse { 1. } else { -1. };
+    let mut length = 2;
+    while length <= n {
+        let step = Complex::from_angle(sign * 2. * PI / length as f64);
+        for chunk in data.chunks_mut(length) {
+            let mut twiddle = Complex::new(1., 0.);
+            let (lower, upper) = chunk.split_at_mut(length / 2);
+            for (a, b) in lower.iter_mut().zip(upper.iter_mut()) {
+                let t = b.mul(twiddle);
+                *b = a.sub(t);
+                *a = a.add(t);
+                twiddle = twiddle.mul(step);
+            }
+        }
+        length <<= 1;
+    }
+}
+
+// x のカラムから標本化間隔を求める (等間隔でなければエラー)
+pub fn sampling_interval(x: &[CellRawValue]) -> Result<f64, FourierError> {
+    let values = x
+        .iter()
+        .enumerate()
+        .map(|(row, value)| {
+            value
+                .filter(|value| value.is_finite())
+                .ok_or(FourierError::MissingValue(row))
+        })
+        .collect::<Result<Vec<f64>, _>>()?;
+    if values.len() < 2 {
+        return Err(FourierError::InsufficientPoints);
+    }
+    let interval = (values[values.len() - 1] - values[0]) / (values.len() - 1) as f64;
+    if interval <= 0. {
+        return Err(FourierError::InvalidSamplingInterval(interval));
+    }
+    if let Some(row) = values.windows(2).position(|pair| {
+        ((pair[1] - pair[0]) - interval).abs() > UNIFORM_SAMPLING_TOLERANCE * interval
+    }) {
+        return Err(FourierError::NonUniformSampling(row + 1));
+    }
+    Ok(interval)
+}
+
+// domain service
+// 等間隔に標本化した実数値の信号の片側スペクトル
+// 振幅は窓関数の係数の和で、パワースペクトル密度は係数の二乗和で正規化する
+// (矩形窓、ゼロ詰めなしでは Σ PSD Δf が信号の二乗平均と一致する)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Spectrum {
+    sampling_interval: f64,
+    window_function: WindowFunction,
+    // 元の信号の長さ
+    signal_length: usize,
+    // 窓関数を掛けてゼロ詰めした信号の変換結果 (両側)
+    transform: Vec<Complex>,
+    frequencies: Vec<f64>,
+    amplitudes: Vec<f64>,
+    phases: Vec<f64>,
+    power_spectral_density: Vec<f64>,
+}
+
+impl Spectrum {
+    pub fn compute(
+        values: &[CellRawValue],
+        sampling_interval: f64,
+        window_function: WindowFunction,
+        zero_padding: ZeroPadding,
+    ) -> Result<Self, FourierError> {
+        if !sampling_interval.is_finite() || sampling_interval <= 0. {
+            return Err(FourierError::InvalidSamplingInterval(sampling_interval));
+        }
+        let signal = values
+            .iter()
+            .enumerate()
+            .map(|(row, value)| {
+                value
+                    .filter(|value| value.is_finite())
+                    .ok_or(FourierError::MissingValue(row))
+            })
+            .collect::<Result<Vec<f64>, _>>()?;
+        let signal_length = signal.len();
+        if signal_length < 2 {
+            return Err(FourierError::InsufficientPoints);
+        }
+        let length = match zero_padding {
+            ZeroPadding::None => signal_length,
+            ZeroPadding::NextPowerOfTwo => signal_length.next_power_of_two(),
+            ZeroPadding::Length(length) if length < signal_length => {
+                return Err(FourierError::PaddingTooShort(length, signal_length))
+            }
+            ZeroPadding::Length(length) => {
+                let max_length = MAX_CREATED_CELLS.max(signal_length.next_power_of_two());
+                if length > max_length {
+                    return Err(FourierError::PaddingTooLong(length, max_length));
+                }
+                length
+            }
+        };
+
+        let window = window_function.coefficients(signal_length);
+        let window_sum: f64 = window.iter().sum();
+        // 点数が少なく窓関数の係数がすべて 0 になる場合は正規化できない
+        if window_sum <= f64::EPSILON {
+            return Err(FourierError::DegenerateWindow(signal_length));
+        }
+        let mut input: Vec<Complex> = signal
+            .iter()
+            .zip(&window)
+            .map(|(value, w)| Complex::new(value * w, 0.))
+            .collect();
+        input.resize(length, Complex::new(0., 0.));
+        let transform = fft(&input, false);
+
+        let window_square_sum: f64 = window.iter().map(|w| w * w).sum();
+        let frequency_resolution = 1. / (length as f64 * sampling_interval);
+        let half = length / 2;
+        // 片側スペクトルでは直流成分とナイキスト周波数以外を 2 倍する
+        let one_sided = |k: usize| {
+            if k == 0 || (length.is_multiple_of(2) && k == half) {
+                1.
+            } else {
+                2.
+            }
+        };
+        let bins = 0..=half;
+        let frequencies = bins
+            .clone()
+            .map(|k| k as f64 * frequency_resolution)
+            .collect();
+        let amplitudes = bins
+            .clone()
+            .map(|k| one_sided(k) * transform[k].norm() / window_sum)
+            .collect();
+        let phases = bins.clone().map(|k| transform[k].arg()).collect();
+        let power_spectral_density = bins
+            .map(|k| {
+                one_sided(k) * transform[k].norm().powi(2) * sampling_interval / window_square_sum
+            })
+            .collect();
+
+        Ok(Self {
+            sampling_interval,
+            window_function,
+            signal_length,
+            transform,
+            frequencies,
+            amplitudes,
+            phases,
+            power_spectral_density,
+        })
+    }
+
+    pub fn sampling_interval(&self) -> f64 {
+        self.sampling_interval
+    }
+
+    pub fn window_function(&self) -> WindowFunction {
+        self.window_function
+    }
+
+    // ゼロ詰め後の長さ
+    pub fn length(&self) -> usize {
+        self.transform.len()
+    }
+
+    pub fn frequencies(&self) -> &Vec<f64> {
+        &self.frequencies
+    }
+
+    pub fn amplitudes(&self) -> &Vec<f64> {
+        &self.amplitudes
+    }
+
+    pub fn phases(&self) -> &Vec<f64> {
+        &self.phases
+    }
+
+    pub fn power_spectral_density(&self) -> &Vec<f64> {
+        &self.power_spectral_density
+    }
+
+    // low 以上 high 以下の周波数成分だけを残して逆変換した信号 (元の信号の長さ)
+    // 窓関数は逆変換後に割り戻し、係数が 0 の点は None になる
+    pub fn reconstruct(&self, low: Option<f64>, high: Option<f64>) -> Vec<CellRawValue> {
+        let length = self.length();
+        let frequency_resolution = 1. / (length as f64 * self.sampling_interval);
+        let filtered: Vec<Complex> = self
+            .transform
+            .iter()
+            .enumerate()
+            .map(|(k, value)| {
+                // 負の周波数の成分は対応する正の周波数で判定する
+                let frequency = k.min(length - k) as f64 * frequency_resolution;
+                let passed = low.is_none_or(|low| frequency >= low)
+                    && high.is_none_or(|high| frequency <= high);
+                if passed {
+                    *value
+                } else {
+                    Complex::new(0., 0.)
+                }
+            })
+            .collect();
+        let window = self.window_function.coefficients(self.signal_length);
+        fft(&filtered, true)
+            .iter()
+            .zip(window)
+            .map(|(value, w)| (w.abs() > f64::EPSILON).then(|| value.re / length as f64 / w))
+            .collect()
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum FourierError {
+    #[error("at least 2 points are required")]
+    InsufficientPoints,
+    #[error("value at row {0} is missing")]
+    MissingValue(usize),
+    #[error("sampling interval must be positive, but {0} is given")]
+    InvalidSamplingInterval(f64),
+    #[error("sampling is not uniform at row {0}")]
+    NonUniformSampling(usize),
+    #[error("padded length {0} is shorter than signal length {1}")]
+    PaddingTooShort(usize, usize),
+    #[error("padded length {0} exceeds {1}")]
+    PaddingTooLong(usize, usize),
+    #[error("window function vanishes for {0} points")]
+    DegenerateWindow(usize),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "actual: {}, expected: {}",
+            actual,
+            expected
+        );
+    }
+
+    // 定義どおりの離散フーリエ変換
+    fn naive_dft(input: &[Complex]) -> Vec<Complex> {
+        let n = input.len();
+        (0..n)
+            .map(|k| {
+                input
+                    .iter()
+                    .enumerate()
+                    .fold(Complex::new(0., 0.), |sum, (j, x)| {
+                        let theta = -2. * PI * ((j * k) % n) as f64 / n as f64;
+                        sum.add(x.mul(Complex::from_angle(theta)))
+                    })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_fft_matches_dft() {
+        for n in [8, 12, 7] {
+            let input: Vec<Complex> = (0..n)
+                .map(|i| Complex::new((i as f64 * 0.7).sin() + i as f64, (i as f64).cos()))
+                .collect();
+            let expected = naive_dft(&input);
+            let actual = fft(&input, false);
+            for (actual, expected) in actual.iter().zip(&expected) {
+                assert_close(actual.re, expected.re);
+                assert_close(actual.im, expected.im);
+            }
+            // 逆変換で元に戻る
+            let restored = fft(&actual, true);
+            for (restored, original) in restored.iter().zip(&input) {
+                assert_close(restored.re / n as f64, original.re);
+                assert_close(restored.im / n as f64, original.im);
+            }
+        }
+    }
+
+    #[test]
+    fn test_spectrum() -> anyhow::Result<()> {
+        // 1 + 3 cos(2π 5t) + 2 sin(2π 10t), Δt = 0.01, N = 100
+        let values: Vec<CellRawValue> = (0..100)
+            .map(|i| {
+                let t = i as f64 * 0.01;
+                Some(1. + 3. * (2. * PI * 5. * t).cos() + 2. * (2. * PI * 10. * t).sin())
+            })
+            .collect();
+        let spectrum = Spectrum::compute(
+            &values,
+            0.01,
+            WindowFunction::Rectangular,
+            ZeroPadding::None,
+        )?;
+        assert_eq!(spectrum.frequencies().len(), 51);
+        assert_close(spectrum.frequencies()[5], 5.);
+        assert_close(spectrum.amplitudes()[0], 1.);
+        assert_close(spectrum.amplitudes()[5], 3.);
+        assert_close(spectrum.amplitudes()[10], 2.);
+        assert_close(spectrum.amplitudes()[7], 0.);
+        assert_close(spectrum.phases()[5], 0.);
+        assert_close(spectrum.phases()[10], -PI / 2.);
+
+        // パーセバルの定理: Σ PSD Δf = 二乗平均
+        let mean_square =
+            values.iter().map(|v| v.unwrap().powi(2)).sum::<f64>() / values.len() as f64;
+        let power: f64 =
+            spectrum.power_spectral_density().iter().sum::<f64>() * spectrum.frequencies()[1];
+        assert_close(power, mean_square);
+
+        // 10 Hz の成分を取り除いて再構成する
+        let reconstructed = spectrum.reconstruct(None, Some(7.));
+        for (i, value) in reconstructed.iter().enumerate() {
+            let t = i as f64 * 0.01;
+            assert_close(value.unwrap(), 1. + 3. * (2. * PI * 5. * t).cos());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_window_and_padding() -> anyhow::Result<()> {
+        let hann = WindowFunction::Hann.coefficients(5);
+        for (actual, expected) in hann.iter().zip([0., 0.5, 1., 0.5, 0.]) {
+            assert_close(*actual, expected);
+        }
+        assert_close(WindowFunction::Blackman.coefficients(5)[2], 1.);
+        assert_close(WindowFunction::Hamming.coefficients(5)[0], 0.08);
+
+        let values: Vec<CellRawValue> = (0..6).map(|i| Some(i as f64)).collect();
+        let spectrum = Spectrum::compute(
+            &values,
+            0.5,
+            WindowFunction::Hann,
+            ZeroPadding::NextPowerOfTwo,
+        )?;
+        assert_eq!(spectrum.length(), 8);
+        assert_close(spectrum.frequencies()[4], 1.);
+        // 窓の両端は係数が 0 なので再構成できない
+        let reconstructed = spectrum.reconstruct(None, None);
+        assert_eq!(reconstructed.len(), 6);
+        assert_eq!(reconstructed[0], None);
+        assert_close(reconstructed[2].unwrap(), 2.);
+        Ok(())
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(matches!(
+            Spectrum::compute(
+                &[Some(1.), None, Some(2.)],
+                1.,
+                WindowFunction::Rectangular,
+                ZeroPadding::None
+            ),
+            Err(FourierError::MissingValue(1))
+        ));
+        assert!(matches!(
+            Spectrum::compute(
+                &[Some(1.), Some(2.), Some(3.)],
+                1.,
+                WindowFunction::Rectangular,
+                ZeroPadding::Length(2)
+            ),
+            Err(FourierError::PaddingTooShort(2, 3))
+        ));
+        for length in [MAX_CREATED_CELLS + 1, usize::MAX] {
+            assert!(matches!(
+                Spectrum::compute(
+                    &[Some(1.), Some(2.), Some(3.)],
+                    1.,
+                    WindowFunction::Rectangular,
+                    ZeroPadding::Length(length)
+                ),
+                Err(FourierError::PaddingTooLong(_, MAX_CREATED_CELLS))
+            ));
+        }
+        for window_function in [WindowFunction::Hann, WindowFunction::Blackman] {
+            assert!(matches!(
+                Spectrum::compute(
+                    &[Some(1.), Some(2.)],
+                    1.,
+                    window_function,
+                    ZeroPadding::None
+                ),
+                Err(FourierError::DegenerateWindow(2))
+            ));
+        }
+        assert_close(
+            sampling_interval(&[Some(0.), Some(0.5), Some(1.)]).unwrap(),
+            0.5,
+        );
+        assert!(matches!(
+            sampling_interval(&[Some(0.), Some(0.5), Some(1.5)]),
+            Err(FourierError::NonUniformSampling(1))
+        ));
+    }
+}
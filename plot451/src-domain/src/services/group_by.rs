@@ -0,0 +1,299 @@
+use std::cmp::Ordering;
+
+use crate::models::column::column_cell::column_cell_value::CellRawValue;
+
+// グループごとの集計方法
+// 集計には None と NaN 以外の値だけを使う
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregation {
+    Count,
+    Sum,
+    Mean,
+    // 標本標準偏差
+    StandardDeviation,
+    Min,
+    Max,
+    // 行の順で最初 (最後) の値
+    First,
+    Last,
+}
+
+impl std::fmt::Display for Aggregation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Aggregation::Count => write!(f, "count"),
+            Aggregation::Sum => write!(f, "sum"),
+            Aggregation::Mean => write!(f, "mean"),
+            Aggregation::StandardDeviation => write!(f, "sd"),
+            Aggregation::Min => write!(f, "min"),
+            Aggregation::Max => write!(f, "max"),
+            Aggregation::First => write!(f, "first"),
+            Aggregation::Last => write!(f, "last"),
+        }
+    }
+}
+
+impl Aggregation {
+    // 値がない場合は個数と和は 0、それ以外は None
+    pub fn apply(&self, values: &[f64]) -> CellRawValue {
+        let n = values.len() as f64;
+        match self {
+            Aggregation::Count => Some(n),
+            Aggregation::Sum => Some(values.iter().sum()),
+            Aggregation::Mean => (!values.is_empty()).then(|| values.iter().sum::<f64>() / n),
+            Aggregation::StandardDeviation => (values.len() >= 2).then(|| {
+                let mean = values.iter().sum::<f64>() / n;
+                (values
+                    .iter()
+                    .map(|value| (value - mean).powi(2))
+                    .sum::<f64>()
+                    / (n - 1.))
+                    .sqrt()
+            }),
+            Aggregation::Min => values.iter().copied().reduce(f64::min),
+            Aggregation::Max => values.iter().copied().reduce(f64::max),
+            Aggregation::First => values.first().copied(),
+            Aggregation::Last => values.last().copied(),
+        }
+    }
+}
+
+// キーの値の比較 (None と NaN は同じ値とみなし、最後に並べる)
+fn compare_keys(a: &[CellRawValue], b: &[CellRawValue]) -> Ordering {
+    let normalize = |value: &CellRawValue| value.filter(|value| !value.is_nan());
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| match (normalize(a), normalize(b)) {
+            (Some(a), Some(b)) => a.total_cmp(&b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        })
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or(Ordering::Equal)
+}
+
+fn present_values(values: &[CellRawValue], rows: &[usize]) -> Vec<f64> {
+    rows.iter()
+        .filter_map(|row| values.get(*row).copied().flatten())
+        .filter(|value| !value.is_nan())
+        .collect()
+}
+
+// domain service
+// キーカラムの値の組が等しい行をまとめたグループ (キーの昇順)
+// キーカラムがなければすべての行が 1 つのグループになる
+#[derive(Debug, Clone, PartialEq)]
+pub struct RowGroups {
+    // グループごとのキーの値の組
+    keys: Vec<Vec<CellRawValue>>,
+    // グループごとの行番号 (昇順)
+    rows: Vec<Vec<usize>>,
+}
+
+impl RowGroups {
+    pub fn new(key_columns: &[Vec<CellRawValue>], row_count: usize) -> Self {
+        let key_at = |row: usize| -> Vec<CellRawValue> {
+            key_columns
+                .iter()
+                .map(|column| {
+                    column
+                        .get(row)
+                        .copied()
+                        .flatten()
+                        .filter(|value| !value.is_nan())
+                })
+                .collect()
+        };
+        let mut order: Vec<usize> = (0..row_count).collect();
+        // 安定ソートなのでグループ内の行の順序は保たれる
+        order.sort_by(|a, b| compare_keys(&key_at(*a), &key_at(*b)));
+
+        let mut keys: Vec<Vec<CellRawValue>> = vec![];
+        let mut rows: Vec<Vec<usize>> = vec![];
+        for row in order {
+            let key = key_at(row);
+            match keys.last() {
+                Some(last) if compare_keys(last, &key).is_eq() => {
+                    rows.last_mut().unwrap().push(row);
+                }
+                _ => {
+                    keys.push(key);
+                    rows.push(vec![row]);
+                }
+            }
+        }
+        Self { keys, rows }
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    // index 番目のキーカラムのグループごとの値
+    pub fn key_column(&self, index: usize) -> Vec<CellRawValue> {
+        self.keys.iter().map(|key| key[index]).collect()
+    }
+
+    pub fn rows(&self) -> &Vec<Vec<usize>> {
+        &self.rows
+    }
+
+    // グループごとに集計した値
+    pub fn aggregate(
+        &self,
+        values: &[CellRawValue],
+        aggregation: Aggregation,
+    ) -> Vec<CellRawValue> {
+        self.rows
+            .iter()
+            .map(|rows| aggregation.apply(&present_values(values, rows)))
+            .collect()
+    }
+}
+
+// domain service
+// ピボットテーブル
+// 行はキーカラムのグループ、列はピボットカラムの異なる値 (昇順) で、各セルは該当する行の値の集計
+// ピボットカラムの値が None (または NaN) の行は使わない
+#[derive(Debug, Clone, PartialEq)]
+pub struct PivotTable {
+    groups: RowGroups,
+    pivot_values: Vec<f64>,
+    // ピボットカラムの値ごとの列
+    columns: Vec<Vec<CellRawValue>>,
+}
+
+impl PivotTable {
+    pub fn new(
+        key_columns: &[Vec<CellRawValue>],
+        pivot_column: &[CellRawValue],
+        values: &[CellRawValue],
+        aggregation: Aggregation,
+        row_count: usize,
+    ) -> Self {
+        let pivot_at = |row: usize| {
+            pivot_column
+                .get(row)
+                .copied()
+                .flatten()
+                .filter(|value| !value.is_nan())
+        };
+        let rows: Vec<usize> = (0..row_count)
+            .filter(|row| pivot_at(*row).is_some())
+            .collect();
+        let subset = |column: &[CellRawValue]| -> Vec<CellRawValue> {
+            rows.iter()
+                .map(|row| column.get(*row).copied().flatten())
+                .collect()
+        };
+        let key_columns: Vec<Vec<CellRawValue>> =
+            key_columns.iter().map(|column| subset(column)).collect();
+        let pivots: Vec<f64> = rows.iter().filter_map(|row| pivot_at(*row)).collect();
+        let values = subset(values);
+        let groups = RowGroups::new(&key_columns, rows.len());
+
+        let mut pivot_values = pivots.clone();
+        pivot_values.sort_by(|a, b| a.total_cmp(b));
+        pivot_values.dedup();
+        let columns = pivot_values
+            .iter()
+            .map(|pivot_value| {
+                groups
+                    .rows()
+                    .iter()
+                    .map(|group_rows| {
+                        let matching: Vec<usize> = group_rows
+                            .iter()
+                            .copied()
+                            .filter(|row| pivots[*row] == *pivot_value)
+                            .collect();
+                        aggregation.apply(&present_values(&values, &matching))
+                    })
+                    .collect()
+            })
+            .collect();
+        Self {
+            groups,
+            pivot_values,
+            columns,
+        }
+    }
+
+    pub fn groups(&self) -> &RowGroups {
+        &self.groups
+    }
+
+    pub fn pivot_values(&self) -> &Vec<f64> {
+        &self.pivot_values
+    }
+
+    pub fn columns(&self) -> &Vec<Vec<CellRawValue>> {
+        &self.columns
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_group_and_aggregate() {
+        let sample = vec![Some(2.), Some(1.), Some(2.), None, Some(1.), Some(f64::NAN)];
+        let run = vec![Some(0.), Some(0.), Some(0.), Some(1.), Some(1.)];
+        let values = vec![Some(10.), Some(20.), Some(30.), Some(40.), None, Some(60.)];
+        let groups = RowGroups::new(&[sample, run], 6);
+        // (1, 0), (1, 1), (2, 0), (None, 1), (None, None)
+        assert_eq!(groups.len(), 5);
+        assert_eq!(
+            groups.key_column(0),
+            vec![Some(1.), Some(1.), Some(2.), None, None]
+        );
+        assert_eq!(
+            groups.key_column(1),
+            vec![Some(0.), Some(1.), Some(0.), Some(1.), None]
+        );
+        assert_eq!(
+            groups.rows(),
+            &vec![vec![1], vec![4], vec![0, 2], vec![3], vec![5]]
+        );
+        assert_eq!(
+            groups.aggregate(&values, Aggregation::Count),
+            vec![Some(1.), Some(0.), Some(2.), Some(1.), Some(1.)]
+        );
+        assert_eq!(
+            groups.aggregate(&values, Aggregation::Mean),
+            vec![Some(20.), None, Some(20.), Some(40.), Some(60.)]
+        );
+        assert_eq!(groups.aggregate(&values, Aggregation::Last)[2], Some(30.));
+        assert_eq!(
+            groups.aggregate(&values, Aggregation::StandardDeviation)[2],
+            Some(200f64.sqrt())
+        );
+
+        // キーがなければ 1 つのグループ
+        let groups = RowGroups::new(&[], 6);
+        assert_eq!(
+            groups.aggregate(&values, Aggregation::Sum),
+            vec![Some(160.)]
+        );
+    }
+
+    #[test]
+    fn test_pivot() {
+        let sample = vec![Some(1.), Some(1.), Some(2.), Some(2.), Some(2.)];
+        let temperature = vec![Some(300.), Some(310.), Some(300.), Some(300.), None];
+        let values = vec![Some(1.), Some(2.), Some(3.), Some(5.), Some(7.)];
+        let pivot = PivotTable::new(&[sample], &temperature, &values, Aggregation::Mean, 5);
+        assert_eq!(pivot.pivot_values(), &vec![300., 310.]);
+        assert_eq!(pivot.groups().key_column(0), vec![Some(1.), Some(2.)]);
+        assert_eq!(
+            pivot.columns(),
+            &vec![vec![Some(1.), Some(4.)], vec![Some(2.), None]]
+        );
+    }
+}
@@ -0,0 +1,324 @@
+use thiserror::Error;
+
+use crate::{
+    models::column::column_cell::column_cell_value::CellRawValue, shared::limits::MAX_CREATED_CELLS,
+};
+
+use super::descriptive_statistics::quantile;
+
+// ビンの数の上限
+pub const MAX_BIN_COUNT: usize = MAX_CREATED_CELLS;
+
+// ビンの決め方
+#[derive(Debug, Clone, PartialEq)]
+pub enum BinningRule {
+    // 最小値から最大値までを指定した数に等分する
+    Count(usize),
+    // 指定した幅 (境界は幅の整数倍)
+    Width(f64),
+    // Sturges の公式 (ceil(log2 n) + 1 個)
+    Sturges,
+    // Freedman–Diaconis の規則 (幅 2 IQR / n^(1/3))
+    FreedmanDiaconis,
+    // 境界を直接指定する (狭義単調増加)
+    Edges(Vec<f64>),
+}
+
+// domain service
+// 値のヒストグラム
+// ビンは左閉右開区間で、最後のビンだけ右端を含む
+// None と NaN、ビンの範囲外の値は数えない
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+    edges: Vec<f64>,
+    counts: Vec<usize>,
+    // 範囲外で数えなかった値の数
+    outside: usize,
+}
+
+impl Histogram {
+    pub fn new(values: &[CellRawValue], rule: &BinningRule) -> Result<Self, HistogramError> {
+        let mut sorted: Vec<f64> = values
+            .iter()
+            .filter_map(|value| value.filter(|value| value.is_finite()))
+            .collect();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        let edges = match rule {
+            BinningRule::Edges(edges) => {
+                if edges.len() < 2 {
+                    return Err(HistogramError::InsufficientEdges);
+                }
+                if edges.len() - 1 > MAX_BIN_COUNT {
+                    return Err(HistogramError::TooManyBins(edges.len() - 1));
+                }
+                if let Some(index) = edges.windows(2).position(|pair| {
+                    !pair.iter().all(|edge| edge.is_finite()) || pair[0] >= pair[1]
+                }) {
+                    return Err(HistogramError::NonIncreasingEdges(index + 1));
+                }
+                edges.clone()
+            }
+            BinningRule::Width(width) => Self::width_edges(&sorted, *width)?,
+            BinningRule::Count(count) => {
+                if *count == 0 {
+                    return Err(HistogramError::InvalidBinCount);
+                }
+                Self::automatic_edges(&sorted, *count)?
+            }
+            BinningRule::Sturges => Self::automatic_edges(&sorted, Self::sturges(&sorted))?,
+            BinningRule::FreedmanDiaconis => {
+                Self::automatic_edges(&sorted, Self::freedman_diaconis(&sorted))?
+            }
+        };
+
+        let mut counts = vec![0; edges.len() - 1];
+        let mut outside = 0;
+        for value in &sorted {
+            match Self::bin_index(&edges, *value) {
+                Some(index) => counts[index] += 1,
+                None => outside += 1,
+            }
+        }
+        Ok(Self {
+            edges,
+            counts,
+            outside,
+        })
+    }
+
+    // 値の範囲 (すべて同じ値の場合は値を中心とする幅 1 の範囲にする)
+    fn range(sorted: &[f64]) -> Result<(f64, f64), HistogramError> {
+        match (sorted.first(), sorted.last()) {
+            (Some(min), Some(max)) if min == max => Ok((min - 0.5, max + 0.5)),
+            (Some(min), Some(max)) => Ok((*min, *max)),
+            _ => Err(HistogramError::NoValues),
+        }
+    }
+
+    fn sturges(sorted: &[f64]) -> usize {
+        (sorted.len() as f64).log2().ceil() as usize + 1
+    }
+
+    fn freedman_diaconis(sorted: &[f64]) -> usize {
+        let iqr = quantile(sorted, 0.75).unwrap_or(0.) - quantile(sorted, 0.25).unwrap_or(0.);
+        // 四分位範囲が 0 の場合は Sturges の公式にする
+        match (iqr > 0., sorted.first(), sorted.last()) {
+            (true, Some(min), Some(max)) => {
+                let width = 2. * iqr / (sorted.len() as f64).cbrt();
+                // 非常に大きい場合は usize に飽和し、上限のチェックでエラーになる
+                ((max - min) / width).ceil().max(1.) as usize
+            }
+            _ => Self::sturges(sorted),
+        }
+    }
+
+    // 最小値から最大値までを count 個に等分した境界
+    fn automatic_edges(sorted: &[f64], count: usize) -> Result<Vec<f64>, HistogramError> {
+        let (min, max) = Self::range(sorted)?;
+        if count > MAX_BIN_COUNT {
+            return Err(HistogramError::TooManyBins(count));
+        }
+        let width = (max - min) / count as f64;
+        Ok((0..=count)
+            .map(|i| {
+                if i == count {
+                    max
+                } else {
+                    min + i as f64 * width
+                }
+            })
+            .collect())
+    }
+
+    // 幅の整数倍の境界
+    fn width_edges(sorted: &[f64], width: f64) -> Result<Vec<f64>, HistogramError> {
+        if !width.is_finite() || width <= 0. {
+            return Err(HistogramError::InvalidBinWidth(width));
+        }
+        let (min, max) = Self::range(sorted)?;
+        let start = (min / width).floor();
+        let end = (max / width).floor() + 1.;
+        let count = end - start;
+        if count.is_nan() || count > MAX_BIN_COUNT as f64 {
+            return Err(HistogramError::TooManyBins(count as usize));
+        }
+        Ok((0..=count as usize)
+            .map(|i| (start + i as f64) * width)
+            .collect())
+    }
+
+    fn bin_index(edges: &[f64], value: f64) -> Option<usize> {
+        let last = edges.len() - 1;
+        if value < edges[0] || value > edges[last] {
+            return None;
+        }
+        if value == edges[last] {
+            return Some(last - 1);
+        }
+        // value 以下の境界のうち最も右のもの
+        Some(edges.partition_point(|edge| *edge <= value) - 1)
+    }
+
+    pub fn edges(&self) -> &Vec<f64> {
+        &self.edges
+    }
+
+    pub fn lower_edges(&self) -> Vec<f64> {
+        self.edges[..self.edges.len() - 1].to_vec()
+    }
+
+    pub fn upper_edges(&self) -> Vec<f64> {
+        self.edges[1..].to_vec()
+    }
+
+    pub fn centers(&self) -> Vec<f64> {
+        self.edges
+            .windows(2)
+            .map(|pair| (pair[0] + pair[1]) / 2.)
+            .collect()
+    }
+
+    pub fn counts(&self) -> &Vec<usize> {
+        &self.counts
+    }
+
+    pub fn outside(&self) -> usize {
+        self.outside
+    }
+
+    // 確率密度 (全ビンの面積の和が 1 になるように正規化した値)
+    pub fn densities(&self) -> Vec<f64> {
+        let total: usize = self.counts.iter().sum();
+        self.edges
+            .windows(2)
+            .zip(&self.counts)
+            .map(|(pair, count)| {
+                if total == 0 {
+                    0.
+                } else {
+                    *count as f64 / (total as f64 * (pair[1] - pair[0]))
+                }
+            })
+            .collect()
+    }
+
+    // 累積度数
+    pub fn cumulative_counts(&self) -> Vec<usize> {
+        self.counts
+            .iter()
+            .scan(0, |sum, count| {
+                *sum += count;
+                Some(*sum)
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum HistogramError {
+    #[error("no values to bin")]
+    NoValues,
+    #[error("bin count must be positive")]
+    InvalidBinCount,
+    #[error("bin width must be a positive number, but {0} is given")]
+    InvalidBinWidth(f64),
+    #[error("too many bins: {0} (at most {MAX_BIN_COUNT})")]
+    TooManyBins(usize),
+    #[error("at least 2 edges are required")]
+    InsufficientEdges,
+    #[error("edges must be strictly increasing, at index {0}")]
+    NonIncreasingEdges(usize),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(values: &[f64]) -> Vec<CellRawValue> {
+        values.iter().map(|value| Some(*value)).collect()
+    }
+
+    #[test]
+    fn test_count_and_width() -> anyhow::Result<()> {
+        let data = values(&[0., 1., 1.5, 2., 3.9, 4.]);
+        let histogram = Histogram::new(&data, &BinningRule::Count(4))?;
+        assert_eq!(histogram.edges(), &vec![0., 1., 2., 3., 4.]);
+        // 最後のビンだけ右端 (4) を含む
+        assert_eq!(histogram.counts(), &vec![1, 2, 1, 2]);
+        assert_eq!(histogram.centers(), vec![0.5, 1.5, 2.5, 3.5]);
+        assert_eq!(histogram.cumulative_counts(), vec![1, 3, 4, 6]);
+        let area: f64 = histogram.densities().iter().sum();
+        assert!((area - 1.).abs() < 1e-12);
+
+        let histogram = Histogram::new(&data, &BinningRule::Width(1.5))?;
+        assert_eq!(histogram.edges(), &vec![0., 1.5, 3., 4.5]);
+        assert_eq!(histogram.counts(), &vec![2, 2, 2]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_automatic_rules() -> anyhow::Result<()> {
+        // n = 16 → Sturges: 5 個
+        let data: Vec<CellRawValue> = (0..16).map(|i| Some(i as f64)).collect();
+        let histogram = Histogram::new(&data, &BinningRule::Sturges)?;
+        assert_eq!(histogram.counts().len(), 5);
+        assert_eq!(histogram.counts().iter().sum::<usize>(), 16);
+
+        // IQR = 7.5, 幅 = 2 * 7.5 / 16^(1/3) ≈ 5.95 → 範囲 15 で 3 個
+        let histogram = Histogram::new(&data, &BinningRule::FreedmanDiaconis)?;
+        assert_eq!(histogram.counts().len(), 3);
+
+        // すべて同じ値
+        let histogram = Histogram::new(&values(&[2., 2., 2.]), &BinningRule::FreedmanDiaconis)?;
+        assert_eq!(histogram.edges().first(), Some(&1.5));
+        assert_eq!(histogram.counts().iter().sum::<usize>(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_too_many_bins() {
+        let values = vec![Some(0.), Some(0.5), Some(1.)];
+        assert!(matches!(
+            Histogram::new(&values, &BinningRule::Width(1e-12)),
+            Err(HistogramError::TooManyBins(_))
+        ));
+        assert!(matches!(
+            Histogram::new(&values, &BinningRule::Count(MAX_BIN_COUNT + 1)),
+            Err(HistogramError::TooManyBins(_))
+        ));
+        // 範囲に比べて四分位範囲が非常に小さい
+        let mut values = vec![Some(0.); 8];
+        values.extend([Some(1e-300), Some(2e-300), Some(1e300)]);
+        assert!(matches!(
+            Histogram::new(&values, &BinningRule::FreedmanDiaconis),
+            Err(HistogramError::TooManyBins(_))
+        ));
+        assert!(Histogram::new(&values, &BinningRule::Count(MAX_BIN_COUNT)).is_ok());
+    }
+
+    #[test]
+    fn test_explicit_edges() -> anyhow::Result<()> {
+        let data = vec![
+            Some(-1.),
+            Some(0.5),
+            None,
+            Some(f64::NAN),
+            Some(2.),
+            Some(5.),
+        ];
+        let histogram = Histogram::new(&data, &BinningRule::Edges(vec![0., 1., 3.]))?;
+        assert_eq!(histogram.counts(), &vec![1, 1]);
+        assert_eq!(histogram.outside(), 2);
+        assert_eq!(histogram.densities(), vec![0.5, 0.25]);
+
+        assert!(matches!(
+            Histogram::new(&data, &BinningRule::Edges(vec![0., 1., 1.])),
+            Err(HistogramError::NonIncreasingEdges(2))
+        ));
+        assert!(matches!(
+            Histogram::new(&[None], &BinningRule::Sturges),
+            Err(HistogramError::NoValues)
+        ));
+        Ok(())
+    }
+}
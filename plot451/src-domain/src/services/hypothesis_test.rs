@@ -0,0 +1,707 @@
+use thiserror::Error;
+
+use crate::{
+    models::column::column_cell::column_cell_value::CellRawValue,
+    shared::distributions::{
+        chi_square_survival, f_survival, kolmogorov_survival, normal_cdf, student_t_cdf,
+        student_t_quantile,
+    },
+};
+
+use super::correlation::ranks;
+
+// 対立仮説
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Alternative {
+    #[default]
+    TwoSided,
+    // 1 番目の標本の方が小さい
+    Less,
+    // 1 番目の標本の方が大きい
+    Greater,
+}
+
+impl std::fmt::Display for Alternative {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Alternative::TwoSided => write!(f, "two-sided"),
+            Alternative::Less => write!(f, "less"),
+            Alternative::Greater => write!(f, "greater"),
+        }
+    }
+}
+
+// 検定の種類
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HypothesisTest {
+    // 1 標本 t 検定 (母平均 mean との比較)
+    OneSampleT { mean: f64 },
+    // Welch の t 検定 (2 標本、等分散を仮定しない)
+    WelchT,
+    // 対応のある t 検定 (差は 1 番目 - 2 番目、両方に値がある行だけを使う)
+    PairedT,
+    // 一元配置分散分析 (2 群以上)
+    OneWayAnova,
+    // Mann–Whitney の U 検定 (同順位の補正と連続修正をした正規近似)
+    MannWhitneyU,
+    // 2 標本 Kolmogorov–Smirnov 検定 (漸近分布)
+    KolmogorovSmirnov,
+    // χ² 適合度検定 (1 番目は観測度数、2 番目は期待度数で、省略した場合は一様)
+    ChiSquareGoodnessOfFit,
+    // χ² 独立性検定 (各標本を分割表の列とし、すべての列に値がある行だけを使う)
+    ChiSquareIndependence,
+}
+
+impl std::fmt::Display for HypothesisTest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HypothesisTest::OneSampleT { mean } => write!(f, "one-sample t-test (mean {})", mean),
+            HypothesisTest::WelchT => write!(f, "Welch's t-test"),
+            HypothesisTest::PairedT => write!(f, "paired t-test"),
+            HypothesisTest::OneWayAnova => write!(f, "one-way ANOVA"),
+            HypothesisTest::MannWhitneyU => write!(f, "Mann-Whitney U test"),
+            HypothesisTest::KolmogorovSmirnov => write!(f, "Kolmogorov-Smirnov test"),
+            HypothesisTest::ChiSquareGoodnessOfFit => write!(f, "chi-square goodness-of-fit test"),
+            HypothesisTest::ChiSquareIndependence => write!(f, "chi-square test of independence"),
+        }
+    }
+}
+
+// 推定値の信頼区間 (片側の対立仮説では一方が無限大)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConfidenceInterval {
+    level: f64,
+    estimate: f64,
+    lower: f64,
+    upper: f64,
+}
+
+impl ConfidenceInterval {
+    pub fn level(&self) -> f64 {
+        self.level
+    }
+
+    pub fn estimate(&self) -> f64 {
+        self.estimate
+    }
+
+    pub fn lower(&self) -> f64 {
+        self.lower
+    }
+
+    pub fn upper(&self) -> f64 {
+        self.upper
+    }
+}
+
+// domain service
+// 検定の結果
+// 独立な標本の None と NaN は標本ごとに除く
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestResult {
+    statistic: f64,
+    // 分散分析は (群間, 群内) の 2 つ、自由度のない検定は空
+    degrees_of_freedom: Vec<f64>,
+    p_value: f64,
+    // t 検定の平均 (または平均の差) の信頼区間
+    confidence_interval: Option<ConfidenceInterval>,
+    // 検定に使った値の個数
+    sample_sizes: Vec<usize>,
+}
+
+impl TestResult {
+    pub fn statistic(&self) -> f64 {
+        self.statistic
+    }
+
+    pub fn degrees_of_freedom(&self) -> &Vec<f64> {
+        &self.degrees_of_freedom
+    }
+
+    pub fn p_value(&self) -> f64 {
+        self.p_value
+    }
+
+    pub fn confidence_interval(&self) -> Option<ConfidenceInterval> {
+        self.confidence_interval
+    }
+
+    pub fn sample_sizes(&self) -> &Vec<usize> {
+        &self.sample_sizes
+    }
+}
+
+impl HypothesisTest {
+    pub fn run(
+        &self,
+        samples: &[Vec<CellRawValue>],
+        alternative: Alternative,
+        confidence_level: f64,
+    ) -> Result<TestResult, HypothesisTestError> {
+        if !(confidence_level > 0. && confidence_level < 1.) {
+            return Err(HypothesisTestError::InvalidConfidenceLevel(
+                confidence_level,
+            ));
+        }
+        let sample_count_is_valid = match self {
+            HypothesisTest::OneSampleT { .. } => samples.len() == 1,
+            HypothesisTest::ChiSquareGoodnessOfFit => matches!(samples.len(), 1 | 2),
+            HypothesisTest::OneWayAnova | HypothesisTest::ChiSquareIndependence => {
+                samples.len() >= 2
+            }
+            _ => samples.len() == 2,
+        };
+        if !sample_count_is_valid {
+            return Err(HypothesisTestError::InvalidSampleCount(samples.len()));
+        }
+        let supports_one_sided = matches!(
+            self,
+            HypothesisTest::OneSampleT { .. }
+                | HypothesisTest::WelchT
+                | HypothesisTest::PairedT
+                | HypothesisTest::MannWhitneyU
+        );
+        if alternative != Alternative::TwoSided && !supports_one_sided {
+            return Err(HypothesisTestError::UnsupportedAlternative(alternative));
+        }
+
+        let values: Vec<Vec<f64>> = samples.iter().map(|sample| finite_values(sample)).collect();
+        match self {
+            HypothesisTest::OneSampleT { mean } => {
+                let sample = &values[0];
+                let summary = Summary::new(sample, 0)?;
+                t_test(
+                    summary.mean - mean,
+                    summary.mean,
+                    summary.standard_error(),
+                    summary.count as f64 - 1.,
+                    alternative,
+                    confidence_level,
+                    vec![summary.count],
+                )
+            }
+            HypothesisTest::WelchT => {
+                let first = Summary::new(&values[0], 0)?;
+                let second = Summary::new(&values[1], 1)?;
+                let (v1, v2) = (
+                    first.variance / first.count as f64,
+                    second.variance / second.count as f64,
+                );
+                let standard_error = (v1 + v2).sqrt();
+                // Welch–Satterthwaite の式
+                let df = (v1 + v2).powi(2)
+                    / (v1 * v1 / (first.count as f64 - 1.) + v2 * v2 / (second.count as f64 - 1.));
+                let difference = first.mean - second.mean;
+                t_test(
+                    difference,
+                    difference,
+                    standard_error,
+                    df,
+                    alternative,
+                    confidence_level,
+                    vec![first.count, second.count],
+                )
+            }
+            HypothesisTest::PairedT => {
+                let differences: Vec<f64> = samples[0]
+                    .iter()
+                    .zip(&samples[1])
+                    .filter_map(|(first, second)| match (first, second) {
+                        (Some(first), Some(second)) => Some(first - second),
+                        _ => None,
+                    })
+                    .filter(|difference| difference.is_finite())
+                    .collect();
+                let summary = Summary::new(&differences, 0)?;
+                t_test(
+                    summary.mean,
+                    summary.mean,
+                    summary.standard_error(),
+                    summary.count as f64 - 1.,
+                    alternative,
+                    confidence_level,
+                    vec![summary.count, summary.count],
+                )
+            }
+            HypothesisTest::OneWayAnova => one_way_anova(&values),
+            HypothesisTest::MannWhitneyU => mann_whitney_u(&values[0], &values[1], alternative),
+            HypothesisTest::KolmogorovSmirnov => kolmogorov_smirnov(&values[0], &values[1]),
+            HypothesisTest::ChiSquareGoodnessOfFit => chi_square_goodness_of_fit(samples),
+            HypothesisTest::ChiSquareIndependence => chi_square_independence(samples),
+        }
+    }
+}
+
+// None と NaN を除いた値
+fn finite_values(sample: &[CellRawValue]) -> Vec<f64> {
+    sample
+        .iter()
+        .filter_map(|value| value.filter(|value| value.is_finite()))
+        .collect()
+}
+
+// 標本の個数・平均・不偏分散
+struct Summary {
+    count: usize,
+    mean: f64,
+    variance: f64,
+}
+
+impl Summary {
+    // index は誤差の報告に使う標本の番号
+    fn new(values: &[f64], index: usize) -> Result<Self, HypothesisTestError> {
+        let count = values.len();
+        if count < 2 {
+            return Err(HypothesisTestError::InsufficientValues(index, 2, count));
+        }
+        let mean = values.iter().sum::<f64>() / count as f64;
+        let variance = values
+            .iter()
+            .map(|value| (value - mean).powi(2))
+            .sum::<f64>()
+            / (count - 1) as f64;
+        Ok(Self {
+            count,
+            mean,
+            variance,
+        })
+    }
+
+    fn standard_error(&self) -> f64 {
+        (self.variance / self.count as f64).sqrt()
+    }
+}
+
+// 統計量 t = difference / standard_error の t 検定と estimate の信頼区間
+fn t_test(
+    difference: f64,
+    estimate: f64,
+    standard_error: f64,
+    df: f64,
+    alternative: Alternative,
+    confidence_level: f64,
+    sample_sizes: Vec<usize>,
+) -> Result<TestResult, HypothesisTestError> {
+    if standard_error <= 0. || !standard_error.is_finite() {
+        return Err(HypothesisTestError::ZeroVariance);
+    }
+    let statistic = difference / standard_error;
+    let p_value = match alternative {
+        Alternative::TwoSided => 2. * student_t_cdf(-statistic.abs(), df),
+        Alternative::Less => student_t_cdf(statistic, df),
+        Alternative::Greater => student_t_cdf(-statistic, df),
+    };
+    let (lower, upper) = match alternative {
+        Alternative::TwoSided => {
+            let margin = student_t_quantile((1. + confidence_level) / 2., df) * standard_error;
+            (estimate - margin, estimate + margin)
+        }
+        Alternative::Less => (
+            f64::NEG_INFINITY,
+            estimate + student_t_quantile(confidence_level, df) * standard_error,
+        ),
+        Alternative::Greater => (
+            estimate - student_t_quantile(confidence_level, df) * standard_error,
+            f64::INFINITY,
+        ),
+    };
+    Ok(TestResult {
+        statistic,
+        degrees_of_freedom: vec![df],
+        p_value: p_value.min(1.),
+        confidence_interval: Some(ConfidenceInterval {
+            level: confidence_level,
+            estimate,
+            lower,
+            upper,
+        }),
+        sample_sizes,
+    })
+}
+
+fn one_way_anova(groups: &[Vec<f64>]) -> Result<TestResult, HypothesisTestError> {
+    if let Some(index) = groups.iter().position(|group| group.is_empty()) {
+        return Err(HypothesisTestError::InsufficientValues(index, 1, 0));
+    }
+    let total_count: usize = groups.iter().map(Vec::len).sum();
+    let k = groups.len();
+    if total_count <= k {
+        return Err(HypothesisTestError::InsufficientValues(
+            0,
+            2,
+            groups[0].len(),
+        ));
+    }
+    let grand_mean = groups.iter().flatten().sum::<f64>() / total_count as f64;
+    let mut between = 0.;
+    let mut within = 0.;
+    for group in groups {
+        let mean = group.iter().sum::<f64>() / group.len() as f64;
+        between += group.len() as f64 * (mean - grand_mean).powi(2);
+        within += group
+            .iter()
+            .map(|value| (value - mean).powi(2))
+            .sum::<f64>();
+    }
+    if within <= 0. {
+        return Err(HypothesisTestError::ZeroVariance);
+    }
+    let (df_between, df_within) = ((k - 1) as f64, (total_count - k) as f64);
+    let statistic = (between / df_between) / (within / df_within);
+    Ok(TestResult {
+        statistic,
+        degrees_of_freedom: vec![df_between, df_within],
+        p_value: f_survival(statistic, df_between, df_within),
+        confidence_interval: None,
+        sample_sizes: groups.iter().map(Vec::len).collect(),
+    })
+}
+
+// 統計量は 1 番目の標本の U
+fn mann_whitney_u(
+    first: &[f64],
+    second: &[f64],
+    alternative: Alternative,
+) -> Result<TestResult, HypothesisTestError> {
+    for (index, sample) in [first, second].iter().enumerate() {
+        if sample.is_empty() {
+            return Err(HypothesisTestError::InsufficientValues(index, 1, 0));
+        }
+    }
+    let (n1, n2) = (first.len() as f64, second.len() as f64);
+    let n = n1 + n2;
+    let combined: Vec<f64> = first.iter().chain(second).copied().collect();
+    let ranks = ranks(&combined);
+    let rank_sum: f64 = ranks[..first.len()].iter().sum();
+    let statistic = rank_sum - n1 * (n1 + 1.) / 2.;
+
+    // 同順位の組の大きさ t について Σ (t³ - t)
+    let mut sorted = combined.clone();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let mut tie_sum = 0.;
+    let mut start = 0;
+    while start < sorted.len() {
+        let end = start
+            + sorted[start..]
+                .iter()
+                .take_while(|v| **v == sorted[start])
+                .count();
+        let t = (end - start) as f64;
+        tie_sum += t * t * t - t;
+        start = end;
+    }
+    let mean = n1 * n2 / 2.;
+    let variance = n1 * n2 / 12. * ((n + 1.) - tie_sum / (n * (n - 1.)));
+    if variance <= 0. {
+        return Err(HypothesisTestError::ZeroVariance);
+    }
+    let sd = variance.sqrt();
+    let p_value = match alternative {
+        Alternative::TwoSided => {
+            let z = ((statistic - mean).abs() - 0.5).max(0.) / sd;
+            2. * normal_cdf(-z)
+        }
+        Alternative::Less => normal_cdf((statistic - mean + 0.5) / sd),
+        Alternative::Greater => normal_cdf(-(statistic - mean - 0.5) / sd),
+    };
+    Ok(TestResult {
+        statistic,
+        degrees_of_freedom: vec![],
+        p_value: p_value.min(1.),
+        confidence_interval: None,
+        sample_sizes: vec![first.len(), second.len()],
+    })
+}
+
+// 統計量は 2 つの経験分布関数の差の最大値 D
+fn kolmogorov_smirnov(first: &[f64], second: &[f64]) -> Result<TestResult, HypothesisTestError> {
+    for (index, sample) in [first, second].iter().enumerate() {
+        if sample.is_empty() {
+            return Err(HypothesisTestError::InsufficientValues(index, 1, 0));
+        }
+    }
+    let sort = |values: &[f64]| {
+        let mut sorted = values.to_vec();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        sorted
+    };
+    let (first, second) = (sort(first), sort(second));
+    let (n1, n2) = (first.len() as f64, second.len() as f64);
+    let statistic = first
+        .iter()
+        .chain(&second)
+        .map(|value| {
+            let cdf1 = first.partition_point(|v| v <= value) as f64 / n1;
+            let cdf2 = second.partition_point(|v| v <= value) as f64 / n2;
+            (cdf1 - cdf2).abs()
+        })
+        .fold(0., f64::max);
+    // Stephens による有限標本の補正
+    let effective = (n1 * n2 / (n1 + n2)).sqrt();
+    let p_value = kolmogorov_survival((effective + 0.12 + 0.11 / effective) * statistic);
+    Ok(TestResult {
+        statistic,
+        degrees_of_freedom: vec![],
+        p_value,
+        confidence_interval: None,
+        sample_sizes: vec![first.len(), second.len()],
+    })
+}
+
+fn chi_square_goodness_of_fit(
+    samples: &[Vec<CellRawValue>],
+) -> Result<TestResult, HypothesisTestError> {
+    // 観測度数と期待度数の組 (どちらかがない行は除く)
+    let rows: Vec<(usize, f64, f64)> = samples[0]
+        .iter()
+        .enumerate()
+        .filter_map(|(row, observed)| {
+            let expected = match samples.get(1) {
+                Some(expected) => expected.get(row).copied().flatten()?,
+                None => 1.,
+            };
+            Some((row, (*observed)?, expected))
+        })
+        .collect();
+    if rows.len() < 2 {
+        return Err(HypothesisTestError::InsufficientValues(0, 2, rows.len()));
+    }
+    for (row, observed, expected) in &rows {
+        if !observed.is_finite() || *observed < 0. || !expected.is_finite() || *expected <= 0. {
+            return Err(HypothesisTestError::InvalidFrequency(*row));
+        }
+    }
+    let pairs: Vec<(f64, f64)> = rows
+        .iter()
+        .map(|(_, observed, expected)| (*observed, *expected))
+        .collect();
+    // 期待度数は観測度数の合計に合わせて正規化する
+    let observed_total: f64 = pairs.iter().map(|(observed, _)| observed).sum();
+    let expected_total: f64 = pairs.iter().map(|(_, expected)| expected).sum();
+    let statistic = pairs
+        .iter()
+        .map(|(observed, expected)| {
+            let expected = expected * observed_total / expected_total;
+            (observed - expected).powi(2) / expected
+        })
+        .sum::<f64>();
+    let df = (pairs.len() - 1) as f64;
+    Ok(TestResult {
+        statistic,
+        degrees_of_freedom: vec![df],
+        p_value: chi_square_survival(statistic, df),
+        confidence_interval: None,
+        sample_sizes: vec![pairs.len(); samples.len()],
+    })
+}
+
+fn chi_square_independence(
+    samples: &[Vec<CellRawValue>],
+) -> Result<TestResult, HypothesisTestError> {
+    let row_count = samples.iter().map(Vec::len).max().unwrap_or(0);
+    let mut table: Vec<Vec<f64>> = vec![];
+    for row in 0..row_count {
+        let counts: Option<Vec<f64>> = samples
+            .iter()
+            .map(|sample| sample.get(row).copied().flatten())
+            .collect();
+        if let Some(counts) = counts {
+            if counts.iter().any(|count| !count.is_finite() || *count < 0.) {
+                return Err(HypothesisTestError::InvalidFrequency(row));
+            }
+            table.push(counts);
+        }
+    }
+    if table.len() < 2 {
+        return Err(HypothesisTestError::InsufficientValues(0, 2, table.len()));
+    }
+    let row_totals: Vec<f64> = table.iter().map(|counts| counts.iter().sum()).collect();
+    let column_totals: Vec<f64> = (0..samples.len())
+        .map(|column| table.iter().map(|counts| counts[column]).sum())
+        .collect();
+    let total: f64 = row_totals.iter().sum();
+    if row_totals
+        .iter()
+        .chain(&column_totals)
+        .any(|marginal| *marginal <= 0.)
+    {
+        return Err(HypothesisTestError::ZeroMarginal);
+    }
+    let mut statistic = 0.;
+    for (counts, row_total) in table.iter().zip(&row_totals) {
+        for (count, column_total) in counts.iter().zip(&column_totals) {
+            let expected = row_total * column_total / total;
+            statistic += (count - expected).powi(2) / expected;
+        }
+    }
+    let df = ((table.len() - 1) * (samples.len() - 1)) as f64;
+    Ok(TestResult {
+        statistic,
+        degrees_of_freedom: vec![df],
+        p_value: chi_square_survival(statistic, df),
+        confidence_interval: None,
+        sample_sizes: vec![table.len(); samples.len()],
+    })
+}
+
+#[derive(Debug, Error)]
+pub enum HypothesisTestError {
+    #[error("confidence level must be between 0 and 1 exclusive, but {0} is given")]
+    InvalidConfidenceLevel(f64),
+    #[error("invalid number of samples for the test: {0}")]
+    InvalidSampleCount(usize),
+    #[error("the test does not support the {0} alternative")]
+    UnsupportedAlternative(Alternative),
+    #[error("sample {0} needs at least {1} values, but {2} values are given")]
+    InsufficientValues(usize, usize, usize),
+    #[error("variance is zero")]
+    ZeroVariance,
+    #[error("frequencies must be non-negative finite numbers, at row {0}")]
+    InvalidFrequency(usize),
+    #[error("row and column totals of the contingency table must be positive")]
+    ZeroMarginal,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual - expected).abs() < tolerance,
+            "actual: {}, expected: {}",
+            actual,
+            expected
+        );
+    }
+
+    fn sample(values: &[f64]) -> Vec<CellRawValue> {
+        values.iter().map(|value| Some(*value)).collect()
+    }
+
+    #[test]
+    fn test_t_tests() -> anyhow::Result<()> {
+        let mut x = sample(&[5.1, 4.9, 5.6, 5.8, 6.0, 5.7, 5.3]);
+        x.push(None);
+        let result = HypothesisTest::OneSampleT { mean: 5. }.run(
+            &[x.clone()],
+            Alternative::TwoSided,
+            0.95,
+        )?;
+        assert_close(result.statistic(), 3.231_993_677_7, 1e-9);
+        assert_eq!(result.degrees_of_freedom(), &vec![6.]);
+        assert_close(result.p_value(), 0.017_865_665, 1e-7);
+        assert_eq!(result.sample_sizes(), &vec![7]);
+        // 信頼区間は平均 ± t(0.975, 6) × 標準誤差
+        let interval = result.confidence_interval().unwrap();
+        let margin = 2.446_912 * (result.statistic().recip() * (interval.estimate() - 5.));
+        assert_close(interval.estimate(), 5.485_714_285_7, 1e-9);
+        assert_close(interval.lower(), interval.estimate() - margin, 1e-5);
+        assert_close(interval.upper(), interval.estimate() + margin, 1e-5);
+
+        let a = sample(&[20.1, 22.3, 19.8, 21.5, 23.0]);
+        let b = sample(&[18.2, 19.1, 17.5, 20.0, 18.8, 19.4]);
+        let result = HypothesisTest::WelchT.run(&[a, b], Alternative::TwoSided, 0.95)?;
+        assert_close(result.statistic(), 3.504_161_891_6, 1e-9);
+        assert_close(result.degrees_of_freedom()[0], 6.603_639_258, 1e-8);
+        assert_close(result.p_value(), 0.010_918_433, 1e-7);
+
+        // 片側検定の信頼区間は一方が無限大
+        let before = sample(&[72., 80., 65., 90., 77., 84.]);
+        let after = sample(&[70., 76., 66., 85., 72., 80.]);
+        let result = HypothesisTest::PairedT.run(&[before, after], Alternative::Greater, 0.95)?;
+        assert_close(result.statistic(), 3.348_310_040, 1e-8);
+        assert_close(result.p_value(), 0.010_183_218, 1e-7);
+        assert_eq!(result.confidence_interval().unwrap().upper(), f64::INFINITY);
+
+        assert!(matches!(
+            HypothesisTest::OneSampleT { mean: 0. }.run(
+                &[sample(&[1., 1., 1.])],
+                Alternative::TwoSided,
+                0.95
+            ),
+            Err(HypothesisTestError::ZeroVariance)
+        ));
+        assert!(matches!(
+            HypothesisTest::WelchT.run(&[x], Alternative::TwoSided, 0.95),
+            Err(HypothesisTestError::InvalidSampleCount(1))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_anova_and_nonparametric() -> anyhow::Result<()> {
+        let groups = vec![
+            sample(&[4.2, 4.8, 5.1, 4.5]),
+            sample(&[5.9, 6.1, 5.5, 6.3, 5.8]),
+            sample(&[4.9, 5.2, 5.0]),
+        ];
+        let result = HypothesisTest::OneWayAnova.run(&groups, Alternative::TwoSided, 0.95)?;
+        assert_close(result.statistic(), 19.869_217_425, 1e-8);
+        assert_eq!(result.degrees_of_freedom(), &vec![2., 9.]);
+        assert_close(result.p_value(), 0.000_499_654, 1e-8);
+        assert!(result.confidence_interval().is_none());
+
+        // 3.5 が同順位
+        let a = sample(&[1.1, 2.3, 3.5, 4.2, 5.0]);
+        let b = sample(&[3.5, 6.1, 7.2, 8.0]);
+        let result = HypothesisTest::MannWhitneyU.run(
+            &[a.clone(), b.clone()],
+            Alternative::TwoSided,
+            0.95,
+        )?;
+        assert_eq!(result.statistic(), 2.5);
+        assert_close(result.p_value(), 0.085_099_933, 1e-8);
+
+        let result = HypothesisTest::KolmogorovSmirnov.run(
+            &[a.clone(), b.clone()],
+            Alternative::TwoSided,
+            0.95,
+        )?;
+        assert_close(result.statistic(), 0.75, 1e-12);
+        assert!(matches!(
+            HypothesisTest::KolmogorovSmirnov.run(&[a, b], Alternative::Less, 0.95),
+            Err(HypothesisTestError::UnsupportedAlternative(
+                Alternative::Less
+            ))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_chi_square() -> anyhow::Result<()> {
+        // 期待度数を省略すると一様
+        let observed = sample(&[18., 22., 20., 40.]);
+        let result = HypothesisTest::ChiSquareGoodnessOfFit.run(
+            std::slice::from_ref(&observed),
+            Alternative::TwoSided,
+            0.95,
+        )?;
+        assert_close(result.statistic(), 12.32, 1e-12);
+        assert_eq!(result.degrees_of_freedom(), &vec![3.]);
+        assert_close(result.p_value(), 0.006_363_630, 1e-8);
+        // 期待度数は合計が観測度数と同じになるように正規化される
+        let result = HypothesisTest::ChiSquareGoodnessOfFit.run(
+            &[observed, sample(&[1., 1., 1., 1.])],
+            Alternative::TwoSided,
+            0.95,
+        )?;
+        assert_close(result.statistic(), 12.32, 1e-12);
+
+        let table = vec![sample(&[10., 30.]), sample(&[20., 40.])];
+        let result =
+            HypothesisTest::ChiSquareIndependence.run(&table, Alternative::TwoSided, 0.95)?;
+        assert_close(result.statistic(), 0.793_650_794, 1e-9);
+        assert_close(result.p_value(), 0.372_998_484, 1e-8);
+
+        assert!(matches!(
+            HypothesisTest::ChiSquareGoodnessOfFit.run(
+                &[sample(&[1., -1.])],
+                Alternative::TwoSided,
+                0.95
+            ),
+            Err(HypothesisTestError::InvalidFrequency(1))
+        ));
+        Ok(())
+    }
+}
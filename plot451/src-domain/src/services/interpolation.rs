@@ -0,0 +1,365 @@
+use thiserror::Error;
+
+use crate::{
+    models::column::column_cell::column_cell_value::CellRawValue, shared::limits::MAX_CREATED_CELLS,
+};
+
+// 補間の方法
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterpolationMethod {
+    // 折れ線
+    Linear,
+    // 自然 3 次スプライン (両端の 2 階微分が 0)
+    CubicSpline,
+    // 秋間補間 (外れ値の影響を受けにくい区分 3 次補間)
+    Akima,
+    // 最近傍の点の値 (中点では x の小さい方を採る)
+    Nearest,
+}
+
+// x の範囲外での扱い
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Extrapolation {
+    // None にする
+    Missing,
+    // 端の点の値を使う
+    Clamp,
+    // 端の区間の補間式をそのまま延長する
+    Extend,
+}
+
+// domain service
+// 標本点列の補間
+// x, y のいずれかが None (または NaN) の点は除外し、x の昇順に並べ替える
+#[derive(Debug, Clone, PartialEq)]
+pub struct Interpolator {
+    method: InterpolationMethod,
+    x: Vec<f64>,
+    y: Vec<f64>,
+    // 3 次補間での各点の傾き (エルミート補間に使う)
+    slopes: Vec<f64>,
+}
+
+impl Interpolator {
+    pub fn new(
+        x: &[CellRawValue],
+        y: &[CellRawValue],
+        method: InterpolationMethod,
+    ) -> Result<Self, InterpolationError> {
+        let value_at = |values: &[CellRawValue], row: usize| {
+            values
+                .get(row)
+                .copied()
+                .flatten()
+                .filter(|value| !value.is_nan())
+        };
+        let mut points: Vec<(f64, f64)> = (0..x.len().max(y.len()))
+            .filter_map(|row| Some((value_at(x, row)?, value_at(y, row)?)))
+            .collect();
+        if points.len() < 2 {
+            return Err(InterpolationError::InsufficientPoints(points.len()));
+        }
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        if let Some(pair) = points.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+            return Err(InterpolationError::DuplicateX(pair[0].0));
+        }
+
+        let (x, y): (Vec<f64>, Vec<f64>) = points.into_iter().unzip();
+        let slopes = match method {
+            InterpolationMethod::CubicSpline => natural_spline_slopes(&x, &y),
+            InterpolationMethod::Akima => akima_slopes(&x, &y),
+            InterpolationMethod::Linear | InterpolationMethod::Nearest => vec![],
+        };
+        Ok(Self {
+            method,
+            x,
+            y,
+            slopes,
+        })
+    }
+
+    pub fn method(&self) -> InterpolationMethod {
+        self.method
+    }
+
+    pub fn evaluate(&self, t: f64, extrapolation: Extrapolation) -> Option<f64> {
+        if t.is_nan() {
+            return None;
+        }
+        let last = self.x.len() - 1;
+        let outside = t < self.x[0] || t > self.x[last];
+        if outside {
+            match extrapolation {
+                Extrapolation::Missing => return None,
+                Extrapolation::Clamp => {
+                    return Some(if t < self.x[0] {
+                        self.y[0]
+                    } else {
+                        self.y[last]
+                    })
+                }
+                Extrapolation::Extend => {}
+            }
+        }
+
+        // t を含む区間 [x_i, x_i+1] (範囲外は端の区間)
+        let i = self.x.partition_point(|x| *x <= t).clamp(1, last) - 1;
+        let (x0, x1, y0, y1) = (self.x[i], self.x[i + 1], self.y[i], self.y[i + 1]);
+        let h = x1 - x0;
+        let s = (t - x0) / h;
+        let value = match self.method {
+            InterpolationMethod::Linear => y0 + (y1 - y0) * s,
+            InterpolationMethod::Nearest => {
+                if outside {
+                    if t < x0 {
+                        y0
+                    } else {
+                        y1
+                    }
+                } else if s <= 0.5 {
+                    y0
+                } else {
+                    y1
+                }
+            }
+            InterpolationMethod::CubicSpline | InterpolationMethod::Akima => {
+                // 3 次エルミート補間
+                let (d0, d1) = (self.slopes[i], self.slopes[i + 1]);
+                let s2 = s * s;
+                let s3 = s2 * s;
+                (2. * s3 - 3. * s2 + 1.) * y0
+                    + (s3 - 2. * s2 + s) * h * d0
+                    + (-2. * s3 + 3. * s2) * y1
+                    + (s3 - s2) * h * d1
+            }
+        };
+        Some(value).filter(|value| value.is_finite())
+    }
+
+    // 目標の x の列で評価する (None の行は None)
+    pub fn resample(
+        &self,
+        targets: &[CellRawValue],
+        extrapolation: Extrapolation,
+    ) -> Vec<CellRawValue> {
+        targets
+            .iter()
+            .map(|t| t.and_then(|t| self.evaluate(t, extrapolation)))
+            .collect()
+    }
+}
+
+// start から end までを count 等分した点列 (両端を含む)
+pub fn linspace(start: f64, end: f64, count: usize) -> Result<Vec<f64>, InterpolationError> {
+    if count == 0 || !start.is_finite() || !end.is_finite() {
+        return Err(InterpolationError::InvalidGrid);
+    }
+    if count > MAX_CREATED_CELLS {
+        return Err(InterpolationError::TooManyPoints(count));
+    }
+    if count == 1 {
+        return Ok(vec![start]);
+    }
+    let step = (end - start) / (count - 1) as f64;
+    Ok((0..count)
+        .map(|i| {
+            if i == count - 1 {
+                end
+            } else {
+                start + step * i as f64
+            }
+        })
+        .collect())
+}
+
+// 自然 3 次スプラインの各点の傾き
+// 2 階微分 M を三重対角方程式 (トーマス法) で求め、傾きに換算する
+fn natural_spline_slopes(x: &[f64], y: &[f64]) -> Vec<f64> {
+    let n = x.len();
+    let h: Vec<f64> = x.windows(2).map(|pair| pair[1] - pair[0]).collect();
+    let secant: Vec<f64> = (0..n - 1).map(|i| (y[i + 1] - y[i]) / h[i]).collect();
+
+    let mut second = vec![0.; n];
+    if n > 2 {
+        // 内部の点 i = 1..n-1 について h_i-1 M_i-1 + 2(h_i-1 + h_i) M_i + h_i M_i+1 = 6 (s_i - s_i-1)
+        let m = n - 2;
+        let mut diagonal: Vec<f64> = (1..n - 1).map(|i| 2. * (h[i - 1] + h[i])).collect();
+        let mut rhs: Vec<f64> = (1..n - 1)
+            .map(|i| 6. * (secant[i] - secant[i - 1]))
+            .collect();
+        for k in 1..m {
+            let factor = h[k] / diagonal[k - 1];
+            diagonal[k] -= factor * h[k];
+            rhs[k] -= factor * rhs[k - 1];
+        }
+        second[m] = rhs[m - 1] / diagonal[m - 1];
+        for k in (0..m - 1).rev() {
+            second[k + 1] = (rhs[k] - h[k + 1] * second[k + 2]) / diagonal[k];
+        }
+    }
+
+    let mut slopes: Vec<f64> = (0..n - 1)
+        .map(|i| secant[i] - h[i] * (2. * second[i] + second[i + 1]) / 6.)
+        .collect();
+    slopes.push(secant[n - 2] + h[n - 2] * (second[n - 2] + 2. * second[n - 1]) / 6.);
+    slopes
+}
+
+// 秋間補間の各点の傾き
+// 端では区間の傾きを線形に外挿して 2 つずつ補う
+fn akima_slopes(x: &[f64], y: &[f64]) -> Vec<f64> {
+    let n = x.len();
+    let secant: Vec<f64> = (0..n - 1)
+        .map(|i| (y[i + 1] - y[i]) / (x[i + 1] - x[i]))
+        .collect();
+    if n == 2 {
+        return vec![secant[0]; 2];
+    }
+
+    // m[k + 2] が区間 k の傾き
+    let mut m = Vec::with_capacity(n + 3);
+    let before = 2. * secant[0] - secant[1];
+    m.push(2. * before - secant[0]);
+    m.push(before);
+    m.extend(&secant);
+    let after = 2. * secant[n - 2] - secant[n - 3];
+    m.push(after);
+    m.push(2. * after - secant[n - 2]);
+
+    (0..n)
+        .map(|i| {
+            let (m0, m1, m2, m3) = (m[i], m[i + 1], m[i + 2], m[i + 3]);
+            let w1 = (m3 - m2).abs();
+            let w2 = (m1 - m0).abs();
+            if w1 + w2 == 0. {
+                (m1 + m2) / 2.
+            } else {
+                (w1 * m1 + w2 * m2) / (w1 + w2)
+            }
+        })
+        .collect()
+}
+
+#[derive(Debug, Error)]
+pub enum InterpolationError {
+    #[error("at least 2 points are required, but {0} points are given")]
+    InsufficientPoints(usize),
+    #[error("x value {0} is duplicated")]
+    DuplicateX(f64),
+    #[error("grid must have at least one point and finite limits")]
+    InvalidGrid,
+    #[error("too many grid points: {0} (at most {MAX_CREATED_CELLS})")]
+    TooManyPoints(usize),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: Option<f64>, expected: f64) {
+        let actual = actual.expect("value is None");
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "actual: {}, expected: {}",
+            actual,
+            expected
+        );
+    }
+
+    fn points(values: &[(f64, f64)]) -> (Vec<CellRawValue>, Vec<CellRawValue>) {
+        values.iter().map(|(x, y)| (Some(*x), Some(*y))).unzip()
+    }
+
+    #[test]
+    fn test_linear_and_nearest() -> anyhow::Result<()> {
+        // 順不同・欠損を含む点列
+        let x = vec![Some(2.), Some(0.), None, Some(1.)];
+        let y = vec![Some(0.), Some(0.), Some(5.), Some(2.)];
+        let linear = Interpolator::new(&x, &y, InterpolationMethod::Linear)?;
+        assert_close(linear.evaluate(0.5, Extrapolation::Missing), 1.);
+        assert_close(linear.evaluate(1.75, Extrapolation::Missing), 0.5);
+        assert_eq!(linear.evaluate(3., Extrapolation::Missing), None);
+        assert_close(linear.evaluate(3., Extrapolation::Clamp), 0.);
+        assert_close(linear.evaluate(3., Extrapolation::Extend), -2.);
+        assert_close(linear.evaluate(-1., Extrapolation::Extend), -2.);
+
+        let nearest = Interpolator::new(&x, &y, InterpolationMethod::Nearest)?;
+        assert_close(nearest.evaluate(0.4, Extrapolation::Missing), 0.);
+        assert_close(nearest.evaluate(0.6, Extrapolation::Missing), 2.);
+        assert_close(nearest.evaluate(5., Extrapolation::Extend), 0.);
+
+        let resampled = linear.resample(&[Some(0.), None, Some(2.)], Extrapolation::Missing);
+        assert_eq!(resampled, vec![Some(0.), None, Some(0.)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_cubic_spline() -> anyhow::Result<()> {
+        // 直線は厳密に再現される
+        let (x, y) = points(&[(0., 1.), (1., 3.), (3., 7.), (4., 9.)]);
+        let spline = Interpolator::new(&x, &y, InterpolationMethod::CubicSpline)?;
+        assert_close(spline.evaluate(2., Extrapolation::Missing), 5.);
+        assert_close(spline.evaluate(5., Extrapolation::Extend), 11.);
+
+        // 節点を通り、自然スプラインの値と一致する
+        let (x, y) = points(&[(0., 0.), (1., 1.), (2., 0.)]);
+        let spline = Interpolator::new(&x, &y, InterpolationMethod::CubicSpline)?;
+        assert_close(spline.evaluate(1., Extrapolation::Missing), 1.);
+        // M1 = -3, 区間 [0, 1] で S(x) = 1.5x - 0.5x^3
+        assert_close(spline.evaluate(0.5, Extrapolation::Missing), 0.6875);
+        Ok(())
+    }
+
+    #[test]
+    fn test_akima() -> anyhow::Result<()> {
+        // 段差の前後で平坦な部分は平坦に保たれる (オーバーシュートしない)
+        let (x, y) = points(&[(0., 0.), (1., 0.), (2., 0.), (3., 1.), (4., 1.), (5., 1.)]);
+        let akima = Interpolator::new(&x, &y, InterpolationMethod::Akima)?;
+        assert_close(akima.evaluate(1.5, Extrapolation::Missing), 0.);
+        assert_close(akima.evaluate(4.5, Extrapolation::Missing), 1.);
+        let middle = akima.evaluate(2.5, Extrapolation::Missing).unwrap();
+        assert!(0. < middle && middle < 1.);
+
+        let (x, y) = points(&[(0., 1.), (2., 5.)]);
+        let akima = Interpolator::new(&x, &y, InterpolationMethod::Akima)?;
+        assert_close(akima.evaluate(1., Extrapolation::Missing), 3.);
+        Ok(())
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(matches!(
+            Interpolator::new(&[Some(1.)], &[Some(1.)], InterpolationMethod::Linear),
+            Err(InterpolationError::InsufficientPoints(1))
+        ));
+        assert!(matches!(
+            Interpolator::new(
+                &[Some(1.), Some(1.)],
+                &[Some(1.), Some(2.)],
+                InterpolationMethod::Linear
+            ),
+            Err(InterpolationError::DuplicateX(_))
+        ));
+        assert!(matches!(
+            linspace(0., 1., 0),
+            Err(InterpolationError::InvalidGrid)
+        ));
+    }
+
+    #[test]
+    fn test_linspace() -> anyhow::Result<()> {
+        assert_eq!(linspace(0., 1., 5)?, vec![0., 0.25, 0.5, 0.75, 1.]);
+        assert_eq!(linspace(2., 3., 1)?, vec![2.]);
+        assert_eq!(
+            linspace(0., 1., MAX_CREATED_CELLS)?.len(),
+            MAX_CREATED_CELLS
+        );
+        for count in [MAX_CREATED_CELLS + 1, usize::MAX] {
+            assert!(matches!(
+                linspace(0., 1., count),
+                Err(InterpolationError::TooManyPoints(_))
+            ));
+        }
+        Ok(())
+    }
+}
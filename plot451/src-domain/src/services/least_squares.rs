@@ -0,0 +1,331 @@
+use thiserror::Error;
+
+use crate::models::column::column_cell::column_cell_value::CellRawValue;
+
+use super::linear_algebra::{gram, invert, solve, transpose_multiply, Matrix};
+
+// 多項式の次数の上限 (これより高い次数は正規方程式の条件が悪く意味のある当てはめにならない)
+pub const MAX_POLYNOMIAL_DEGREE: usize = 10;
+
+// 線形最小二乗法で当てはめるモデル
+#[derive(Debug, Clone, PartialEq)]
+pub enum LeastSquaresModel {
+    // y = c0 + c1 x + ... + cn x^n
+    Polynomial(usize),
+    // y = c0 + c1 x (c0 は固定)
+    LinearFixedIntercept(f64),
+}
+
+impl LeastSquaresModel {
+    pub fn linear() -> Self {
+        LeastSquaresModel::Polynomial(1)
+    }
+
+    // 推定する係数の個数
+    fn free_parameter_count(&self) -> usize {
+        match self {
+            LeastSquaresModel::Polynomial(degree) => degree + 1,
+            LeastSquaresModel::LinearFixedIntercept(_) => 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FitCoefficient {
+    value: f64,
+    // 固定した係数、または自由度が 0 の場合は None
+    standard_error: Option<f64>,
+    fixed: bool,
+}
+
+impl FitCoefficient {
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    pub fn standard_error(&self) -> Option<f64> {
+        self.standard_error
+    }
+
+    pub fn fixed(&self) -> bool {
+        self.fixed
+    }
+}
+
+// domain service
+// 線形最小二乗法による当てはめの結果
+// x, y のいずれかが None (または NaN) の行は当てはめから除外する
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeastSquaresFit {
+    model: LeastSquaresModel,
+    // 次数の昇順 (c0, c1, ...)
+    coefficients: Vec<FitCoefficient>,
+    r_squared: Option<f64>,
+    degrees_of_freedom: usize,
+    residual_sum_of_squares: f64,
+    // x が存在する行の当てはめ値
+    fitted: Vec<CellRawValue>,
+    // 当てはめに使った行の残差 (y - 当てはめ値)
+    residuals: Vec<CellRawValue>,
+}
+
+impl LeastSquaresFit {
+    pub fn fit(
+        model: LeastSquaresModel,
+        x: &[CellRawValue],
+        y: &[CellRawValue],
+    ) -> Result<Self, LeastSquaresError> {
+        if let LeastSquaresModel::Polynomial(degree) = model {
+            if !(1..=MAX_POLYNOMIAL_DEGREE).contains(&degree) {
+                return Err(LeastSquaresError::InvalidDegree(degree));
+            }
+        }
+
+        let row_count = x.len().max(y.len());
+        let value_at = |values: &[CellRawValue], row: usize| {
+            values
+                .get(row)
+                .copied()
+                .flatten()
+                .filter(|value| !value.is_nan())
+        };
+        let points: Vec<(f64, f64)> = (0..row_count)
+            .filter_map(|row| Some((value_at(x, row)?, value_at(y, row)?)))
+            .collect();
+
+        let parameter_count = model.free_parameter_count();
+        if points.len() < parameter_count {
+            return Err(LeastSquaresError::InsufficientPoints(
+                parameter_count,
+                points.len(),
+            ));
+        }
+
+        // 計画行列と目的変数 (固定した切片は目的変数から差し引く)
+        let (design, target): (Matrix, Vec<f64>) = match &model {
+            LeastSquaresModel::Polynomial(degree) => points
+                .iter()
+                .map(|(x, y)| ((0..=*degree as i32).map(|k| x.powi(k)).collect(), *y))
+                .unzip(),
+            LeastSquaresModel::LinearFixedIntercept(intercept) => points
+                .iter()
+                .map(|(x, y)| (vec![*x], y - intercept))
+                .unzip(),
+        };
+
+        // 正規方程式
+        let normal_matrix = gram(&design);
+        let free_values = solve(&normal_matrix, &transpose_multiply(&design, &target))
+            .ok_or(LeastSquaresError::SingularMatrix)?;
+        let covariance_base = invert(&normal_matrix).ok_or(LeastSquaresError::SingularMatrix)?;
+
+        let coefficient_values: Vec<f64> = match &model {
+            LeastSquaresModel::Polynomial(_) => free_values.clone(),
+            LeastSquaresModel::LinearFixedIntercept(intercept) => vec![*intercept, free_values[0]],
+        };
+        let evaluate = |x: f64| polynomial(&coefficient_values, x);
+
+        let residual_sum_of_squares: f64 =
+            points.iter().map(|(x, y)| (y - evaluate(*x)).powi(2)).sum();
+        let degrees_of_freedom = points.len() - parameter_count;
+        let mean = points.iter().map(|(_, y)| y).sum::<f64>() / points.len() as f64;
+        let total_sum_of_squares: f64 = points.iter().map(|(_, y)| (y - mean).powi(2)).sum();
+        let r_squared = (total_sum_of_squares > 0.)
+            .then(|| 1. - residual_sum_of_squares / total_sum_of_squares);
+
+        // 係数の標準誤差 (残差分散 × (X^T X)^-1 の対角成分の平方根)
+        let residual_variance =
+            (degrees_of_freedom > 0).then(|| residual_sum_of_squares / degrees_of_freedom as f64);
+        let free_standard_errors: Vec<Option<f64>> = (0..parameter_count)
+            .map(|i| residual_variance.map(|variance| (variance * covariance_base[i][i]).sqrt()))
+            .collect();
+        let coefficients = match &model {
+            LeastSquaresModel::Polynomial(_) => coefficient_values
+                .iter()
+                .zip(free_standard_errors)
+                .map(|(value, standard_error)| FitCoefficient {
+                    value: *value,
+                    standard_error,
+                    fixed: false,
+                })
+                .collect(),
+            LeastSquaresModel::LinearFixedIntercept(intercept) => vec![
+                FitCoefficient {
+                    value: *intercept,
+                    standard_error: None,
+                    fixed: true,
+                },
+                FitCoefficient {
+                    value: coefficient_values[1],
+                    standard_error: free_standard_errors[0],
+                    fixed: false,
+                },
+            ],
+        };
+
+        let fitted = (0..row_count)
+            .map(|row| value_at(x, row).map(evaluate))
+            .collect();
+        let residuals = (0..row_count)
+            .map(|row| Some(value_at(y, row)? - evaluate(value_at(x, row)?)))
+            .collect();
+
+        Ok(Self {
+            model,
+            coefficients,
+            r_squared,
+            degrees_of_freedom,
+            residual_sum_of_squares,
+            fitted,
+            residuals,
+        })
+    }
+
+    pub fn model(&self) -> &LeastSquaresModel {
+        &self.model
+    }
+
+    pub fn coefficients(&self) -> &Vec<FitCoefficient> {
+        &self.coefficients
+    }
+
+    pub fn r_squared(&self) -> Option<f64> {
+        self.r_squared
+    }
+
+    pub fn degrees_of_freedom(&self) -> usize {
+        self.degrees_of_freedom
+    }
+
+    pub fn residual_sum_of_squares(&self) -> f64 {
+        self.residual_sum_of_squares
+    }
+
+    pub fn fitted(&self) -> &Vec<CellRawValue> {
+        &self.fitted
+    }
+
+    pub fn residuals(&self) -> &Vec<CellRawValue> {
+        &self.residuals
+    }
+
+    pub fn evaluate(&self, x: f64) -> f64 {
+        let values: Vec<f64> = self.coefficients.iter().map(|c| c.value).collect();
+        polynomial(&values, x)
+    }
+}
+
+// ホーナー法による多項式の評価 (係数は次数の昇順)
+fn polynomial(coefficients: &[f64], x: f64) -> f64 {
+    coefficients
+        .iter()
+        .rev()
+        .fold(0., |sum, coefficient| sum * x + coefficient)
+}
+
+#[derive(Debug, Error)]
+pub enum LeastSquaresError {
+    #[error("at least {0} points are required, but {1} points are given")]
+    InsufficientPoints(usize, usize),
+    #[error("normal matrix is singular")]
+    SingularMatrix,
+    #[error(
+        "polynomial degree must be between 1 and {}, but {0} is given",
+        MAX_POLYNOMIAL_DEGREE
+    )]
+    InvalidDegree(usize),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "actual: {}, expected: {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_linear() -> anyhow::Result<()> {
+        // None を含む行は除外される
+        let x = vec![Some(1.), Some(2.), Some(3.), Some(4.), None, Some(5.)];
+        let y = vec![Some(2.), Some(4.), Some(5.), Some(4.), Some(100.), Some(5.)];
+        let fit = LeastSquaresFit::fit(LeastSquaresModel::linear(), &x, &y)?;
+
+        let coefficients = fit.coefficients();
+        assert_close(coefficients[0].value(), 2.2);
+        assert_close(coefficients[1].value(), 0.6);
+        // s^2 = 2.4 / 3, Sxx = 10
+        assert_close(coefficients[1].standard_error().unwrap(), (0.08f64).sqrt());
+        assert_close(
+            coefficients[0].standard_error().unwrap(),
+            (0.8f64 * (1. / 5. + 9. / 10.)).sqrt(),
+        );
+        assert_close(fit.r_squared().unwrap(), 0.6);
+        assert_eq!(fit.degrees_of_freedom(), 3);
+        assert_eq!(fit.fitted()[4], None);
+        assert_eq!(fit.residuals()[4], None);
+        assert_close(fit.residuals()[0].unwrap(), -0.8);
+        Ok(())
+    }
+
+    #[test]
+    fn test_polynomial() -> anyhow::Result<()> {
+        let x: Vec<CellRawValue> = (-3..=3).map(|x| Some(x as f64)).collect();
+        let y: Vec<CellRawValue> = x
+            .iter()
+            .map(|x| x.map(|x| 1. - 2. * x + 0.5 * x * x))
+            .collect();
+        let fit = LeastSquaresFit::fit(LeastSquaresModel::Polynomial(2), &x, &y)?;
+
+        let values: Vec<f64> = fit.coefficients().iter().map(|c| c.value()).collect();
+        assert_close(values[0], 1.);
+        assert_close(values[1], -2.);
+        assert_close(values[2], 0.5);
+        assert_close(fit.r_squared().unwrap(), 1.);
+        assert_close(fit.evaluate(10.), 31.);
+        Ok(())
+    }
+
+    #[test]
+    fn test_fixed_intercept() -> anyhow::Result<()> {
+        let x = vec![Some(1.), Some(2.), Some(3.)];
+        let y = vec![Some(2.), Some(3.), Some(5.)];
+        let fit = LeastSquaresFit::fit(LeastSquaresModel::LinearFixedIntercept(1.), &x, &y)?;
+
+        // (y - 1) = b x -> b = Σx(y-1) / Σx^2 = 17 / 14
+        let coefficients = fit.coefficients();
+        assert!(coefficients[0].fixed());
+        assert_eq!(coefficients[0].standard_error(), None);
+        assert_close(coefficients[0].value(), 1.);
+        assert_close(coefficients[1].value(), 17. / 14.);
+        assert_eq!(fit.degrees_of_freedom(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_errors() {
+        let x = vec![Some(1.), Some(1.), Some(1.)];
+        let y = vec![Some(1.), Some(2.), Some(3.)];
+        assert!(matches!(
+            LeastSquaresFit::fit(LeastSquaresModel::linear(), &x, &y),
+            Err(LeastSquaresError::SingularMatrix)
+        ));
+        assert!(matches!(
+            LeastSquaresFit::fit(LeastSquaresModel::Polynomial(3), &x, &y),
+            Err(LeastSquaresError::InsufficientPoints(4, 3))
+        ));
+        assert!(matches!(
+            LeastSquaresFit::fit(LeastSquaresModel::Polynomial(0), &x, &y),
+            Err(LeastSquaresError::InvalidDegree(0))
+        ));
+        assert!(matches!(
+            LeastSquaresFit::fit(LeastSquaresModel::Polynomial(usize::MAX), &x, &y),
+            Err(LeastSquaresError::InvalidDegree(usize::MAX))
+        ));
+    }
+}
@@ -0,0 +1,125 @@
+// 解析処理で共通に使う小規模な密行列の演算
+// 行列は行ベクトルの Vec で表す
+
+pub type Matrix = Vec<Vec<f64>>;
+
+// 部分ピボット選択付きガウスの消去法で a x = b を解く (特異な場合は None)
+pub fn solve(a: &Matrix, b: &[f64]) -> Option<Vec<f64>> {
+    let n = b.len();
+    let mut augmented: Matrix = a
+        .iter()
+        .zip(b)
+        .map(|(row, value)| {
+            let mut row = row.clone();
+            row.push(*value);
+            row
+        })
+        .collect();
+    eliminate(&mut augmented, n)?;
+    Some(augmented.iter().map(|row| row[n]).collect())
+}
+
+// ガウス・ジョルダン法による逆行列 (特異な場合は None)
+pub fn invert(a: &Matrix) -> Option<Matrix> {
+    let n = a.len();
+    let mut augmented: Matrix = a
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            let mut row = row.clone();
+            row.extend((0..n).map(|j| if i == j { 1. } else { 0. }));
+            row
+        })
+        .collect();
+    eliminate(&mut augmented, n)?;
+    Some(augmented.into_iter().map(|row| row[n..].to_vec()).collect())
+}
+
+// 左側 n 列を単位行列に変形する
+fn eliminate(augmented: &mut Matrix, n: usize) -> Option<()> {
+    // 特異性の判定に使う許容誤差
+    let scale = augmented
+        .iter()
+        .flat_map(|row| row[..n].iter())
+        .fold(0f64, |max, value| max.max(value.abs()));
+    let tolerance = scale * n as f64 * f64::EPSILON;
+
+    for column in 0..n {
+        let pivot = (column..n).max_by(|&i, &j| {
+            augmented[i][column]
+                .abs()
+                .total_cmp(&augmented[j][column].abs())
+        })?;
+        let pivot_abs = augmented[pivot][column].abs();
+        if pivot_abs.is_nan() || pivot_abs <= tolerance {
+            return None;
+        }
+        augmented.swap(column, pivot);
+
+        let pivot_value = augmented[column][column];
+        augmented[column]
+            .iter_mut()
+            .for_each(|value| *value /= pivot_value);
+        let pivot_row = augmented[column].clone();
+        for (i, row) in augmented.iter_mut().enumerate() {
+            if i == column || row[column] == 0. {
+                continue;
+            }
+            let factor = row[column];
+            row.iter_mut()
+                .zip(&pivot_row)
+                .for_each(|(value, pivot)| *value -= factor * pivot);
+        }
+    }
+    Some(())
+}
+
+// a^T a
+pub fn gram(a: &Matrix) -> Matrix {
+    let columns = a.first().map(|row| row.len()).unwrap_or(0);
+    (0..columns)
+        .map(|i| {
+            (0..columns)
+                .map(|j| a.iter().map(|row| row[i] * row[j]).sum())
+                .collect()
+        })
+        .collect()
+}
+
+// a^T b
+pub fn transpose_multiply(a: &Matrix, b: &[f64]) -> Vec<f64> {
+    let columns = a.first().map(|row| row.len()).unwrap_or(0);
+    (0..columns)
+        .map(|i| a.iter().zip(b).map(|(row, value)| row[i] * value).sum())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_and_invert() {
+        let a = vec![vec![0., 2., 1.], vec![1., 1., 0.], vec![2., 1., 3.]];
+        let x = solve(&a, &[7., 3., 13.]).unwrap();
+        for (actual, expected) in x.iter().zip([1., 2., 3.]) {
+            assert!((actual - expected).abs() < 1e-12);
+        }
+
+        let inverse = invert(&a).unwrap();
+        for (i, row) in a.iter().enumerate() {
+            for j in 0..3 {
+                let product: f64 = row.iter().zip(&inverse).map(|(a, b)| a * b[j]).sum();
+                let expected = if i == j { 1. } else { 0. };
+                assert!((product - expected).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_singular() {
+        let a = vec![vec![1., 2.], vec![2., 4.]];
+        assert!(solve(&a, &[1., 2.]).is_none());
+        assert!(invert(&a).is_none());
+    }
+}
@@ -0,0 +1,68 @@
+// 派生カラムの再計算
+pub mod derived_column_service;
+
+// 記述統計
+pub mod descriptive_statistics;
+
+// 解析結果からのカラム作成
+pub mod column_creation_service;
+
+// 解析の入力となるカラムの値の取得
+pub mod column_values_service;
+
+// 行の操作の前のカラムの長さの揃え
+pub mod table_rows_padding_service;
+
+// 行列演算
+pub mod linear_algebra;
+
+// 線形最小二乗法
+pub mod least_squares;
+
+// 非線形最小二乗法 (Levenberg–Marquardt 法)
+pub mod nonlinear_fit;
+
+// 数値微分・数値積分
+pub mod numerical_calculus;
+
+// 補間・再標本化
+pub mod interpolation;
+
+// 平滑化フィルタ
+pub mod smoothing;
+
+// フーリエ変換・パワースペクトル
+pub mod fourier;
+
+// ヒストグラム
+pub mod histogram;
+
+// ピーク検出
+pub mod peak_detection;
+
+// ベースライン推定
+pub mod baseline;
+
+// 相関行列・共分散行列
+pub mod correlation;
+
+// 統計的仮説検定
+pub mod hypothesis_test;
+
+// グループ化・集計・ピボット
+pub mod group_by;
+
+// テーブルの結合
+pub mod table_join;
+
+// カラムの値の変換・正規化
+pub mod transformation;
+
+// カラムの値の生成 (数列・乱数)
+pub mod column_generator;
+
+// 軸の自動範囲・目盛り
+pub mod axis_ticks;
+
+// グラフの SVG 出力
+pub mod plot_2d_svg;
@@ -0,0 +1,716 @@
+use std::collections::HashMap;
+
+use thiserror::Error;
+
+use crate::models::column::{
+    column_cell::column_cell_value::CellRawValue,
+    column_formula::formula_expression::{FormulaExpression, FormulaExpressionError},
+};
+
+use super::linear_algebra::{gram, invert, solve, transpose_multiply, Matrix};
+
+// モデル式の独立変数の名前
+pub const INDEPENDENT_VARIABLE: &str = "x";
+
+// 減衰係数の初期値と上限
+const INITIAL_DAMPING: f64 = 1e-3;
+const MAX_DAMPING: f64 = 1e16;
+// χ² の相対変化、パラメータの相対変化による収束判定の閾値
+const CHI_SQUARE_TOLERANCE: f64 = 1e-12;
+const STEP_TOLERANCE: f64 = 1e-10;
+
+// 当てはめるパラメータ
+#[derive(Debug, Clone, PartialEq)]
+pub struct FitParameter {
+    name: String,
+    initial_value: f64,
+    fixed: bool,
+    lower_bound: Option<f64>,
+    upper_bound: Option<f64>,
+}
+
+impl FitParameter {
+    pub fn new(
+        name: String,
+        initial_value: f64,
+        fixed: bool,
+        lower_bound: Option<f64>,
+        upper_bound: Option<f64>,
+    ) -> Result<Self, NonlinearFitError> {
+        if name == INDEPENDENT_VARIABLE {
+            return Err(NonlinearFitError::ReservedParameterName(name));
+        }
+        if let (Some(lower), Some(upper)) = (lower_bound, upper_bound) {
+            if lower > upper {
+                return Err(NonlinearFitError::InvalidBounds(name));
+            }
+        }
+        if lower_bound.is_some_and(|lower| initial_value < lower)
+            || upper_bound.is_some_and(|upper| initial_value > upper)
+        {
+            return Err(NonlinearFitError::InitialValueOutOfBounds(name));
+        }
+        Ok(Self {
+            name,
+            initial_value,
+            fixed,
+            lower_bound,
+            upper_bound,
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn initial_value(&self) -> f64 {
+        self.initial_value
+    }
+
+    pub fn fixed(&self) -> bool {
+        self.fixed
+    }
+
+    pub fn lower_bound(&self) -> Option<f64> {
+        self.lower_bound
+    }
+
+    pub fn upper_bound(&self) -> Option<f64> {
+        self.upper_bound
+    }
+
+    fn clamp(&self, value: f64) -> f64 {
+        let value = self.lower_bound.map_or(value, |lower| value.max(lower));
+        self.upper_bound.map_or(value, |upper| value.min(upper))
+    }
+}
+
+// モデル式 (独立変数 x とパラメータの式) とパラメータの組
+#[derive(Debug, Clone, PartialEq)]
+pub struct FitModel {
+    expression: FormulaExpression,
+    parameters: Vec<FitParameter>,
+}
+
+impl FitModel {
+    pub fn new(
+        expression: FormulaExpression,
+        parameters: Vec<FitParameter>,
+    ) -> Result<Self, NonlinearFitError> {
+        if !expression.references().is_empty() {
+            return Err(NonlinearFitError::FormulaExpressionError(
+                FormulaExpressionError::UnexpectedColumnReference,
+            ));
+        }
+        for (i, parameter) in parameters.iter().enumerate() {
+            if parameters[..i]
+                .iter()
+                .any(|other| other.name == parameter.name)
+            {
+                return Err(NonlinearFitError::DuplicateParameter(
+                    parameter.name.clone(),
+                ));
+            }
+        }
+        let variables = expression.variables();
+        if let Some(name) = variables.iter().find(|name| {
+            **name != INDEPENDENT_VARIABLE
+                && !parameters.iter().any(|parameter| parameter.name == **name)
+        }) {
+            return Err(NonlinearFitError::UndefinedParameter(name.to_string()));
+        }
+        if let Some(parameter) = parameters
+            .iter()
+            .find(|parameter| !variables.contains(&parameter.name.as_str()))
+        {
+            return Err(NonlinearFitError::UnusedParameter(parameter.name.clone()));
+        }
+        Ok(Self {
+            expression,
+            parameters,
+        })
+    }
+
+    pub fn expression(&self) -> &FormulaExpression {
+        &self.expression
+    }
+
+    pub fn parameters(&self) -> &Vec<FitParameter> {
+        &self.parameters
+    }
+
+    // パラメータの値 (parameters と同じ順序) を与えてモデルを評価する
+    pub fn evaluate(&self, x: f64, values: &[f64]) -> Result<f64, NonlinearFitError> {
+        let mut variables: HashMap<String, f64> = self
+            .parameters
+            .iter()
+            .zip(values)
+            .map(|(parameter, value)| (parameter.name.clone(), *value))
+            .collect();
+        variables.insert(INDEPENDENT_VARIABLE.to_string(), x);
+        self.expression
+            .evaluate_with_variables(&variables)
+            .map_err(NonlinearFitError::FormulaExpressionError)
+    }
+}
+
+// 反復を終了した理由
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FitTermination {
+    // χ² の相対変化が閾値を下回った
+    ChiSquareConverged,
+    // パラメータの相対変化が閾値を下回った
+    StepConverged,
+    // 最大反復回数に達した
+    MaxIterations,
+    // 減衰係数を大きくしても χ² が減少しなかった
+    DampingOverflow,
+}
+
+impl FitTermination {
+    pub fn converged(&self) -> bool {
+        matches!(
+            self,
+            FitTermination::ChiSquareConverged | FitTermination::StepConverged
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FittedParameter {
+    name: String,
+    value: f64,
+    // 固定したパラメータ、または共分散が求まらない場合は None
+    standard_error: Option<f64>,
+    fixed: bool,
+}
+
+impl FittedParameter {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    pub fn standard_error(&self) -> Option<f64> {
+        self.standard_error
+    }
+
+    pub fn fixed(&self) -> bool {
+        self.fixed
+    }
+}
+
+// domain service
+// Levenberg–Marquardt 法による非線形最小二乗法の当てはめの結果
+// x, y のいずれかが None (または NaN) の行、誤差が None または 0 以下の行は当てはめから除外する
+// 誤差を与えた場合は 1 / σ² で重み付けし、共分散は (J^T W J)^-1 そのものを用いる
+// 誤差を与えない場合は共分散を換算 χ² でスケールする
+#[derive(Debug, Clone, PartialEq)]
+pub struct NonlinearFit {
+    parameters: Vec<FittedParameter>,
+    // parameters と同じ順序の共分散行列 (固定したパラメータの行と列は 0)
+    covariance: Option<Matrix>,
+    chi_square: f64,
+    reduced_chi_square: Option<f64>,
+    degrees_of_freedom: usize,
+    iterations: usize,
+    termination: FitTermination,
+    weighted: bool,
+    // x が存在する行の当てはめ値
+    fitted: Vec<CellRawValue>,
+    // x, y が存在する行の残差 (y - 当てはめ値)
+    residuals: Vec<CellRawValue>,
+}
+
+impl NonlinearFit {
+    pub fn fit(
+        model: &FitModel,
+        x: &[CellRawValue],
+        y: &[CellRawValue],
+        errors: Option<&[CellRawValue]>,
+        max_iterations: usize,
+    ) -> Result<Self, NonlinearFitError> {
+        let row_count = x.len().max(y.len());
+        let value_at = |values: &[CellRawValue], row: usize| {
+            values
+                .get(row)
+                .copied()
+                .flatten()
+                .filter(|value| !value.is_nan())
+        };
+        // (x, y, 重み)
+        let points: Vec<(f64, f64, f64)> = (0..row_count)
+            .filter_map(|row| {
+                let weight = match errors {
+                    Some(errors) => {
+                        let error = value_at(errors, row).filter(|error| *error > 0.)?;
+                        1. / (error * error)
+                    }
+                    None => 1.,
+                };
+                Some((value_at(x, row)?, value_at(y, row)?, weight))
+            })
+            .collect();
+
+        let free: Vec<usize> = (0..model.parameters.len())
+            .filter(|i| !model.parameters[*i].fixed)
+            .collect();
+        if points.len() < free.len() || points.is_empty() {
+            return Err(NonlinearFitError::InsufficientPoints(
+                free.len().max(1),
+                points.len(),
+            ));
+        }
+
+        let mut values: Vec<f64> = model
+            .parameters
+            .iter()
+            .map(|parameter| parameter.initial_value)
+            .collect();
+        let mut chi_square = chi_square_of(model, &points, &values)?
+            .ok_or(NonlinearFitError::NonFiniteModelValue)?;
+        let mut damping = INITIAL_DAMPING;
+        let mut iterations = 0;
+        let mut termination = if free.is_empty() {
+            FitTermination::StepConverged
+        } else {
+            FitTermination::MaxIterations
+        };
+
+        while !free.is_empty() && iterations < max_iterations {
+            iterations += 1;
+            let (jacobian, residuals) = weighted_jacobian(model, &points, &values, &free)?;
+            let normal_matrix = gram(&jacobian);
+            let gradient = transpose_multiply(&jacobian, &residuals);
+
+            // χ² が減少するまで減衰係数を大きくする
+            let accepted = loop {
+                let damped: Matrix = normal_matrix
+                    .iter()
+                    .enumerate()
+                    .map(|(i, row)| {
+                        let mut row = row.clone();
+                        row[i] += damping * row[i].max(f64::EPSILON);
+                        row
+                    })
+                    .collect();
+                let trial = solve(&damped, &gradient).map(|step| {
+                    let mut trial = values.clone();
+                    for (index, delta) in free.iter().zip(step) {
+                        trial[*index] = model.parameters[*index].clamp(trial[*index] + delta);
+                    }
+                    trial
+                });
+                if let Some(trial) = trial {
+                    if let Some(trial_chi_square) = chi_square_of(model, &points, &trial)? {
+                        if trial_chi_square <= chi_square {
+                            damping = (damping / 10.).max(f64::EPSILON);
+                            break Some((trial, trial_chi_square));
+                        }
+                    }
+                }
+                damping *= 10.;
+                if damping > MAX_DAMPING {
+                    break None;
+                }
+            };
+            let Some((trial, trial_chi_square)) = accepted else {
+                termination = FitTermination::DampingOverflow;
+                break;
+            };
+
+            let chi_square_change = chi_square - trial_chi_square;
+            let step_converged = free.iter().all(|index| {
+                (trial[*index] - values[*index]).abs()
+                    <= STEP_TOLERANCE * (values[*index].abs() + STEP_TOLERANCE)
+            });
+            values = trial;
+            chi_square = trial_chi_square;
+            if chi_square_change <= CHI_SQUARE_TOLERANCE * chi_square {
+                termination = FitTermination::ChiSquareConverged;
+                break;
+            }
+            if step_converged {
+                termination = FitTermination::StepConverged;
+                break;
+            }
+        }
+
+        let degrees_of_freedom = points.len() - free.len();
+        let reduced_chi_square =
+            (degrees_of_freedom > 0).then(|| chi_square / degrees_of_freedom as f64);
+        let weighted = errors.is_some();
+
+        // 共分散行列 (J^T W J)^-1
+        let covariance = if free.is_empty() {
+            None
+        } else {
+            let (jacobian, _) = weighted_jacobian(model, &points, &values, &free)?;
+            let scale = if weighted {
+                Some(1.)
+            } else {
+                reduced_chi_square
+            };
+            scale.zip(invert(&gram(&jacobian))).map(|(scale, inverse)| {
+                let mut covariance = vec![vec![0.; values.len()]; values.len()];
+                for (i, row) in free.iter().zip(&inverse) {
+                    for (j, value) in free.iter().zip(row) {
+                        covariance[*i][*j] = value * scale;
+                    }
+                }
+                covariance
+            })
+        };
+
+        let parameters = model
+            .parameters
+            .iter()
+            .zip(&values)
+            .enumerate()
+            .map(|(i, (parameter, value))| FittedParameter {
+                name: parameter.name.clone(),
+                value: *value,
+                standard_error: covariance
+                    .as_ref()
+                    .filter(|_| !parameter.fixed)
+                    .map(|covariance| covariance[i][i].sqrt()),
+                fixed: parameter.fixed,
+            })
+            .collect();
+
+        let evaluate = |x: f64| {
+            model
+                .evaluate(x, &values)
+                .ok()
+                .filter(|value| value.is_finite())
+        };
+        let fitted = (0..row_count)
+            .map(|row| value_at(x, row).and_then(evaluate))
+            .collect();
+        let residuals = (0..row_count)
+            .map(|row| Some(value_at(y, row)? - evaluate(value_at(x, row)?)?))
+            .collect();
+
+        Ok(Self {
+            parameters,
+            covariance,
+            chi_square,
+            reduced_chi_square,
+            degrees_of_freedom,
+            iterations,
+            termination,
+            weighted,
+            fitted,
+            residuals,
+        })
+    }
+
+    pub fn parameters(&self) -> &Vec<FittedParameter> {
+        &self.parameters
+    }
+
+    pub fn covariance(&self) -> Option<&Matrix> {
+        self.covariance.as_ref()
+    }
+
+    pub fn chi_square(&self) -> f64 {
+        self.chi_square
+    }
+
+    pub fn reduced_chi_square(&self) -> Option<f64> {
+        self.reduced_chi_square
+    }
+
+    pub fn degrees_of_freedom(&self) -> usize {
+        self.degrees_of_freedom
+    }
+
+    pub fn iterations(&self) -> usize {
+        self.iterations
+    }
+
+    pub fn termination(&self) -> FitTermination {
+        self.termination
+    }
+
+    pub fn converged(&self) -> bool {
+        self.termination.converged()
+    }
+
+    pub fn weighted(&self) -> bool {
+        self.weighted
+    }
+
+    pub fn fitted(&self) -> &Vec<CellRawValue> {
+        &self.fitted
+    }
+
+    pub fn residuals(&self) -> &Vec<CellRawValue> {
+        &self.residuals
+    }
+}
+
+// 重み付き χ² (モデルの値が有限でない場合は None)
+fn chi_square_of(
+    model: &FitModel,
+    points: &[(f64, f64, f64)],
+    values: &[f64],
+) -> Result<Option<f64>, NonlinearFitError> {
+    let mut sum = 0.;
+    for (x, y, weight) in points {
+        let value = model.evaluate(*x, values)?;
+        if !value.is_finite() {
+            return Ok(None);
+        }
+        sum += weight * (y - value).powi(2);
+    }
+    Ok(Some(sum))
+}
+
+// 自由なパラメータに関する重み付きヤコビ行列 (前進差分) と重み付き残差
+// 差分の刻みは上限を越える場合に負の向きへ取る
+fn weighted_jacobian(
+    model: &FitModel,
+    points: &[(f64, f64, f64)],
+    values: &[f64],
+    free: &[usize],
+) -> Result<(Matrix, Vec<f64>), NonlinearFitError> {
+    let steps: Vec<(usize, f64, Vec<f64>)> = free
+        .iter()
+        .map(|index| {
+            let value = values[*index];
+            let mut step = f64::EPSILON.sqrt() * value.abs().max(1.);
+            if model.parameters[*index]
+                .upper_bound
+                .is_some_and(|upper| value + step > upper)
+            {
+                step = -step;
+            }
+            let mut shifted = values.to_vec();
+            shifted[*index] = value + step;
+            (*index, step, shifted)
+        })
+        .collect();
+
+    let mut jacobian = Vec::with_capacity(points.len());
+    let mut residuals = Vec::with_capacity(points.len());
+    for (x, y, weight) in points {
+        let value = model.evaluate(*x, values)?;
+        let sqrt_weight = weight.sqrt();
+        let mut row = Vec::with_capacity(steps.len());
+        for (_, step, shifted) in &steps {
+            let derivative = (model.evaluate(*x, shifted)? - value) / step;
+            if !derivative.is_finite() {
+                return Err(NonlinearFitError::NonFiniteModelValue);
+            }
+            row.push(sqrt_weight * derivative);
+        }
+        jacobian.push(row);
+        residuals.push(sqrt_weight * (y - value));
+    }
+    Ok((jacobian, residuals))
+}
+
+#[derive(Debug, Error)]
+pub enum NonlinearFitError {
+    #[error("FormulaExpressionError: [{0}]")]
+    FormulaExpressionError(FormulaExpressionError),
+    #[error("'{0}' is reserved for the independent variable")]
+    ReservedParameterName(String),
+    #[error("parameter '{0}' is defined more than once")]
+    DuplicateParameter(String),
+    #[error("parameter '{0}' is not defined")]
+    UndefinedParameter(String),
+    #[error("parameter '{0}' is not used in the model")]
+    UnusedParameter(String),
+    #[error("lower bound of parameter '{0}' is greater than upper bound")]
+    InvalidBounds(String),
+    #[error("initial value of parameter '{0}' is out of bounds")]
+    InitialValueOutOfBounds(String),
+    #[error("at least {0} points are required, but {1} points are given")]
+    InsufficientPoints(usize, usize),
+    #[error("model value is not finite")]
+    NonFiniteModelValue,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual - expected).abs() < tolerance,
+            "actual: {}, expected: {}",
+            actual,
+            expected
+        );
+    }
+
+    fn parameter(name: &str, initial_value: f64) -> anyhow::Result<FitParameter> {
+        Ok(FitParameter::new(
+            name.to_string(),
+            initial_value,
+            false,
+            None,
+            None,
+        )?)
+    }
+
+    fn exponential_model(parameters: Vec<FitParameter>) -> anyhow::Result<FitModel> {
+        Ok(FitModel::new(
+            FormulaExpression::parse("A * exp(-x / tau) + C")?,
+            parameters,
+        )?)
+    }
+
+    #[test]
+    fn test_exponential_decay() -> anyhow::Result<()> {
+        let x: Vec<CellRawValue> = (0..20).map(|i| Some(i as f64 * 0.5)).collect();
+        let y: Vec<CellRawValue> = x
+            .iter()
+            .map(|x| x.map(|x| 3. * (-x / 2.).exp() + 0.5))
+            .collect();
+        let model = exponential_model(vec![
+            parameter("A", 1.)?,
+            parameter("tau", 1.)?,
+            parameter("C", 0.)?,
+        ])?;
+        let fit = NonlinearFit::fit(&model, &x, &y, None, 100)?;
+
+        assert!(fit.converged());
+        let values: Vec<f64> = fit.parameters().iter().map(|p| p.value()).collect();
+        assert_close(values[0], 3., 1e-6);
+        assert_close(values[1], 2., 1e-6);
+        assert_close(values[2], 0.5, 1e-6);
+        assert!(fit.chi_square() < 1e-12);
+        assert_eq!(fit.degrees_of_freedom(), 17);
+        assert_eq!(fit.covariance().unwrap().len(), 3);
+        assert_close(fit.fitted()[0].unwrap(), 3.5, 1e-6);
+        assert!(fit.residuals().iter().all(|r| r.unwrap().abs() < 1e-6));
+        Ok(())
+    }
+
+    #[test]
+    fn test_fixed_and_bounded_parameters() -> anyhow::Result<()> {
+        let x: Vec<CellRawValue> = (0..10).map(|i| Some(i as f64)).collect();
+        let y: Vec<CellRawValue> = x.iter().map(|x| x.map(|x| 2. * x + 1.)).collect();
+
+        // 切片を固定
+        let model = FitModel::new(
+            FormulaExpression::parse("a * x + b")?,
+            vec![
+                parameter("a", 0.)?,
+                FitParameter::new("b".to_string(), 1., true, None, None)?,
+            ],
+        )?;
+        let fit = NonlinearFit::fit(&model, &x, &y, None, 100)?;
+        assert_close(fit.parameters()[0].value(), 2., 1e-8);
+        assert_eq!(fit.parameters()[1].value(), 1.);
+        assert_eq!(fit.parameters()[1].standard_error(), None);
+        assert_eq!(fit.covariance().unwrap()[1], vec![0., 0.]);
+        assert_eq!(fit.degrees_of_freedom(), 9);
+
+        // 傾きの上限が効く
+        let model = FitModel::new(
+            FormulaExpression::parse("a * x + b")?,
+            vec![
+                FitParameter::new("a".to_string(), 0., false, Some(-1.), Some(1.5))?,
+                parameter("b", 0.)?,
+            ],
+        )?;
+        let fit = NonlinearFit::fit(&model, &x, &y, None, 200)?;
+        assert!(fit.parameters()[0].value() <= 1.5);
+        assert_close(fit.parameters()[0].value(), 1.5, 1e-8);
+        Ok(())
+    }
+
+    #[test]
+    fn test_weighted() -> anyhow::Result<()> {
+        // 重み付き直線の当てはめ: 共分散は (X^T W X)^-1
+        let x = vec![Some(0.), Some(1.), Some(2.), Some(3.), None];
+        let y = vec![Some(1.), Some(2.9), Some(5.2), Some(6.9), Some(9.)];
+        let errors = vec![Some(0.1), Some(0.2), Some(0.1), None, Some(0.1)];
+        let model = FitModel::new(
+            FormulaExpression::parse("a + b * x")?,
+            vec![parameter("a", 0.)?, parameter("b", 0.)?],
+        )?;
+        let fit = NonlinearFit::fit(&model, &x, &y, Some(&errors), 100)?;
+        assert!(fit.weighted());
+        assert_eq!(fit.degrees_of_freedom(), 1);
+
+        // 使用する点: (0, 1, 100), (1, 2.9, 25), (2, 5.2, 100)
+        let (s, sx, sxx) = (225., 225., 425.);
+        let (sy, sxy) = (100. * 1. + 25. * 2.9 + 100. * 5.2, 25. * 2.9 + 200. * 5.2);
+        let determinant: f64 = s * sxx - sx * sx;
+        let a = (sxx * sy - sx * sxy) / determinant;
+        let b = (s * sxy - sx * sy) / determinant;
+        assert_close(fit.parameters()[0].value(), a, 1e-6);
+        assert_close(fit.parameters()[1].value(), b, 1e-6);
+        let covariance = fit.covariance().unwrap();
+        assert_close(covariance[0][0], sxx / determinant, 1e-6);
+        assert_close(covariance[1][1], s / determinant, 1e-6);
+        assert_close(covariance[0][1], -sx / determinant, 1e-6);
+        // 誤差がない行も残差は計算する
+        assert!(fit.residuals()[3].is_some());
+        assert_eq!(fit.fitted()[4], None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_errors() -> anyhow::Result<()> {
+        let expression = FormulaExpression::parse("A * exp(-x / tau) + C")?;
+        assert!(matches!(
+            FitModel::new(
+                expression.clone(),
+                vec![parameter("A", 1.)?, parameter("tau", 1.)?]
+            ),
+            Err(NonlinearFitError::UndefinedParameter(name)) if name == "C"
+        ));
+        assert!(matches!(
+            FitModel::new(
+                FormulaExpression::parse("A * x")?,
+                vec![parameter("A", 1.)?, parameter("B", 1.)?]
+            ),
+            Err(NonlinearFitError::UnusedParameter(name)) if name == "B"
+        ));
+        assert!(matches!(
+            FitModel::new(
+                FormulaExpression::parse("A * x")?,
+                vec![parameter("A", 1.)?, parameter("A", 2.)?]
+            ),
+            Err(NonlinearFitError::DuplicateParameter(_))
+        ));
+        assert!(matches!(
+            FitParameter::new("x".to_string(), 1., false, None, None),
+            Err(NonlinearFitError::ReservedParameterName(_))
+        ));
+        assert!(matches!(
+            FitParameter::new("A".to_string(), 1., false, Some(2.), Some(1.)),
+            Err(NonlinearFitError::InvalidBounds(_))
+        ));
+        assert!(matches!(
+            FitParameter::new("A".to_string(), 3., false, Some(0.), Some(2.)),
+            Err(NonlinearFitError::InitialValueOutOfBounds(_))
+        ));
+
+        let model = exponential_model(vec![
+            parameter("A", 1.)?,
+            parameter("tau", 1.)?,
+            parameter("C", 0.)?,
+        ])?;
+        assert!(matches!(
+            NonlinearFit::fit(
+                &model,
+                &[Some(1.), Some(2.)],
+                &[Some(1.), Some(2.)],
+                None,
+                10
+            ),
+            Err(NonlinearFitError::InsufficientPoints(3, 2))
+        ));
+        Ok(())
+    }
+}
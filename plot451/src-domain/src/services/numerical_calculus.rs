@@ -0,0 +1,382 @@
+use thiserror::Error;
+
+use crate::models::column::column_cell::column_cell_value::CellRawValue;
+
+// 差分の取り方
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DifferenceMethod {
+    // 前進差分 (末尾の点は後退差分)
+    Forward,
+    // 中心差分 (両隣の点を結ぶ傾き、端点は片側差分)
+    Central,
+    // 不等間隔の 3 点公式 (隣接する 3 点を通る放物線の傾き)
+    NonUniform,
+}
+
+// 積分の公式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntegrationMethod {
+    // 台形公式
+    Trapezoid,
+    // 不等間隔のシンプソン公式 (2 区間ごとに 3 点を通る放物線を積分する)
+    Simpson,
+}
+
+// x, y のいずれかが None (または NaN) の行の扱い
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GapHandling {
+    // 欠損行を除いて前後の点を直接つなぐ
+    Bridge,
+    // 欠損行でデータを区切り、区切りをまたいだ差分・積分をしない (区切りの面積は 0 とする)
+    Split,
+}
+
+// 点列の座標 (行番号, x, y)
+type Point = (usize, f64, f64);
+
+// domain service
+// 数値微分・数値積分の対象となる標本点列
+// x は欠損行を除いて狭義単調増加でなければならない
+// 結果は元のカラムと同じ行数で、欠損行や計算できない行は None になる
+#[derive(Debug, Clone, PartialEq)]
+pub struct SampledFunction {
+    row_count: usize,
+    segments: Vec<Vec<Point>>,
+}
+
+impl SampledFunction {
+    pub fn new(
+        x: &[CellRawValue],
+        y: &[CellRawValue],
+        gap_handling: GapHandling,
+    ) -> Result<Self, NumericalCalculusError> {
+        let row_count = x.len().max(y.len());
+        let value_at = |values: &[CellRawValue], row: usize| {
+            values
+                .get(row)
+                .copied()
+                .flatten()
+                .filter(|value| !value.is_nan())
+        };
+
+        let mut segments: Vec<Vec<Point>> = vec![];
+        let mut current: Vec<Point> = vec![];
+        let mut last_x: Option<f64> = None;
+        for row in 0..row_count {
+            match (value_at(x, row), value_at(y, row)) {
+                (Some(x), Some(y)) => {
+                    if last_x.is_some_and(|last_x| x <= last_x) {
+                        return Err(NumericalCalculusError::NonIncreasingX(row));
+                    }
+                    last_x = Some(x);
+                    current.push((row, x, y));
+                }
+                _ => {
+                    if gap_handling == GapHandling::Split && !current.is_empty() {
+                        segments.push(std::mem::take(&mut current));
+                    }
+                }
+            }
+        }
+        if !current.is_empty() {
+            segments.push(current);
+        }
+        if segments.iter().map(|segment| segment.len()).sum::<usize>() < 2 {
+            return Err(NumericalCalculusError::InsufficientPoints);
+        }
+        Ok(Self {
+            row_count,
+            segments,
+        })
+    }
+
+    // 欠損行を除いた x の最小値と最大値
+    pub fn x_range(&self) -> (f64, f64) {
+        let first = self.segments[0][0].1;
+        let last = self.segments.last().and_then(|segment| segment.last());
+        (first, last.map_or(first, |point| point.1))
+    }
+
+    // dy/dx (点が 1 つだけの区切りは None)
+    pub fn derivative(&self, method: DifferenceMethod) -> Vec<CellRawValue> {
+        let mut result = vec![None; self.row_count];
+        for segment in self.segments.iter().filter(|segment| segment.len() >= 2) {
+            let last = segment.len() - 1;
+            for (i, (row, x, _)) in segment.iter().enumerate() {
+                let slope = |a: &Point, b: &Point| (b.2 - a.2) / (b.1 - a.1);
+                let value = match method {
+                    DifferenceMethod::Forward if i < last => slope(&segment[i], &segment[i + 1]),
+                    DifferenceMethod::Central if 0 < i && i < last => {
+                        slope(&segment[i - 1], &segment[i + 1])
+                    }
+                    DifferenceMethod::Forward | DifferenceMethod::Central => {
+                        let (a, b) = if i == 0 { (0, 1) } else { (i - 1, i) };
+                        slope(&segment[a], &segment[b])
+                    }
+                    DifferenceMethod::NonUniform if segment.len() == 2 => {
+                        slope(&segment[0], &segment[1])
+                    }
+                    DifferenceMethod::NonUniform => {
+                        let start = i.saturating_sub(1).min(segment.len() - 3);
+                        parabola_derivative(&segment[start..start + 3], *x)
+                    }
+                };
+                result[*row] = Some(value).filter(|value| value.is_finite());
+            }
+        }
+        result
+    }
+
+    // 最初の点を 0 とする累積積分
+    pub fn cumulative_integral(&self, method: IntegrationMethod) -> Vec<CellRawValue> {
+        let mut result = vec![None; self.row_count];
+        let mut sum = 0.;
+        for segment in &self.segments {
+            result[segment[0].0] = Some(sum);
+            for i in 1..segment.len() {
+                sum += interval_integral(segment, i - 1, segment[i].1, method);
+                result[segment[i].0] = Some(sum);
+            }
+        }
+        result
+    }
+
+    // x = lower から x = upper までの定積分 (lower > upper の場合は符号が反転する)
+    pub fn definite_integral(
+        &self,
+        method: IntegrationMethod,
+        lower: f64,
+        upper: f64,
+    ) -> Result<f64, NumericalCalculusError> {
+        Ok(self.integral_to(method, upper)? - self.integral_to(method, lower)?)
+    }
+
+    // 全区間の定積分
+    pub fn total_integral(&self, method: IntegrationMethod) -> f64 {
+        self.segments
+            .iter()
+            .map(|segment| {
+                (1..segment.len())
+                    .map(|i| interval_integral(segment, i - 1, segment[i].1, method))
+                    .sum::<f64>()
+            })
+            .sum()
+    }
+
+    // 最初の点から x = limit までの積分
+    fn integral_to(
+        &self,
+        method: IntegrationMethod,
+        limit: f64,
+    ) -> Result<f64, NumericalCalculusError> {
+        let (first, last) = self.x_range();
+        if limit < first || limit > last {
+            return Err(NumericalCalculusError::LimitOutOfRange(limit));
+        }
+
+        let mut sum = 0.;
+        for segment in &self.segments {
+            for i in 1..segment.len() {
+                if segment[i].1 < limit {
+                    sum += interval_integral(segment, i - 1, segment[i].1, method);
+                } else {
+                    if segment[i - 1].1 < limit {
+                        sum += interval_integral(segment, i - 1, limit, method);
+                    }
+                    return Ok(sum);
+                }
+            }
+        }
+        Ok(sum)
+    }
+}
+
+// segment[start] の x から end までの区間 [x_start, x_start+1] 内の積分
+fn interval_integral(segment: &[Point], start: usize, end: f64, method: IntegrationMethod) -> f64 {
+    match method {
+        IntegrationMethod::Simpson if segment.len() >= 3 => {
+            // 区切りの先頭から 2 区間ずつ放物線を当てる (区間数が奇数の場合、最後の区間は末尾の 3 点を使う)
+            let first = (start - start % 2).min(segment.len() - 3);
+            parabola_integral(&segment[first..first + 3], segment[start].1, end)
+        }
+        _ => {
+            let (_, x0, y0) = segment[start];
+            let (_, x1, y1) = segment[start + 1];
+            let y_end = y0 + (y1 - y0) * (end - x0) / (x1 - x0);
+            (y0 + y_end) / 2. * (end - x0)
+        }
+    }
+}
+
+// 3 点を通る放物線 (ラグランジュ補間) の x = t における傾き
+fn parabola_derivative(points: &[Point], t: f64) -> f64 {
+    (0..3)
+        .map(|k| {
+            let (_, x_k, y_k) = points[k];
+            let (_, x_a, _) = points[(k + 1) % 3];
+            let (_, x_b, _) = points[(k + 2) % 3];
+            y_k * ((t - x_a) + (t - x_b)) / ((x_k - x_a) * (x_k - x_b))
+        })
+        .sum()
+}
+
+// 3 点を通る放物線 (ラグランジュ補間) の a から b までの積分
+fn parabola_integral(points: &[Point], a: f64, b: f64) -> f64 {
+    // 桁落ちを避けるため先頭の点を原点に取る
+    let origin = points[0].1;
+    let (a, b) = (a - origin, b - origin);
+    (0..3)
+        .map(|k| {
+            let (_, x_k, y_k) = points[k];
+            let x_a = points[(k + 1) % 3].1 - origin;
+            let x_b = points[(k + 2) % 3].1 - origin;
+            let x_k = x_k - origin;
+            // (t - x_a)(t - x_b) の原始関数
+            let antiderivative =
+                |t: f64| t.powi(3) / 3. - (x_a + x_b) * t.powi(2) / 2. + x_a * x_b * t;
+            y_k * (antiderivative(b) - antiderivative(a)) / ((x_k - x_a) * (x_k - x_b))
+        })
+        .sum()
+}
+
+#[derive(Debug, Error)]
+pub enum NumericalCalculusError {
+    #[error("at least 2 points are required")]
+    InsufficientPoints,
+    #[error("x must be strictly increasing, but it is not at row {0}")]
+    NonIncreasingX(usize),
+    #[error("integration limit {0} is out of the range of x")]
+    LimitOutOfRange(f64),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: CellRawValue, expected: f64) {
+        let actual = actual.expect("value is None");
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "actual: {}, expected: {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_derivative() -> anyhow::Result<()> {
+        // y = x^2 (不等間隔)
+        let x = vec![Some(0.), Some(1.), Some(3.), Some(4.)];
+        let y: Vec<CellRawValue> = x.iter().map(|x| x.map(|x| x * x)).collect();
+        let function = SampledFunction::new(&x, &y, GapHandling::Bridge)?;
+
+        let forward = function.derivative(DifferenceMethod::Forward);
+        assert_close(forward[0], 1.);
+        assert_close(forward[1], 4.);
+        assert_close(forward[3], 7.);
+        let central = function.derivative(DifferenceMethod::Central);
+        assert_close(central[0], 1.);
+        assert_close(central[1], 3.);
+        assert_close(central[2], 5.);
+        // 放物線は 3 点公式で厳密に微分できる
+        let non_uniform = function.derivative(DifferenceMethod::NonUniform);
+        for (actual, expected) in non_uniform.iter().zip([0., 2., 6., 8.]) {
+            assert_close(*actual, expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_integral() -> anyhow::Result<()> {
+        // y = x^2 (不等間隔、区間数は奇数)
+        let x = vec![Some(0.), Some(1.), Some(3.), Some(4.)];
+        let y: Vec<CellRawValue> = x.iter().map(|x| x.map(|x| x * x)).collect();
+        let function = SampledFunction::new(&x, &y, GapHandling::Bridge)?;
+
+        let trapezoid = function.cumulative_integral(IntegrationMethod::Trapezoid);
+        assert_close(trapezoid[0], 0.);
+        assert_close(trapezoid[1], 0.5);
+        assert_close(trapezoid[2], 10.5);
+        assert_close(trapezoid[3], 23.);
+
+        // シンプソン公式は放物線を厳密に積分できる
+        let simpson = function.cumulative_integral(IntegrationMethod::Simpson);
+        for (actual, expected) in simpson.iter().zip([0., 1. / 3., 9., 64. / 3.]) {
+            assert_close(*actual, expected);
+        }
+        assert_eq!(function.x_range(), (0., 4.));
+        assert_close(
+            Some(function.total_integral(IntegrationMethod::Simpson)),
+            64. / 3.,
+        );
+        assert_close(
+            Some(function.definite_integral(IntegrationMethod::Simpson, 0.5, 2.)?),
+            (8. - 0.125) / 3.,
+        );
+        assert_close(
+            Some(function.definite_integral(IntegrationMethod::Trapezoid, 1., 0.5)?),
+            -(0.5 - 0.125),
+        );
+        assert!(matches!(
+            function.definite_integral(IntegrationMethod::Trapezoid, 0., 5.),
+            Err(NumericalCalculusError::LimitOutOfRange(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_gap_handling() -> anyhow::Result<()> {
+        let x = vec![Some(0.), Some(1.), Some(2.), Some(3.), Some(4.)];
+        let y = vec![Some(0.), Some(1.), None, Some(3.), Some(4.)];
+
+        // 欠損行をまたいで前後の点をつなぐ
+        let function = SampledFunction::new(&x, &y, GapHandling::Bridge)?;
+        let derivative = function.derivative(DifferenceMethod::Central);
+        assert_eq!(derivative[2], None);
+        assert_close(derivative[1], 1.);
+        let integral = function.cumulative_integral(IntegrationMethod::Trapezoid);
+        assert_eq!(integral[2], None);
+        assert_close(integral[3], 4.5);
+        assert_close(integral[4], 8.);
+
+        // 欠損行で区切る (区切りの面積は 0)
+        let function = SampledFunction::new(&x, &y, GapHandling::Split)?;
+        let integral = function.cumulative_integral(IntegrationMethod::Trapezoid);
+        assert_close(integral[1], 0.5);
+        assert_close(integral[3], 0.5);
+        assert_close(integral[4], 4.);
+        assert_close(
+            Some(function.definite_integral(IntegrationMethod::Trapezoid, 0.5, 3.5)?),
+            (0.5 - 0.125) + (3.5 * 3.5 - 9.) / 2.,
+        );
+
+        // 点が 1 つだけの区切りの微分は None
+        let y = vec![Some(0.), None, Some(2.), None, Some(4.)];
+        let function = SampledFunction::new(&x, &y, GapHandling::Split)?;
+        assert!(function
+            .derivative(DifferenceMethod::Forward)
+            .iter()
+            .all(|value| value.is_none()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(matches!(
+            SampledFunction::new(
+                &[Some(0.), Some(2.), Some(1.)],
+                &[Some(0.), Some(0.), Some(0.)],
+                GapHandling::Bridge
+            ),
+            Err(NumericalCalculusError::NonIncreasingX(2))
+        ));
+        assert!(matches!(
+            SampledFunction::new(
+                &[Some(0.), None],
+                &[Some(0.), Some(1.)],
+                GapHandling::Bridge
+            ),
+            Err(NumericalCalculusError::InsufficientPoints)
+        ));
+    }
+}
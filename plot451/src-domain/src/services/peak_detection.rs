@@ -0,0 +1,375 @@
+use thiserror::Error;
+
+use crate::models::column::column_cell::column_cell_value::CellRawValue;
+
+// ピークとみなす条件 (None は条件なし、距離と幅は x の単位)
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PeakCriteria {
+    min_height: Option<f64>,
+    min_prominence: Option<f64>,
+    min_distance: Option<f64>,
+    min_width: Option<f64>,
+}
+
+impl PeakCriteria {
+    pub fn new(
+        min_height: Option<f64>,
+        min_prominence: Option<f64>,
+        min_distance: Option<f64>,
+        min_width: Option<f64>,
+    ) -> Result<Self, PeakDetectionError> {
+        for threshold in [min_prominence, min_distance, min_width]
+            .into_iter()
+            .flatten()
+        {
+            if !threshold.is_finite() || threshold < 0. {
+                return Err(PeakDetectionError::InvalidThreshold(threshold));
+            }
+        }
+        if let Some(min_height) = min_height.filter(|height| !height.is_finite()) {
+            return Err(PeakDetectionError::InvalidThreshold(min_height));
+        }
+        Ok(Self {
+            min_height,
+            min_prominence,
+            min_distance,
+            min_width,
+        })
+    }
+}
+
+// 検出したピーク
+// 半値全幅と面積は、頂点から左右に下っていった谷 (最寄りの極小点) を結ぶ直線を基線として求める
+#[derive(Debug, Clone, PartialEq)]
+pub struct Peak {
+    // 元のカラムの行番号
+    row: usize,
+    position: f64,
+    height: f64,
+    prominence: f64,
+    // 基線からの高さが半分になる位置の間隔 (裾までに半分にならない場合は None)
+    fwhm: Option<f64>,
+    area: f64,
+    left_base: f64,
+    right_base: f64,
+}
+
+impl Peak {
+    pub fn row(&self) -> usize {
+        self.row
+    }
+
+    pub fn position(&self) -> f64 {
+        self.position
+    }
+
+    pub fn height(&self) -> f64 {
+        self.height
+    }
+
+    pub fn prominence(&self) -> f64 {
+        self.prominence
+    }
+
+    pub fn fwhm(&self) -> Option<f64> {
+        self.fwhm
+    }
+
+    pub fn area(&self) -> f64 {
+        self.area
+    }
+
+    pub fn left_base(&self) -> f64 {
+        self.left_base
+    }
+
+    pub fn right_base(&self) -> f64 {
+        self.right_base
+    }
+}
+
+// domain service
+// x, y の点列の極大点のうち条件を満たすものを x の昇順で返す
+// x, y のいずれかが None (または NaN) の行は除き、x は狭義単調増加でなければならない
+// 条件は高さ → 距離 (高いピークを優先) → プロミネンス → 幅の順に適用する
+pub fn find_peaks(
+    x: &[CellRawValue],
+    y: &[CellRawValue],
+    criteria: &PeakCriteria,
+) -> Result<Vec<Peak>, PeakDetectionError> {
+    let value_at = |values: &[CellRawValue], row: usize| {
+        values
+            .get(row)
+            .copied()
+            .flatten()
+            .filter(|value| !value.is_nan())
+    };
+    let mut points: Vec<(usize, f64, f64)> = vec![];
+    for row in 0..x.len().max(y.len()) {
+        if let (Some(x), Some(y)) = (value_at(x, row), value_at(y, row)) {
+            if points.last().is_some_and(|(_, last_x, _)| x <= *last_x) {
+                return Err(PeakDetectionError::NonIncreasingX(row));
+            }
+            points.push((row, x, y));
+        }
+    }
+    let xs: Vec<f64> = points.iter().map(|(_, x, _)| *x).collect();
+    let ys: Vec<f64> = points.iter().map(|(_, _, y)| *y).collect();
+
+    // 極大点 (平坦な頂上は中央の点)
+    let mut candidates = vec![];
+    let mut i = 1;
+    while i + 1 < ys.len() {
+        if ys[i - 1] < ys[i] {
+            let mut end = i;
+            while end + 1 < ys.len() && ys[end + 1] == ys[i] {
+                end += 1;
+            }
+            if end + 1 < ys.len() && ys[end + 1] < ys[i] {
+                candidates.push((i + end) / 2);
+            }
+            i = end + 1;
+        } else {
+            i += 1;
+        }
+    }
+
+    if let Some(min_height) = criteria.min_height {
+        candidates.retain(|&index| ys[index] >= min_height);
+    }
+
+    // 高いピークから順に、近すぎるピークを除く
+    if let Some(min_distance) = criteria.min_distance {
+        let mut by_height = candidates.clone();
+        by_height.sort_by(|a, b| ys[*b].total_cmp(&ys[*a]));
+        let mut kept: Vec<usize> = vec![];
+        for index in by_height {
+            if kept
+                .iter()
+                .all(|other| (xs[index] - xs[*other]).abs() >= min_distance)
+            {
+                kept.push(index);
+            }
+        }
+        kept.sort();
+        candidates = kept;
+    }
+
+    let mut peaks = vec![];
+    for index in candidates {
+        let peak = measure_peak(&xs, &ys, index, points[index].0);
+        if criteria
+            .min_prominence
+            .is_some_and(|min_prominence| peak.prominence < min_prominence)
+        {
+            continue;
+        }
+        if let Some(min_width) = criteria.min_width {
+            if peak.fwhm.is_none_or(|fwhm| fwhm < min_width) {
+                continue;
+            }
+        }
+        peaks.push(peak);
+    }
+    Ok(peaks)
+}
+
+fn measure_peak(xs: &[f64], ys: &[f64], index: usize, row: usize) -> Peak {
+    let height = ys[index];
+
+    // プロミネンス: 左右それぞれ、より高い点 (または端) までの範囲の最小値のうち高い方からの高さ
+    let lowest = |range: &mut dyn Iterator<Item = usize>| {
+        range
+            .take_while(|&i| ys[i] <= height)
+            .map(|i| ys[i])
+            .fold(height, f64::min)
+    };
+    let prominence = height - lowest(&mut (0..index).rev()).max(lowest(&mut (index + 1..ys.len())));
+
+    // 頂点から左右に下っていった谷を裾とし、裾を結ぶ直線を基線とする
+    let mut left_base = index;
+    while left_base > 0 && ys[left_base - 1] <= ys[left_base] {
+        left_base -= 1;
+    }
+    let mut right_base = index;
+    while right_base + 1 < ys.len() && ys[right_base + 1] <= ys[right_base] {
+        right_base += 1;
+    }
+    let baseline = |x: f64| {
+        if right_base == left_base {
+            ys[left_base]
+        } else {
+            ys[left_base]
+                + (ys[right_base] - ys[left_base]) * (x - xs[left_base])
+                    / (xs[right_base] - xs[left_base])
+        }
+    };
+    let above = |i: usize| ys[i] - baseline(xs[i]);
+    let half = above(index) / 2.;
+
+    // 基線からの高さが半分になる位置を線形補間で求める
+    let crossing = |from: usize, to: usize| {
+        let (a, b) = (above(from), above(to));
+        xs[from] + (half - a) * (xs[to] - xs[from]) / (b - a)
+    };
+    let left_half = (left_base..index)
+        .rev()
+        .find(|&i| above(i) <= half)
+        .map(|i| crossing(i, i + 1));
+    let right_half = (index + 1..=right_base)
+        .find(|&i| above(i) <= half)
+        .map(|i| crossing(i - 1, i));
+    let fwhm = match (left_half, right_half) {
+        (Some(left), Some(right)) if half > 0. => Some(right - left),
+        _ => None,
+    };
+
+    // 裾から裾までの基線より上の面積 (台形公式)
+    let area = (left_base..right_base)
+        .map(|i| (above(i) + above(i + 1)) * (xs[i + 1] - xs[i]) / 2.)
+        .sum();
+
+    Peak {
+        row,
+        position: xs[index],
+        height,
+        prominence,
+        fwhm,
+        area,
+        left_base: xs[left_base],
+        right_base: xs[right_base],
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum PeakDetectionError {
+    #[error("x must be strictly increasing, at row {0}")]
+    NonIncreasingX(usize),
+    #[error("threshold must be a non-negative finite number, but {0} is given")]
+    InvalidThreshold(f64),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual - expected).abs() < tolerance,
+            "actual: {}, expected: {}",
+            actual,
+            expected
+        );
+    }
+
+    // 位置 center、高さ amplitude、標準偏差 sigma のガウス関数の和 + 一定の背景
+    fn spectrum(
+        peaks: &[(f64, f64, f64)],
+        background: f64,
+    ) -> (Vec<CellRawValue>, Vec<CellRawValue>) {
+        let x: Vec<f64> = (0..=1000).map(|i| i as f64 * 0.01).collect();
+        let y = x
+            .iter()
+            .map(|x| {
+                Some(
+                    background
+                        + peaks
+                            .iter()
+                            .map(|(center, amplitude, sigma)| {
+                                amplitude * (-(x - center).powi(2) / (2. * sigma * sigma)).exp()
+                            })
+                            .sum::<f64>(),
+                )
+            })
+            .collect();
+        (x.into_iter().map(Some).collect(), y)
+    }
+
+    #[test]
+    fn test_gaussian_peaks() -> anyhow::Result<()> {
+        let (x, y) = spectrum(&[(3., 2., 0.2), (7., 1., 0.3)], 0.5);
+        let peaks = find_peaks(&x, &y, &PeakCriteria::default())?;
+        assert_eq!(peaks.len(), 2);
+        assert_close(peaks[0].position(), 3., 1e-9);
+        assert_close(peaks[0].height(), 2.5, 1e-6);
+        assert_close(peaks[0].prominence(), 2., 1e-6);
+        // FWHM = 2√(2 ln 2) σ, 面積 = √(2π) A σ
+        let factor = 2. * (2. * 2f64.ln()).sqrt();
+        assert_close(peaks[0].fwhm().unwrap(), factor * 0.2, 1e-3);
+        assert_close(
+            peaks[0].area(),
+            (2. * std::f64::consts::PI).sqrt() * 2. * 0.2,
+            1e-3,
+        );
+        assert_close(peaks[1].fwhm().unwrap(), factor * 0.3, 1e-3);
+        assert_eq!(peaks[1].row(), 700);
+        Ok(())
+    }
+
+    #[test]
+    fn test_criteria() -> anyhow::Result<()> {
+        let (x, y) = spectrum(&[(3., 2., 0.2), (3.5, 1., 0.1), (7., 0.2, 0.5)], 0.);
+        assert_eq!(find_peaks(&x, &y, &PeakCriteria::default())?.len(), 3);
+
+        let criteria = PeakCriteria::new(Some(0.5), None, None, None)?;
+        assert_eq!(find_peaks(&x, &y, &criteria)?.len(), 2);
+
+        // 高いピークから 1 以内のピークを除く
+        let criteria = PeakCriteria::new(None, None, Some(1.), None)?;
+        let positions: Vec<f64> = find_peaks(&x, &y, &criteria)?
+            .iter()
+            .map(|peak| peak.position())
+            .collect();
+        assert_eq!(positions, vec![3., 7.]);
+
+        // 3.5 のピークは 3 のピークの裾に乗っているため、7 のピークは低いためプロミネンスが小さい
+        let criteria = PeakCriteria::new(None, Some(0.5), None, None)?;
+        let peaks = find_peaks(&x, &y, &criteria)?;
+        assert_eq!(peaks.len(), 1);
+        assert_close(peaks[0].position(), 3., 1e-9);
+
+        let criteria = PeakCriteria::new(None, None, None, Some(0.6))?;
+        let peaks = find_peaks(&x, &y, &criteria)?;
+        assert_eq!(peaks.len(), 1);
+        assert_close(peaks[0].position(), 7., 1e-9);
+
+        assert!(matches!(
+            PeakCriteria::new(None, Some(-1.), None, None),
+            Err(PeakDetectionError::InvalidThreshold(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_plateau_and_missing() -> anyhow::Result<()> {
+        let x = vec![
+            Some(0.),
+            Some(1.),
+            None,
+            Some(2.),
+            Some(3.),
+            Some(4.),
+            Some(5.),
+        ];
+        let y = vec![
+            Some(0.),
+            Some(1.),
+            Some(9.),
+            Some(2.),
+            Some(2.),
+            Some(2.),
+            Some(0.),
+        ];
+        let peaks = find_peaks(&x, &y, &PeakCriteria::default())?;
+        assert_eq!(peaks.len(), 1);
+        assert_eq!(peaks[0].row(), 4);
+        assert_eq!(peaks[0].position(), 3.);
+
+        let x = vec![Some(0.), Some(1.), Some(1.)];
+        assert!(matches!(
+            find_peaks(&x, &y, &PeakCriteria::default()),
+            Err(PeakDetectionError::NonIncreasingX(2))
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,705 @@
+use std::{collections::HashMap, fmt::Write};
+
+use thiserror::Error;
+
+use crate::{
+    models::{
+        column::{
+            column_cell::column_cell_value::CellRawValue, column_id::ColumnId,
+            column_with_cells::ColumnWithCells,
+        },
+        plot_2d::{
+            plot_2d::Plot2D,
+            plot_2d_axis::{AxisError, AxisRange, Plot2DAxis},
+            plot_2d_series::Plot2DSeries,
+            plot_2d_series_style::{LineDash, MarkerShape, SeriesStyle},
+        },
+    },
+    shared::value_object::ValueObject,
+};
+
+use super::axis_ticks::{resolve_range, AxisTicks};
+
+// 描画領域の外側の余白 (px)
+const MARGIN_LEFT: f64 = 70.;
+const MARGIN_RIGHT: f64 = 20.;
+const MARGIN_TOP: f64 = 20.;
+const MARGIN_BOTTOM: f64 = 50.;
+const TITLE_HEIGHT: f64 = 24.;
+const MAJOR_TICK_LENGTH: f64 = 6.;
+const MINOR_TICK_LENGTH: f64 = 3.;
+// 主目盛りの間隔のおおよその最小値 (px)
+const X_TICK_SPACING: f64 = 80.;
+const Y_TICK_SPACING: f64 = 50.;
+const LEGEND_ROW_HEIGHT: f64 = 18.;
+const LEGEND_SAMPLE_WIDTH: f64 = 24.;
+
+// 軸上の位置の計算に使う、範囲と目盛りを決めた軸
+struct ResolvedAxis<'a> {
+    axis: &'a Plot2DAxis,
+    range: AxisRange,
+    ticks: AxisTicks,
+    // 範囲の両端の軸上の座標
+    start: f64,
+    end: f64,
+}
+
+impl<'a> ResolvedAxis<'a> {
+    fn new(
+        axis: &'a Plot2DAxis,
+        columns: &[&[CellRawValue]],
+        length: f64,
+        spacing: f64,
+    ) -> Result<Self, AxisError> {
+        let range = resolve_range(axis, columns)?;
+        let scale = axis.scale();
+        let ticks = AxisTicks::new(range, scale, (length / spacing).floor() as usize);
+        let start = scale.transform(range.min());
+        let end = scale.transform(range.max());
+        match (start, end) {
+            (Some(start), Some(end)) => Ok(Self {
+                axis,
+                range,
+                ticks,
+                start,
+                end,
+            }),
+            _ => Err(AxisError::RangeOutsideScale(
+                scale,
+                range.min(),
+                range.max(),
+            )),
+        }
+    }
+
+    // 範囲の min を 0、max を 1 とする割合 (範囲外は 0 〜 1 の外、描けない値は None)
+    fn fraction(&self, value: f64) -> Option<f64> {
+        let coordinate = self.axis.scale().transform(value)?;
+        Some((coordinate - self.start) / (self.end - self.start))
+    }
+
+    fn contains(&self, value: f64) -> bool {
+        self.range.min() <= value && value <= self.range.max()
+    }
+}
+
+// domain service
+// 2 次元グラフを単独の SVG 文書として描く
+// columns には系列が参照するカラムとセルを渡す
+pub struct Plot2DSvgRenderer {
+    width: f64,
+    height: f64,
+}
+
+impl Plot2DSvgRenderer {
+    pub fn new(width: f64, height: f64) -> Result<Self, Plot2DSvgError> {
+        // 描画領域が残る大きさでなければならない
+        let valid = width.is_finite()
+            && width > MARGIN_LEFT + MARGIN_RIGHT
+            && height.is_finite()
+            && height > MARGIN_TOP + TITLE_HEIGHT + MARGIN_BOTTOM;
+        if !valid {
+            return Err(Plot2DSvgError::InvalidSize(width, height));
+        }
+        Ok(Self { width, height })
+    }
+
+    pub fn render(
+        &self,
+        plot: &Plot2D,
+        columns: &[ColumnWithCells],
+    ) -> Result<String, Plot2DSvgError> {
+        let columns: HashMap<&ColumnId, &ColumnWithCells> =
+            columns.iter().map(|column| (column.id(), column)).collect();
+        let mut values: HashMap<&ColumnId, Vec<CellRawValue>> = HashMap::new();
+        for column_id in plot.columns().iter() {
+            let column = columns
+                .get(column_id)
+                .ok_or(Plot2DSvgError::MissingColumn(column_id.clone()))?;
+            values.insert(
+                column.id(),
+                column
+                    .cells()
+                    .iter()
+                    .map(|cell| cell.cell_value().clone_value())
+                    .collect(),
+            );
+        }
+        let series_values = |series: &Plot2DSeries| (&values[series.x()], &values[series.y()]);
+
+        // 描画領域
+        let top = MARGIN_TOP
+            + if plot.title().value().is_empty() {
+                0.
+            } else {
+                TITLE_HEIGHT
+            };
+        let left = MARGIN_LEFT;
+        let plot_width = self.width - MARGIN_LEFT - MARGIN_RIGHT;
+        let plot_height = self.height - top - MARGIN_BOTTOM;
+
+        let x_columns: Vec<&[CellRawValue]> = plot
+            .series()
+            .iter()
+            .map(|series| series_values(series).0.as_slice())
+            .collect();
+        let y_columns: Vec<&[CellRawValue]> = plot
+            .series()
+            .iter()
+            .map(|series| series_values(series).1.as_slice())
+            .collect();
+        let x_axis = ResolvedAxis::new(plot.x_axis(), &x_columns, plot_width, X_TICK_SPACING)
+            .map_err(Plot2DSvgError::AxisError)?;
+        let y_axis = ResolvedAxis::new(plot.y_axis(), &y_columns, plot_height, Y_TICK_SPACING)
+            .map_err(Plot2DSvgError::AxisError)?;
+        let to_x = |fraction: f64| left + fraction * plot_width;
+        let to_y = |fraction: f64| top + (1. - fraction) * plot_height;
+        let point = |x: CellRawValue, y: CellRawValue| {
+            Some((to_x(x_axis.fraction(x?)?), to_y(y_axis.fraction(y?)?)))
+        };
+
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}" font-family="sans-serif" font-size="12">"#,
+            w = number(self.width),
+            h = number(self.height)
+        );
+        let _ = writeln!(
+            svg,
+            r#"<defs><clipPath id="plot-area"><rect x="{}" y="{}" width="{}" height="{}"/></clipPath></defs>"#,
+            number(left),
+            number(top),
+            number(plot_width),
+            number(plot_height)
+        );
+        svg.push_str("<rect width=\"100%\" height=\"100%\" fill=\"#ffffff\"/>\n");
+        if !plot.title().value().is_empty() {
+            let _ = writeln!(
+                svg,
+                r#"<text class="title" x="{}" y="{}" text-anchor="middle" font-size="16">{}</text>"#,
+                number(left + plot_width / 2.),
+                number(MARGIN_TOP + 12.),
+                escape(plot.title().value())
+            );
+        }
+
+        // グリッド (主目盛りの位置)
+        svg.push_str("<g class=\"grid\" stroke=\"#e0e0e0\" stroke-width=\"1\">\n");
+        if x_axis.axis.show_grid() {
+            for tick in x_axis.ticks.major() {
+                if let Some(fraction) = x_axis.fraction(tick.value()) {
+                    let x = number(to_x(fraction));
+                    let _ = writeln!(
+                        svg,
+                        r#"<line x1="{x}" y1="{}" x2="{x}" y2="{}"/>"#,
+                        number(top),
+                        number(top + plot_height)
+                    );
+                }
+            }
+        }
+        if y_axis.axis.show_grid() {
+            for tick in y_axis.ticks.major() {
+                if let Some(fraction) = y_axis.fraction(tick.value()) {
+                    let y = number(to_y(fraction));
+                    let _ = writeln!(
+                        svg,
+                        r#"<line x1="{}" y1="{y}" x2="{}" y2="{y}"/>"#,
+                        number(left),
+                        number(left + plot_width)
+                    );
+                }
+            }
+        }
+        svg.push_str("</g>\n");
+
+        // 系列 (x, y のいずれかが描けない行で線を区切る)
+        svg.push_str("<g class=\"series\" clip-path=\"url(#plot-area)\">\n");
+        for series in plot.series() {
+            let (x_values, y_values) = series_values(series);
+            let rows: Vec<(CellRawValue, CellRawValue)> = (0..x_values.len().max(y_values.len()))
+                .map(|row| {
+                    (
+                        x_values.get(row).copied().flatten(),
+                        y_values.get(row).copied().flatten(),
+                    )
+                })
+                .collect();
+            let points: Vec<Option<(f64, f64)>> = rows.iter().map(|(x, y)| point(*x, *y)).collect();
+            let style = series.style();
+            if style.kind().has_line() {
+                let mut path = String::new();
+                let mut pen_down = false;
+                for point in &points {
+                    match point {
+                        Some((x, y)) => {
+                            let command = if pen_down { 'L' } else { 'M' };
+                            let _ = write!(path, "{}{},{}", command, number(*x), number(*y));
+                            pen_down = true;
+                        }
+                        None => pen_down = false,
+                    }
+                }
+                if !path.is_empty() {
+                    let _ = writeln!(svg, r#"<path d="{}" {}/>"#, path, line_attributes(style));
+                }
+            }
+            if style.kind().has_marker() {
+                for (row, point) in points.iter().enumerate() {
+                    let (x_value, y_value) = rows[row];
+                    let inside = x_value.is_some_and(|x| x_axis.contains(x))
+                        && y_value.is_some_and(|y| y_axis.contains(y));
+                    if let (Some((x, y)), true) = (point, inside) {
+                        svg.push_str(&marker(style, *x, *y));
+                    }
+                }
+            }
+        }
+        svg.push_str("</g>\n");
+
+        // 軸の枠
+        let _ = writeln!(
+            svg,
+            r##"<rect class="frame" x="{}" y="{}" width="{}" height="{}" fill="none" stroke="#000000" stroke-width="1"/>"##,
+            number(left),
+            number(top),
+            number(plot_width),
+            number(plot_height)
+        );
+
+        // 目盛りとラベル
+        svg.push_str("<g class=\"ticks\" stroke=\"#000000\" stroke-width=\"1\">\n");
+        let bottom = top + plot_height;
+        let x_ticks = x_axis
+            .ticks
+            .major()
+            .iter()
+            .map(|tick| (tick.value(), MAJOR_TICK_LENGTH))
+            .chain(
+                x_axis
+                    .ticks
+                    .minor()
+                    .iter()
+                    .map(|value| (*value, MINOR_TICK_LENGTH)),
+            );
+        for (value, length) in x_ticks {
+            if let Some(fraction) = x_axis.fraction(value) {
+                let x = number(to_x(fraction));
+                let _ = writeln!(
+                    svg,
+                    r#"<line x1="{x}" y1="{}" x2="{x}" y2="{}"/>"#,
+                    number(bottom),
+                    number(bottom + length)
+                );
+            }
+        }
+        let y_ticks = y_axis
+            .ticks
+            .major()
+            .iter()
+            .map(|tick| (tick.value(), MAJOR_TICK_LENGTH))
+            .chain(
+                y_axis
+                    .ticks
+                    .minor()
+                    .iter()
+                    .map(|value| (*value, MINOR_TICK_LENGTH)),
+            );
+        for (value, length) in y_ticks {
+            if let Some(fraction) = y_axis.fraction(value) {
+                let y = number(to_y(fraction));
+                let _ = writeln!(
+                    svg,
+                    r#"<line x1="{}" y1="{y}" x2="{}" y2="{y}"/>"#,
+                    number(left - length),
+                    number(left)
+                );
+            }
+        }
+        svg.push_str("</g>\n");
+        svg.push_str("<g class=\"tick-labels\" fill=\"#000000\">\n");
+        for tick in x_axis.ticks.major() {
+            if let Some(fraction) = x_axis.fraction(tick.value()) {
+                let _ = writeln!(
+                    svg,
+                    r#"<text x="{}" y="{}" text-anchor="middle">{}</text>"#,
+                    number(to_x(fraction)),
+                    number(bottom + MAJOR_TICK_LENGTH + 14.),
+                    escape(tick.label())
+                );
+            }
+        }
+        for tick in y_axis.ticks.major() {
+            if let Some(fraction) = y_axis.fraction(tick.value()) {
+                let _ = writeln!(
+                    svg,
+                    r#"<text x="{}" y="{}" text-anchor="end">{}</text>"#,
+                    number(left - MAJOR_TICK_LENGTH - 4.),
+                    number(to_y(fraction) + 4.),
+                    escape(tick.label())
+                );
+            }
+        }
+        svg.push_str("</g>\n");
+
+        // 軸ラベル
+        if !x_axis.axis.label().is_empty() {
+            let _ = writeln!(
+                svg,
+                r#"<text class="x-label" x="{}" y="{}" text-anchor="middle">{}</text>"#,
+                number(left + plot_width / 2.),
+                number(self.height - 10.),
+                escape(x_axis.axis.label())
+            );
+        }
+        if !y_axis.axis.label().is_empty() {
+            let (x, y) = (number(16.), number(top + plot_height / 2.));
+            let _ = writeln!(
+                svg,
+                r#"<text class="y-label" x="{x}" y="{y}" text-anchor="middle" transform="rotate(-90 {x} {y})">{}</text>"#,
+                escape(y_axis.axis.label())
+            );
+        }
+
+        // 凡例 (描画領域の右上、ラベルが空の系列は y のカラム名)
+        if !plot.series().is_empty() {
+            let labels: Vec<String> = plot
+                .series()
+                .iter()
+                .map(|series| match series.label().is_empty() {
+                    true => columns[series.y()].name().clone_value(),
+                    false => series.label().clone(),
+                })
+                .collect();
+            // 文字幅は文字数から概算する
+            let text_width = labels
+                .iter()
+                .map(|label| label.chars().count())
+                .max()
+                .unwrap_or(0) as f64
+                * 7.;
+            let legend_width = LEGEND_SAMPLE_WIDTH + text_width + 18.;
+            let legend_height = LEGEND_ROW_HEIGHT * labels.len() as f64 + 8.;
+            let legend_left = left + plot_width - legend_width - 8.;
+            let legend_top = top + 8.;
+            svg.push_str("<g class=\"legend\">\n");
+            let _ = writeln!(
+                svg,
+                r##"<rect x="{}" y="{}" width="{}" height="{}" fill="#ffffff" fill-opacity="0.8" stroke="#808080" stroke-width="1"/>"##,
+                number(legend_left),
+                number(legend_top),
+                number(legend_width),
+                number(legend_height)
+            );
+            for (i, (series, label)) in plot.series().iter().zip(&labels).enumerate() {
+                let y = legend_top + 4. + LEGEND_ROW_HEIGHT * (i as f64 + 0.5);
+                let sample_left = legend_left + 6.;
+                let style = series.style();
+                if style.kind().has_line() {
+                    let _ = writeln!(
+                        svg,
+                        r#"<path d="M{},{}L{},{}" {}/>"#,
+                        number(sample_left),
+                        number(y),
+                        number(sample_left + LEGEND_SAMPLE_WIDTH),
+                        number(y),
+                        line_attributes(style)
+                    );
+                }
+                if style.kind().has_marker() {
+                    svg.push_str(&marker(style, sample_left + LEGEND_SAMPLE_WIDTH / 2., y));
+                }
+                let _ = writeln!(
+                    svg,
+                    r#"<text x="{}" y="{}">{}</text>"#,
+                    number(sample_left + LEGEND_SAMPLE_WIDTH + 6.),
+                    number(y + 4.),
+                    escape(label)
+                );
+            }
+            svg.push_str("</g>\n");
+        }
+
+        svg.push_str("</svg>\n");
+        Ok(svg)
+    }
+}
+
+fn line_attributes(style: &SeriesStyle) -> String {
+    let dash = match style.line_dash() {
+        LineDash::Solid => String::new(),
+        LineDash::Dashed => format!(
+            r#" stroke-dasharray="{} {}""#,
+            number(style.line_width() * 4.),
+            number(style.line_width() * 3.)
+        ),
+        LineDash::Dotted => format!(
+            r#" stroke-dasharray="{} {}" stroke-linecap="round""#,
+            number(style.line_width()),
+            number(style.line_width() * 2.)
+        ),
+    };
+    format!(
+        r#"fill="none" stroke="{}" stroke-width="{}"{}"#,
+        style.color(),
+        number(style.line_width()),
+        dash
+    )
+}
+
+fn marker(style: &SeriesStyle, x: f64, y: f64) -> String {
+    let r = style.marker_size() / 2.;
+    let color = style.color();
+    match style.marker() {
+        MarkerShape::Circle => format!(
+            "<circle cx=\"{}\" cy=\"{}\" r=\"{}\" fill=\"{}\"/>\n",
+            number(x),
+            number(y),
+            number(r),
+            color
+        ),
+        MarkerShape::Square => format!(
+            "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>\n",
+            number(x - r),
+            number(y - r),
+            number(2. * r),
+            number(2. * r),
+            color
+        ),
+        MarkerShape::Triangle => format!(
+            "<path d=\"M{},{}L{},{}L{},{}Z\" fill=\"{}\"/>\n",
+            number(x),
+            number(y - r),
+            number(x + r),
+            number(y + r),
+            number(x - r),
+            number(y + r),
+            color
+        ),
+        MarkerShape::Cross => format!(
+            "<path d=\"M{},{}L{},{}M{},{}L{},{}\" stroke=\"{}\" stroke-width=\"1.5\"/>\n",
+            number(x - r),
+            number(y - r),
+            number(x + r),
+            number(y + r),
+            number(x - r),
+            number(y + r),
+            number(x + r),
+            number(y - r),
+            color
+        ),
+    }
+}
+
+// 座標は小数第 2 位までにし、末尾の 0 を除く
+fn number(value: f64) -> String {
+    let formatted = format!("{:.2}", value);
+    let trimmed = formatted.trim_end_matches('0').trim_end_matches('.');
+    match trimmed {
+        "-0" => "0".to_string(),
+        _ => trimmed.to_string(),
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[derive(Debug, Error)]
+pub enum Plot2DSvgError {
+    #[error("SVG size must leave room for the plot area, but {0} x {1} is given")]
+    InvalidSize(f64, f64),
+    #[error("cells of column {0:?} are not given")]
+    MissingColumn(ColumnId),
+    #[error("AxisError: [{0}]")]
+    AxisError(AxisError),
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::{
+        column::{
+            column::Column,
+            column_cell::{
+                column_cell::ColumnCell, column_cell_id::ColumnCellId,
+                column_cell_value::ColumnCellValue,
+            },
+            column_directory::column_directory_id::ColumnDirectoryId,
+            column_name::ColumnName,
+        },
+        plot_2d::{
+            plot_2d_axis::AxisScale,
+            plot_2d_id::Plot2DId,
+            plot_2d_series_style::{Color, SeriesKind},
+            plot_2d_title::Plot2DTitle,
+        },
+    };
+
+    use super::*;
+
+    // 期待する SVG と比較する (UPDATE_SNAPSHOTS を設定して実行すると期待値を書き換える)
+    fn assert_snapshot(name: &str, actual: &str) {
+        let path = format!(
+            "{}/src/services/snapshots/{}.svg",
+            env!("CARGO_MANIFEST_DIR"),
+            name
+        );
+        if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+            std::fs::write(&path, actual).unwrap();
+            return;
+        }
+        let expected = std::fs::read_to_string(&path)
+            .unwrap_or_else(|_| panic!("snapshot {} is not found", path));
+        assert_eq!(actual, expected, "snapshot {} does not match", name);
+    }
+
+    fn column(id: &str, name: &str, values: &[Option<f64>]) -> anyhow::Result<ColumnWithCells> {
+        let cells = values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| {
+                Ok(ColumnCell::new(
+                    Some(ColumnCellId::new(format!("{}-{}", id, i))?),
+                    ColumnCellValue::new(*value)?,
+                ))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let column = Column::new(
+            Some(ColumnId::new(id.to_string())?),
+            ColumnName::new(name.to_string())?,
+            ColumnDirectoryId::new("0".to_string())?,
+            cells.iter().map(|cell| cell.id().clone()).collect(),
+        );
+        Ok(ColumnWithCells::new(&column, cells))
+    }
+
+    fn series(x: &str, y: &str, label: &str, style: SeriesStyle) -> anyhow::Result<Plot2DSeries> {
+        Ok(Plot2DSeries::new(
+            ColumnId::new(x.to_string())?,
+            ColumnId::new(y.to_string())?,
+            label.to_string(),
+            style,
+        ))
+    }
+
+    fn columns() -> anyhow::Result<Vec<ColumnWithCells>> {
+        Ok(vec![
+            column(
+                "t",
+                "time",
+                &[Some(0.), Some(1.), Some(2.), Some(3.), Some(4.), Some(5.)],
+            )?,
+            column(
+                "a",
+                "A & B",
+                &[Some(1.), Some(3.), None, Some(7.), Some(8.), Some(9.5)],
+            )?,
+            column(
+                "b",
+                "b",
+                &[Some(2.), Some(2.5), Some(4.), Some(3.), Some(6.), Some(5.)],
+            )?,
+        ])
+    }
+
+    #[test]
+    fn test_render_line_and_scatter() -> anyhow::Result<()> {
+        let scatter = SeriesStyle::new(
+            SeriesKind::Scatter,
+            Color::new(0xd6, 0x27, 0x28),
+            1.,
+            LineDash::Solid,
+            MarkerShape::Square,
+            6.,
+        )?;
+        let plot = Plot2D::new(
+            Some(Plot2DId::new("1".to_string())?),
+            Plot2DTitle::new("Growth <test>".to_string())?,
+            vec![
+                series("t", "a", "", SeriesStyle::default())?,
+                series("t", "b", "measured", scatter)?,
+            ],
+            Plot2DAxis::new("time / s".to_string(), AxisScale::Linear, None, true)?,
+            Plot2DAxis::new(
+                "signal".to_string(),
+                AxisScale::Linear,
+                Some(AxisRange::new(0., 10.)?),
+                false,
+            )?,
+        );
+        let svg = Plot2DSvgRenderer::new(400., 300.)?.render(&plot, &columns()?)?;
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        // 欠損行で線が区切られる
+        let line = svg
+            .lines()
+            .find(|line| line.starts_with("<path d=\"M"))
+            .unwrap();
+        assert_eq!(line.matches('M').count(), 2);
+        // ラベルが空の系列は y のカラム名を凡例に使う
+        assert!(svg.contains(">A &amp; B</text>"));
+        assert!(svg.contains("Growth &lt;test&gt;"));
+        assert_snapshot("line_and_scatter", &svg);
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_log_axis() -> anyhow::Result<()> {
+        let dashed = SeriesStyle::new(
+            SeriesKind::LineAndScatter,
+            Color::new(0x2c, 0xa0, 0x2c),
+            2.,
+            LineDash::Dashed,
+            MarkerShape::Triangle,
+            5.,
+        )?;
+        let plot = Plot2D::new(
+            Some(Plot2DId::new("2".to_string())?),
+            Plot2DTitle::new(String::new())?,
+            vec![series("t", "a", "decay", dashed)?],
+            Plot2DAxis::default(),
+            Plot2DAxis::new("count".to_string(), AxisScale::Log10, None, true)?,
+        );
+        let svg = Plot2DSvgRenderer::new(320., 240.)?.render(&plot, &columns()?)?;
+        assert!(!svg.contains("class=\"title\""));
+        assert_snapshot("log_axis", &svg);
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_errors() -> anyhow::Result<()> {
+        let plot = Plot2D::new(
+            Some(Plot2DId::new("3".to_string())?),
+            Plot2DTitle::new(String::new())?,
+            vec![series("t", "c", "", SeriesStyle::default())?],
+            Plot2DAxis::default(),
+            Plot2DAxis::default(),
+        );
+        assert!(matches!(
+            Plot2DSvgRenderer::new(400., 300.)?.render(&plot, &columns()?),
+            Err(Plot2DSvgError::MissingColumn(_))
+        ));
+        assert!(matches!(
+            Plot2DSvgRenderer::new(50., 300.),
+            Err(Plot2DSvgError::InvalidSize(_, _))
+        ));
+
+        // 系列がなく範囲も指定されていない軸は範囲を決められない
+        let plot = Plot2D::new(
+            Some(Plot2DId::new("4".to_string())?),
+            Plot2DTitle::new(String::new())?,
+            vec![],
+            Plot2DAxis::default(),
+            Plot2DAxis::default(),
+        );
+        assert!(matches!(
+            Plot2DSvgRenderer::new(400., 300.)?.render(&plot, &[]),
+            Err(Plot2DSvgError::AxisError(AxisError::NoValues(_)))
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,264 @@
+use thiserror::Error;
+
+use crate::models::column::{
+    column_cell::column_cell_value::CellRawValue,
+    column_smoothing::{EdgeHandling, SmoothingFilter},
+};
+
+use super::{
+    descriptive_statistics::quantile,
+    linear_algebra::{gram, solve, transpose_multiply, Matrix},
+};
+
+// domain service
+// 等間隔に並んだ値の平滑化
+// 元の値が None (または NaN) の行は None のまま残し、窓内の None は除いて計算する
+// 窓内に計算に必要な点がない場合は None になる
+// 窓がカラムより長い (ガウス核は 3σ がカラムの点数より大きい) 場合はエラー
+pub fn smooth(
+    values: &[CellRawValue],
+    filter: &SmoothingFilter,
+    edge_handling: EdgeHandling,
+) -> Result<Vec<CellRawValue>, SmoothingError> {
+    let length = values.len();
+    if length == 0 {
+        return Ok(vec![]);
+    }
+    match *filter {
+        SmoothingFilter::MovingAverage { window }
+        | SmoothingFilter::SavitzkyGolay { window, .. }
+        | SmoothingFilter::Median { window } => {
+            if window > length {
+                return Err(SmoothingError::WindowTooLong(window, length));
+            }
+        }
+        SmoothingFilter::Gaussian { sigma } => {
+            if filter.half_width() > length {
+                return Err(SmoothingError::SigmaTooLarge(sigma, length));
+            }
+        }
+    }
+
+    let value_at = |row: usize| values[row].filter(|value| !value.is_nan());
+    let half_width = filter.half_width() as isize;
+    let last = length as isize - 1;
+
+    Ok((0..length)
+        .map(|row| {
+            value_at(row)?;
+
+            // 窓内の (中心からの距離, 値)
+            let mut window: Vec<(f64, f64)> = vec![];
+            for offset in -half_width..=half_width {
+                let index = row as isize + offset;
+                let index = if (0..=last).contains(&index) {
+                    index
+                } else {
+                    match edge_handling {
+                        EdgeHandling::Missing => return None,
+                        EdgeHandling::Shrink => continue,
+                        EdgeHandling::Nearest => index.clamp(0, last),
+                        EdgeHandling::Reflect => {
+                            let reflected = if index < 0 { -index } else { 2 * last - index };
+                            reflected.clamp(0, last)
+                        }
+                    }
+                };
+                if let Some(value) = value_at(index as usize) {
+                    window.push((offset as f64, value));
+                }
+            }
+            apply_filter(filter, &window)
+        })
+        .collect())
+}
+
+fn apply_filter(filter: &SmoothingFilter, window: &[(f64, f64)]) -> Option<f64> {
+    if window.is_empty() {
+        return None;
+    }
+    match filter {
+        SmoothingFilter::MovingAverage { .. } => {
+            Some(window.iter().map(|(_, value)| value).sum::<f64>() / window.len() as f64)
+        }
+        SmoothingFilter::Gaussian { sigma } => {
+            let (weighted_sum, weight_sum) =
+                window
+                    .iter()
+                    .fold((0., 0.), |(weighted_sum, weight_sum), (offset, value)| {
+                        let weight = (-offset * offset / (2. * sigma * sigma)).exp();
+                        (weighted_sum + weight * value, weight_sum + weight)
+                    });
+            Some(weighted_sum / weight_sum)
+        }
+        SmoothingFilter::Median { .. } => {
+            let mut sorted: Vec<f64> = window.iter().map(|(_, value)| *value).collect();
+            sorted.sort_by(|a, b| a.total_cmp(b));
+            quantile(&sorted, 0.5)
+        }
+        SmoothingFilter::SavitzkyGolay {
+            polynomial_order, ..
+        } => {
+            // 窓内の点に最小二乗法で多項式を当てはめ、中心 (距離 0) での値 = 定数項を採る
+            // 点が揃った窓では通常の Savitzky–Golay 係数による畳み込みと一致する
+            if window.len() <= *polynomial_order {
+                return None;
+            }
+            let (design, target): (Matrix, Vec<f64>) = window
+                .iter()
+                .map(|(offset, value)| {
+                    (
+                        (0..=*polynomial_order as i32)
+                            .map(|k| offset.powi(k))
+                            .collect(),
+                        *value,
+                    )
+                })
+                .unzip();
+            solve(&gram(&design), &transpose_multiply(&design, &target))
+                .map(|coefficients| coefficients[0])
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum SmoothingError {
+    #[error("window {0} is longer than the column of {1} values")]
+    WindowTooLong(usize, usize),
+    #[error("window of sigma {0} (3 sigma) is longer than the column of {1} values")]
+    SigmaTooLarge(f64, usize),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_all_close(actual: &[CellRawValue], expected: &[CellRawValue]) {
+        assert_eq!(actual.len(), expected.len());
+        for (actual, expected) in actual.iter().zip(expected) {
+            match (actual, expected) {
+                (Some(actual), Some(expected)) => assert!(
+                    (actual - expected).abs() < 1e-9,
+                    "actual: {}, expected: {}",
+                    actual,
+                    expected
+                ),
+                _ => assert_eq!(actual, expected),
+            }
+        }
+    }
+
+    #[test]
+    fn test_moving_average_edges() {
+        let values = vec![Some(1.), Some(2.), Some(3.), Some(4.), Some(5.)];
+        let filter = SmoothingFilter::MovingAverage { window: 3 };
+        assert_all_close(
+            &smooth(&values, &filter, EdgeHandling::Missing).unwrap(),
+            &[None, Some(2.), Some(3.), Some(4.), None],
+        );
+        assert_all_close(
+            &smooth(&values, &filter, EdgeHandling::Shrink).unwrap(),
+            &[Some(1.5), Some(2.), Some(3.), Some(4.), Some(4.5)],
+        );
+        assert_all_close(
+            &smooth(&values, &filter, EdgeHandling::Nearest).unwrap(),
+            &[Some(4. / 3.), Some(2.), Some(3.), Some(4.), Some(14. / 3.)],
+        );
+        // 折り返し: [2, 1, 2], [4, 5, 4]
+        assert_all_close(
+            &smooth(&values, &filter, EdgeHandling::Reflect).unwrap(),
+            &[Some(5. / 3.), Some(2.), Some(3.), Some(4.), Some(13. / 3.)],
+        );
+    }
+
+    #[test]
+    fn test_missing_values() {
+        let values = vec![Some(1.), None, Some(3.), Some(f64::NAN), Some(5.)];
+        let filter = SmoothingFilter::MovingAverage { window: 3 };
+        assert_all_close(
+            &smooth(&values, &filter, EdgeHandling::Shrink).unwrap(),
+            &[Some(1.), None, Some(3.), None, Some(5.)],
+        );
+    }
+
+    #[test]
+    fn test_savitzky_golay() {
+        // 2 次多項式は 2 次の Savitzky–Golay フィルタで変化しない
+        let values: Vec<CellRawValue> = (0..9)
+            .map(|i| Some(0.5 * (i * i) as f64 - i as f64 + 2.))
+            .collect();
+        let filter = SmoothingFilter::SavitzkyGolay {
+            window: 5,
+            polynomial_order: 2,
+        };
+        assert_all_close(
+            &smooth(&values, &filter, EdgeHandling::Shrink).unwrap(),
+            &values,
+        );
+
+        // 5 点 2 次の係数 (-3, 12, 17, 12, -3) / 35
+        let values = vec![Some(0.), Some(0.), Some(35.), Some(0.), Some(0.)];
+        let smoothed = smooth(&values, &filter, EdgeHandling::Missing).unwrap();
+        assert_all_close(&smoothed, &[None, None, Some(17.), None, None]);
+    }
+
+    #[test]
+    fn test_gaussian_and_median() {
+        let values = vec![Some(0.), Some(0.), Some(1.), Some(0.), Some(0.)];
+        let gaussian = smooth(
+            &values,
+            &SmoothingFilter::Gaussian { sigma: 1. },
+            EdgeHandling::Nearest,
+        )
+        .unwrap();
+        // 中心の重みは 1 / (1 + 2e^-1/2 + 2e^-2 + 2e^-9/2)
+        let weight_sum = 1. + 2. * (-0.5f64).exp() + 2. * (-2f64).exp() + 2. * (-4.5f64).exp();
+        assert!((gaussian[2].unwrap() - 1. / weight_sum).abs() < 1e-12);
+        assert!((gaussian[1].unwrap() - gaussian[3].unwrap()).abs() < 1e-12);
+
+        // 外れ値を取り除く
+        let values = vec![Some(1.), Some(1.), Some(100.), Some(1.), Some(2.)];
+        assert_all_close(
+            &smooth(
+                &values,
+                &SmoothingFilter::Median { window: 3 },
+                EdgeHandling::Shrink,
+            )
+            .unwrap(),
+            &[Some(1.), Some(1.), Some(1.), Some(2.), Some(1.5)],
+        );
+    }
+
+    #[test]
+    fn test_window_too_long() {
+        let values = vec![Some(1.), Some(2.), Some(3.)];
+        assert!(matches!(
+            smooth(
+                &values,
+                &SmoothingFilter::MovingAverage { window: 5 },
+                EdgeHandling::Shrink
+            ),
+            Err(SmoothingError::WindowTooLong(5, 3))
+        ));
+        // 3σ が非常に大きい場合も窓を走査せずにエラーにする
+        for sigma in [2., 1e9, 1e300] {
+            assert!(matches!(
+                smooth(
+                    &values,
+                    &SmoothingFilter::Gaussian { sigma },
+                    EdgeHandling::Nearest
+                ),
+                Err(SmoothingError::SigmaTooLarge(_, 3))
+            ));
+        }
+        assert_eq!(
+            smooth(
+                &[],
+                &SmoothingFilter::Median { window: 1 },
+                EdgeHandling::Shrink
+            )
+            .unwrap(),
+            vec![]
+        );
+    }
+}
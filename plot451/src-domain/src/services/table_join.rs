@@ -0,0 +1,258 @@
+use std::collections::{HashMap, HashSet};
+
+use thiserror::Error;
+
+use crate::models::column::column_cell::column_cell_value::CellRawValue;
+
+// 結合の種類
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum JoinKind {
+    // キーが一致する行の組だけを残す
+    Inner,
+    // 左の行をすべて残す
+    Left,
+    // 両方の行をすべて残す (右だけの行は最後に並べる)
+    Outer,
+    // 左の行ごとに差が tolerance 以内で最も近いキーを持つ右の行を 1 つ対応させる
+    // (キーは 1 つで、左の行はすべて残す)
+    Nearest { tolerance: f64 },
+}
+
+// 結合後の行 (左右のテーブルの行番号、対応する行がなければ None)
+pub type JoinedRow = (Option<usize>, Option<usize>);
+
+// domain service
+// キーカラムの値で左右のテーブルの行を対応させる
+// キーに None (または NaN) を含む行はどの行とも一致しない
+pub fn join_rows(
+    left_keys: &[Vec<CellRawValue>],
+    right_keys: &[Vec<CellRawValue>],
+    left_row_count: usize,
+    right_row_count: usize,
+    kind: JoinKind,
+) -> Result<Vec<JoinedRow>, TableJoinError> {
+    if left_keys.is_empty() {
+        return Err(TableJoinError::NoKeyColumns);
+    }
+    if left_keys.len() != right_keys.len() {
+        return Err(TableJoinError::KeyCountMismatch(
+            left_keys.len(),
+            right_keys.len(),
+        ));
+    }
+    let key_at = |keys: &[Vec<CellRawValue>], row: usize| -> Option<Vec<f64>> {
+        keys.iter()
+            .map(|column| {
+                column
+                    .get(row)
+                    .copied()
+                    .flatten()
+                    .filter(|value| !value.is_nan())
+            })
+            .collect()
+    };
+    let right: Vec<Option<Vec<f64>>> = (0..right_row_count)
+        .map(|row| key_at(right_keys, row))
+        .collect();
+
+    let mut joined = vec![];
+    match kind {
+        JoinKind::Nearest { tolerance } => {
+            if !tolerance.is_finite() || tolerance < 0. {
+                return Err(TableJoinError::InvalidTolerance(tolerance));
+            }
+            if left_keys.len() != 1 {
+                return Err(TableJoinError::NearestRequiresSingleKey(left_keys.len()));
+            }
+            // キーの昇順に並べ、同じキーは先の行だけを残す
+            let mut sorted: Vec<(f64, usize)> = right
+                .iter()
+                .enumerate()
+                .filter_map(|(row, key)| Some((normalize_zero(key.as_ref()?[0]), row)))
+                .collect();
+            sorted.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+            sorted.dedup_by(|b, a| a.0 == b.0);
+            for left_row in 0..left_row_count {
+                let nearest = key_at(left_keys, left_row).and_then(|left_key| {
+                    let key = normalize_zero(left_key[0]);
+                    let index = sorted.partition_point(|(right_key, _)| *right_key < key);
+                    // 直前と直後のキーのどちらかが最も近い
+                    sorted[index.saturating_sub(1)..(index + 1).min(sorted.len())]
+                        .iter()
+                        .map(|(right_key, row)| (*row, (right_key - key).abs()))
+                        .filter(|(_, distance)| *distance <= tolerance)
+                        // 同じ距離なら先の行
+                        .min_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)))
+                        .map(|(row, _)| row)
+                });
+                joined.push((Some(left_row), nearest));
+            }
+        }
+        JoinKind::Inner | JoinKind::Left | JoinKind::Outer => {
+            // キーのビット列から右の行番号 (昇順) を引けるようにする
+            let mut right_rows: HashMap<Vec<u64>, Vec<usize>> = HashMap::new();
+            for (row, key) in right.iter().enumerate() {
+                if let Some(key) = key {
+                    right_rows.entry(key_bits(key)).or_default().push(row);
+                }
+            }
+            let mut matched_right = HashSet::new();
+            for left_row in 0..left_row_count {
+                let matches: &[usize] = key_at(left_keys, left_row)
+                    .and_then(|left_key| right_rows.get(&key_bits(&left_key)))
+                    .map_or(&[], |rows| rows.as_slice());
+                if matches.is_empty() {
+                    if kind != JoinKind::Inner {
+                        joined.push((Some(left_row), None));
+                    }
+                    continue;
+                }
+                for &right_row in matches {
+                    matched_right.insert(right_row);
+                    joined.push((Some(left_row), Some(right_row)));
+                }
+            }
+            if kind == JoinKind::Outer {
+                joined.extend(
+                    (0..right_row_count)
+                        .filter(|row| !matched_right.contains(row))
+                        .map(|row| (None, Some(row))),
+                );
+            }
+        }
+    }
+    Ok(joined)
+}
+
+// -0 と 0 を同じキーとして扱う
+fn normalize_zero(value: f64) -> f64 {
+    if value == 0. {
+        0.
+    } else {
+        value
+    }
+}
+
+fn key_bits(key: &[f64]) -> Vec<u64> {
+    key.iter()
+        .map(|value| normalize_zero(*value).to_bits())
+        .collect()
+}
+
+// 右のテーブルのカラム名のうち、左のテーブルのカラム名と重複するものに
+// " ({suffix})" を付け、それでも重複する場合はさらに番号を付ける
+pub fn resolve_name_clashes(left: &[String], right: &[String], suffix: &str) -> Vec<String> {
+    let mut taken: HashSet<String> = left.iter().cloned().collect();
+    right
+        .iter()
+        .map(|name| {
+            let mut candidate = name.clone();
+            let mut number = 1;
+            while taken.contains(&candidate) {
+                candidate = if number == 1 {
+                    format!("{} ({})", name, suffix)
+                } else {
+                    format!("{} ({} {})", name, suffix, number)
+                };
+                number += 1;
+            }
+            taken.insert(candidate.clone());
+            candidate
+        })
+        .collect()
+}
+
+#[derive(Debug, Error)]
+pub enum TableJoinError {
+    #[error("at least one key column is required")]
+    NoKeyColumns,
+    #[error("numbers of key columns differ: left {0}, right {1}")]
+    KeyCountMismatch(usize, usize),
+    #[error("nearest join requires exactly one key column, but {0} are given")]
+    NearestRequiresSingleKey(usize),
+    #[error("tolerance must be a non-negative number, but {0} is given")]
+    InvalidTolerance(f64),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exact_joins() -> anyhow::Result<()> {
+        let left = vec![vec![Some(1.), Some(2.), None, Some(4.)]];
+        let right = vec![vec![Some(2.), Some(1.), Some(2.), Some(5.)]];
+        assert_eq!(
+            join_rows(&left, &right, 4, 4, JoinKind::Inner)?,
+            vec![(Some(0), Some(1)), (Some(1), Some(0)), (Some(1), Some(2))]
+        );
+        assert_eq!(
+            join_rows(&left, &right, 4, 4, JoinKind::Left)?,
+            vec![
+                (Some(0), Some(1)),
+                (Some(1), Some(0)),
+                (Some(1), Some(2)),
+                (Some(2), None),
+                (Some(3), None)
+            ]
+        );
+        let joined = join_rows(&left, &right, 4, 4, JoinKind::Outer)?;
+        assert_eq!(joined.len(), 6);
+        assert_eq!(joined[5], (None, Some(3)));
+
+        // 複数のキー
+        let left = vec![vec![Some(1.), Some(1.)], vec![Some(10.), Some(20.)]];
+        let right = vec![vec![Some(1.)], vec![Some(20.)]];
+        assert_eq!(
+            join_rows(&left, &right, 2, 1, JoinKind::Inner)?,
+            vec![(Some(1), Some(0))]
+        );
+        assert!(matches!(
+            join_rows(&left, &right[..1], 2, 1, JoinKind::Inner),
+            Err(TableJoinError::KeyCountMismatch(2, 1))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_nearest_join() -> anyhow::Result<()> {
+        let left = vec![vec![Some(0.), Some(1.), Some(2.), Some(10.)]];
+        let right = vec![vec![Some(0.9), Some(2.05), Some(1.2)]];
+        assert_eq!(
+            join_rows(&left, &right, 4, 3, JoinKind::Nearest { tolerance: 0.5 })?,
+            vec![
+                (Some(0), None),
+                (Some(1), Some(0)),
+                (Some(2), Some(1)),
+                (Some(3), None)
+            ]
+        );
+
+        // 同じ距離なら先の行、-0 と 0 は同じキー
+        let left = vec![vec![Some(1.), Some(-0.)]];
+        let right = vec![vec![Some(2.), Some(0.), Some(0.), Some(2.)]];
+        assert_eq!(
+            join_rows(&left, &right, 2, 4, JoinKind::Nearest { tolerance: 1. })?,
+            vec![(Some(0), Some(0)), (Some(1), Some(1))]
+        );
+        assert_eq!(
+            join_rows(&left, &right, 2, 4, JoinKind::Inner)?,
+            vec![(Some(1), Some(1)), (Some(1), Some(2))]
+        );
+        assert!(matches!(
+            join_rows(&left, &right, 2, 4, JoinKind::Nearest { tolerance: -1. }),
+            Err(TableJoinError::InvalidTolerance(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolve_name_clashes() {
+        let left = vec!["id".to_string(), "T".to_string(), "T (right)".to_string()];
+        let right = vec!["T".to_string(), "P".to_string()];
+        assert_eq!(
+            resolve_name_clashes(&left, &right, "right"),
+            vec!["T (right 2)".to_string(), "P".to_string()]
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use thiserror::Error;
+
+use crate::{
+    models::{
+        column::{
+            column_cell::{
+                column_cell_id::ColumnCellId,
+                column_cell_value::{ColumnCellValue, ColumnCellValueError},
+            },
+            column_factory::{ColumnFactoryError, IColumnFactory},
+            column_repository::{ColumnRepositoryError, IColumnRepository},
+        },
+        table::table_rows::{TableRows, TableRowsError},
+    },
+    shared::value_object::ValueObject,
+};
+
+pub type TableRowsPaddingServiceResult<T> = anyhow::Result<T, TableRowsPaddingServiceError>;
+
+// domain service
+// 行の操作の前にカラムの長さを空セルで揃える
+pub struct TableRowsPaddingService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory,
+    CR: IColumnRepository,
+{
+    column_factory: &'a CF,
+    column_repository: &'b CR,
+}
+
+impl<'a, 'b, CF, CR> TableRowsPaddingService<'a, 'b, CF, CR>
+where
+    CF: IColumnFactory + Sync,
+    CR: IColumnRepository + Sync,
+{
+    pub fn new(column_factory: &'a CF, column_repository: &'b CR) -> Self {
+        Self {
+            column_factory,
+            column_repository,
+        }
+    }
+
+    // 空セルを count 個作成して永続化する
+    pub async fn create_empty_cells(
+        &self,
+        count: usize,
+    ) -> TableRowsPaddingServiceResult<Vec<ColumnCellId>> {
+        let mut cell_ids = vec![];
+        for _ in 0..count {
+            let cell_value = ColumnCellValue::new(None)
+                .map_err(TableRowsPaddingServiceError::ColumnCellValueError)?;
+            let cell = self
+                .column_factory
+                .create_cell(cell_value)
+                .await
+                .map_err(TableRowsPaddingServiceError::ColumnFactoryError)?;
+            let cell_id = self
+                .column_repository
+                .save_cell(&cell)
+                .await
+                .map_err(TableRowsPaddingServiceError::ColumnRepositoryError)?;
+            cell_ids.push(cell_id);
+        }
+        Ok(cell_ids)
+    }
+
+    // 長さの足りないカラムを空セルで埋め、作成したセルの id を返す
+    pub async fn pad(
+        &self,
+        table_rows: &mut TableRows,
+    ) -> TableRowsPaddingServiceResult<Vec<ColumnCellId>> {
+        if table_rows.is_aligned() {
+            return Ok(vec![]);
+        }
+        let mut created_cell_ids = vec![];
+        let mut padding = vec![];
+        for length in table_rows.padding_lengths() {
+            let cell_ids = self.create_empty_cells(length).await?;
+            created_cell_ids.extend(cell_ids.iter().cloned());
+            padding.push(cell_ids);
+        }
+        table_rows
+            .pad(padding)
+            .map_err(TableRowsPaddingServiceError::TableRowsError)?;
+        Ok(created_cell_ids)
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum TableRowsPaddingServiceError {
+    // repository errors
+    #[error("ColumnRepositoryError: [{0}]")]
+    ColumnRepositoryError(ColumnRepositoryError),
+    // factory errors
+    #[error("ColumnFactoryError: [{0}]")]
+    ColumnFactoryError(ColumnFactoryError),
+    // value object errors
+    #[error("ColumnCellValueError: [{0}]")]
+    ColumnCellValueError(ColumnCellValueError),
+    // first class collection errors
+    #[error("TableRowsError: [{0}]")]
+    TableRowsError(TableRowsError),
+}
@@ -0,0 +1,217 @@
+use thiserror::Error;
+
+use crate::models::column::{
+    column_cell::column_cell_value::CellRawValue, column_transformation::Transformation,
+};
+
+use super::{
+    descriptive_statistics::DescriptiveStatistics,
+    numerical_calculus::{GapHandling, IntegrationMethod, NumericalCalculusError, SampledFunction},
+};
+
+// domain service
+// カラムの値を行ごとに変換する
+// reference は変換に別のカラムが必要な場合 (Transformation::reference) のそのカラムの値
+// 元の値が None (または NaN) の行と、変換結果が有限でない行 (0 以下の対数など) は None になる
+pub fn transform(
+    values: &[CellRawValue],
+    transformation: &Transformation,
+    reference: Option<&[CellRawValue]>,
+) -> Result<Vec<CellRawValue>, TransformationError> {
+    let statistics = || DescriptiveStatistics::from_values(values);
+    let apply = |f: &dyn Fn(usize, f64) -> Option<f64>| {
+        values
+            .iter()
+            .enumerate()
+            .map(|(row, value)| {
+                value
+                    .filter(|value| !value.is_nan())
+                    .and_then(|value| f(row, value))
+                    .filter(|value| value.is_finite())
+            })
+            .collect()
+    };
+
+    Ok(match transformation {
+        Transformation::ZScore => {
+            let statistics = statistics();
+            let mean = statistics.mean().ok_or(TransformationError::NoValues)?;
+            let sd = statistics
+                .standard_deviation()
+                .ok_or(TransformationError::InsufficientValues)?;
+            let sd = nonzero(sd)?;
+            apply(&|_, value| Some((value - mean) / sd))
+        }
+        Transformation::MinMax => {
+            let statistics = statistics();
+            let (min, max) = statistics
+                .min()
+                .zip(statistics.max())
+                .ok_or(TransformationError::NoValues)?;
+            let range = nonzero(max - min)?;
+            apply(&|_, value| Some((value - min) / range))
+        }
+        Transformation::NormalizeToMax => {
+            let statistics = statistics();
+            let (min, max) = statistics
+                .min()
+                .zip(statistics.max())
+                .ok_or(TransformationError::NoValues)?;
+            let scale = nonzero(min.abs().max(max.abs()))?;
+            apply(&|_, value| Some(value / scale))
+        }
+        Transformation::NormalizeToArea { x } => {
+            let rows: Vec<CellRawValue>;
+            let x = match x {
+                Some(_) => reference.ok_or(TransformationError::MissingReference)?,
+                None => {
+                    rows = (0..values.len()).map(|row| Some(row as f64)).collect();
+                    &rows
+                }
+            };
+            let area = SampledFunction::new(x, values, GapHandling::Bridge)
+                .map_err(TransformationError::NumericalCalculusError)?
+                .total_integral(IntegrationMethod::Trapezoid);
+            let area = nonzero(area)?;
+            apply(&|_, value| Some(value / area))
+        }
+        Transformation::Log10 => apply(&|_, value| Some(value.log10())),
+        Transformation::Ln => apply(&|_, value| Some(value.ln())),
+        Transformation::Exp => apply(&|_, value| Some(value.exp())),
+        Transformation::Reciprocal => apply(&|_, value| Some(1. / value)),
+        Transformation::Offset { value: offset } => apply(&|_, value| Some(value + offset)),
+        Transformation::Scale { factor } => apply(&|_, value| Some(value * factor)),
+        Transformation::ScaleByColumn { .. } => {
+            let reference = reference.ok_or(TransformationError::MissingReference)?;
+            apply(&|row, value| {
+                reference
+                    .get(row)
+                    .copied()
+                    .flatten()
+                    .map(|factor| value * factor)
+            })
+        }
+    })
+}
+
+// 割る数が 0 (または有限でない) の場合はエラーにする
+fn nonzero(divisor: f64) -> Result<f64, TransformationError> {
+    if divisor == 0. || !divisor.is_finite() {
+        return Err(TransformationError::ZeroDivisor(divisor));
+    }
+    Ok(divisor)
+}
+
+#[derive(Debug, Error)]
+pub enum TransformationError {
+    #[error("no values to transform")]
+    NoValues,
+    #[error("at least 2 values are required")]
+    InsufficientValues,
+    #[error("values cannot be divided by {0}")]
+    ZeroDivisor(f64),
+    #[error("values of the referenced column are not given")]
+    MissingReference,
+    #[error("NumericalCalculusError: [{0}]")]
+    NumericalCalculusError(NumericalCalculusError),
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{models::column::column_id::ColumnId, shared::value_object::ValueObject};
+
+    use super::*;
+
+    fn values(values: &[f64]) -> Vec<CellRawValue> {
+        values.iter().map(|value| Some(*value)).collect()
+    }
+
+    #[test]
+    fn test_normalization() -> anyhow::Result<()> {
+        let data = vec![Some(1.), Some(2.), None, Some(3.), Some(-4.)];
+        // 平均 0.5, 標本標準偏差 √(29 / 3)
+        let sd = (29f64 / 3.).sqrt();
+        let z = transform(&data, &Transformation::ZScore, None)?;
+        assert_eq!(z[2], None);
+        assert!((z[0].unwrap() - 0.5 / sd).abs() < 1e-12);
+        assert!((z[4].unwrap() + 4.5 / sd).abs() < 1e-12);
+
+        assert_eq!(
+            transform(&data, &Transformation::MinMax, None)?,
+            vec![Some(5. / 7.), Some(6. / 7.), None, Some(1.), Some(0.)]
+        );
+        assert_eq!(
+            transform(&data, &Transformation::NormalizeToMax, None)?,
+            vec![Some(0.25), Some(0.5), None, Some(0.75), Some(-1.)]
+        );
+
+        // 面積: 行番号を x とすると 4、x = [0, 1, 3] とすると 2 + 4 = 6
+        let data = values(&[0., 4., 0.]);
+        assert_eq!(
+            transform(&data, &Transformation::NormalizeToArea { x: None }, None)?,
+            vec![Some(0.), Some(1.), Some(0.)]
+        );
+        let x = values(&[0., 1., 3.]);
+        let normalized = transform(
+            &data,
+            &Transformation::NormalizeToArea {
+                x: Some(ColumnId::new("x".to_string())?),
+            },
+            Some(&x),
+        )?;
+        assert!((normalized[1].unwrap() - 4. / 6.).abs() < 1e-12);
+
+        assert!(matches!(
+            transform(&values(&[2., 2.]), &Transformation::MinMax, None),
+            Err(TransformationError::ZeroDivisor(_))
+        ));
+        assert!(matches!(
+            transform(&[Some(1.)], &Transformation::ZScore, None),
+            Err(TransformationError::InsufficientValues)
+        ));
+        assert!(matches!(
+            transform(&[None], &Transformation::NormalizeToMax, None),
+            Err(TransformationError::NoValues)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_elementwise() -> anyhow::Result<()> {
+        let data = vec![Some(100.), Some(0.), Some(-1.), None];
+        assert_eq!(
+            transform(&data, &Transformation::Log10, None)?,
+            vec![Some(2.), None, None, None]
+        );
+        assert_eq!(
+            transform(&data, &Transformation::Reciprocal, None)?,
+            vec![Some(0.01), None, Some(-1.), None]
+        );
+        assert_eq!(
+            transform(&values(&[0., 1.]), &Transformation::Exp, None)?,
+            vec![Some(1.), Some(std::f64::consts::E)]
+        );
+        assert_eq!(
+            transform(&data, &Transformation::Offset { value: 1. }, None)?,
+            vec![Some(101.), Some(1.), Some(0.), None]
+        );
+        assert_eq!(
+            transform(&data, &Transformation::Scale { factor: 2. }, None)?,
+            vec![Some(200.), Some(0.), Some(-2.), None]
+        );
+
+        // 別のカラムの値が足りない行は None
+        let scale = Transformation::ScaleByColumn {
+            column: ColumnId::new("factor".to_string())?,
+        };
+        assert_eq!(
+            transform(&data, &scale, Some(&[Some(0.5), None, Some(3.)]))?,
+            vec![Some(50.), None, Some(-3.), None]
+        );
+        assert!(matches!(
+            transform(&data, &scale, None),
+            Err(TransformationError::MissingReference)
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,307 @@
+// 確率分布の累積分布関数・分位点と特殊関数 (仮説検定や正規確率軸で使う)
+
+// ln Γ(x) (x > 0、Lanczos 近似)
+pub fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+    if x < 0.5 {
+        // 相反公式
+        return (std::f64::consts::PI / (std::f64::consts::PI * x).sin()).ln() - ln_gamma(1. - x);
+    }
+    let x = x - 1.;
+    let t = x + 7.5;
+    let sum = COEFFICIENTS[1..]
+        .iter()
+        .enumerate()
+        .fold(COEFFICIENTS[0], |sum, (i, c)| sum + c / (x + i as f64 + 1.));
+    0.5 * (2. * std::f64::consts::PI).ln() + (x + 0.5) * t.ln() - t + sum.ln()
+}
+
+// 連分数・級数の打ち切りに使う許容誤差と反復回数の上限
+const EPSILON: f64 = 1e-15;
+const MAX_ITERATIONS: usize = 1000;
+
+// 正則化下側不完全ガンマ関数 P(a, x)
+pub fn regularized_lower_gamma(a: f64, x: f64) -> f64 {
+    if x <= 0. {
+        return 0.;
+    }
+    if x < a + 1. {
+        // 級数展開
+        let mut term = 1. / a;
+        let mut sum = term;
+        for n in 1..MAX_ITERATIONS {
+            term *= x / (a + n as f64);
+            sum += term;
+            if term.abs() < sum.abs() * EPSILON {
+                break;
+            }
+        }
+        (sum.ln() - x + a * x.ln() - ln_gamma(a)).exp()
+    } else {
+        1. - regularized_upper_gamma(a, x)
+    }
+}
+
+// 正則化上側不完全ガンマ関数 Q(a, x) = 1 - P(a, x)
+pub fn regularized_upper_gamma(a: f64, x: f64) -> f64 {
+    if x < a + 1. {
+        return 1. - regularized_lower_gamma(a, x);
+    }
+    // 連分数展開 (modified Lentz 法)
+    let tiny = 1e-300;
+    let mut b = x + 1. - a;
+    let mut c = 1. / tiny;
+    let mut d = 1. / b;
+    let mut h = d;
+    for n in 1..MAX_ITERATIONS {
+        let an = -(n as f64) * (n as f64 - a);
+        b += 2.;
+        d = an * d + b;
+        if d.abs() < tiny {
+            d = tiny;
+        }
+        c = b + an / c;
+        if c.abs() < tiny {
+            c = tiny;
+        }
+        d = 1. / d;
+        let delta = d * c;
+        h *= delta;
+        if (delta - 1.).abs() < EPSILON {
+            break;
+        }
+    }
+    (-x + a * x.ln() - ln_gamma(a)).exp() * h
+}
+
+// 正則化不完全ベータ関数 I_x(a, b)
+pub fn regularized_incomplete_beta(a: f64, b: f64, x: f64) -> f64 {
+    if x <= 0. {
+        return 0.;
+    }
+    if x >= 1. {
+        return 1.;
+    }
+    let front =
+        (ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1. - x).ln()).exp();
+    // 連分数が速く収束する側で計算する
+    if x < (a + 1.) / (a + b + 2.) {
+        front * beta_continued_fraction(a, b, x) / a
+    } else {
+        1. - front * beta_continued_fraction(b, a, 1. - x) / b
+    }
+}
+
+fn beta_continued_fraction(a: f64, b: f64, x: f64) -> f64 {
+    let tiny = 1e-300;
+    let mut c = 1.;
+    let mut d = 1. - (a + b) * x / (a + 1.);
+    if d.abs() < tiny {
+        d = tiny;
+    }
+    d = 1. / d;
+    let mut h = d;
+    for m in 1..MAX_ITERATIONS {
+        let m = m as f64;
+        // 偶数項と奇数項
+        for numerator in [
+            m * (b - m) * x / ((a + 2. * m - 1.) * (a + 2. * m)),
+            -(a + m) * (a + b + m) * x / ((a + 2. * m) * (a + 2. * m + 1.)),
+        ] {
+            d = 1. + numerator * d;
+            if d.abs() < tiny {
+                d = tiny;
+            }
+            c = 1. + numerator / c;
+            if c.abs() < tiny {
+                c = tiny;
+            }
+            d = 1. / d;
+            h *= d * c;
+        }
+        if (d * c - 1.).abs() < EPSILON {
+            break;
+        }
+    }
+    h
+}
+
+// 標準正規分布の累積分布関数
+pub fn normal_cdf(z: f64) -> f64 {
+    // erf(x) = P(1/2, x²)
+    let half = 0.5 * regularized_lower_gamma(0.5, z * z / 2.);
+    if z >= 0. {
+        0.5 + half
+    } else {
+        0.5 - half
+    }
+}
+
+// 標準正規分布の分位点 (Acklam の有理関数近似を Halley 法で 1 回補正する)
+pub fn normal_quantile(p: f64) -> f64 {
+    if p <= 0. {
+        return f64::NEG_INFINITY;
+    }
+    if p >= 1. {
+        return f64::INFINITY;
+    }
+    const A: [f64; 6] = [
+        -3.969_683_028_665_376e1,
+        2.209_460_984_245_205e2,
+        -2.759_285_104_469_687e2,
+        1.383_577_518_672_69e2,
+        -3.066_479_806_614_716e1,
+        2.506_628_277_459_239,
+    ];
+    const B: [f64; 5] = [
+        -5.447_609_879_822_406e1,
+        1.615_858_368_580_409e2,
+        -1.556_989_798_598_866e2,
+        6.680_131_188_771_972e1,
+        -1.328_068_155_288_572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784_894_002_430_293e-3,
+        -3.223_964_580_411_365e-1,
+        -2.400_758_277_161_838,
+        -2.549_732_539_343_734,
+        4.374_664_141_464_968,
+        2.938_163_982_698_783,
+    ];
+    const D: [f64; 4] = [
+        7.784_695_709_041_462e-3,
+        3.224_671_290_700_398e-1,
+        2.445_134_137_142_996,
+        3.754_408_661_907_416,
+    ];
+    let tail = |q: f64| {
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.)
+    };
+    let x = if p < 0.024_25 {
+        tail((-2. * p.ln()).sqrt())
+    } else if p > 1. - 0.024_25 {
+        -tail((-2. * (1. - p).ln()).sqrt())
+    } else {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.)
+    };
+    let error = normal_cdf(x) - p;
+    let u = error * (2. * std::f64::consts::PI).sqrt() * (x * x / 2.).exp();
+    x - u / (1. + x * u / 2.)
+}
+
+// 自由度 df の t 分布の累積分布関数 (df は実数でもよい)
+pub fn student_t_cdf(t: f64, df: f64) -> f64 {
+    let tail = 0.5 * regularized_incomplete_beta(df / 2., 0.5, df / (df + t * t));
+    if t > 0. {
+        1. - tail
+    } else {
+        tail
+    }
+}
+
+// t 分布の分位点 (二分法)
+pub fn student_t_quantile(p: f64, df: f64) -> f64 {
+    let (mut low, mut high) = (-1., 1.);
+    while student_t_cdf(low, df) > p {
+        low *= 2.;
+    }
+    while student_t_cdf(high, df) < p {
+        high *= 2.;
+    }
+    for _ in 0..200 {
+        let middle = (low + high) / 2.;
+        if student_t_cdf(middle, df) < p {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    (low + high) / 2.
+}
+
+// 自由度 (d1, d2) の F 分布の上側確率
+pub fn f_survival(f: f64, d1: f64, d2: f64) -> f64 {
+    if f <= 0. {
+        return 1.;
+    }
+    regularized_incomplete_beta(d2 / 2., d1 / 2., d2 / (d2 + d1 * f))
+}
+
+// 自由度 k の χ² 分布の上側確率
+pub fn chi_square_survival(x: f64, k: f64) -> f64 {
+    if x <= 0. {
+        return 1.;
+    }
+    regularized_upper_gamma(k / 2., x / 2.)
+}
+
+// コルモゴロフ分布の上側確率 Q(λ) = 2 Σ (-1)^(k-1) exp(-2 k² λ²)
+pub fn kolmogorov_survival(lambda: f64) -> f64 {
+    if lambda < 0.2 {
+        return 1.;
+    }
+    let sum: f64 = (1..=100)
+        .map(|k| {
+            let sign = if k % 2 == 1 { 1. } else { -1. };
+            sign * (-2. * (k * k) as f64 * lambda * lambda).exp()
+        })
+        .sum();
+    (2. * sum).clamp(0., 1.)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual - expected).abs() < tolerance,
+            "actual: {}, expected: {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_special_functions() {
+        // Γ(5) = 24, Γ(1/2) = √π
+        assert_close(ln_gamma(5.), 24f64.ln(), 1e-12);
+        assert_close(ln_gamma(0.5), std::f64::consts::PI.sqrt().ln(), 1e-12);
+        // P(1, x) = 1 - e^-x
+        assert_close(regularized_lower_gamma(1., 2.), 1. - (-2f64).exp(), 1e-12);
+        assert_close(regularized_upper_gamma(1., 0.5), (-0.5f64).exp(), 1e-12);
+        // I_x(1, 1) = x, I_x(a, 1) = x^a
+        assert_close(regularized_incomplete_beta(1., 1., 0.3), 0.3, 1e-12);
+        assert_close(regularized_incomplete_beta(3., 1., 0.7), 0.343, 1e-12);
+    }
+
+    #[test]
+    fn test_distributions() {
+        // 数表の値
+        assert_close(normal_cdf(1.959_964), 0.975, 1e-6);
+        assert_close(normal_cdf(-1.), 0.158_655_25, 1e-8);
+        assert_close(normal_quantile(0.975), 1.959_964, 1e-6);
+        assert_close(normal_quantile(0.001), -3.090_232, 1e-6);
+        assert_close(student_t_quantile(0.975, 10.), 2.228_139, 1e-6);
+        assert_close(student_t_cdf(-2.228_139, 10.), 0.025, 1e-6);
+        assert_close(f_survival(4.102_821, 2., 10.), 0.05, 1e-6);
+        assert_close(chi_square_survival(3.841_459, 1.), 0.05, 1e-6);
+        // 自由度 2 の χ² 分布の上側確率は exp(-x / 2)
+        assert_close(chi_square_survival(5., 2.), (-2.5f64).exp(), 1e-12);
+        assert_close(kolmogorov_survival(1.358_099), 0.05, 1e-6);
+    }
+}
@@ -0,0 +1,3 @@
+// 一度の操作で作成できるセルの数の上限
+// 行の挿入・ヒストグラムのビン・値の生成など、入力の数値で作成するセルの数が決まる操作で共通
+pub const MAX_CREATED_CELLS: usize = 100_000;
@@ -1,3 +1,5 @@
 pub mod entity;
 pub mod value_object;
-pub mod specification;
\ No newline at end of file
+pub mod specification;
+pub mod limits;
+pub mod distributions;
\ No newline at end of file
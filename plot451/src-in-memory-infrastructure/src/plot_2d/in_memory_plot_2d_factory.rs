@@ -0,0 +1,29 @@
+use src_domain::models::plot_2d::{
+    plot_2d::Plot2D,
+    plot_2d_axis::Plot2DAxis,
+    plot_2d_factory::{IPlot2DFactory, Plot2DFactoryResult},
+    plot_2d_series::Plot2DSeries,
+    plot_2d_title::Plot2DTitle,
+};
+
+#[derive(Default)]
+pub struct InMemoryPlot2DFactory {}
+
+impl InMemoryPlot2DFactory {
+    pub fn new() -> Self {
+        InMemoryPlot2DFactory {}
+    }
+}
+
+impl IPlot2DFactory for InMemoryPlot2DFactory {
+    async fn create_plot_2d(
+        &self,
+        title: Plot2DTitle,
+        series: Vec<Plot2DSeries>,
+        x_axis: Plot2DAxis,
+        y_axis: Plot2DAxis,
+    ) -> Plot2DFactoryResult<Plot2D> {
+        let plot = Plot2D::new(None, title, series, x_axis, y_axis);
+        Ok(plot)
+    }
+}
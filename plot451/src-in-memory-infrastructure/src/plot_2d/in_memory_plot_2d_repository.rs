@@ -0,0 +1,211 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+
+use src_domain::{
+    models::{
+        column::column_id::ColumnId,
+        plot_2d::{
+            plot_2d::Plot2D,
+            plot_2d_id::Plot2DId,
+            plot_2d_repository::{IPlot2DRepository, Plot2DRepositoryResult},
+        },
+    },
+    shared::value_object::ValueObject,
+};
+
+#[derive(Default)]
+struct Store {
+    current_id: u64,
+    plot_store: HashMap<Plot2DId, Plot2D>,
+}
+
+#[derive(Default)]
+pub struct InMemoryPlot2DRepository {
+    data: Arc<RwLock<Store>>,
+}
+
+impl InMemoryPlot2DRepository {
+    pub fn new() -> Self {
+        Self {
+            data: Arc::default(),
+        }
+    }
+
+    fn next_plot_id(store: &mut RwLockWriteGuard<Store>) -> Plot2DId {
+        store.current_id += 1;
+        Plot2DId::new(store.current_id.to_string()).unwrap()
+    }
+
+    fn write_store_ref(&self) -> RwLockWriteGuard<'_, Store> {
+        self.data.write().unwrap()
+    }
+
+    fn read_store_ref(&self) -> RwLockReadGuard<'_, Store> {
+        self.data.read().unwrap()
+    }
+}
+
+impl IPlot2DRepository for InMemoryPlot2DRepository {
+    async fn save(&self, plot: &Plot2D) -> Plot2DRepositoryResult<Plot2DId> {
+        let mut plot = plot.clone();
+        let mut store = self.write_store_ref();
+        let id = match plot.id_wrapped() {
+            Some(id) => id.clone(),
+            None => {
+                let id = Self::next_plot_id(&mut store);
+                plot.set_id(id.clone());
+                id
+            }
+        };
+        store.plot_store.insert(id.clone(), plot);
+        Ok(id)
+    }
+
+    async fn find(&self, id: &Plot2DId) -> Plot2DRepositoryResult<Option<Plot2D>> {
+        let store = self.read_store_ref();
+        Ok(store.plot_store.get(id).cloned())
+    }
+
+    async fn find_by_column_id(&self, column_id: &ColumnId) -> Plot2DRepositoryResult<Vec<Plot2D>> {
+        let store = self.read_store_ref();
+        // 指定されたカラムを系列で参照しているグラフを探す
+        let found_plots = store
+            .plot_store
+            .values()
+            .filter(|plot| plot.columns().contains(column_id))
+            .cloned()
+            .collect();
+        Ok(found_plots)
+    }
+
+    async fn find_all(&self) -> Plot2DRepositoryResult<Vec<Plot2D>> {
+        let store = self.read_store_ref();
+        let plots_found = store.plot_store.values().cloned().collect();
+        Ok(plots_found)
+    }
+
+    async fn delete(&self, plot: Plot2D) -> Plot2DRepositoryResult<()> {
+        let mut store = self.write_store_ref();
+        store.plot_store.remove(plot.id());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use src_domain::models::plot_2d::{
+        plot_2d_axis::{AxisRange, AxisScale, Plot2DAxis},
+        plot_2d_series::Plot2DSeries,
+        plot_2d_series_style::SeriesStyle,
+        plot_2d_title::Plot2DTitle,
+    };
+
+    use super::*;
+
+    fn plot(id: Option<&str>, series: &[(&str, &str)]) -> anyhow::Result<Plot2D> {
+        let id = id.map(|id| Plot2DId::new(id.to_string())).transpose()?;
+        let series = series
+            .iter()
+            .map(|(x, y)| {
+                Ok(Plot2DSeries::new(
+                    ColumnId::new(x.to_string())?,
+                    ColumnId::new(y.to_string())?,
+                    String::new(),
+                    SeriesStyle::default(),
+                ))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        Ok(Plot2D::new(
+            id,
+            Plot2DTitle::new("test_plot".to_string())?,
+            series,
+            Plot2DAxis::default(),
+            Plot2DAxis::new(
+                "intensity".to_string(),
+                AxisScale::Log10,
+                Some(AxisRange::new(1., 100.)?),
+                false,
+            )?,
+        ))
+    }
+
+    #[tokio::test]
+    async fn test_save_and_find() -> anyhow::Result<()> {
+        let repository = InMemoryPlot2DRepository::new();
+
+        // id がない場合は採番して保存する
+        let mut new_plot = plot(None, &[("1", "2")])?;
+        let plot_id = repository.save(&new_plot).await?;
+        new_plot.set_id(plot_id.clone());
+        let found_plot = repository.find(&plot_id).await?.unwrap();
+        assert_eq!(found_plot, new_plot);
+        assert_eq!(found_plot.series(), new_plot.series());
+        assert_eq!(found_plot.y_axis(), new_plot.y_axis());
+
+        // id がある場合は上書きする
+        let mut updated_plot = found_plot.clone();
+        updated_plot.change_title(Plot2DTitle::new("updated".to_string())?);
+        assert_eq!(repository.save(&updated_plot).await?, plot_id);
+        let found_plot = repository.find(&plot_id).await?.unwrap();
+        assert_eq!(found_plot.title().value(), "updated");
+
+        assert_eq!(
+            repository.find(&Plot2DId::new("2".to_string())?).await?,
+            None
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_find_by_column_id() -> anyhow::Result<()> {
+        let repository = InMemoryPlot2DRepository::new();
+
+        // ストア内に保存するデータの作成
+        let plot1 = plot(Some("1"), &[("1", "2")])?;
+        let plot2 = plot(Some("2"), &[("1", "3"), ("4", "5")])?;
+        {
+            let mut store = repository.write_store_ref();
+            store.plot_store.insert(plot1.id().clone(), plot1.clone());
+            store.plot_store.insert(plot2.id().clone(), plot2.clone());
+        }
+
+        let found_plots = repository
+            .find_by_column_id(&ColumnId::new("1".to_string())?)
+            .await?;
+        assert_eq!(
+            HashSet::from_iter(found_plots.iter().cloned()),
+            HashSet::from([plot1.clone(), plot2.clone()])
+        );
+        let found_plots = repository
+            .find_by_column_id(&ColumnId::new("5".to_string())?)
+            .await?;
+        assert_eq!(found_plots, vec![plot2.clone()]);
+        let found_plots = repository
+            .find_by_column_id(&ColumnId::new("6".to_string())?)
+            .await?;
+        assert_eq!(found_plots, vec![]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_find_all_and_delete() -> anyhow::Result<()> {
+        let repository = InMemoryPlot2DRepository::new();
+        let plot1 = plot(Some("1"), &[("1", "2")])?;
+        let plot2 = plot(Some("2"), &[])?;
+        repository.save(&plot1).await?;
+        repository.save(&plot2).await?;
+        assert_eq!(
+            HashSet::from_iter(repository.find_all().await?.iter().cloned()),
+            HashSet::from([plot1.clone(), plot2.clone()])
+        );
+
+        // delete メソッドのテスト
+        repository.delete(plot1.clone()).await?;
+        assert_eq!(repository.find_all().await?, vec![plot2]);
+        Ok(())
+    }
+}
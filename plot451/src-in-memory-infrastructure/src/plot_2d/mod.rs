@@ -0,0 +1,2 @@
+pub mod in_memory_plot_2d_factory;
+pub mod in_memory_plot_2d_repository;